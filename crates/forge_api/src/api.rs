@@ -6,6 +6,7 @@ use forge_app::{User, UserUsage};
 use forge_domain::{AgentId, Effort, ModelId, ProviderModels};
 use forge_stream::MpscStream;
 use futures::stream::BoxStream;
+use tokio_util::sync::CancellationToken;
 use url::Url;
 
 use crate::*;
@@ -37,14 +38,24 @@ pub trait API: Sync + Send {
     /// configured provider or model
     async fn get_agent_infos(&self) -> Result<Vec<AgentInfo>>;
 
+    /// Watches custom agent files and reloads them when they change, yielding
+    /// the ID of each reloaded agent
+    async fn watch_agents(&self) -> Result<MpscStream<AgentId>>;
+
     /// Provides a list of providers available in the current environment
     async fn get_providers(&self) -> Result<Vec<AnyProvider>>;
 
     /// Gets a provider by ID
     async fn get_provider(&self, id: &ProviderId) -> Result<AnyProvider>;
 
-    /// Executes a chat request and returns a stream of responses
-    async fn chat(&self, chat: ChatRequest) -> Result<MpscStream<Result<ChatResponse>>>;
+    /// Executes a chat request and returns a stream of responses. Cancelling
+    /// `cancel` stops the turn and saves the conversation as it stands before
+    /// the stream ends.
+    async fn chat(
+        &self,
+        chat: ChatRequest,
+        cancel: CancellationToken,
+    ) -> Result<MpscStream<Result<ChatResponse>>>;
 
     /// Commits changes with an AI-generated commit message
     async fn commit(
@@ -53,6 +64,38 @@ pub trait API: Sync + Send {
         max_diff_size: Option<usize>,
         diff: Option<String>,
         additional_context: Option<String>,
+        format: Option<forge_config::CommitFormat>,
+        sign: bool,
+    ) -> Result<forge_app::CommitResult>;
+
+    /// Renders the system prompt the given agent would start a new
+    /// conversation with
+    async fn get_agent_system_prompt(&self, agent_id: AgentId) -> Result<String>;
+
+    /// Validates tool call arguments against the tool's input schema without
+    /// executing it. Returns every problem found, or an empty list when the
+    /// arguments are valid.
+    async fn validate_tool_arguments(
+        &self,
+        tool_name: &ToolName,
+        arguments: &ToolCallArguments,
+    ) -> Result<Vec<ValidationError>>;
+
+    /// Executes a single tool as the active agent without going through the
+    /// agent loop, streaming the text of its output
+    async fn stream_tool_call(
+        &self,
+        tool_name: ToolName,
+        arguments: ToolCallArguments,
+    ) -> Result<MpscStream<Result<String>>>;
+
+    /// Generates a commit message for the current changes without committing
+    /// them, using the configured commit format. The returned result always
+    /// has `committed` set to false.
+    async fn generate_commit_message(
+        &self,
+        max_diff_size: Option<usize>,
+        diff: Option<String>,
     ) -> Result<forge_app::CommitResult>;
 
     /// Returns the current environment
@@ -101,6 +144,16 @@ pub trait API: Sync + Send {
         conversation_id: &ConversationId,
     ) -> Result<CompactionResult>;
 
+    /// Restores the files modified during the last turn of the given
+    /// conversation to their content before that turn, and returns the
+    /// restored paths. With `dry_run` only the paths that would be restored
+    /// are returned.
+    async fn rollback(
+        &self,
+        conversation_id: &ConversationId,
+        dry_run: bool,
+    ) -> Result<Vec<PathBuf>>;
+
     /// Executes a shell command using the shell tool infrastructure
     async fn execute_shell_command(
         &self,
@@ -207,6 +260,10 @@ pub trait API: Sync + Send {
     /// Remove provider credentials (logout)
     async fn remove_provider(&self, provider_id: &ProviderId) -> Result<()>;
 
+    /// Lists the aliases of the credentials stored for a provider, in the
+    /// order they were added. Unaliased credentials are not included.
+    async fn get_provider_aliases(&self, provider_id: &ProviderId) -> Result<Vec<String>>;
+
     /// Sync a workspace directory for semantic search
     async fn sync_workspace(
         &self,
@@ -257,6 +314,13 @@ pub trait API: Sync + Send {
         data_parameters: DataGenerationParameters,
     ) -> Result<BoxStream<'static, Result<serde_json::Value, anyhow::Error>>>;
 
+    /// Submits all `requests` as a single batch to the session provider and
+    /// returns the batch ID
+    async fn submit_batch(&self, requests: Vec<forge_domain::BatchRequest>) -> Result<String>;
+
+    /// Returns the processing status of a previously submitted batch
+    async fn poll_batch(&self, batch_id: &str) -> Result<forge_domain::BatchStatus>;
+
     /// Authenticate with an MCP server via OAuth flow
     async fn mcp_auth(&self, server_url: &str) -> Result<()>;
 
@@ -7,7 +7,7 @@ use forge_app::dto::ToolsOverview;
 use forge_app::{
     AgentProviderResolver, AgentRegistry, AppConfigService, AuthService, CommandInfra,
     CommandLoaderService, ConversationService, DataGenerationApp, EnvironmentInfra,
-    FileDiscoveryService, ForgeApp, GitApp, GrpcInfra, McpConfigManager, McpService,
+    FileDiscoveryService, ForgeApp, FsUndoService, GitApp, GrpcInfra, McpConfigManager, McpService,
     ProviderAuthService, ProviderService, Services, User, UserUsage, Walker, WorkspaceService,
 };
 use forge_config::ForgeConfig;
@@ -17,6 +17,7 @@ use forge_repo::ForgeRepo;
 use forge_services::ForgeServices;
 use forge_stream::MpscStream;
 use futures::stream::BoxStream;
+use tokio_util::sync::CancellationToken;
 use url::Url;
 
 use crate::API;
@@ -41,6 +42,20 @@ impl<A, F> ForgeAPI<A, F> {
     }
 }
 
+impl<A: Services, F> ForgeAPI<A, F> {
+    /// Resolves the session provider, with refreshed credentials, and model.
+    async fn session_provider_and_model(&self) -> Result<(Provider<Url>, ModelId)> {
+        let model_config = self
+            .services
+            .get_session_config()
+            .await
+            .ok_or(Error::NoDefaultSession)?;
+        let provider = self.services.get_provider(model_config.provider).await?;
+        let provider = self.services.refresh_provider_credential(provider).await?;
+        Ok((provider, model_config.model))
+    }
+}
+
 impl ForgeAPI<ForgeServices<ForgeRepo<ForgeInfra>>, ForgeRepo<ForgeInfra>> {
     /// Creates a fully-initialized [`ForgeAPI`] from a pre-read configuration.
     ///
@@ -67,6 +82,7 @@ impl<
     F: CommandInfra
         + EnvironmentInfra<Config = forge_config::ForgeConfig>
         + SkillRepository
+        + BatchRepository
         + GrpcInfra,
 > API for ForgeAPI<A, F>
 {
@@ -96,6 +112,10 @@ impl<
         self.services.get_agent_infos().await
     }
 
+    async fn watch_agents(&self) -> Result<MpscStream<AgentId>> {
+        self.services.watch_agents().await
+    }
+
     async fn get_providers(&self) -> Result<Vec<AnyProvider>> {
         Ok(self.services.get_all_providers().await?)
     }
@@ -106,27 +126,75 @@ impl<
         max_diff_size: Option<usize>,
         diff: Option<String>,
         additional_context: Option<String>,
+        format: Option<forge_config::CommitFormat>,
+        sign: bool,
     ) -> Result<forge_app::CommitResult> {
-        let use_forge_committer = self
+        let config = self
             .services
             .get_config()
-            .context("Failed to read forge config for commit settings")?
-            .use_forge_committer;
+            .context("Failed to read forge config for commit settings")?;
+        let format = format.or(config.commit_format).unwrap_or_default();
 
         let git_app = GitApp::new(self.services.clone());
         let result = git_app
-            .commit_message(max_diff_size, diff, additional_context)
+            .commit_message(max_diff_size, diff, additional_context, format)
             .await?;
 
         if preview {
             Ok(result)
         } else {
             git_app
-                .commit(result.message, result.has_staged_files, use_forge_committer)
+                .commit(
+                    result.message,
+                    result.has_staged_files,
+                    config.use_forge_committer,
+                    sign,
+                )
                 .await
         }
     }
 
+    async fn get_agent_system_prompt(&self, agent_id: AgentId) -> Result<String> {
+        self.app().get_agent_system_prompt(agent_id).await
+    }
+
+    async fn validate_tool_arguments(
+        &self,
+        tool_name: &ToolName,
+        arguments: &ToolCallArguments,
+    ) -> Result<Vec<ValidationError>> {
+        let definitions: Vec<ToolDefinition> = self.app().list_tools().await?.into();
+        let definition = definitions
+            .into_iter()
+            .find(|definition| &definition.name == tool_name)
+            .ok_or_else(|| forge_app::Error::NotFound(tool_name.clone()))?;
+        Ok(definition.validate_arguments(&arguments.parse()?))
+    }
+
+    async fn stream_tool_call(
+        &self,
+        tool_name: ToolName,
+        arguments: ToolCallArguments,
+    ) -> Result<MpscStream<Result<String>>> {
+        let agent_id = self
+            .services
+            .get_active_agent_id()
+            .await?
+            .unwrap_or_default();
+        self.app()
+            .stream_tool_call(agent_id, tool_name, arguments)
+            .await
+    }
+
+    async fn generate_commit_message(
+        &self,
+        max_diff_size: Option<usize>,
+        diff: Option<String>,
+    ) -> Result<forge_app::CommitResult> {
+        self.commit(true, max_diff_size, diff, None, None, false)
+            .await
+    }
+
     async fn get_provider(&self, id: &ProviderId) -> Result<AnyProvider> {
         let providers = self.services.get_all_providers().await?;
         Ok(providers
@@ -138,13 +206,14 @@ impl<
     async fn chat(
         &self,
         chat: ChatRequest,
+        cancel: CancellationToken,
     ) -> anyhow::Result<MpscStream<Result<ChatResponse, anyhow::Error>>> {
         let agent_id = self
             .services
             .get_active_agent_id()
             .await?
             .unwrap_or_default();
-        self.app().chat(agent_id, chat).await
+        self.app().chat(agent_id, chat, cancel).await
     }
 
     async fn upsert_conversation(&self, conversation: Conversation) -> anyhow::Result<()> {
@@ -165,6 +234,32 @@ impl<
             .await
     }
 
+    async fn rollback(
+        &self,
+        conversation_id: &ConversationId,
+        dry_run: bool,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        let conversation = self
+            .services
+            .find_conversation(conversation_id)
+            .await?
+            .ok_or_else(|| Error::ConversationNotFound(*conversation_id))?;
+        let turn_started_at = conversation
+            .metrics
+            .started_at
+            .context("Conversation has no turn to roll back")?;
+        let file_paths = conversation
+            .metrics
+            .file_operations
+            .keys()
+            .map(PathBuf::from)
+            .collect();
+
+        self.services
+            .rollback(file_paths, turn_started_at, dry_run)
+            .await
+    }
+
     fn environment(&self) -> Environment {
         self.services.get_environment().clone()
     }
@@ -350,6 +445,16 @@ impl<
         self.services.remove_credential(provider_id).await
     }
 
+    async fn get_provider_aliases(&self, provider_id: &ProviderId) -> Result<Vec<String>> {
+        Ok(self
+            .services
+            .get_credentials(provider_id)
+            .await?
+            .into_iter()
+            .filter_map(|credential| credential.alias)
+            .collect())
+    }
+
     async fn sync_workspace(
         &self,
         path: PathBuf,
@@ -408,6 +513,16 @@ impl<
         app.execute(data_parameters).await
     }
 
+    async fn submit_batch(&self, requests: Vec<BatchRequest>) -> Result<String> {
+        let (provider, model) = self.session_provider_and_model().await?;
+        self.infra.submit_batch(&model, requests, provider).await
+    }
+
+    async fn poll_batch(&self, batch_id: &str) -> Result<BatchStatus> {
+        let (provider, _) = self.session_provider_and_model().await?;
+        self.infra.poll_batch(batch_id, provider).await
+    }
+
     async fn get_session_config(&self) -> Option<forge_domain::ModelConfig> {
         self.services.get_session_config().await
     }
@@ -462,3 +577,433 @@ impl<A: Send + Sync, F: ConsoleWriter> ConsoleWriter for ForgeAPI<A, F> {
         self.infra.flush_err()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use forge_config::ConfigReader;
+    use futures::StreamExt;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /// Builds an API over the real services, keeping the working directory
+    /// and all Forge data inside `dir`. The session uses the `mock` provider,
+    /// whose OpenAI compatible chat endpoint is served at `url`.
+    async fn fixture(
+        dir: &TempDir,
+        url: &str,
+    ) -> ForgeAPI<ForgeServices<ForgeRepo<ForgeInfra>>, ForgeRepo<ForgeInfra>> {
+        fixture_with_infra(infra_fixture(dir, url)).await
+    }
+
+    fn infra_fixture(dir: &TempDir, url: &str) -> ForgeInfra {
+        ForgeInfra::new(dir.path().to_path_buf(), config(url))
+            .with_base_path(dir.path().join(".forge"))
+    }
+
+    async fn fixture_with_infra(
+        infra: ForgeInfra,
+    ) -> ForgeAPI<ForgeServices<ForgeRepo<ForgeInfra>>, ForgeRepo<ForgeInfra>> {
+        let repo = Arc::new(ForgeRepo::new(Arc::new(infra)));
+        repo.upsert_credential(AuthCredential::new_api_key(
+            ProviderId::from("mock".to_string()),
+            ApiKey::from("test-key".to_string()),
+        ))
+        .await
+        .unwrap();
+        ForgeAPI::new(Arc::new(ForgeServices::new(repo.clone())), repo)
+    }
+
+    /// Streamed OpenAI chat completion whose single chunk carries `content`
+    fn completion(content: &str) -> String {
+        let chunk = serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion.chunk",
+            "created": 0,
+            "model": "mock-model",
+            "choices": [{
+                "index": 0,
+                "delta": { "role": "assistant", "content": content },
+                "finish_reason": "stop"
+            }]
+        });
+        format!("data: {chunk}\n\ndata: [DONE]\n\n")
+    }
+
+    fn config(url: &str) -> ForgeConfig {
+        let fixture = format!(
+            r#"
+[session]
+provider_id = "mock"
+model_id = "mock-model"
+
+[[providers]]
+id = "mock"
+url = "{url}/chat/completions"
+response_type = "OpenAI"
+
+[[providers.models]]
+id = "mock-model"
+tools_supported = true
+"#
+        );
+        ConfigReader::default()
+            .read_defaults()
+            .read_toml(&fixture)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_stream_tool_call_streams_output_before_result() {
+        let dir = TempDir::new().unwrap();
+        let api = fixture(&dir, "http://127.0.0.1:1").await;
+        let arguments = ToolCallArguments::from_json(
+            r#"{"todos": [{"content": "Write tests", "status": "pending"}]}"#,
+        );
+
+        let actual = api
+            .stream_tool_call(ToolName::new("todo_write"), arguments)
+            .await
+            .unwrap()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+
+        let occurrences = actual
+            .iter()
+            .map(|text| text.matches("Write tests").count())
+            .sum::<usize>();
+        assert_eq!(occurrences, 1, "{actual:?}");
+    }
+
+    #[tokio::test]
+    async fn test_read_tool_reads_s3_object() {
+        use aws_sdk_s3::config::{Credentials, Region};
+
+        let fixture_body = "first line from s3\nsecond line from s3\n";
+        let mut server = mockito::Server::new_async().await;
+        let _head = server
+            .mock("HEAD", "/bucket/docs/notes.txt")
+            .with_status(200)
+            .with_header("content-length", &fixture_body.len().to_string())
+            .create_async()
+            .await;
+        let mock = server
+            .mock("GET", "/bucket/docs/notes.txt")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_body(fixture_body)
+            .expect_at_least(1)
+            .create_async()
+            .await;
+        let s3_config = aws_sdk_s3::Config::builder()
+            .region(Region::new("us-east-1"))
+            .endpoint_url(server.url())
+            .force_path_style(true)
+            .credentials_provider(Credentials::new("key", "secret", None, None, "test"))
+            .build();
+        let dir = TempDir::new().unwrap();
+        let infra = infra_fixture(&dir, "http://127.0.0.1:1").with_s3_file_system(
+            forge_infra::S3FileSystem::with_client(aws_sdk_s3::Client::from_conf(s3_config)),
+        );
+        let api = fixture_with_infra(infra).await;
+        let arguments =
+            ToolCallArguments::from_json(r#"{"file_path": "s3://bucket/docs/notes.txt"}"#);
+
+        let actual = api
+            .stream_tool_call(ToolName::new("read"), arguments)
+            .await
+            .unwrap()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await
+            .concat();
+
+        mock.assert_async().await;
+        assert!(actual.contains("first line from s3"), "{actual}");
+        assert!(actual.contains("second line from s3"), "{actual}");
+    }
+
+    #[tokio::test]
+    async fn test_get_agent_system_prompt_renders_agent_tools() {
+        let dir = TempDir::new().unwrap();
+        let api = fixture(&dir, "http://127.0.0.1:1").await;
+
+        let actual = api
+            .get_agent_system_prompt(AgentId::new("forge"))
+            .await
+            .unwrap();
+
+        assert!(actual.contains("todo_write"), "{actual}");
+        assert!(
+            actual.contains(&dir.path().display().to_string()),
+            "{actual}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_commit_message_sends_prompt_and_diff() {
+        let mut server = mockito::Server::new_async().await;
+        let fixture_diff = "diff --git a/greet.rs b/greet.rs\n+greet_the_world";
+        let content = serde_json::json!({ "commit_message": "feat: add greet function" });
+        let mock = server
+            .mock("POST", "/chat/completions")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("commit message generator".to_string()),
+                mockito::Matcher::Regex("greet_the_world".to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "text/event-stream")
+            .with_body(completion(&content.to_string()))
+            .create_async()
+            .await;
+        let dir = TempDir::new().unwrap();
+        let api = fixture(&dir, &server.url()).await;
+
+        let actual = api
+            .generate_commit_message(None, Some(fixture_diff.to_string()))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(actual.message, "feat: add greet function");
+        assert!(!actual.committed);
+    }
+
+    #[tokio::test]
+    async fn test_commit_conventional_rejects_non_conventional_message() {
+        let mut server = mockito::Server::new_async().await;
+        let fixture_diff = "diff --git a/greet.rs b/greet.rs\n+greet_the_world";
+        let content = serde_json::json!({ "commit_message": "Add greet function" });
+        let mock = server
+            .mock("POST", "/chat/completions")
+            .match_body(mockito::Matcher::Regex(
+                "Conventional Commits required".to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "text/event-stream")
+            .with_body(completion(&content.to_string()))
+            .create_async()
+            .await;
+        let dir = TempDir::new().unwrap();
+        let api = fixture(&dir, &server.url()).await;
+
+        let actual = api
+            .commit(
+                false,
+                None,
+                Some(fixture_diff.to_string()),
+                None,
+                Some(forge_config::CommitFormat::Conventional),
+                false,
+            )
+            .await
+            .unwrap_err()
+            .downcast::<forge_app::GitAppError>()
+            .unwrap();
+
+        mock.assert_async().await;
+        assert!(matches!(
+            actual,
+            forge_app::GitAppError::NonConventionalMessage(message) if message == "Add greet function"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_chat_persists_request_metadata_on_conversation() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/chat/completions")
+            .with_status(200)
+            .with_header("content-type", "text/event-stream")
+            .with_body(completion("Hello!"))
+            .create_async()
+            .await;
+        let dir = TempDir::new().unwrap();
+        let api = fixture(&dir, &server.url()).await;
+        let conversation = Conversation::generate();
+        let conversation_id = conversation.id;
+        api.upsert_conversation(conversation).await.unwrap();
+        let request = ChatRequest::new(Event::new("Say hello"), conversation_id)
+            .with_metadata("trace_id", "trace-123");
+
+        api.chat(request, CancellationToken::new())
+            .await
+            .unwrap()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+        let actual = api
+            .conversation(&conversation_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .metadata
+            .request_metadata;
+
+        let expected = std::collections::HashMap::from([(
+            "trace_id".to_string(),
+            serde_json::json!("trace-123"),
+        )]);
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_chat_max_requests_overrides_agent_limit() {
+        let mut server = mockito::Server::new_async().await;
+        let chunk = serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion.chunk",
+            "created": 0,
+            "model": "mock-model",
+            "choices": [{
+                "index": 0,
+                "delta": {
+                    "role": "assistant",
+                    "tool_calls": [{
+                        "index": 0,
+                        "id": "call_1",
+                        "type": "function",
+                        "function": {
+                            "name": "todo_write",
+                            "arguments": r#"{"todos": [{"content": "Loop", "status": "completed"}]}"#
+                        }
+                    }]
+                },
+                "finish_reason": "tool_calls"
+            }]
+        });
+        server
+            .mock("POST", "/chat/completions")
+            .with_status(200)
+            .with_header("content-type", "text/event-stream")
+            .with_body(format!("data: {chunk}\n\ndata: [DONE]\n\n"))
+            .create_async()
+            .await;
+        let dir = TempDir::new().unwrap();
+        let api = fixture(&dir, &server.url()).await;
+        let conversation = Conversation::generate();
+        let conversation_id = conversation.id;
+        api.upsert_conversation(conversation).await.unwrap();
+        let request =
+            ChatRequest::new(Event::new("Keep going"), conversation_id).max_requests(2usize);
+
+        let actual = api
+            .chat(request, CancellationToken::new())
+            .await
+            .unwrap()
+            .filter_map(|response| async move {
+                match response.unwrap() {
+                    // Stands in for the UI, which acknowledges each tool
+                    // header before the tool runs
+                    ChatResponse::ToolCallStart { notifier, .. } => {
+                        notifier.notify_one();
+                        None
+                    }
+                    ChatResponse::Interrupt { reason } => Some(reason),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+        let expected = vec![InterruptionReason::MaxRequestPerTurnLimitReached { limit: 2 }];
+        assert_eq!(actual, expected);
+
+        let actual = api
+            .conversation(&conversation_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .context
+            .unwrap()
+            .messages
+            .iter()
+            .filter(|message| message.has_role(Role::Assistant))
+            .count();
+        assert_eq!(actual, 2);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_chat_saves_partial_turn() {
+        let mut server = mockito::Server::new_async().await;
+        let chunk = serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion.chunk",
+            "created": 0,
+            "model": "mock-model",
+            "choices": [{
+                "index": 0,
+                "delta": {
+                    "role": "assistant",
+                    "tool_calls": [{
+                        "index": 0,
+                        "id": "call_1",
+                        "type": "function",
+                        "function": {
+                            "name": "todo_write",
+                            "arguments": r#"{"todos": [{"content": "Loop", "status": "completed"}]}"#
+                        }
+                    }]
+                },
+                "finish_reason": "tool_calls"
+            }]
+        });
+        server
+            .mock("POST", "/chat/completions")
+            .with_status(200)
+            .with_header("content-type", "text/event-stream")
+            .with_body(format!("data: {chunk}\n\ndata: [DONE]\n\n"))
+            .create_async()
+            .await;
+        let dir = TempDir::new().unwrap();
+        let api = fixture(&dir, &server.url()).await;
+        let conversation = Conversation::generate();
+        let conversation_id = conversation.id;
+        api.upsert_conversation(conversation).await.unwrap();
+        let request = ChatRequest::new(Event::new("Keep going"), conversation_id);
+        let cancel = CancellationToken::new();
+
+        let mut stream = api.chat(request, cancel.clone()).await.unwrap();
+        let mut tool_calls = 0;
+        while let Some(response) = stream.next().await {
+            if let ChatResponse::ToolCallStart { notifier, .. } = response.unwrap() {
+                tool_calls += 1;
+                // Interrupt while the second tool call waits on the UI
+                if tool_calls == 1 {
+                    notifier.notify_one();
+                } else {
+                    cancel.cancel();
+                }
+            }
+        }
+
+        let actual = api
+            .conversation(&conversation_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .context
+            .unwrap();
+        let user_prompts = actual
+            .messages
+            .iter()
+            .filter(|message| {
+                message.has_role(Role::User)
+                    && message
+                        .content()
+                        .is_some_and(|content| content.contains("Keep going"))
+            })
+            .count();
+        let assistant_turns = actual
+            .messages
+            .iter()
+            .filter(|message| message.has_role(Role::Assistant))
+            .count();
+        assert_eq!(tool_calls, 2);
+        assert_eq!(user_prompts, 1);
+        assert_eq!(assistant_turns, 1);
+    }
+}
@@ -2,9 +2,9 @@ use std::sync::Arc;
 
 use forge_config::ForgeConfig;
 use forge_domain::{
-    Agent, ChatCompletionMessage, Compact, Context, Conversation, Effort, MaxTokens, ModelId,
-    ProviderId, ReasoningConfig, ResultStream, Temperature, ToolCallContext, ToolCallFull,
-    ToolResult, TopK, TopP,
+    Agent, ChatCompletionMessage, Compact, Context, Conversation, ConversationId, Effort,
+    MaxTokens, ModelId, ProviderId, ReasoningConfig, ResultStream, Temperature, ToolCallContext,
+    ToolCallFull, ToolResult, TopK, TopP,
 };
 use merge::Merge;
 
@@ -34,6 +34,10 @@ pub trait AgentService: Send + Sync + 'static {
 
     /// Synchronize the on-going conversation
     async fn update(&self, conversation: Conversation) -> anyhow::Result<()>;
+
+    /// Sets the title of a stored conversation without touching the rest of
+    /// its state
+    async fn update_title(&self, id: &ConversationId, title: String) -> anyhow::Result<()>;
 }
 
 /// Blanket implementation of AgentService for any type that implements Services
@@ -71,6 +75,10 @@ impl<T: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> AgentSe
     async fn update(&self, conversation: Conversation) -> anyhow::Result<()> {
         self.upsert_conversation(conversation).await
     }
+
+    async fn update_title(&self, id: &ConversationId, title: String) -> anyhow::Result<()> {
+        self.update_conversation_title(id, title).await
+    }
 }
 
 /// Extension trait for applying workflow-level configuration overrides to an
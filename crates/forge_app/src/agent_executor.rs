@@ -9,6 +9,7 @@ use forge_domain::{
 use forge_template::Element;
 use futures::StreamExt;
 use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
 
 use crate::error::Error;
 use crate::{AgentRegistry, ConversationService, EnvironmentInfra, Services};
@@ -81,6 +82,7 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> AgentEx
             .chat(
                 agent_id.clone(),
                 ChatRequest::new(Event::new(task.clone()), conversation.id),
+                CancellationToken::new(),
             )
             .await?;
 
@@ -107,10 +109,11 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> AgentEx
                     }
                 },
                 ChatResponse::TaskReasoning { .. } => {}
-                ChatResponse::TaskComplete => {}
+                ChatResponse::TaskComplete { .. } => {}
                 ChatResponse::ToolCallStart { .. } => ctx.send(message).await?,
                 ChatResponse::ToolCallEnd(_) => ctx.send(message).await?,
                 ChatResponse::RetryAttempt { .. } => ctx.send(message).await?,
+                ChatResponse::AutoCompacted { .. } => {}
                 ChatResponse::Interrupt { reason } => {
                     return Err(Error::AgentToolInterrupted(reason))
                         .context(format!(
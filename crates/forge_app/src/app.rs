@@ -5,6 +5,7 @@ use chrono::Local;
 use forge_config::ForgeConfig;
 use forge_domain::*;
 use forge_stream::MpscStream;
+use tokio_util::sync::CancellationToken;
 
 use crate::apply_tunable_parameters::ApplyTunableParameters;
 use crate::changed_files::ChangedFiles;
@@ -41,6 +42,16 @@ pub(crate) fn build_template_config(config: &ForgeConfig) -> forge_domain::Templ
     }
 }
 
+/// An agent resolved for the current config and environment, with a
+/// conversation whose system prompt has been rendered for it
+struct PreparedAgent {
+    agent: Agent,
+    provider: Provider<url::Url>,
+    models: Vec<Model>,
+    tool_definitions: Vec<ToolDefinition>,
+    conversation: Conversation,
+}
+
 /// ForgeApp handles the core chat functionality by orchestrating various
 /// services. It encapsulates the complex logic previously contained in the
 /// ForgeAPI chat method.
@@ -57,10 +68,14 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> ForgeAp
 
     /// Executes a chat request and returns a stream of responses.
     /// This method contains the core chat logic extracted from ForgeAPI.
+    ///
+    /// Cancelling `cancel` stops the orchestrator at its next await point and
+    /// saves the conversation with whatever the turn has produced so far.
     pub async fn chat(
         &self,
         agent_id: AgentId,
         chat: ChatRequest,
+        cancel: CancellationToken,
     ) -> Result<MpscStream<Result<ChatResponse, anyhow::Error>>> {
         let services = self.services.clone();
 
@@ -70,60 +85,41 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> ForgeAp
             .await?
             .ok_or_else(|| forge_domain::Error::ConversationNotFound(chat.conversation_id))?;
 
-        // Discover files using the discovery service
         let forge_config = self.services.get_config()?;
         let environment = services.get_environment();
 
-        let files = services.list_current_directory().await?;
-
-        let custom_instructions = services.get_custom_instructions().await;
-
-        // Prepare agents with user configuration
-        let agent_provider_resolver = AgentProviderResolver::new(services.clone());
-
-        // Get agent and apply workflow config
-        let agent = self
-            .services
-            .get_agent(&agent_id)
-            .await?
-            .ok_or(crate::Error::AgentNotFound(agent_id.clone()))?
-            .apply_config(&forge_config)
-            .set_compact_model_if_none();
-
-        let agent_provider = agent_provider_resolver
-            .get_provider(Some(agent.id.clone()))
-            .await?;
-        let agent_provider = self
-            .services
-            .provider_auth_service()
-            .refresh_provider_credential(agent_provider)
-            .await?;
-
-        let models = services.models(agent_provider).await?;
-        let selected_model = models.iter().find(|model| model.id == agent.model);
-        let agent = agent.compaction_threshold(selected_model);
+        let PreparedAgent {
+            mut agent,
+            provider: agent_provider,
+            models,
+            tool_definitions,
+            conversation,
+        } = self.prepare_agent(agent_id, conversation).await?;
+
+        // A per-request cap (e.g. `--max-turns`) always wins over the agent's
+        // own limit
+        if let Some(max_requests) = chat.max_requests {
+            agent.max_requests_per_turn = Some(max_requests);
+        }
+
+        // Warn the user ahead of time when they will soon have to log in again
+        let expiry_warning = agent_provider
+            .credential
+            .as_ref()
+            .filter(|credential| credential.is_near_expiry())
+            .and_then(|credential| credential.expires_at)
+            .map(|expires_at| {
+                TitleFormat::warning(format!(
+                    "Credentials for {} expire at {}. Log in again to keep using this provider",
+                    agent_provider.id,
+                    expires_at.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+                ))
+            });
 
-        // Get system and mcp tool definitions and resolve them for the agent
-        let all_tool_definitions = self.tool_registry.list().await?;
-        let tool_resolver = ToolResolver::new(all_tool_definitions);
-        let tool_definitions: Vec<ToolDefinition> =
-            tool_resolver.resolve(&agent).into_iter().cloned().collect();
         let max_tool_failure_per_turn = agent.max_tool_failure_per_turn.unwrap_or(3);
 
         let current_time = Local::now();
 
-        // Insert system prompt
-        let conversation =
-            SystemPrompt::new(self.services.clone(), environment.clone(), agent.clone())
-                .custom_instructions(custom_instructions.clone())
-                .tool_definitions(tool_definitions.clone())
-                .models(models.clone())
-                .files(files.clone())
-                .max_extensions(forge_config.max_extensions)
-                .template_config(build_template_config(&forge_config))
-                .add_system_message(conversation)
-                .await?;
-
         // Insert user prompt
         let conversation = UserPromptGenerator::new(
             self.services.clone(),
@@ -142,7 +138,13 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> ForgeAp
         let conversation = InitConversationMetrics::new(current_time).apply(conversation);
         let conversation = ApplyTunableParameters::new(agent.clone(), tool_definitions.clone())
             .apply(conversation);
-        let conversation = SetConversationId.apply(conversation);
+        let mut conversation = SetConversationId.apply(conversation);
+
+        // Persist caller-defined metadata alongside the conversation
+        conversation
+            .metadata
+            .request_metadata
+            .extend(chat.metadata.clone());
 
         // Create the orchestrator with all necessary dependencies
         let tracing_handler = TracingHandler::new();
@@ -180,15 +182,28 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> ForgeAp
         .error_tracker(ToolErrorTracker::new(max_tool_failure_per_turn))
         .tool_definitions(tool_definitions)
         .models(models)
+        .request_metadata(chat.metadata)
         .hook(Arc::new(hook));
 
         // Create and return the stream
         let stream = MpscStream::spawn(
             |tx: tokio::sync::mpsc::Sender<Result<ChatResponse, anyhow::Error>>| {
                 async move {
+                    if let Some(warning) = expiry_warning
+                        && let Err(e) = tx.send(Ok(warning.into())).await
+                    {
+                        tracing::error!("Failed to send credential expiry warning: {}", e);
+                    }
+
                     // Execute dispatch and always save conversation afterwards
                     let mut orch = orch.sender(tx.clone());
-                    let dispatch_result = orch.run().await;
+                    let dispatch_result = tokio::select! {
+                        result = orch.run() => result,
+                        _ = cancel.cancelled() => {
+                            tracing::info!("Chat cancelled, saving the partial turn");
+                            Ok(())
+                        }
+                    };
 
                     // Always save conversation using get_conversation()
                     let conversation = orch.get_conversation().clone();
@@ -279,6 +294,160 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> ForgeAp
         ))
     }
 
+    /// Renders the system prompt `agent_id` would receive at the start of a
+    /// new conversation, including tool descriptions, skills and custom
+    /// instructions for the current environment.
+    pub async fn get_agent_system_prompt(&self, agent_id: AgentId) -> Result<String> {
+        let PreparedAgent { conversation, .. } = self
+            .prepare_agent(agent_id, Conversation::generate())
+            .await?;
+
+        Ok(conversation
+            .context
+            .iter()
+            .flat_map(|context| context.messages.iter())
+            .filter(|message| message.has_role(Role::System))
+            .filter_map(|message| message.content())
+            .collect::<Vec<_>>()
+            .join("\n\n"))
+    }
+
+    /// Resolves `agent_id` for the current config and environment, along with
+    /// its provider, models and tools, and renders its system prompt into
+    /// `conversation`.
+    async fn prepare_agent(
+        &self,
+        agent_id: AgentId,
+        conversation: Conversation,
+    ) -> Result<PreparedAgent> {
+        let services = self.services.clone();
+        let forge_config = services.get_config()?;
+        let environment = services.get_environment();
+
+        // Discover files using the discovery service
+        let files = services.list_current_directory().await?;
+
+        let custom_instructions = services.get_custom_instructions().await;
+
+        // Get agent and apply workflow config
+        let agent = services
+            .get_agent(&agent_id)
+            .await?
+            .ok_or(crate::Error::AgentNotFound(agent_id))?
+            .apply_config(&forge_config)
+            .set_compact_model_if_none();
+
+        let provider = AgentProviderResolver::new(services.clone())
+            .get_provider(Some(agent.id.clone()))
+            .await?;
+        let provider = services
+            .provider_auth_service()
+            .refresh_provider_credential(provider)
+            .await?;
+
+        let models = services.models(provider.clone()).await?;
+        let selected_model = models.iter().find(|model| model.id == agent.model);
+        let agent = agent.compaction_threshold(selected_model);
+
+        // Get system and mcp tool definitions and resolve them for the agent
+        let all_tool_definitions = self.tool_registry.list().await?;
+        let tool_definitions: Vec<ToolDefinition> = ToolResolver::new(all_tool_definitions)
+            .resolve(&agent)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        // Insert system prompt
+        let conversation = SystemPrompt::new(services, environment, agent.clone())
+            .custom_instructions(custom_instructions)
+            .tool_definitions(tool_definitions.clone())
+            .models(models.clone())
+            .files(files)
+            .max_extensions(forge_config.max_extensions)
+            .template_config(build_template_config(&forge_config))
+            .add_system_message(conversation)
+            .await?;
+
+        Ok(PreparedAgent { agent, provider, models, tool_definitions, conversation })
+    }
+
+    /// Executes a single tool on behalf of `agent_id` without running the
+    /// agent loop, streaming the output the tool reports while it runs. Tools
+    /// that report nothing while running yield each text value of their
+    /// result instead, so output is never sent twice.
+    ///
+    /// The tool must be available to the agent. Tool failures are yielded as
+    /// an error item rather than the error message sent to the model.
+    pub async fn stream_tool_call(
+        &self,
+        agent_id: AgentId,
+        tool_name: ToolName,
+        arguments: ToolCallArguments,
+    ) -> Result<MpscStream<Result<String>>> {
+        let agent = self
+            .services
+            .get_agent(&agent_id)
+            .await?
+            .ok_or(crate::Error::AgentNotFound(agent_id))?
+            .apply_config(&self.services.get_config()?);
+        let tool_registry = ToolRegistry::new(self.services.clone());
+
+        Ok(MpscStream::spawn(move |tx| async move {
+            // Forward output as the tool reports it instead of waiting for the
+            // final result
+            let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel(1);
+            let progress = tokio::spawn({
+                let tx = tx.clone();
+                async move {
+                    let mut streamed = false;
+                    while let Some(response) = progress_rx.recv().await {
+                        let text = match response {
+                            Ok(ChatResponse::TaskMessage {
+                                content:
+                                    ChatResponseContent::ToolOutput(text)
+                                    | ChatResponseContent::Markdown { text, .. },
+                            }) => text,
+                            // Delegated agents wait for their tool calls to be
+                            // acknowledged before running them
+                            Ok(ChatResponse::ToolCallStart { notifier, .. }) => {
+                                notifier.notify_one();
+                                continue;
+                            }
+                            _ => continue,
+                        };
+                        streamed = true;
+                        if tx.send(Ok(text)).await.is_err() {
+                            break;
+                        }
+                    }
+                    streamed
+                }
+            });
+
+            let context = ToolCallContext::new(Metrics::default()).sender(Some(progress_tx));
+            let call = ToolCallFull::new(tool_name).arguments(arguments);
+            let result = tool_registry.call(&agent, &context, call).await;
+            drop(context);
+            let streamed = progress.await.unwrap_or_default();
+
+            let texts = result.output.values.iter().filter_map(ToolValue::as_str);
+            if result.output.is_error {
+                let message = texts.collect::<Vec<_>>().join("\n");
+                let _ = tx.send(Err(anyhow::anyhow!(message))).await;
+                return;
+            }
+            // The result repeats what was already streamed
+            if streamed {
+                return;
+            }
+            for text in texts {
+                if tx.send(Ok(text.to_string())).await.is_err() {
+                    return;
+                }
+            }
+        }))
+    }
+
     pub async fn list_tools(&self) -> Result<ToolsOverview> {
         self.tool_registry.tools_overview().await
     }
@@ -24,6 +24,12 @@ impl ApplyTunableParameters {
         if let Some(top_k) = self.agent.top_k {
             ctx = ctx.top_k(top_k);
         }
+        if let Some(min_p) = self.agent.min_p {
+            ctx = ctx.min_p(min_p);
+        }
+        if let Some(repetition_penalty) = self.agent.repetition_penalty {
+            ctx = ctx.repetition_penalty(repetition_penalty);
+        }
         if let Some(max_tokens) = self.agent.max_tokens {
             ctx = ctx.max_tokens(max_tokens.value() as usize);
         }
@@ -38,8 +44,8 @@ impl ApplyTunableParameters {
 #[cfg(test)]
 mod tests {
     use forge_domain::{
-        AgentId, Context, ConversationId, MaxTokens, ModelId, ProviderId, ReasoningConfig,
-        Temperature, ToolDefinition, TopK, TopP,
+        AgentId, Context, ConversationId, MaxTokens, MinP, ModelId, ProviderId, ReasoningConfig,
+        RepetitionPenalty, Temperature, ToolDefinition, TopK, TopP,
     };
     use pretty_assertions::assert_eq;
 
@@ -80,4 +86,27 @@ mod tests {
         assert_eq!(ctx.reasoning, Some(reasoning));
         assert_eq!(ctx.tools, vec![tool_def]);
     }
+
+    #[test]
+    fn test_apply_sets_local_sampling_parameters() {
+        let agent = Agent::new(
+            AgentId::new("test"),
+            ProviderId::OPENAI,
+            ModelId::new("qwen3"),
+        )
+        .top_k(TopK::new(40).unwrap())
+        .min_p(MinP::new(0.05).unwrap())
+        .repetition_penalty(RepetitionPenalty::new(1.1).unwrap());
+        let conversation = Conversation::new(ConversationId::generate());
+
+        let actual = ApplyTunableParameters::new(agent, vec![]).apply(conversation);
+
+        let ctx = actual.context.unwrap();
+        assert_eq!(ctx.top_k, Some(TopK::new(40).unwrap()));
+        assert_eq!(ctx.min_p, Some(MinP::new(0.05).unwrap()));
+        assert_eq!(
+            ctx.repetition_penalty,
+            Some(RepetitionPenalty::new(1.1).unwrap())
+        );
+    }
 }
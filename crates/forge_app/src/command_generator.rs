@@ -275,6 +275,8 @@ mod tests {
                     id: ProviderId::OPENAI,
                     auth_details: AuthDetails::ApiKey("test-key".to_string().into()),
                     url_params: Default::default(),
+                    alias: None,
+                    expires_at: None,
                 }),
                 custom_headers: None,
             })
@@ -288,6 +290,10 @@ mod tests {
             Ok(())
         }
 
+        async fn get_credentials(&self, _id: &ProviderId) -> Result<Vec<AuthCredential>> {
+            Ok(vec![])
+        }
+
         async fn remove_credential(&self, _id: &ProviderId) -> Result<()> {
             Ok(())
         }
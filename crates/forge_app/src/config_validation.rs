@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use forge_config::{ForgeConfig, ModelConfig};
+use forge_domain::{MaxTokens, Temperature, TopK, TopP};
+
+/// A single problem found while validating a [`ForgeConfig`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigValidationError {
+    /// Dotted path of the offending field (e.g. `session.model_id`)
+    pub field: String,
+    /// Human-readable description of what is wrong with the field
+    pub problem: String,
+}
+
+impl ConfigValidationError {
+    /// Creates a new validation error for `field`
+    pub fn new(field: impl Into<String>, problem: impl Into<String>) -> Self {
+        Self { field: field.into(), problem: problem.into() }
+    }
+}
+
+impl fmt::Display for ConfigValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.problem)
+    }
+}
+
+/// Validates a [`ForgeConfig`] and reports every problem found in one pass
+///
+/// Checks that model and provider references are non-empty, sampling
+/// parameters are within their supported ranges, and inline provider entries
+/// are complete and uniquely identified. Returns an empty list when the
+/// configuration is valid.
+pub fn validate_config(config: &ForgeConfig) -> Vec<ConfigValidationError> {
+    let mut errors = Vec::new();
+
+    for (name, model) in [
+        ("session", &config.session),
+        ("commit", &config.commit),
+        ("suggest", &config.suggest),
+    ] {
+        if let Some(model) = model {
+            validate_model(name, model, &mut errors);
+        }
+    }
+
+    if let Some(temperature) = config.temperature
+        && !Temperature::is_valid(temperature.value() as f32)
+    {
+        errors.push(ConfigValidationError::new(
+            "temperature",
+            format!("must be between 0.0 and 2.0, got {}", temperature.value()),
+        ));
+    }
+
+    if let Some(top_p) = config.top_p
+        && !TopP::is_valid(top_p.value() as f32)
+    {
+        errors.push(ConfigValidationError::new(
+            "top_p",
+            format!("must be between 0.0 and 1.0, got {}", top_p.value()),
+        ));
+    }
+
+    if let Some(top_k) = config.top_k
+        && !TopK::is_valid(top_k)
+    {
+        errors.push(ConfigValidationError::new(
+            "top_k",
+            format!("must be between 1 and 1000, got {top_k}"),
+        ));
+    }
+
+    if let Some(max_tokens) = config.max_tokens
+        && !MaxTokens::is_valid(max_tokens)
+    {
+        errors.push(ConfigValidationError::new(
+            "max_tokens",
+            format!("must be between 1 and 100000, got {max_tokens}"),
+        ));
+    }
+
+    let mut provider_ids = HashSet::new();
+    for (index, provider) in config.providers.iter().enumerate() {
+        let field = format!("providers[{index}]");
+        if provider.id.trim().is_empty() {
+            errors.push(ConfigValidationError::new(
+                format!("{field}.id"),
+                "must not be empty",
+            ));
+        } else if !provider_ids.insert(provider.id.as_str()) {
+            errors.push(ConfigValidationError::new(
+                format!("{field}.id"),
+                format!("duplicate provider id '{}'", provider.id),
+            ));
+        }
+        if provider.url.trim().is_empty() {
+            errors.push(ConfigValidationError::new(
+                format!("{field}.url"),
+                "must not be empty",
+            ));
+        }
+    }
+
+    errors
+}
+
+fn validate_model(name: &str, model: &ModelConfig, errors: &mut Vec<ConfigValidationError>) {
+    if model.provider_id.trim().is_empty() {
+        errors.push(ConfigValidationError::new(
+            format!("{name}.provider_id"),
+            "must not be empty",
+        ));
+    }
+    if model.model_id.trim().is_empty() {
+        errors.push(ConfigValidationError::new(
+            format!("{name}.model_id"),
+            "must not be empty",
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use forge_config::{Decimal, ProviderEntry};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_validate_config_default_is_valid() {
+        let fixture = ForgeConfig::default();
+        let actual = validate_config(&fixture);
+        let expected = vec![];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_validate_config_reports_missing_model_id() {
+        let fixture = ForgeConfig::default().session(ModelConfig::new("anthropic", ""));
+        let actual = validate_config(&fixture)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let expected = vec!["session.model_id: must not be empty".to_string()];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_validate_config_reports_all_errors() {
+        let fixture = ForgeConfig::default()
+            .temperature(Decimal(3.0))
+            .top_k(0u32)
+            .providers(vec![
+                ProviderEntry { id: "custom".to_string(), ..Default::default() },
+                ProviderEntry {
+                    id: "custom".to_string(),
+                    url: "https://example.com".to_string(),
+                    ..Default::default()
+                },
+            ]);
+        let actual = validate_config(&fixture);
+        let expected = vec![
+            ConfigValidationError::new("temperature", "must be between 0.0 and 2.0, got 3"),
+            ConfigValidationError::new("top_k", "must be between 1 and 1000, got 0"),
+            ConfigValidationError::new("providers[0].url", "must not be empty"),
+            ConfigValidationError::new("providers[1].id", "duplicate provider id 'custom'"),
+        ];
+        assert_eq!(actual, expected);
+    }
+}
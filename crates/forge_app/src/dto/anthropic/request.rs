@@ -443,12 +443,12 @@ impl TryFrom<forge_domain::ToolResult> for Content {
         Ok(Content::ToolResult {
             tool_use_id: call_id.as_str().to_string(),
             cache_control: None,
-            content: value
-                .output
-                .values
-                .iter()
-                .filter_map(|item| item.as_str().map(|s| s.to_string()))
-                .next(),
+            // Anthropic has no JSON block for tool results, so structured output is
+            // sent as its JSON text
+            content: value.output.values.iter().find_map(|item| match item {
+                forge_domain::ToolValue::Json(json) => Some(json.to_string()),
+                item => item.as_str().map(|s| s.to_string()),
+            }),
             is_error: Some(value.is_error()),
         })
     }
@@ -869,4 +869,25 @@ mod tests {
 
         assert_eq!(actual.stream, Some(false));
     }
+
+    #[test]
+    fn test_json_tool_result_is_sent_as_json_content() {
+        let files = serde_json::json!({"files": ["a.rs", "b.rs"]});
+        let fixture = forge_domain::ToolResult::new("search")
+            .call_id(forge_domain::ToolCallId::new("call_1"))
+            .output(Ok(forge_domain::ToolOutput::json(files.clone())));
+
+        let actual = serde_json::to_value(Content::try_from(fixture).unwrap()).unwrap();
+
+        let expected = serde_json::json!({
+            "type": "tool_result",
+            "tool_use_id": "call_1",
+            "content": files.to_string(),
+            "is_error": false
+        });
+        assert_eq!(actual, expected);
+        let content: serde_json::Value =
+            serde_json::from_str(actual["content"].as_str().unwrap()).unwrap();
+        assert_eq!(content, files);
+    }
 }
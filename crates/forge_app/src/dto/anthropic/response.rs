@@ -1,6 +1,6 @@
 use forge_domain::{
-    ChatCompletionMessage, Content, ModelId, Reasoning, ReasoningPart, TokenCount, ToolCallId,
-    ToolCallPart, ToolName,
+    ChatCompletionMessage, Content, ModelId, Reasoning, ReasoningFull, ReasoningPart, TokenCount,
+    ToolCallArguments, ToolCallFull, ToolCallId, ToolCallPart, ToolName,
 };
 use serde::{Deserialize, Serialize};
 
@@ -54,6 +54,8 @@ impl From<Model> for forge_domain::Model {
             supports_parallel_tool_calls: None,
             supports_reasoning: None,
             input_modalities,
+            cost_per_million_input_tokens: None,
+            cost_per_million_output_tokens: None,
         }
     }
 }
@@ -345,6 +347,56 @@ impl TryFrom<Event> for ChatCompletionMessage {
     }
 }
 
+/// Converts the body of a non-streaming Messages API response, which has the
+/// same shape as the `message` carried by a `message_start` event
+impl TryFrom<MessageStart> for ChatCompletionMessage {
+    type Error = anyhow::Error;
+    fn try_from(message: MessageStart) -> Result<Self, Self::Error> {
+        let mut text = String::new();
+        let mut reasoning = String::new();
+        let mut reasoning_details = Vec::new();
+        let mut tool_calls = Vec::new();
+
+        for block in message.content {
+            match block {
+                ContentBlock::Text { text: part } | ContentBlock::TextDelta { text: part } => {
+                    text.push_str(&part)
+                }
+                ContentBlock::Thinking { thinking, signature } => {
+                    reasoning.push_str(thinking.as_deref().unwrap_or_default());
+                    reasoning_details
+                        .push(ReasoningFull::default().text(thinking).signature(signature));
+                }
+                ContentBlock::RedactedThinking { data: Some(data) } => {
+                    reasoning.push_str(&data);
+                    reasoning_details.push(ReasoningFull::default().text(Some(data)));
+                }
+                ContentBlock::ToolUse { id, name, input } => tool_calls.push(ToolCallFull {
+                    call_id: Some(ToolCallId::new(id)),
+                    name: ToolName::new(name),
+                    arguments: ToolCallArguments::from(input),
+                    thought_signature: None,
+                }),
+                // Deltas only appear in streamed events
+                _ => {}
+            }
+        }
+
+        let mut result = ChatCompletionMessage::assistant(Content::full(text))
+            .extend_calls(tool_calls)
+            .finish_reason_opt(message.stop_reason.map(Into::into))
+            .usage(message.usage);
+        if !reasoning.is_empty() {
+            result = result.reasoning(Content::full(reasoning));
+        }
+        if !reasoning_details.is_empty() {
+            result = result.add_reasoning_detail(Reasoning::Full(reasoning_details));
+        }
+
+        Ok(result)
+    }
+}
+
 impl TryFrom<ContentBlock> for ChatCompletionMessage {
     type Error = anyhow::Error;
     fn try_from(value: ContentBlock) -> Result<Self, Self::Error> {
@@ -844,4 +896,47 @@ mod tests {
 
         assert_eq!(actual.usage, None);
     }
+
+    #[test]
+    fn test_non_streaming_message_conversion() {
+        let fixture = r#"{
+            "id": "msg_01",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude-sonnet-4-5",
+            "content": [
+                {"type": "thinking", "thinking": "Need the weather", "signature": "sig"},
+                {"type": "text", "text": "Checking now."},
+                {"type": "tool_use", "id": "toolu_01", "name": "weather", "input": {"city": "Paris"}}
+            ],
+            "stop_reason": "tool_use",
+            "stop_sequence": null,
+            "usage": {"input_tokens": 10, "output_tokens": 5}
+        }"#;
+        let message: MessageStart = serde_json::from_str(fixture).unwrap();
+
+        let actual = ChatCompletionMessage::try_from(message).unwrap();
+
+        let expected = ChatCompletionMessage::assistant(Content::full("Checking now."))
+            .add_tool_call(ToolCallFull {
+                call_id: Some(ToolCallId::new("toolu_01")),
+                name: ToolName::new("weather"),
+                arguments: ToolCallArguments::from(serde_json::json!({"city": "Paris"})),
+                thought_signature: None,
+            })
+            .finish_reason(forge_domain::FinishReason::ToolCalls)
+            .usage(Usage {
+                input_tokens: Some(10),
+                output_tokens: Some(5),
+                cache_read_input_tokens: None,
+                cache_creation_input_tokens: None,
+            })
+            .reasoning(Content::full("Need the weather"))
+            .add_reasoning_detail(Reasoning::Full(vec![
+                ReasoningFull::default()
+                    .text(Some("Need the weather".to_string()))
+                    .signature(Some("sig".to_string())),
+            ]));
+        assert_eq!(actual, expected);
+    }
 }
@@ -0,0 +1,5 @@
+mod request;
+mod response;
+
+pub use request::Request;
+pub use response::{Model, StreamEvent};
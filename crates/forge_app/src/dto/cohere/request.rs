@@ -0,0 +1,349 @@
+use std::collections::{BTreeMap, HashMap};
+
+use derive_setters::Setters;
+use forge_domain::{Context, ContextMessage, Role, ToolCallFull, ToolCallId, ToolValue};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Request body for Cohere's `/v1/chat` endpoint
+///
+/// Unlike OpenAI, the latest user turn goes in `message`, earlier turns in
+/// `chat_history` and system prompts in `preamble`.
+#[derive(Serialize, Default, Setters, Debug, PartialEq)]
+#[setters(into, strip_option)]
+pub struct Request {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preamble: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub chat_history: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<Tool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tool_results: Vec<ToolResult>,
+    pub stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub k: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<usize>,
+}
+
+/// A previous turn in the conversation
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "role", rename_all = "UPPERCASE")]
+pub enum ChatMessage {
+    User {
+        message: String,
+    },
+    Chatbot {
+        message: String,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tool_calls: Vec<ToolCall>,
+    },
+    Tool {
+        tool_results: Vec<ToolResult>,
+    },
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ToolCall {
+    pub name: String,
+    pub parameters: Value,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ToolResult {
+    pub call: ToolCall,
+    pub outputs: Vec<Value>,
+}
+
+/// Tool definition in Cohere's flat parameter format
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Tool {
+    pub name: String,
+    pub description: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub parameter_definitions: BTreeMap<String, ParameterDefinition>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ParameterDefinition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub required: bool,
+}
+
+impl From<Context> for Request {
+    fn from(context: Context) -> Self {
+        let system: Vec<String> = context
+            .messages
+            .iter()
+            .filter(|msg| msg.has_role(Role::System))
+            .filter_map(|msg| msg.content().map(str::to_string))
+            .collect();
+        let preamble = (!system.is_empty()).then(|| system.join("\n\n"));
+
+        // Tool results must echo the call they answer, which Cohere matches
+        // by name and parameters rather than by id
+        let mut calls: HashMap<ToolCallId, ToolCall> = HashMap::new();
+        let mut chat_history: Vec<ChatMessage> = Vec::new();
+        for entry in context.messages {
+            match entry.message {
+                ContextMessage::Text(message) => match message.role {
+                    Role::System => {}
+                    Role::User => chat_history.push(ChatMessage::User { message: message.content }),
+                    Role::Assistant => {
+                        let tool_calls = message
+                            .tool_calls
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|call| {
+                                let id = call.call_id.clone();
+                                let call = ToolCall::from(call);
+                                if let Some(id) = id {
+                                    calls.insert(id, call.clone());
+                                }
+                                call
+                            })
+                            .collect();
+                        chat_history
+                            .push(ChatMessage::Chatbot { message: message.content, tool_calls });
+                    }
+                },
+                ContextMessage::Tool(result) => {
+                    let call = result
+                        .call_id
+                        .as_ref()
+                        .and_then(|id| calls.get(id).cloned())
+                        .unwrap_or_else(|| ToolCall {
+                            name: result.name.to_string(),
+                            parameters: Value::Object(Default::default()),
+                        });
+                    let outputs = result
+                        .output
+                        .values
+                        .iter()
+                        .filter_map(|value| match value {
+                            ToolValue::Json(json) => Some(serde_json::json!({ "output": json })),
+                            value => value
+                                .as_str()
+                                .map(|text| serde_json::json!({ "output": text })),
+                        })
+                        .collect();
+                    let result = ToolResult { call, outputs };
+                    // Consecutive results answer the same assistant turn
+                    match chat_history.last_mut() {
+                        Some(ChatMessage::Tool { tool_results }) => tool_results.push(result),
+                        _ => chat_history.push(ChatMessage::Tool { tool_results: vec![result] }),
+                    }
+                }
+                // The v1 chat API accepts text only
+                ContextMessage::Image(_) => {}
+            }
+        }
+
+        // The final turn is sent on its own: either the new user message or
+        // the results of the tools the model just called
+        let (message, tool_results) = match chat_history.pop() {
+            Some(ChatMessage::User { message }) => (message, Vec::new()),
+            Some(ChatMessage::Tool { tool_results }) => (String::new(), tool_results),
+            Some(other) => {
+                chat_history.push(other);
+                (String::new(), Vec::new())
+            }
+            None => (String::new(), Vec::new()),
+        };
+
+        Request {
+            model: None,
+            message,
+            preamble,
+            chat_history,
+            tools: context.tools.into_iter().map(Tool::from).collect(),
+            tool_results,
+            stream: context.stream.unwrap_or(true),
+            temperature: context.temperature.map(|t| t.value()),
+            p: context.top_p.map(|p| p.value()),
+            k: context.top_k.map(|k| k.value()),
+            max_tokens: context.max_tokens,
+        }
+    }
+}
+
+impl From<ToolCallFull> for ToolCall {
+    fn from(call: ToolCallFull) -> Self {
+        ToolCall {
+            name: call.name.to_string(),
+            parameters: call
+                .arguments
+                .parse()
+                .unwrap_or(Value::Object(Default::default())),
+        }
+    }
+}
+
+impl From<forge_domain::ToolDefinition> for Tool {
+    fn from(tool: forge_domain::ToolDefinition) -> Self {
+        let schema = tool.input_schema.as_value();
+        let required: Vec<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|names| names.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        let parameter_definitions = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .map(|properties| {
+                properties
+                    .iter()
+                    .map(|(name, property)| {
+                        let definition = ParameterDefinition {
+                            description: property
+                                .get("description")
+                                .and_then(Value::as_str)
+                                .map(str::to_string),
+                            type_name: parameter_type(property).to_string(),
+                            required: required.contains(&name.as_str()),
+                        };
+                        (name.clone(), definition)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Tool {
+            name: tool.name.to_string(),
+            description: tool.description,
+            parameter_definitions,
+        }
+    }
+}
+
+/// Maps a JSON Schema type onto the Python-style names Cohere expects
+fn parameter_type(property: &Value) -> &'static str {
+    let type_name = match property.get("type") {
+        Some(Value::String(name)) => Some(name.as_str()),
+        // Optional fields are generated as `["string", "null"]`
+        Some(Value::Array(names)) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|name| *name != "null"),
+        _ => None,
+    };
+    match type_name {
+        Some("integer") => "int",
+        Some("number") => "float",
+        Some("boolean") => "bool",
+        Some("array") => "list",
+        Some("object") => "dict",
+        _ => "str",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use forge_domain::{
+        ContextMessage, ToolCallArguments, ToolDefinition, ToolName, ToolOutput, ToolResult,
+    };
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_system_messages_become_preamble() {
+        let fixture = Context::default()
+            .add_message(ContextMessage::system("You are helpful."))
+            .add_message(ContextMessage::user("Hi", None))
+            .add_message(ContextMessage::assistant("Hello!", None, None, None))
+            .add_message(ContextMessage::user("What is RAG?", None));
+
+        let actual = serde_json::to_value(Request::from(fixture)).unwrap();
+
+        let expected = json!({
+            "message": "What is RAG?",
+            "preamble": "You are helpful.",
+            "chat_history": [
+                {"role": "USER", "message": "Hi"},
+                {"role": "CHATBOT", "message": "Hello!"}
+            ],
+            "stream": true
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_trailing_tool_results_are_sent_as_tool_results() {
+        let call = forge_domain::ToolCallFull {
+            name: ToolName::new("read"),
+            call_id: Some(ToolCallId::new("call_1")),
+            arguments: ToolCallArguments::from_json(r#"{"path":"a.rs"}"#),
+            thought_signature: None,
+        };
+        let fixture = Context::default()
+            .add_message(ContextMessage::user("Read a.rs", None))
+            .add_message(ContextMessage::assistant("", None, None, Some(vec![call])))
+            .add_tool_results(vec![
+                ToolResult::new("read")
+                    .call_id(ToolCallId::new("call_1"))
+                    .output(Ok(ToolOutput::text("fn main() {}"))),
+            ]);
+
+        let actual = serde_json::to_value(Request::from(fixture)).unwrap();
+
+        let expected = json!({
+            "message": "",
+            "chat_history": [
+                {"role": "USER", "message": "Read a.rs"},
+                {
+                    "role": "CHATBOT",
+                    "message": "",
+                    "tool_calls": [{"name": "read", "parameters": {"path": "a.rs"}}]
+                }
+            ],
+            "tool_results": [{
+                "call": {"name": "read", "parameters": {"path": "a.rs"}},
+                "outputs": [{"output": "fn main() {}"}]
+            }],
+            "stream": true
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_tool_definition_uses_parameter_definitions() {
+        let fixture = ToolDefinition::new("read")
+            .description("Read a file")
+            .input_schema(
+                serde_json::from_value::<schemars::Schema>(json!({
+                    "type": "object",
+                    "properties": {
+                        "path": {"type": "string", "description": "File path"},
+                        "limit": {"type": ["integer", "null"]}
+                    },
+                    "required": ["path"]
+                }))
+                .unwrap(),
+            );
+
+        let actual = serde_json::to_value(Tool::from(fixture)).unwrap();
+
+        let expected = json!({
+            "name": "read",
+            "description": "Read a file",
+            "parameter_definitions": {
+                "limit": {"type": "int", "required": false},
+                "path": {"description": "File path", "type": "str", "required": true}
+            }
+        });
+        assert_eq!(actual, expected);
+    }
+}
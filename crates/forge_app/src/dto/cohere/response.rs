@@ -0,0 +1,215 @@
+use forge_domain::{
+    ChatCompletionMessage, Content, FinishReason, TokenCount, ToolCallArguments, ToolCallFull,
+    ToolCallId, ToolName,
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// One line of Cohere's newline-delimited `/v1/chat` stream
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "event_type", rename_all = "kebab-case")]
+pub enum StreamEvent {
+    StreamStart {
+        #[serde(default)]
+        generation_id: Option<String>,
+    },
+    TextGeneration {
+        text: String,
+    },
+    ToolCallsGeneration {
+        #[serde(default)]
+        tool_calls: Vec<ToolCall>,
+    },
+    StreamEnd {
+        #[serde(default)]
+        finish_reason: Option<String>,
+        #[serde(default)]
+        response: Option<EndResponse>,
+    },
+    /// Citations, search results and partial tool call chunks are not used
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ToolCall {
+    pub name: String,
+    #[serde(default)]
+    pub parameters: Value,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct EndResponse {
+    #[serde(default)]
+    pub meta: Option<Meta>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Meta {
+    #[serde(default)]
+    pub billed_units: Option<BilledUnits>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct BilledUnits {
+    #[serde(default)]
+    pub input_tokens: Option<f64>,
+    #[serde(default)]
+    pub output_tokens: Option<f64>,
+}
+
+/// Model entry returned by `/v1/models`
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Model {
+    pub name: String,
+    #[serde(default)]
+    pub context_length: Option<u64>,
+    #[serde(default)]
+    pub features: Option<Vec<String>>,
+}
+
+impl From<Model> for forge_domain::Model {
+    fn from(value: Model) -> Self {
+        let tools_supported = value
+            .features
+            .as_ref()
+            .map(|features| features.iter().any(|feature| feature == "tools"));
+        forge_domain::Model {
+            id: forge_domain::ModelId::new(value.name.clone()),
+            name: Some(value.name),
+            description: None,
+            context_length: value.context_length,
+            tools_supported,
+            supports_parallel_tool_calls: tools_supported,
+            supports_reasoning: Some(false),
+            input_modalities: vec![forge_domain::InputModality::Text],
+            cost_per_million_input_tokens: None,
+            cost_per_million_output_tokens: None,
+        }
+    }
+}
+
+impl TryFrom<StreamEvent> for ChatCompletionMessage {
+    type Error = anyhow::Error;
+
+    fn try_from(event: StreamEvent) -> Result<Self, Self::Error> {
+        let message = ChatCompletionMessage::assistant(Content::part(""));
+        Ok(match event {
+            StreamEvent::TextGeneration { text } => {
+                ChatCompletionMessage::assistant(Content::part(text))
+            }
+            StreamEvent::ToolCallsGeneration { tool_calls } => {
+                message.extend_calls(
+                    tool_calls
+                        .into_iter()
+                        .map(|call| ToolCallFull {
+                            name: ToolName::new(call.name),
+                            // Cohere doesn't assign ids to tool calls
+                            call_id: Some(ToolCallId::generate()),
+                            arguments: ToolCallArguments::from(call.parameters),
+                            thought_signature: None,
+                        })
+                        .collect(),
+                )
+            }
+            StreamEvent::StreamEnd { finish_reason, response } => {
+                let usage = response
+                    .and_then(|response| response.meta)
+                    .and_then(|meta| meta.billed_units)
+                    .map(forge_domain::Usage::from);
+                let message = message
+                    .finish_reason_opt(finish_reason.as_deref().and_then(parse_finish_reason));
+                match usage {
+                    Some(usage) => message.usage(usage),
+                    None => message,
+                }
+            }
+            StreamEvent::StreamStart { .. } | StreamEvent::Other => message,
+        })
+    }
+}
+
+impl From<BilledUnits> for forge_domain::Usage {
+    fn from(units: BilledUnits) -> Self {
+        let prompt_tokens = units.input_tokens.unwrap_or_default() as usize;
+        let completion_tokens = units.output_tokens.unwrap_or_default() as usize;
+        forge_domain::Usage {
+            prompt_tokens: TokenCount::Actual(prompt_tokens),
+            completion_tokens: TokenCount::Actual(completion_tokens),
+            total_tokens: TokenCount::Actual(prompt_tokens + completion_tokens),
+            ..Default::default()
+        }
+    }
+}
+
+fn parse_finish_reason(reason: &str) -> Option<FinishReason> {
+    match reason {
+        "COMPLETE" => Some(FinishReason::Stop),
+        "MAX_TOKENS" => Some(FinishReason::Length),
+        "ERROR_TOXIC" => Some(FinishReason::ContentFilter),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn parse(line: &str) -> ChatCompletionMessage {
+        let event: StreamEvent = serde_json::from_str(line).unwrap();
+        ChatCompletionMessage::try_from(event).unwrap()
+    }
+
+    #[test]
+    fn test_text_generation() {
+        let actual = parse(r#"{"is_finished":false,"event_type":"text-generation","text":"Hi"}"#);
+
+        let expected = ChatCompletionMessage::assistant(Content::part("Hi"));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_stream_end_carries_finish_reason_and_usage() {
+        let actual = parse(
+            r#"{"is_finished":true,"event_type":"stream-end","finish_reason":"COMPLETE","response":{"text":"Hi","meta":{"billed_units":{"input_tokens":12,"output_tokens":3}}}}"#,
+        );
+
+        let expected = ChatCompletionMessage::assistant(Content::part(""))
+            .finish_reason_opt(Some(FinishReason::Stop))
+            .usage(forge_domain::Usage {
+                prompt_tokens: TokenCount::Actual(12),
+                completion_tokens: TokenCount::Actual(3),
+                total_tokens: TokenCount::Actual(15),
+                ..Default::default()
+            });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_tool_calls_generation() {
+        let actual = parse(
+            r#"{"is_finished":false,"event_type":"tool-calls-generation","tool_calls":[{"name":"read","parameters":{"path":"a.rs"}}]}"#,
+        );
+
+        let call = match actual.tool_calls.as_slice() {
+            [forge_domain::ToolCall::Full(call)] => call.clone(),
+            other => panic!("expected one complete tool call, got {other:?}"),
+        };
+        assert_eq!(call.name, ToolName::new("read"));
+        assert_eq!(
+            call.arguments.parse().unwrap(),
+            serde_json::json!({"path": "a.rs"})
+        );
+        assert!(call.call_id.is_some());
+    }
+
+    #[test]
+    fn test_unknown_events_are_ignored() {
+        let actual = parse(r#"{"is_finished":false,"event_type":"citation-generation"}"#);
+
+        let expected = ChatCompletionMessage::assistant(Content::part(""));
+        assert_eq!(actual, expected);
+    }
+}
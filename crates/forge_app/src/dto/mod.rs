@@ -1,6 +1,7 @@
 // Due to a conflict between names of Anthropic and OpenAI we will namespace the
 // DTOs instead of using Prefixes for type names
 pub mod anthropic;
+pub mod cohere;
 pub mod google;
 pub mod openai;
 
@@ -90,6 +90,34 @@ pub struct ListModelResponse {
     pub data: Vec<Model>,
 }
 
+/// Entry of the Together AI model listing
+///
+/// Together returns a bare array that mixes chat models with embedding,
+/// image, audio and rerank models, distinguished by the `type` field.
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub struct TogetherModel {
+    #[serde(flatten)]
+    pub model: Model,
+    #[serde(rename = "type")]
+    pub model_type: Option<String>,
+    pub display_name: Option<String>,
+}
+
+impl TogetherModel {
+    /// Returns true if the model can be used for chat completions
+    pub fn is_chat(&self) -> bool {
+        self.model_type.as_deref() == Some("chat")
+    }
+}
+
+impl From<TogetherModel> for forge_domain::Model {
+    fn from(value: TogetherModel) -> Self {
+        let mut model = forge_domain::Model::from(value.model);
+        model.name = model.name.or(value.display_name);
+        model
+    }
+}
+
 impl From<Model> for forge_domain::Model {
     fn from(value: Model) -> Self {
         let has_param = |name: &str| {
@@ -116,6 +144,10 @@ impl From<Model> for forge_domain::Model {
         let supports_parallel_tool_calls = has_param("supports_parallel_tool_calls");
         let supports_reasoning = has_param("reasoning");
 
+        // OpenRouter reports prices in USD per token
+        let per_million = |price: Option<f32>| price.map(|price| price as f64 * 1_000_000.0);
+        let pricing = value.pricing.as_ref();
+
         forge_domain::Model {
             id: value.id,
             name: value.name,
@@ -125,6 +157,8 @@ impl From<Model> for forge_domain::Model {
             supports_parallel_tool_calls,
             supports_reasoning,
             input_modalities,
+            cost_per_million_input_tokens: per_million(pricing.and_then(|p| p.prompt)),
+            cost_per_million_output_tokens: per_million(pricing.and_then(|p| p.completion)),
         }
     }
 }
@@ -287,6 +321,32 @@ mod tests {
         assert_eq!(domain_model.supports_reasoning, None);
     }
 
+    #[tokio::test]
+    async fn test_model_conversion_with_pricing() {
+        let model = Model {
+            id: "test-model".into(),
+            name: None,
+            created: None,
+            description: None,
+            context_length: None,
+            architecture: None,
+            pricing: Some(Pricing {
+                prompt: Some(0.5),
+                completion: Some(0.25),
+                image: None,
+                request: None,
+            }),
+            top_provider: None,
+            per_request_limits: None,
+            supported_parameters: None,
+        };
+
+        let actual: forge_domain::Model = model.into();
+
+        assert_eq!(actual.cost_per_million_input_tokens, Some(500_000.0));
+        assert_eq!(actual.cost_per_million_output_tokens, Some(250_000.0));
+    }
+
     #[tokio::test]
     async fn test_model_conversion_with_supported_parameters() {
         let model = Model {
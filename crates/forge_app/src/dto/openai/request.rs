@@ -280,6 +280,10 @@ pub struct Request {
     pub max_completion_tokens: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thinking: Option<ThinkingConfig>,
+    /// Restricts Perplexity's online search to sources published within the
+    /// given window (`hour`, `day`, `week`, `month` or `year`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_recency_filter: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
@@ -343,6 +347,7 @@ impl From<ToolDefinition> for Tool {
 
 impl From<Context> for Request {
     fn from(context: Context) -> Self {
+        let stream = context.stream.unwrap_or(true);
         Request {
             messages: {
                 let messages = context
@@ -379,7 +384,7 @@ impl From<Context> for Request {
                 }
             }),
             stop: Default::default(),
-            stream: Some(context.stream.unwrap_or(true)),
+            stream: Some(stream),
             max_tokens: context.max_tokens.map(|t| t as u32),
             temperature: context.temperature.map(|t| t.value()),
             tool_choice: context.tool_choice.map(|tc| tc.into()),
@@ -388,10 +393,10 @@ impl From<Context> for Request {
             top_k: context.top_k.map(|t| t.value()),
             frequency_penalty: Default::default(),
             presence_penalty: Default::default(),
-            repetition_penalty: Default::default(),
+            repetition_penalty: context.repetition_penalty.map(|t| t.value()),
             logit_bias: Default::default(),
             top_logprobs: Default::default(),
-            min_p: Default::default(),
+            min_p: context.min_p.map(|t| t.value()),
             top_a: Default::default(),
             prediction: Default::default(),
             // Since compaction is support on the client we don't need middle-out transforms any
@@ -402,13 +407,15 @@ impl From<Context> for Request {
             provider: Default::default(),
             parallel_tool_calls: Some(true), /* Default to true, transformers will adjust based
                                               * on model capabilities */
-            stream_options: Some(StreamOptions { include_usage: Some(true) }),
+            // `stream_options` is only valid on streaming requests
+            stream_options: stream.then_some(StreamOptions { include_usage: Some(true) }),
             session_id: context.conversation_id.map(|id| id.to_string()),
             initiator: context.initiator,
             reasoning: context.reasoning,
             reasoning_effort: Default::default(),
             max_completion_tokens: Default::default(),
             thinking: Default::default(),
+            search_recency_filter: Default::default(),
         }
     }
 }
@@ -515,6 +522,9 @@ impl From<ToolResult> for MessageContent {
         {
             return MessageContent::Text(text.to_string());
         }
+        if let [ToolValue::Json(json)] = result.output.values.as_slice() {
+            return MessageContent::Text(json.to_string());
+        }
         let mut parts = Vec::new();
         for value in result.output.values.into_iter() {
             match value {
@@ -528,6 +538,9 @@ impl From<ToolResult> for MessageContent {
                     };
                     parts.push(content);
                 }
+                ToolValue::Json(json) => {
+                    parts.push(ContentPart::Text { text: json.to_string(), cache_control: None });
+                }
                 ToolValue::Empty => {
                     // Handle empty case if needed
                 }
@@ -566,6 +579,18 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_json_tool_result_is_sent_as_json_text() {
+        let files = serde_json::json!({"files": ["a.rs", "b.rs"]});
+        let fixture =
+            ToolResult::new("search").output(Ok(forge_domain::ToolOutput::json(files.clone())));
+
+        let actual = MessageContent::from(fixture);
+
+        let expected = MessageContent::Text(files.to_string());
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_cached_text_true() {
         let fixture = MessageContent::Text("hello".to_string());
@@ -903,6 +928,20 @@ mod tests {
         let actual = Request::from(fixture);
 
         assert_eq!(actual.stream, Some(false));
+        assert!(actual.stream_options.is_none());
+    }
+
+    #[test]
+    fn test_context_conversion_sampling_parameters() {
+        let fixture = forge_domain::Context::default()
+            .top_k(forge_domain::TopK::new(40).unwrap())
+            .min_p(forge_domain::MinP::new(0.05).unwrap())
+            .repetition_penalty(forge_domain::RepetitionPenalty::new(1.1).unwrap());
+        let actual = Request::from(fixture);
+
+        assert_eq!(actual.top_k, Some(40));
+        assert_eq!(actual.min_p, Some(0.05));
+        assert_eq!(actual.repetition_penalty, Some(1.1));
     }
 
     #[test]
@@ -43,6 +43,9 @@ pub enum Response {
         usage: Option<ResponseUsage>,
         #[serde(default)]
         prompt_filter_results: Option<Vec<PromptFilterResult>>,
+        /// Sources used by Perplexity's online search
+        #[serde(default)]
+        citations: Option<Vec<String>>,
     },
     CostOnly {
         choices: Vec<Choice>,
@@ -323,7 +326,7 @@ impl TryFrom<Response> for ChatCompletionMessage {
 
     fn try_from(res: Response) -> Result<Self, Self::Error> {
         match res {
-            Response::Success { choices, usage, prompt_filter_results, .. } => {
+            Response::Success { choices, usage, prompt_filter_results, citations, .. } => {
                 if let Some(choice) = choices.first() {
                     // Check if the choice has an error first
                     let error = match choice {
@@ -477,6 +480,12 @@ impl TryFrom<Response> for ChatCompletionMessage {
                     if let Some(usage) = usage {
                         response.usage = Some(usage.into());
                     }
+                    if let Some(citations) = citations {
+                        response.citations = citations
+                            .iter()
+                            .filter_map(|citation| url::Url::parse(citation).ok())
+                            .collect();
+                    }
                     Ok(response)
                 } else {
                     // Check if content was filtered
@@ -713,6 +722,7 @@ mod tests {
             system_fingerprint: None,
             usage: None,
             prompt_filter_results: None,
+            citations: None,
         };
 
         let result = ChatCompletionMessage::try_from(response);
@@ -753,6 +763,7 @@ mod tests {
             system_fingerprint: None,
             usage: None,
             prompt_filter_results: None,
+            citations: None,
         };
 
         let result = ChatCompletionMessage::try_from(response);
@@ -791,6 +802,7 @@ mod tests {
             system_fingerprint: None,
             usage: None,
             prompt_filter_results: None,
+            citations: None,
         };
 
         let result = ChatCompletionMessage::try_from(response);
@@ -829,6 +841,7 @@ mod tests {
             system_fingerprint: None,
             usage: None,
             prompt_filter_results: None,
+            citations: None,
         };
 
         let result = ChatCompletionMessage::try_from(response);
@@ -849,6 +862,7 @@ mod tests {
             system_fingerprint: None,
             usage: None,
             prompt_filter_results: None,
+            citations: None,
         };
 
         let result = ChatCompletionMessage::try_from(response);
@@ -1062,6 +1076,7 @@ mod tests {
                     violence: Some(FilterResult { filtered: false, severity: "safe".to_string() }),
                 },
             }]),
+            citations: None,
         };
 
         let result = ChatCompletionMessage::try_from(response);
@@ -1117,4 +1132,22 @@ mod tests {
             actual.err()
         );
     }
+
+    #[test]
+    fn test_perplexity_citations() {
+        let fixture = r#"{"id":"3c90c3cc","model":"sonar","created":1724369245,"object":"chat.completion","citations":["https://www.rust-lang.org/","https://blog.rust-lang.org/2015/05/15/Rust-1.0.html","not a url"],"choices":[{"index":0,"finish_reason":"stop","message":{"role":"assistant","content":"Rust 1.0 was released in May 2015 [1][2]."}}]}"#;
+        let response = serde_json::from_str::<Response>(fixture).unwrap();
+
+        let actual = ChatCompletionMessage::try_from(response).unwrap();
+
+        let expected = vec![
+            url::Url::parse("https://www.rust-lang.org/").unwrap(),
+            url::Url::parse("https://blog.rust-lang.org/2015/05/15/Rust-1.0.html").unwrap(),
+        ];
+        assert_eq!(actual.citations, expected);
+        assert_eq!(
+            actual.content.unwrap().as_str(),
+            "Rust 1.0 was released in May 2015 [1][2]."
+        );
+    }
 }
@@ -0,0 +1,65 @@
+use forge_domain::Transformer;
+
+use super::make_openai_compat::MakeOpenAiCompat;
+use crate::dto::openai::Request;
+
+/// makes the Request compatible with the Together AI API.
+///
+/// Together accepts the OpenAI request format plus the open-source sampling
+/// parameters, so those are kept while everything else OpenAI rejects is
+/// stripped.
+pub struct MakeTogetherCompat;
+
+impl Transformer for MakeTogetherCompat {
+    type Value = Request;
+
+    fn transform(&mut self, request: Self::Value) -> Self::Value {
+        let top_k = request.top_k;
+        let top_p = request.top_p;
+        let min_p = request.min_p;
+        let repetition_penalty = request.repetition_penalty;
+        let max_tokens = request.max_tokens;
+
+        let request = MakeOpenAiCompat.transform(request);
+
+        // Together still expects `max_tokens` rather than
+        // `max_completion_tokens`.
+        Request {
+            top_k,
+            top_p,
+            min_p,
+            repetition_penalty,
+            max_tokens,
+            max_completion_tokens: None,
+            ..request
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_sampling_parameters_kept() {
+        let fixture = Request::default()
+            .top_k(40u32)
+            .top_p(0.9)
+            .min_p(0.05)
+            .repetition_penalty(1.1)
+            .max_tokens(1024u32)
+            .session_id("session".to_string());
+        let mut transformer = MakeTogetherCompat;
+        let actual = transformer.transform(fixture);
+
+        assert_eq!(actual.top_k, Some(40));
+        assert_eq!(actual.top_p, Some(0.9));
+        assert_eq!(actual.min_p, Some(0.05));
+        assert_eq!(actual.repetition_penalty, Some(1.1));
+        assert_eq!(actual.max_tokens, Some(1024));
+        assert_eq!(actual.max_completion_tokens, None);
+        assert_eq!(actual.session_id, None);
+    }
+}
@@ -4,9 +4,11 @@ mod ensure_system_first;
 mod github_copilot_reasoning;
 mod make_cerebras_compat;
 mod make_openai_compat;
+mod make_together_compat;
 mod make_xai_compat;
 mod minimax;
 mod normalize_tool_schema;
+mod perplexity;
 mod pipeline;
 mod reasoning_content;
 mod set_cache;
@@ -0,0 +1,54 @@
+use forge_domain::Transformer;
+
+use crate::dto::openai::Request;
+
+/// Transformer that enables recency filtering for Perplexity's online search
+///
+/// The filter comes from the optional `PERPLEXITY_SEARCH_RECENCY_FILTER`
+/// provider parameter. When it is not configured the request is left
+/// untouched and Perplexity searches without a time window.
+pub struct SetSearchRecencyFilter {
+    pub filter: Option<String>,
+}
+
+impl SetSearchRecencyFilter {
+    pub fn new(filter: Option<String>) -> Self {
+        Self { filter }
+    }
+}
+
+impl Transformer for SetSearchRecencyFilter {
+    type Value = Request;
+
+    fn transform(&mut self, mut request: Self::Value) -> Self::Value {
+        if let Some(filter) = self.filter.as_ref().filter(|f| !f.is_empty()) {
+            request.search_recency_filter = Some(filter.clone());
+        }
+        request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_sets_search_recency_filter() {
+        let fixture = Request::default();
+        let mut transformer = SetSearchRecencyFilter::new(Some("week".to_string()));
+        let actual = transformer.transform(fixture);
+
+        assert_eq!(actual.search_recency_filter, Some("week".to_string()));
+    }
+
+    #[test]
+    fn test_missing_filter_leaves_request_untouched() {
+        let fixture = Request::default();
+        let mut transformer = SetSearchRecencyFilter::new(None);
+        let actual = transformer.transform(fixture);
+
+        assert_eq!(actual.search_recency_filter, None);
+    }
+}
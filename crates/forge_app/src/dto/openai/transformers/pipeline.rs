@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use forge_domain::{DefaultTransformation, Provider, ProviderId, Transformer};
+use forge_domain::{DefaultTransformation, Provider, ProviderId, Transformer, URLParam};
 use url::Url;
 
 use super::default_reasoning_content::DefaultReasoningContent;
@@ -9,11 +9,13 @@ use super::ensure_system_first::MergeSystemMessages;
 use super::github_copilot_reasoning::GitHubCopilotReasoning;
 use super::make_cerebras_compat::MakeCerebrasCompat;
 use super::make_openai_compat::MakeOpenAiCompat;
+use super::make_together_compat::MakeTogetherCompat;
 use super::make_xai_compat::MakeXaiCompat;
 use super::minimax::SetMinimaxParams;
 use super::normalize_tool_schema::{
     EnforceStrictResponseFormatSchema, EnforceStrictToolSchema, NormalizeToolSchema,
 };
+use super::perplexity::SetSearchRecencyFilter;
 use super::reasoning_content::ReasoningContent;
 use super::set_cache::SetCache;
 use super::set_reasoning_effort::SetReasoningEffort;
@@ -65,7 +67,25 @@ impl Transformer for ProviderPipeline<'_> {
         let strip_thought_signature =
             StripThoughtSignature.when(move |req: &Request| !is_gemini3_model(req));
 
-        let open_ai_compat = MakeOpenAiCompat.when(move |_| !supports_open_router_params(provider));
+        let open_ai_compat = MakeOpenAiCompat.when(move |_| {
+            !supports_open_router_params(provider) && provider.id != ProviderId::TOGETHER_AI
+        });
+
+        let together_compat =
+            MakeTogetherCompat.when(move |_| provider.id == ProviderId::TOGETHER_AI);
+
+        let search_recency_filter = SetSearchRecencyFilter::new(
+            provider
+                .credential
+                .as_ref()
+                .and_then(|c| {
+                    c.url_params.get(&URLParam::from(
+                        "PERPLEXITY_SEARCH_RECENCY_FILTER".to_string(),
+                    ))
+                })
+                .map(|v| v.to_string()),
+        )
+        .when(move |_| provider.id == ProviderId::PERPLEXITY);
 
         let set_reasoning_effort = SetReasoningEffort.when(move |request: &Request| {
             provider.id == ProviderId::REQUESTY
@@ -118,6 +138,8 @@ impl Transformer for ProviderPipeline<'_> {
             .pipe(strip_thought_signature)
             .pipe(set_reasoning_effort)
             .pipe(open_ai_compat)
+            .pipe(together_compat)
+            .pipe(search_recency_filter)
             .pipe(github_copilot_reasoning)
             .pipe(reasoning_content)
             .pipe(default_reasoning_content)
@@ -202,6 +224,8 @@ mod tests {
                 key.to_string(),
             )),
             url_params: HashMap::new(),
+            alias: None,
+            expires_at: None,
         })
     }
 
@@ -595,6 +619,60 @@ mod tests {
         assert_eq!(actual.reasoning, None);
     }
 
+    #[test]
+    fn test_together_ai_provider_keeps_sampling_parameters() {
+        let provider = Provider {
+            id: ProviderId::TOGETHER_AI,
+            provider_type: Default::default(),
+            response: Some(ProviderResponse::OpenAI),
+            url: Url::parse("https://api.together.xyz/v1/chat/completions").unwrap(),
+            auth_methods: vec![forge_domain::AuthMethod::ApiKey],
+            url_params: vec![],
+            credential: make_credential(ProviderId::TOGETHER_AI, "together"),
+            custom_headers: None,
+            models: Some(ModelSource::Url(
+                Url::parse("https://api.together.xyz/v1/models").unwrap(),
+            )),
+        };
+        let fixture = Request::default()
+            .min_p(0.05)
+            .repetition_penalty(1.1)
+            .session_id("session".to_string());
+
+        let mut pipeline = ProviderPipeline::new(&provider, false);
+        let actual = pipeline.transform(fixture);
+
+        assert_eq!(actual.min_p, Some(0.05));
+        assert_eq!(actual.repetition_penalty, Some(1.1));
+        assert_eq!(actual.session_id, None);
+    }
+
+    #[test]
+    fn test_perplexity_provider_sets_search_recency_filter() {
+        let mut credential = make_credential(ProviderId::PERPLEXITY, "perplexity").unwrap();
+        credential.url_params.insert(
+            "PERPLEXITY_SEARCH_RECENCY_FILTER".to_string().into(),
+            "week".to_string().into(),
+        );
+        let provider = Provider {
+            id: ProviderId::PERPLEXITY,
+            provider_type: Default::default(),
+            response: Some(ProviderResponse::OpenAI),
+            url: Url::parse("https://api.perplexity.ai/chat/completions").unwrap(),
+            auth_methods: vec![forge_domain::AuthMethod::ApiKey],
+            url_params: vec![],
+            credential: Some(credential),
+            custom_headers: None,
+            models: None,
+        };
+        let fixture = Request::default();
+
+        let mut pipeline = ProviderPipeline::new(&provider, false);
+        let actual = pipeline.transform(fixture);
+
+        assert_eq!(actual.search_recency_filter, Some("week".to_string()));
+    }
+
     #[test]
     fn test_openai_provider_trims_tool_call_ids() {
         let provider = openai("openai");
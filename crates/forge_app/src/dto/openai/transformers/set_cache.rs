@@ -84,6 +84,8 @@ mod tests {
             temperature: None,
             top_p: None,
             top_k: None,
+            min_p: None,
+            repetition_penalty: None,
             reasoning: None,
             stream: None,
             response_format: None,
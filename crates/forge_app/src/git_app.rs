@@ -1,8 +1,10 @@
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 
 use anyhow::{Context, Result};
+use forge_config::CommitFormat;
 use forge_domain::*;
+use regex::Regex;
 use schemars::JsonSchema;
 use serde::Deserialize;
 
@@ -17,6 +19,35 @@ use crate::{AgentProviderResolver, EnvironmentInfra, Services};
 pub enum GitAppError {
     #[error("nothing to commit, working tree clean")]
     NoChangesToCommit,
+    #[error(
+        "Generated commit message does not follow Conventional Commits format: {0}\nExpected `type(scope): description` where type is one of feat, fix, docs, style, refactor, test, chore"
+    )]
+    NonConventionalMessage(String),
+    #[error(
+        "Failed to GPG-sign the commit: {0}\nMake sure a signing key is configured with `git config user.signingkey <key-id>` and that `gpg` can access it"
+    )]
+    SigningFailed(String),
+}
+
+/// Matches the first line of a Conventional Commits message.
+static CONVENTIONAL_COMMIT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(feat|fix|docs|style|refactor|test|chore)(\(.+\))?: .+").unwrap()
+});
+
+/// Validates a generated commit message against the requested format.
+///
+/// # Errors
+///
+/// Returns [`GitAppError::NonConventionalMessage`] when `format` is
+/// [`CommitFormat::Conventional`] and the message does not match.
+fn validate_commit_message(message: &str, format: CommitFormat) -> Result<()> {
+    match format {
+        CommitFormat::Free => Ok(()),
+        CommitFormat::Conventional if CONVENTIONAL_COMMIT.is_match(message) => Ok(()),
+        CommitFormat::Conventional => {
+            Err(GitAppError::NonConventionalMessage(message.to_string()).into())
+        }
+    }
 }
 
 /// GitApp handles git-related operations like commit message generation.
@@ -63,6 +94,7 @@ struct DiffContext {
     recent_commits: String,
     has_staged_files: bool,
     additional_context: Option<String>,
+    format: CommitFormat,
 }
 
 impl<S> GitApp<S> {
@@ -104,20 +136,25 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> GitApp<
     ///   diff is used instead of fetching from git.
     /// * `additional_context` - Optional additional text to help structure the
     ///   commit message
+    /// * `format` - Format the generated message must follow
     ///
     /// # Errors
     ///
-    /// Returns an error if git operations fail or AI generation fails
+    /// Returns an error if git operations fail, AI generation fails or the
+    /// generated message does not match `format`
     pub async fn commit_message(
         &self,
         max_diff_size: Option<usize>,
         diff: Option<String>,
         additional_context: Option<String>,
+        format: CommitFormat,
     ) -> Result<CommitResult> {
         let CommitMessageDetails { message, has_staged_files } = self
-            .generate_commit_message(max_diff_size, diff, additional_context)
+            .generate_commit_message(max_diff_size, diff, additional_context, format)
             .await?;
 
+        validate_commit_message(&message, format)?;
+
         Ok(CommitResult {
             message,
             committed: false,
@@ -138,19 +175,23 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> GitApp<
     /// * `has_staged_files` - Whether there are staged files
     /// * `use_forge_committer` - Whether to override the Git committer with
     ///   ForgeCode identity
+    /// * `sign` - Whether to GPG-sign the commit by passing `-S` to git
     ///
     /// # Errors
     ///
-    /// Returns an error if git commit fails
+    /// Returns an error if git commit fails, or
+    /// [`GitAppError::SigningFailed`] if signing was requested and git could
+    /// not sign the commit
     pub async fn commit(
         &self,
         message: String,
         has_staged_files: bool,
         use_forge_committer: bool,
+        sign: bool,
     ) -> Result<CommitResult> {
         let cwd = self.services.get_environment().cwd;
-        let flags = if has_staged_files { "" } else { " -a" };
-        let commit_command = build_commit_command(&message, flags, use_forge_committer);
+        let flags = commit_flags(has_staged_files, sign);
+        let commit_command = build_commit_command(&message, &flags, use_forge_committer);
 
         let commit_result = self
             .services
@@ -159,7 +200,11 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> GitApp<
             .context("Failed to commit changes")?;
 
         if !commit_result.output.success() {
-            anyhow::bail!("Git commit failed: {}", commit_result.output.stderr);
+            let stderr = commit_result.output.stderr;
+            if sign && is_signing_failure(&stderr) {
+                return Err(GitAppError::SigningFailed(stderr.trim().to_string()).into());
+            }
+            anyhow::bail!("Git commit failed: {}", stderr);
         }
 
         // Combine stdout and stderr for logging
@@ -184,6 +229,7 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> GitApp<
         max_diff_size: Option<usize>,
         diff: Option<String>,
         additional_context: Option<String>,
+        format: CommitFormat,
     ) -> Result<CommitMessageDetails> {
         // Get current working directory
         let cwd = self.services.get_environment().cwd;
@@ -210,6 +256,7 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> GitApp<
             recent_commits,
             has_staged_files,
             additional_context,
+            format,
         };
 
         let retry_config = self.services.get_config()?.retry.unwrap_or_default();
@@ -341,7 +388,10 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> GitApp<
 
         let rendered_prompt = self
             .services
-            .render_template(Template::new("{{> forge-commit-message-prompt.md }}"), &())
+            .render_template(
+                Template::new("{{> forge-commit-message-prompt.md }}"),
+                &serde_json::json!({ "conventional": ctx.format == CommitFormat::Conventional }),
+            )
             .await?;
 
         // Build user message using structured JSON format
@@ -388,6 +438,25 @@ impl<S: Services + EnvironmentInfra<Config = forge_config::ForgeConfig>> GitApp<
     }
 }
 
+/// Builds the flags appended to `git commit`: `-a` when nothing is staged and
+/// `-S` when the commit should be GPG-signed.
+fn commit_flags(has_staged_files: bool, sign: bool) -> String {
+    let mut flags = String::new();
+    if !has_staged_files {
+        flags.push_str(" -a");
+    }
+    if sign {
+        flags.push_str(" -S");
+    }
+    flags
+}
+
+/// Returns true when git's stderr indicates that GPG signing failed.
+fn is_signing_failure(stderr: &str) -> bool {
+    let stderr = stderr.to_lowercase();
+    stderr.contains("gpg") || stderr.contains("signing")
+}
+
 /// Builds the `git commit` shell command string.
 ///
 /// When `use_forge_committer` is true, prefixes the command with
@@ -439,6 +508,71 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_commit_flags_with_sign() {
+        let actual = (commit_flags(true, true), commit_flags(false, true));
+        let expected = (" -S".to_string(), " -a -S".to_string());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_build_commit_command_with_sign() {
+        let actual = build_commit_command("feat: sign", &commit_flags(true, true), false);
+        let expected = "git commit  -S -m 'feat: sign'";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_is_signing_failure_without_gpg_configured() {
+        let fixture = "error: gpg failed to sign the data\nfatal: failed to write commit object";
+        let actual = is_signing_failure(fixture);
+        let expected = true;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_is_signing_failure_other_error() {
+        let fixture = "fatal: not a git repository";
+        let actual = is_signing_failure(fixture);
+        let expected = false;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_signing_failed_error_mentions_gpg_configuration() {
+        let fixture = GitAppError::SigningFailed("error: gpg failed to sign the data".into());
+        let actual = fixture.to_string().contains("git config user.signingkey");
+        let expected = true;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_validate_commit_message_free_accepts_anything() {
+        let actual = validate_commit_message("Update stuff", CommitFormat::Free).is_ok();
+        let expected = true;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_validate_commit_message_conventional_accepts_scoped_type() {
+        let actual =
+            validate_commit_message("feat(auth): add login", CommitFormat::Conventional).is_ok();
+        let expected = true;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_validate_commit_message_conventional_rejects_plain_message() {
+        let fixture = "Add new login flow";
+        let actual = validate_commit_message(fixture, CommitFormat::Conventional)
+            .unwrap_err()
+            .downcast::<GitAppError>()
+            .unwrap();
+        assert!(
+            matches!(actual, GitAppError::NonConventionalMessage(message) if message == fixture)
+        );
+    }
+
     #[test]
     fn test_build_commit_command_escapes_single_quotes() {
         let actual = build_commit_command("feat: it's done", "", true);
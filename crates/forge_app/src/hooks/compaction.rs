@@ -31,11 +31,15 @@ impl CompactionHandler {
 impl EventHandle<EventData<ResponsePayload>> for CompactionHandler {
     async fn handle(
         &self,
-        _event: &EventData<ResponsePayload>,
+        event: &EventData<ResponsePayload>,
         conversation: &mut Conversation,
     ) -> anyhow::Result<()> {
         if let Some(context) = &conversation.context {
-            let token_count = context.token_count();
+            // The latest response reports the usage of the request that produced
+            // it, which is more accurate than anything recorded in the context.
+            let token_count = context
+                .token_count()
+                .max(event.payload.message.usage.total_tokens);
             if self.agent.compact.should_compact(context, *token_count) {
                 info!(agent_id = %self.agent.id, "Compaction triggered by hook");
                 let compacted =
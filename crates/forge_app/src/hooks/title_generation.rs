@@ -6,6 +6,7 @@ use forge_domain::{
     Conversation, ConversationId, EndPayload, EventData, EventHandle, StartPayload,
 };
 use tokio::sync::oneshot;
+use tokio::sync::oneshot::error::TryRecvError;
 use tokio::task::JoinHandle;
 
 use crate::agent::AgentService;
@@ -89,13 +90,29 @@ impl<S: AgentService> EventHandle<EventData<EndPayload>> for TitleGenerationHand
         conversation: &mut Conversation,
     ) -> anyhow::Result<()> {
         if let Some((_, entry)) = self.title_tasks.remove(&conversation.id) {
-            let handle = &entry.handle;
-            let rx = entry.rx;
-
-            if rx.is_empty() {
-                handle.abort();
-            } else if let Some(title) = rx.await? {
-                conversation.title = Some(title);
+            let TitleGenerationState { mut rx, handle: _ } = entry;
+
+            match rx.try_recv() {
+                Ok(title) => {
+                    if let Some(title) = title {
+                        conversation.title = Some(title);
+                    }
+                }
+                Err(TryRecvError::Empty) => {
+                    // The title is still being generated. Rather than holding up
+                    // the end of the turn, let the task finish in the background
+                    // and persist the title once it arrives.
+                    let services = self.services.clone();
+                    let id = conversation.id;
+                    tokio::spawn(async move {
+                        if let Ok(Some(title)) = rx.await
+                            && let Err(error) = services.update_title(&id, title).await
+                        {
+                            tracing::warn!(conversation_id = %id, error = ?error, "Failed to save generated title");
+                        }
+                    });
+                }
+                Err(TryRecvError::Closed) => {}
             }
         }
 
@@ -123,15 +140,22 @@ mod tests {
     use std::time::Duration;
 
     use forge_domain::{
-        Agent, ChatCompletionMessage, Context, ContextMessage, Conversation, EventValue, ModelId,
-        ProviderId, Role, TextMessage, ToolCallContext, ToolCallFull, ToolResult,
+        Agent, ChatCompletionMessage, Content, Context, ContextMessage, Conversation, EventValue,
+        FinishReason, ModelId, ProviderId, Role, TextMessage, ToolCallContext, ToolCallFull,
+        ToolResult,
     };
     use pretty_assertions::assert_eq;
+    use tokio::sync::Mutex;
 
     use super::*;
 
-    #[derive(Clone)]
-    struct MockAgentService;
+    /// Agent service whose title generation takes `delay` and that records
+    /// titles saved through `update_title`.
+    #[derive(Clone, Default)]
+    struct MockAgentService {
+        delay: Duration,
+        saved_titles: Arc<Mutex<Vec<(ConversationId, String)>>>,
+    }
 
     #[async_trait]
     impl AgentService for MockAgentService {
@@ -141,7 +165,10 @@ mod tests {
             _context: Context,
             _provider_id: Option<ProviderId>,
         ) -> forge_domain::ResultStream<ChatCompletionMessage, anyhow::Error> {
-            Ok(Box::pin(futures::stream::empty()))
+            tokio::time::sleep(self.delay).await;
+            let message = ChatCompletionMessage::assistant(Content::full("Slow title"))
+                .finish_reason(FinishReason::Stop);
+            Ok(Box::pin(futures::stream::iter([Ok(message)])))
         }
 
         async fn call(
@@ -156,10 +183,22 @@ mod tests {
         async fn update(&self, _conversation: Conversation) -> anyhow::Result<()> {
             Ok(())
         }
+
+        async fn update_title(&self, id: &ConversationId, title: String) -> anyhow::Result<()> {
+            self.saved_titles.lock().await.push((*id, title));
+            Ok(())
+        }
     }
 
     fn setup(message: &str) -> (TitleGenerationHandler<MockAgentService>, Conversation) {
-        let handler = TitleGenerationHandler::new(Arc::new(MockAgentService));
+        setup_with_service(message, MockAgentService::default())
+    }
+
+    fn setup_with_service(
+        message: &str,
+        service: MockAgentService,
+    ) -> (TitleGenerationHandler<MockAgentService>, Conversation) {
+        let handler = TitleGenerationHandler::new(Arc::new(service));
         let context = Context::default().add_message(ContextMessage::Text(
             TextMessage::new(Role::User, message).raw_content(EventValue::text(message)),
         ));
@@ -250,10 +289,10 @@ mod tests {
         assert!(!handler.title_tasks.contains_key(&conversation.id));
     }
 
-    /// When EndPayload is received, the spawned task should be aborted so it
-    /// doesn't continue running unnecessarily.
+    /// When EndPayload is received before the title is ready, the handler
+    /// must not wait for the in-progress task.
     #[tokio::test]
-    async fn test_end_aborts_in_progress_task() {
+    async fn test_end_does_not_wait_for_in_progress_task() {
         let (handler, mut conversation) = setup("test message");
         let (tx, rx) = oneshot::channel::<Option<String>>();
         let handle = tokio::spawn(async move {
@@ -274,9 +313,49 @@ mod tests {
         // Entry should have been removed from map
         assert!(!handler.title_tasks.contains_key(&conversation.id));
 
-        // Verify the task is no longer running by checking that the
-        // EndPayload handler didn't hang (it completed immediately).
+        // The EndPayload handler didn't hang (it completed immediately).
+        assert!(conversation.title.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_start_does_not_wait_for_slow_title() {
+        let service = MockAgentService { delay: Duration::from_millis(200), ..Default::default() };
+        let (handler, mut conversation) = setup_with_service("test message", service);
+
+        let started = std::time::Instant::now();
+        handler
+            .handle(&event(StartPayload), &mut conversation)
+            .await
+            .unwrap();
+        let actual = started.elapsed();
+
+        assert!(actual < Duration::from_millis(50), "start took {actual:?}");
+        assert!(handler.title_tasks.contains_key(&conversation.id));
+    }
+
+    #[tokio::test]
+    async fn test_end_saves_title_generated_after_turn() {
+        let service = MockAgentService { delay: Duration::from_millis(200), ..Default::default() };
+        let saved_titles = service.saved_titles.clone();
+        let (handler, mut conversation) = setup_with_service("test message", service);
+
+        handler
+            .handle(&event(StartPayload), &mut conversation)
+            .await
+            .unwrap();
+        handler
+            .handle(&event(EndPayload), &mut conversation)
+            .await
+            .unwrap();
         assert!(conversation.title.is_none());
+
+        // Dropping the handler must not cancel the detached generation.
+        drop(handler);
+        tokio::time::sleep(Duration::from_millis(400)).await;
+
+        let actual = saved_titles.lock().await.clone();
+        let expected = vec![(conversation.id, "Slow title".to_string())];
+        assert_eq!(actual, expected);
     }
 
     /// Many concurrent StartPayload calls for the same conversation id must
@@ -1,9 +1,14 @@
+use std::sync::Arc;
+use std::time::Instant;
+
 use async_trait::async_trait;
+use dashmap::DashMap;
 use forge_domain::{
     Conversation, EndPayload, EventData, EventHandle, RequestPayload, ResponsePayload,
-    StartPayload, ToolcallEndPayload, ToolcallStartPayload,
+    StartPayload, ToolCallFull, ToolcallEndPayload, ToolcallStartPayload,
 };
-use tracing::{debug, info, warn};
+use tracing::field::Empty;
+use tracing::{Span, debug, info, info_span, warn};
 
 /// Handler that provides comprehensive tracing/logging for all lifecycle events
 ///
@@ -12,15 +17,30 @@ use tracing::{debug, info, warn};
 /// - Start: Logs conversation and agent initialization
 /// - Request: Logs each request iteration
 /// - Response: Logs token usage, costs, and conversation metrics
-/// - ToolcallStart: Logs tool execution start
-/// - ToolcallEnd: Logs tool failures with details
+/// - ToolcallStart: Logs tool execution start and opens a `tool_call` span
+/// - ToolcallEnd: Logs tool failures with details and closes the span
 /// - End: Logs title generation when available
-#[derive(Clone)]
-pub struct TracingHandler;
+///
+/// The `tool_call` span carries the `tool.name`, `tool.success` and
+/// `tool.duration_ms` attributes so that it can be exported as an
+/// OpenTelemetry span when an OTLP exporter is configured.
+#[derive(Clone, Default)]
+pub struct TracingHandler {
+    spans: Arc<DashMap<String, (Span, Instant)>>,
+}
 
 impl TracingHandler {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Key identifying a tool call between its start and end events
+    fn span_key(tool_call: &ToolCallFull) -> String {
+        tool_call
+            .call_id
+            .as_ref()
+            .map(|id| id.as_str().to_string())
+            .unwrap_or_else(|| tool_call.name.to_string())
     }
 }
 
@@ -104,6 +124,15 @@ impl EventHandle<EventData<ToolcallStartPayload>> for TracingHandler {
             "Tool call started"
         );
 
+        let span = info_span!(
+            "tool_call",
+            tool.name = %tool_call.name,
+            tool.success = Empty,
+            tool.duration_ms = Empty,
+        );
+        self.spans
+            .insert(Self::span_key(tool_call), (span, Instant::now()));
+
         Ok(())
     }
 }
@@ -118,6 +147,11 @@ impl EventHandle<EventData<ToolcallEndPayload>> for TracingHandler {
         let tool_call = &event.payload.tool_call;
         let result = &event.payload.result;
 
+        if let Some((_, (span, started_at))) = self.spans.remove(&Self::span_key(tool_call)) {
+            span.record("tool.success", !result.is_error());
+            span.record("tool.duration_ms", started_at.elapsed().as_millis() as u64);
+        }
+
         if result.is_error() {
             warn!(
                 agent_id = %event.agent.id,
@@ -157,6 +191,11 @@ mod tests {
     use forge_domain::{
         Agent, ChatCompletionMessageFull, ModelId, ToolCallId, ToolName, ToolResult,
     };
+    use opentelemetry::Value;
+    use opentelemetry::trace::TracerProvider;
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider};
+    use pretty_assertions::assert_eq;
+    use tracing_subscriber::layer::SubscriberExt;
 
     use super::*;
 
@@ -244,4 +283,62 @@ mod tests {
         // Should log debug message with title
         handler.handle(&event, &mut conversation).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_tracing_handler_exports_tool_call_span() {
+        let exporter = InMemorySpanExporter::default();
+        let provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let handler = TracingHandler::new();
+        let mut conversation = Conversation::generate();
+        let tool_call = ToolCallFull {
+            name: ToolName::from("read"),
+            call_id: Some(ToolCallId::new("call-1")),
+            arguments: serde_json::json!({"path": "a.txt"}).into(),
+            thought_signature: None,
+        };
+        let result = ToolResult::new(ToolName::from("read")).call_id(ToolCallId::new("call-1"));
+        let start = EventData::new(
+            test_agent(),
+            test_model_id(),
+            ToolcallStartPayload::new(tool_call.clone()),
+        );
+        let end = EventData::new(
+            test_agent(),
+            test_model_id(),
+            ToolcallEndPayload::new(tool_call, result),
+        );
+
+        handler.handle(&start, &mut conversation).await.unwrap();
+        handler.handle(&end, &mut conversation).await.unwrap();
+
+        let spans = exporter.get_finished_spans().unwrap();
+        let actual: Vec<_> = spans
+            .iter()
+            .map(|span| {
+                let attribute = |key: &str| {
+                    span.attributes
+                        .iter()
+                        .find(|kv| kv.key.as_str() == key)
+                        .map(|kv| kv.value.clone())
+                };
+                (
+                    span.name.to_string(),
+                    attribute("tool.name"),
+                    attribute("tool.success"),
+                )
+            })
+            .collect();
+        let expected = vec![(
+            "tool_call".to_string(),
+            Some(Value::from("read")),
+            Some(Value::Bool(true)),
+        )];
+        assert_eq!(actual, expected);
+    }
 }
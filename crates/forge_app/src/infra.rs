@@ -9,6 +9,7 @@ use forge_domain::{
     OAuthConfig, OAuthTokenResponse, ToolDefinition, ToolName, ToolOutput,
 };
 use forge_eventsource::EventSource;
+use forge_stream::MpscStream;
 use reqwest::Response;
 use reqwest::header::HeaderMap;
 use serde::de::DeserializeOwned;
@@ -317,6 +318,25 @@ pub trait KVStore: Send + Sync {
     async fn cache_clear(&self) -> Result<()>;
 }
 
+/// Access to the platform's secure credential storage (keychain).
+///
+/// Secrets are stored as strings under an account name scoped to the
+/// application.
+#[async_trait::async_trait]
+pub trait KeychainInfra: Send + Sync {
+    /// Reads the secret stored for `account`, or `None` if there is none.
+    ///
+    /// # Errors
+    /// Returns an error if the keychain is unavailable or locked
+    async fn get_password(&self, account: &str) -> anyhow::Result<Option<String>>;
+
+    /// Stores `password` for `account`, replacing any previous secret.
+    ///
+    /// # Errors
+    /// Returns an error if the keychain is unavailable or locked
+    async fn set_password(&self, account: &str, password: &str) -> anyhow::Result<()>;
+}
+
 /// Provides HTTP features for OAuth authentication flows.
 #[async_trait::async_trait]
 pub trait OAuthHttpProvider: Send + Sync {
@@ -402,6 +422,10 @@ pub trait AgentRepository: Send + Sync {
     /// Load lightweight metadata for all agents without requiring a configured
     /// provider or model.
     async fn get_agent_infos(&self) -> anyhow::Result<Vec<forge_domain::AgentInfo>>;
+
+    /// Watch the custom agent directories, yielding the paths of agent files
+    /// that were created, modified or removed.
+    async fn watch_agents(&self) -> anyhow::Result<MpscStream<Vec<PathBuf>>>;
 }
 
 /// Infrastructure trait for providing shared gRPC channel
@@ -6,6 +6,7 @@ mod apply_tunable_parameters;
 mod changed_files;
 mod command_generator;
 mod compact;
+mod config_validation;
 mod data_gen;
 pub mod dto;
 mod error;
@@ -43,6 +44,7 @@ pub use agent::*;
 pub use agent_provider_resolver::*;
 pub use app::*;
 pub use command_generator::*;
+pub use config_validation::*;
 pub use data_gen::*;
 pub use error::*;
 pub use git_app::*;
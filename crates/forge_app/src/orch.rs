@@ -8,9 +8,10 @@ use forge_domain::{Agent, *};
 use forge_template::Element;
 use futures::future::join_all;
 use tokio::sync::Notify;
-use tracing::warn;
+use tracing::{info, warn};
 
 use crate::agent::AgentService;
+use crate::compact::Compactor;
 use crate::transformers::{DropReasoningOnlyMessages, ModelSpecificReasoning};
 use crate::{EnvironmentInfra, TemplateEngine};
 
@@ -26,6 +27,7 @@ pub struct Orchestrator<S> {
     error_tracker: ToolErrorTracker,
     hook: Arc<Hook>,
     config: forge_config::ForgeConfig,
+    request_metadata: std::collections::HashMap<String, serde_json::Value>,
 }
 
 impl<S: AgentService + EnvironmentInfra<Config = forge_config::ForgeConfig>> Orchestrator<S> {
@@ -45,6 +47,7 @@ impl<S: AgentService + EnvironmentInfra<Config = forge_config::ForgeConfig>> Orc
             models: Default::default(),
             error_tracker: Default::default(),
             hook: Arc::new(Hook::default()),
+            request_metadata: Default::default(),
         }
     }
 
@@ -173,6 +176,49 @@ impl<S: AgentService + EnvironmentInfra<Config = forge_config::ForgeConfig>> Orc
         Ok(())
     }
 
+    /// Applies the agent's [`ContextPruner`] when the context exceeds the
+    /// pruning budget of the selected model's context window.
+    async fn prune_context(&self, mut context: Context) -> anyhow::Result<Context> {
+        let Some(context_window) = self
+            .models
+            .iter()
+            .find(|model| model.id == self.agent.model)
+            .and_then(|model| model.context_length)
+        else {
+            return Ok(context);
+        };
+        let budget = ContextPruner::budget(context_window);
+        let token_count = *context.token_count();
+        if token_count <= budget {
+            return Ok(context);
+        }
+
+        let token_count = match self.agent.pruner {
+            ContextPruner::Compact => return Ok(context),
+            ContextPruner::OldestFirst => token_count,
+            ContextPruner::SummarizeAndPrune => {
+                context =
+                    Compactor::new(self.agent.compact.clone(), self.services.get_environment())
+                        .compact(context, true)?;
+                context.token_count_approx()
+            }
+        };
+
+        let removed = ContextPruner::prune_oldest_first(&mut context, token_count, budget);
+        if removed > 0 {
+            info!(agent_id = %self.agent.id, removed, budget, "Pruned oldest context messages");
+            self.send(
+                TitleFormat::info("Pruned context")
+                    .sub_title(format!(
+                        "Dropped {removed} oldest message(s) to fit the context window"
+                    ))
+                    .into(),
+            )
+            .await?;
+        }
+        Ok(context)
+    }
+
     // Returns if agent supports tool or not.
     fn is_tool_supported(&self) -> anyhow::Result<bool> {
         let model_id = &self.agent.model;
@@ -266,6 +312,8 @@ impl<S: AgentService + EnvironmentInfra<Config = forge_config::ForgeConfig>> Orc
             ToolCallContext::new(self.conversation.metrics.clone()).sender(self.sender.clone());
 
         while !should_yield {
+            context = self.prune_context(context).await?;
+
             // Set context for the current loop iteration
             self.conversation.context = Some(context.clone());
             self.services.update(self.conversation.clone()).await?;
@@ -279,7 +327,7 @@ impl<S: AgentService + EnvironmentInfra<Config = forge_config::ForgeConfig>> Orc
                 .handle(&request_event, &mut self.conversation)
                 .await?;
 
-            let message = crate::retry::retry_with_config(
+            let mut message = crate::retry::retry_with_config(
                 &self.config.clone().retry.unwrap_or_default(),
                 || {
                     self.execute_chat_turn(
@@ -309,7 +357,22 @@ impl<S: AgentService + EnvironmentInfra<Config = forge_config::ForgeConfig>> Orc
             )
             .await?;
 
+            // Price the completion from the model's rates when the provider
+            // doesn't report a cost itself
+            if message.usage.cost.is_none() {
+                message.usage.cost = self
+                    .models
+                    .iter()
+                    .find(|model| model.id == model_id)
+                    .and_then(|model| model.cost_of(&message.usage));
+            }
+
             // Fire the Response lifecycle event
+            let before_response = self
+                .conversation
+                .context
+                .as_ref()
+                .map(|context| (context.token_count_approx(), context.messages.len()));
             let response_event = LifecycleEvent::Response(EventData::new(
                 self.agent.clone(),
                 model_id.clone(),
@@ -319,6 +382,24 @@ impl<S: AgentService + EnvironmentInfra<Config = forge_config::ForgeConfig>> Orc
                 .handle(&response_event, &mut self.conversation)
                 .await?;
 
+            // Response hooks only ever shrink the context through compaction
+            if let Some((original_tokens, original_messages)) = before_response
+                && let Some(context) = &self.conversation.context
+                && context.messages.len() < original_messages
+            {
+                let result = CompactionResult::new(
+                    original_tokens,
+                    context.token_count_approx(),
+                    original_messages,
+                    context.messages.len(),
+                );
+                self.send(ChatResponse::AutoCompacted {
+                    token_reduction: result.token_reduction_percentage(),
+                    message_reduction: result.message_reduction_percentage(),
+                })
+                .await?;
+            }
+
             // Turn is completed, if finish_reason is 'stop'. Gemini models return stop as
             // finish reason with tool calls.
             is_complete =
@@ -445,7 +526,8 @@ impl<S: AgentService + EnvironmentInfra<Config = forge_config::ForgeConfig>> Orc
 
         // Signal Task Completion
         if is_complete {
-            self.send(ChatResponse::TaskComplete).await?;
+            self.send(ChatResponse::TaskComplete { metadata: self.request_metadata.clone() })
+                .await?;
         }
 
         Ok(())
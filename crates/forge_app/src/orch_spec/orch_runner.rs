@@ -2,17 +2,19 @@ use std::collections::{BTreeMap, VecDeque};
 use std::sync::Arc;
 
 use forge_domain::{
-    Attachment, ChatCompletionMessage, ChatResponse, Conversation, ConversationId, Environment,
-    Event, Hook, ProviderId, ToolCallFull, ToolErrorTracker, ToolResult,
+    Attachment, ChatCompletionMessage, ChatRepository, ChatResponse, Conversation, ConversationId,
+    Environment, Event, Hook, Provider, ProviderId, ToolCallFull, ToolErrorTracker, ToolResult,
 };
+use forge_test_kit::MockProvider;
 use handlebars::{Handlebars, no_escape};
 use include_dir::{Dir, include_dir};
 use tokio::sync::Mutex;
+use url::Url;
 
 pub use super::orch_setup::TestContext;
 use crate::app::build_template_config;
 use crate::apply_tunable_parameters::ApplyTunableParameters;
-use crate::hooks::{DoomLoopDetector, PendingTodosHandler};
+use crate::hooks::{CompactionHandler, DoomLoopDetector, PendingTodosHandler};
 use crate::init_conversation_metrics::InitConversationMetrics;
 use crate::orch::Orchestrator;
 use crate::set_conversation_id::SetConversationId;
@@ -34,7 +36,7 @@ pub struct Runner {
     test_tool_calls: Mutex<VecDeque<(ToolCallFull, ToolResult)>>,
 
     // Mock completions from the LLM (Each value is produced as an event in the stream)
-    test_completions: Mutex<VecDeque<ChatCompletionMessage>>,
+    test_completions: MockProvider,
 
     // Mock shell command outputs
     test_shell_outputs: Mutex<VecDeque<ShellOutput>>,
@@ -63,7 +65,8 @@ impl Runner {
             env: setup.env.clone(),
             conversation_history: Mutex::new(Vec::new()),
             test_tool_calls: Mutex::new(VecDeque::from(setup.mock_tool_call_responses.clone())),
-            test_completions: Mutex::new(VecDeque::from(setup.mock_assistant_responses.clone())),
+            test_completions: MockProvider::new()
+                .respond_with(setup.mock_assistant_responses.clone()),
             test_shell_outputs: Mutex::new(VecDeque::from(setup.mock_shell_outputs.clone())),
         }
     }
@@ -129,12 +132,15 @@ impl Runner {
             ApplyTunableParameters::new(agent.clone(), system_tools.clone()).apply(conversation);
         let conversation = SetConversationId.apply(conversation);
 
+        let compaction_handler = CompactionHandler::new(agent.clone(), setup.env.clone());
         let orch = Orchestrator::new(services.clone(), conversation, agent, setup.config.clone())
             .error_tracker(ToolErrorTracker::new(3))
             .tool_definitions(system_tools)
+            .models(setup.models.clone())
             .hook(Arc::new(
                 Hook::default()
                     .on_request(DoomLoopDetector::default())
+                    .on_response(compaction_handler)
                     .on_end(PendingTodosHandler::new()),
             ))
             .sender(tx);
@@ -160,21 +166,23 @@ impl Runner {
 impl AgentService for Runner {
     async fn chat_agent(
         &self,
-        _id: &forge_domain::ModelId,
+        id: &forge_domain::ModelId,
         context: forge_domain::Context,
-        _provider_id: Option<ProviderId>,
+        provider_id: Option<ProviderId>,
     ) -> forge_domain::ResultStream<ChatCompletionMessage, anyhow::Error> {
-        let mut responses = self.test_completions.lock().await;
-
-        if let Some(message) = responses.pop_front() {
-            Ok(Box::pin(tokio_stream::iter(std::iter::once(Ok(message)))))
-        } else {
-            let total_messages = context.messages.len();
-            let last_message = context.messages.last();
-            panic!(
-                "No mock response found. Total Messages: {total_messages}. Last Message: {last_message:#?}"
-            )
-        }
+        // The mock provider ignores the provider details, so a bare one suffices
+        let provider = Provider {
+            id: provider_id.unwrap_or(ProviderId::ANTHROPIC),
+            provider_type: Default::default(),
+            response: None,
+            url: Url::parse("http://localhost").unwrap(),
+            models: None,
+            auth_methods: vec![],
+            url_params: vec![],
+            credential: None,
+            custom_headers: None,
+        };
+        self.test_completions.chat(id, context, provider).await
     }
 
     async fn call(
@@ -200,6 +208,14 @@ impl AgentService for Runner {
         self.conversation_history.lock().await.push(conversation);
         Ok(())
     }
+
+    async fn update_title(&self, id: &ConversationId, title: String) -> anyhow::Result<()> {
+        let mut history = self.conversation_history.lock().await;
+        if let Some(conversation) = history.iter().rev().find(|c| &c.id == id).cloned() {
+            history.push(conversation.title(Some(title)));
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
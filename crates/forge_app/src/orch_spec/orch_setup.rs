@@ -6,7 +6,7 @@ use derive_setters::Setters;
 use forge_config::ForgeConfig;
 use forge_domain::{
     Agent, AgentId, Attachment, ChatCompletionMessage, ChatResponse, Conversation, Environment,
-    Event, File, MessageEntry, Metrics, ModelId, ProviderId, Role, Template, ToolCallFull,
+    Event, File, MessageEntry, Metrics, Model, ModelId, ProviderId, Role, Template, ToolCallFull,
     ToolDefinition, ToolResult,
 };
 
@@ -40,6 +40,8 @@ pub struct TestContext {
     pub output: TestOutput,
     pub agent: Agent,
     pub tools: Vec<ToolDefinition>,
+    /// Models made available to the orchestrator
+    pub models: Vec<Model>,
     /// ForgeConfig used to populate TemplateConfig for
     /// system prompt rendering in tests.
     pub config: ForgeConfig,
@@ -81,6 +83,7 @@ impl Default for TestContext {
                 ToolDefinition::new("fs_read"),
                 ToolDefinition::new("fs_write"),
             ],
+            models: Default::default(),
         }
     }
 }
@@ -85,7 +85,7 @@ async fn test_followup_does_not_trigger_session_summary() {
         .chat_responses
         .iter()
         .flatten()
-        .any(|response| matches!(response, ChatResponse::TaskComplete));
+        .any(|response| matches!(response, ChatResponse::TaskComplete { .. }));
 
     assert!(!ctx.output.tools().is_empty(), "Context should've tools.");
     assert!(
@@ -115,6 +115,9 @@ async fn test_empty_responses() {
         status_codes: vec![429, 500, 502, 503, 504, 408, 522, 520, 529],
         max_delay_secs: None,
         suppress_errors: false,
+        jitter_factor: 0.5,
+        circuit_breaker: None,
+        on_specific_errors: vec![],
     });
 
     let _ = ctx.run("Read a file").await;
@@ -398,7 +401,7 @@ async fn test_multiple_consecutive_tool_calls() {
         .chat_responses
         .into_iter()
         .filter_map(|response| response.ok())
-        .filter(|response| matches!(response, ChatResponse::TaskComplete))
+        .filter(|response| matches!(response, ChatResponse::TaskComplete { .. }))
         .count();
 
     assert_eq!(retry_attempts, 1, "Should complete the task");
@@ -560,7 +563,7 @@ async fn test_is_complete_when_stop_with_no_tool_calls() {
         .chat_responses
         .iter()
         .filter_map(|r| r.as_ref().ok())
-        .any(|response| matches!(response, ChatResponse::TaskComplete));
+        .any(|response| matches!(response, ChatResponse::TaskComplete { .. }));
 
     assert!(
         has_task_complete,
@@ -679,7 +682,7 @@ async fn test_complete_when_no_pending_todos() {
         .chat_responses
         .iter()
         .filter_map(|r| r.as_ref().ok())
-        .any(|response| matches!(response, ChatResponse::TaskComplete));
+        .any(|response| matches!(response, ChatResponse::TaskComplete { .. }));
 
     assert!(
         has_task_complete,
@@ -707,10 +710,176 @@ async fn test_complete_when_empty_todos() {
         .chat_responses
         .iter()
         .filter_map(|r| r.as_ref().ok())
-        .any(|response| matches!(response, ChatResponse::TaskComplete));
+        .any(|response| matches!(response, ChatResponse::TaskComplete { .. }));
 
     assert!(
         has_task_complete,
         "Should have TaskComplete when no todos exist"
     );
 }
+
+#[tokio::test]
+async fn test_mock_provider_two_turn_conversation() {
+    let tool_call = ToolCallFull::new("fs_read").arguments(json!({"path": "/a.txt"}));
+    let tool_result = ToolResult::new("fs_read").output(Ok(ToolOutput::text("Hello")));
+
+    let mut ctx = TestContext::default()
+        .mock_tool_call_responses(vec![(tool_call.clone(), tool_result)])
+        .mock_assistant_responses(vec![
+            ChatCompletionMessage::assistant("Reading the file").tool_calls(vec![tool_call.into()]),
+            ChatCompletionMessage::assistant("The file says Hello")
+                .finish_reason(FinishReason::Stop),
+        ]);
+
+    ctx.run("Read /a.txt").await.unwrap();
+
+    let actual = ctx
+        .output
+        .context_messages()
+        .iter()
+        .filter(|message| message.has_role(Role::Assistant))
+        .filter_map(|message| message.content())
+        .map(|content| content.to_string())
+        .collect::<Vec<_>>();
+    let expected = vec![
+        "Reading the file".to_string(),
+        "The file says Hello".to_string(),
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn test_auto_compaction_after_crossing_threshold() {
+    use forge_domain::{Compact, InputModality, Model, ModelId, TokenCount, Usage};
+
+    let model = Model {
+        id: ModelId::new("openai/gpt-1"),
+        name: None,
+        description: None,
+        context_length: Some(10_000),
+        tools_supported: Some(true),
+        supports_parallel_tool_calls: None,
+        supports_reasoning: None,
+        input_modalities: vec![InputModality::Text],
+        cost_per_million_input_tokens: None,
+        cost_per_million_output_tokens: None,
+    };
+    let read_a = ToolCallFull::new("fs_read").arguments(json!({"path": "/a.txt"}));
+    let read_b = ToolCallFull::new("fs_read").arguments(json!({"path": "/b.txt"}));
+    let result = ToolResult::new("fs_read").output(Ok(ToolOutput::text("Hello")));
+    let usage = Usage {
+        total_tokens: TokenCount::Actual(6_000),
+        ..Default::default()
+    };
+
+    let fixture = TestContext::default();
+    let agent = fixture
+        .agent
+        .clone()
+        .compact(Compact::new().token_threshold_percentage(0.5))
+        .compaction_threshold(Some(&model));
+    let mut ctx = fixture
+        .agent(agent)
+        .mock_tool_call_responses(vec![
+            (read_a.clone(), result.clone()),
+            (read_b.clone(), result),
+        ])
+        .mock_assistant_responses(vec![
+            ChatCompletionMessage::assistant("Reading a").tool_calls(vec![read_a.into()]),
+            ChatCompletionMessage::assistant("Reading b")
+                .tool_calls(vec![read_b.into()])
+                .usage(usage),
+            ChatCompletionMessage::assistant("Done").finish_reason(FinishReason::Stop),
+        ]);
+
+    ctx.run("Read both files").await.unwrap();
+
+    let actual = ctx
+        .output
+        .chat_responses
+        .iter()
+        .flatten()
+        .filter(|response| matches!(response, ChatResponse::AutoCompacted { .. }))
+        .count();
+    assert_eq!(actual, 1);
+}
+
+#[tokio::test]
+async fn test_turn_cost_is_computed_from_model_rates() {
+    use forge_domain::{Model, TokenCount, Usage};
+
+    let fixture = TestContext::default();
+    let model = Model::new(fixture.model.clone())
+        .cost_per_million_input_tokens(0.01)
+        .cost_per_million_output_tokens(0.03);
+    let usage = Usage {
+        prompt_tokens: TokenCount::Actual(1000),
+        completion_tokens: TokenCount::Actual(500),
+        total_tokens: TokenCount::Actual(1500),
+        ..Default::default()
+    };
+    let mut ctx = fixture.models(vec![model]).mock_assistant_responses(vec![
+        ChatCompletionMessage::assistant("Done")
+            .finish_reason(FinishReason::Stop)
+            .usage(usage),
+    ]);
+
+    ctx.run("Hi").await.unwrap();
+
+    let actual = ctx
+        .output
+        .conversation_history
+        .last()
+        .and_then(|conversation| conversation.accumulated_cost())
+        .unwrap();
+    assert!(
+        (actual - 0.000025).abs() < 1e-12,
+        "unexpected cost {actual}"
+    );
+}
+
+#[tokio::test]
+async fn test_max_requests_per_turn_interrupts_looping_agent() {
+    use forge_domain::InterruptionReason;
+
+    let read = ToolCallFull::new("fs_read")
+        .arguments(ToolCallArguments::from(json!({"path": "test.txt"})));
+    let result = ToolResult::new("fs_read").output(Ok(ToolOutput::text("file content")));
+
+    let fixture = TestContext::default();
+    let agent = fixture.agent.clone().max_requests_per_turn(2usize);
+    let mut ctx = fixture
+        .agent(agent)
+        .mock_tool_call_responses(vec![
+            (read.clone(), result.clone()),
+            (read.clone(), result.clone()),
+            (read.clone(), result),
+        ])
+        .mock_assistant_responses(vec![
+            ChatCompletionMessage::assistant("Reading").tool_calls(vec![read.clone().into()]),
+            ChatCompletionMessage::assistant("Reading again").tool_calls(vec![read.clone().into()]),
+            ChatCompletionMessage::assistant("Reading once more").tool_calls(vec![read.into()]),
+        ]);
+
+    ctx.run("Keep reading").await.unwrap();
+
+    let actual = ctx
+        .output
+        .context_messages()
+        .iter()
+        .filter(|message| message.has_role(Role::Assistant))
+        .count();
+    assert_eq!(actual, 2);
+
+    let actual = ctx
+        .output
+        .chat_responses
+        .iter()
+        .flatten()
+        .find_map(|response| match response {
+            ChatResponse::Interrupt { reason } => Some(reason.clone()),
+            _ => None,
+        });
+    let expected = Some(InterruptionReason::MaxRequestPerTurnLimitReached { limit: 2 });
+    assert_eq!(actual, expected);
+}
@@ -1,8 +1,9 @@
 use std::time::Duration;
 
-use backon::{ExponentialBuilder, Retryable};
-use forge_config::RetryConfig;
+use backon::{BackoffBuilder, ExponentialBuilder, Retryable};
+use forge_config::{ErrorPattern, RetryConfig};
 use forge_domain::Error;
+use rand::RngExt;
 
 pub async fn retry_with_config<F, Fut, T, C>(
     config: &RetryConfig,
@@ -14,13 +15,9 @@ where
     Fut: std::future::Future<Output = anyhow::Result<T>>,
     C: Fn(&anyhow::Error, Duration) + Send + Sync + 'static,
 {
-    let strategy = ExponentialBuilder::default()
-        .with_min_delay(Duration::from_millis(config.min_delay_ms))
-        .with_factor(config.backoff_factor as f32)
-        .with_max_times(config.max_attempts)
-        .with_jitter();
-
-    let retryable = operation.retry(&strategy).when(should_retry);
+    let retryable = operation
+        .retry(backoff(config))
+        .when(|error| should_retry(error, &config.on_specific_errors));
 
     match notify {
         Some(callback) => retryable.notify(callback).await,
@@ -28,12 +25,123 @@ where
     }
 }
 
+/// Builds the exponential backoff schedule for `config`, adding random jitter
+/// to every delay so concurrent clients don't retry in lockstep.
+fn backoff(config: &RetryConfig) -> impl Iterator<Item = Duration> + Send + Sync + Unpin {
+    let jitter_factor = config.jitter_factor;
+    ExponentialBuilder::default()
+        .with_min_delay(Duration::from_millis(config.min_delay_ms))
+        .with_factor(config.backoff_factor as f32)
+        .with_max_times(config.max_attempts)
+        .build()
+        .map(move |delay| with_jitter(delay, jitter_factor))
+}
+
+/// Adds a random duration in `[0, delay * jitter_factor)` to `delay`.
+fn with_jitter(delay: Duration, jitter_factor: f32) -> Duration {
+    let max_jitter_ms = (delay.as_millis() as f64 * f64::from(jitter_factor.max(0.0))) as u64;
+    if max_jitter_ms == 0 {
+        return delay;
+    }
+    delay + Duration::from_millis(rand::rng().random_range(0..max_jitter_ms))
+}
+
 /// Determines if an error should trigger a retry attempt.
 ///
-/// This function checks if the error is a retryable domain error.
-/// Currently, only `Error::Retryable` errors will trigger retries.
-fn should_retry(error: &anyhow::Error) -> bool {
-    error
+/// Only `Error::Retryable` errors are retried. When `patterns` is not empty
+/// the error message must also match one of them.
+fn should_retry(error: &anyhow::Error, patterns: &[ErrorPattern]) -> bool {
+    let is_retryable = error
         .downcast_ref::<Error>()
-        .is_some_and(|error| matches!(error, Error::Retryable(_)))
+        .is_some_and(|error| matches!(error, Error::Retryable(_)));
+    if !is_retryable || patterns.is_empty() {
+        return is_retryable;
+    }
+
+    let message = format!("{error:#}");
+    patterns.iter().any(|pattern| pattern.is_match(&message))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn fixture(jitter_factor: f32) -> RetryConfig {
+        RetryConfig::default()
+            .min_delay_ms(1000u64)
+            .backoff_factor(2u64)
+            .max_attempts(2usize)
+            .jitter_factor(jitter_factor)
+    }
+
+    #[test]
+    fn test_jitter_spreads_concurrent_retry_delays() {
+        let config = fixture(0.5);
+
+        let delays: Vec<f64> = (0..10)
+            .map(|_| backoff(&config).next().unwrap().as_millis() as f64)
+            .collect();
+
+        let mean = delays.iter().sum::<f64>() / delays.len() as f64;
+        let variance = delays.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / delays.len() as f64;
+        assert!(variance.sqrt() > 0.0, "delays were identical: {delays:?}");
+        assert!(delays.iter().all(|d| (1000.0..1500.0).contains(d)));
+    }
+
+    #[tokio::test]
+    async fn test_retries_only_on_matching_errors() {
+        let config = fixture(0.0)
+            .min_delay_ms(0u64)
+            .on_specific_errors(vec![ErrorPattern::new("(?i)rate limit").unwrap()]);
+        let attempts = std::sync::atomic::AtomicUsize::new(0);
+
+        let actual = retry_with_config(
+            &config,
+            || async {
+                attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                Err::<(), _>(Error::Retryable(anyhow::anyhow!("context too long")).into())
+            },
+            None::<fn(&anyhow::Error, Duration)>,
+        )
+        .await;
+
+        assert!(actual.is_err());
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retries_errors_matching_pattern() {
+        let config = fixture(0.0)
+            .min_delay_ms(0u64)
+            .on_specific_errors(vec![ErrorPattern::new("(?i)rate limit").unwrap()]);
+        let attempts = std::sync::atomic::AtomicUsize::new(0);
+
+        let actual = retry_with_config(
+            &config,
+            || async {
+                attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                Err::<(), _>(Error::Retryable(anyhow::anyhow!("Rate limit exceeded")).into())
+            },
+            None::<fn(&anyhow::Error, Duration)>,
+        )
+        .await;
+
+        assert!(actual.is_err());
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_should_retry_without_patterns() {
+        let fixture = Error::Retryable(anyhow::anyhow!("context too long")).into();
+        assert!(should_retry(&fixture, &[]));
+    }
+
+    #[test]
+    fn test_zero_jitter_keeps_base_delays() {
+        let actual: Vec<Duration> = backoff(&fixture(0.0)).collect();
+        let expected = vec![Duration::from_millis(1000), Duration::from_millis(2000)];
+        assert_eq!(actual, expected);
+    }
 }
@@ -170,6 +170,11 @@ pub trait ProviderService: Send + Sync {
         &self,
         credential: forge_domain::AuthCredential,
     ) -> anyhow::Result<()>;
+    /// Returns every stored credential for the provider, one per alias
+    async fn get_credentials(
+        &self,
+        id: &forge_domain::ProviderId,
+    ) -> anyhow::Result<Vec<forge_domain::AuthCredential>>;
     async fn remove_credential(&self, id: &forge_domain::ProviderId) -> anyhow::Result<()>;
     /// Migrates environment variable-based credentials to file-based
     /// credentials. Returns Some(MigrationResult) if credentials were migrated,
@@ -239,6 +244,14 @@ pub trait ConversationService: Send + Sync {
 
     async fn upsert_conversation(&self, conversation: Conversation) -> anyhow::Result<()>;
 
+    /// Sets the stored title of a conversation without rewriting the rest of
+    /// it, so it cannot race with a turn saving the same conversation
+    async fn update_conversation_title(
+        &self,
+        id: &ConversationId,
+        title: String,
+    ) -> anyhow::Result<()>;
+
     /// This is useful when you want to perform several operations on a
     /// conversation atomically.
     async fn modify_conversation<F, T>(&self, id: &ConversationId, f: F) -> anyhow::Result<T>
@@ -429,6 +442,17 @@ pub trait FsUndoService: Send + Sync {
     // TODO: We should move Snapshot service to Services from infra
     // and drop FsUndoService.
     async fn undo(&self, path: String) -> anyhow::Result<FsUndoOutput>;
+
+    /// Restores each of `file_paths` to the content it had before the turn
+    /// started at `turn_started_at` first modified it, and returns the paths
+    /// that were restored. With `dry_run` the files are left untouched and
+    /// the paths that would be restored are returned.
+    async fn rollback(
+        &self,
+        file_paths: Vec<PathBuf>,
+        turn_started_at: chrono::DateTime<chrono::Utc>,
+        dry_run: bool,
+    ) -> anyhow::Result<Vec<PathBuf>>;
 }
 
 #[async_trait::async_trait]
@@ -477,6 +501,10 @@ pub trait AgentRegistry: Send + Sync {
 
     /// Reload agents by invalidating the cache
     async fn reload_agents(&self) -> anyhow::Result<()>;
+
+    /// Watch agent files and reload the registry whenever one changes,
+    /// yielding the IDs of the agents that were reloaded
+    async fn watch_agents(&self) -> anyhow::Result<forge_stream::MpscStream<AgentId>>;
 }
 
 #[async_trait::async_trait]
@@ -610,6 +638,16 @@ impl<I: Services> ConversationService for I {
             .await
     }
 
+    async fn update_conversation_title(
+        &self,
+        id: &ConversationId,
+        title: String,
+    ) -> anyhow::Result<()> {
+        self.conversation_service()
+            .update_conversation_title(id, title)
+            .await
+    }
+
     async fn modify_conversation<F, T>(&self, id: &ConversationId, f: F) -> anyhow::Result<T>
     where
         F: FnOnce(&mut Conversation) -> T + Send,
@@ -667,6 +705,13 @@ impl<I: Services> ProviderService for I {
         self.provider_service().upsert_credential(credential).await
     }
 
+    async fn get_credentials(
+        &self,
+        id: &forge_domain::ProviderId,
+    ) -> anyhow::Result<Vec<forge_domain::AuthCredential>> {
+        self.provider_service().get_credentials(id).await
+    }
+
     async fn remove_credential(&self, id: &forge_domain::ProviderId) -> anyhow::Result<()> {
         self.provider_service().remove_credential(id).await
     }
@@ -853,6 +898,17 @@ impl<I: Services> FsUndoService for I {
     async fn undo(&self, path: String) -> anyhow::Result<FsUndoOutput> {
         self.fs_undo_service().undo(path).await
     }
+
+    async fn rollback(
+        &self,
+        file_paths: Vec<PathBuf>,
+        turn_started_at: chrono::DateTime<chrono::Utc>,
+        dry_run: bool,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        self.fs_undo_service()
+            .rollback(file_paths, turn_started_at, dry_run)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -940,6 +996,10 @@ impl<I: Services> AgentRegistry for I {
     async fn reload_agents(&self) -> anyhow::Result<()> {
         self.agent_registry().reload_agents().await
     }
+
+    async fn watch_agents(&self) -> anyhow::Result<forge_stream::MpscStream<AgentId>> {
+        self.agent_registry().watch_agents().await
+    }
 }
 
 #[async_trait::async_trait]
@@ -4,7 +4,7 @@ use std::sync::Arc;
 use derive_setters::Setters;
 use forge_domain::{
     Agent, Conversation, Environment, Extension, ExtensionStat, File, Model, SystemContext,
-    Template, TemplateConfig, ToolCatalog, ToolDefinition, ToolUsagePrompt,
+    Template, TemplateConfig, ToolCatalog, ToolDefinition, ToolName, ToolUsagePrompt,
 };
 use serde_json::{Map, Value, json};
 use strum::IntoEnumIterator;
@@ -113,6 +113,23 @@ impl<S: SkillFetchService + ShellService> SystemPrompt<S> {
                 .filter(|(name, _)| agent_tool_names.contains(name))
                 .collect();
 
+            let available_tools: Vec<ToolName> = self
+                .tool_definitions
+                .iter()
+                .map(|def| def.name.clone())
+                .collect();
+            for skill in &skills {
+                let missing = skill.missing_tools(&available_tools);
+                if !missing.is_empty() {
+                    tracing::warn!(
+                        skill = %skill.name,
+                        agent = %agent.id,
+                        missing_tools = ?missing,
+                        "Skill requires tools that are not available to the agent"
+                    );
+                }
+            }
+
             let ctx = SystemContext {
                 env: Some(env),
                 tool_information,
@@ -20,6 +20,49 @@ pub struct TitleResponse {
     pub title: String,
 }
 
+/// Maximum number of characters of the user prompt used as a title hint
+const TITLE_HINT_MAX_CHARS: usize = 200;
+
+/// Extracts the part of a user message that best describes its intent: the
+/// first sentence or line, capped at [`TITLE_HINT_MAX_CHARS`] characters.
+///
+/// Long multi-paragraph prompts produce poor titles when sent in full, while
+/// the opening sentence usually carries the request itself. A first sentence
+/// that exceeds the limit is cut at the last word boundary within it.
+pub fn extract_title_hint(message: &str) -> &str {
+    let message = message.trim();
+    let limit = message
+        .char_indices()
+        .nth(TITLE_HINT_MAX_CHARS)
+        .map_or(message.len(), |(index, _)| index);
+    let window = &message[..limit];
+
+    let sentence_end = window.char_indices().find_map(|(index, c)| {
+        let end = index + c.len_utf8();
+        match c {
+            '\n' => Some(index),
+            '.' | '!' | '?'
+                if message[end..]
+                    .chars()
+                    .next()
+                    .is_none_or(char::is_whitespace) =>
+            {
+                Some(end)
+            }
+            _ => None,
+        }
+    });
+
+    match sentence_end {
+        Some(end) => message[..end].trim_end(),
+        None if limit == message.len() => message,
+        None => window
+            .rfind(char::is_whitespace)
+            .map_or(window, |index| &window[..index])
+            .trim_end(),
+    }
+}
+
 /// Service for generating contextually appropriate titles
 #[derive(Setters)]
 pub struct TitleGenerator<S> {
@@ -57,7 +100,10 @@ impl<S: AS> TitleGenerator<S> {
             &Default::default(),
         )?;
 
-        let prompt = format!("<user_prompt>{}</user_prompt>", self.user_prompt.as_str());
+        let prompt = format!(
+            "<user_prompt>{}</user_prompt>",
+            extract_title_hint(self.user_prompt.as_str())
+        );
 
         // Generate JSON schema from TitleResponse using schemars
         let schema = schemars::schema_for!(TitleResponse);
@@ -91,3 +137,54 @@ impl<S: AS> TitleGenerator<S> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_extract_title_hint_long_prompt_ends_at_sentence() {
+        let fixture = format!(
+            "Refactor the payment module to use the new gateway client. {}",
+            "It should keep the existing retry semantics and logging. ".repeat(60)
+        );
+
+        let actual = extract_title_hint(&fixture);
+
+        let expected = "Refactor the payment module to use the new gateway client.";
+        assert!(actual.chars().count() <= TITLE_HINT_MAX_CHARS);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_extract_title_hint_stops_at_first_line() {
+        let fixture = "Fix this failing test:\n\nassert_eq!(1.0, 2.0);";
+
+        let actual = extract_title_hint(fixture);
+
+        let expected = "Fix this failing test:";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_extract_title_hint_ignores_dots_inside_words() {
+        let fixture = "  Update main.rs to print the version  ";
+
+        let actual = extract_title_hint(fixture);
+
+        let expected = "Update main.rs to print the version";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_extract_title_hint_truncates_long_sentence_at_word() {
+        let fixture = "word ".repeat(100);
+
+        let actual = extract_title_hint(&fixture);
+
+        assert!(actual.chars().count() <= TITLE_HINT_MAX_CHARS);
+        assert!(actual.ends_with("word"));
+    }
+}
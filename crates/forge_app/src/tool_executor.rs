@@ -113,12 +113,12 @@ impl<
     }
 
     /// Converts a path to absolute by joining it with the current working
-    /// directory if it's relative
+    /// directory if it's relative. `s3://` URLs are passed through unchanged.
     fn normalize_path(&self, path: String) -> String {
         let env = self.services.get_environment();
         let path_buf = PathBuf::from(&path);
 
-        if path_buf.is_absolute() {
+        if path_buf.is_absolute() || path.starts_with("s3://") {
             path
         } else {
             PathBuf::from(&env.cwd).join(path_buf).display().to_string()
@@ -811,6 +811,8 @@ fn create_test_model(
         supports_parallel_tool_calls: Some(true),
         supports_reasoning: Some(false),
         input_modalities: modalities,
+        cost_per_million_input_tokens: None,
+        cost_per_million_output_tokens: None,
     }
 }
 
@@ -0,0 +1,124 @@
+//! Dependabot configuration for automated dependency updates
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+const HEADER: &str = "# This file is generated by forge_ci. DO NOT EDIT BY HAND!
+# https://docs.github.com/en/code-security/dependabot/working-with-dependabot/dependabot-options-reference
+";
+
+/// Root of a `.github/dependabot.yml` file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dependabot {
+    pub version: u8,
+    pub updates: Vec<Update>,
+}
+
+/// Update settings for a single package ecosystem
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Update {
+    pub package_ecosystem: String,
+    pub directory: String,
+    pub schedule: Schedule,
+    pub open_pull_requests_limit: u32,
+    pub labels: Vec<String>,
+    pub groups: IndexMap<String, Group>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Schedule {
+    pub interval: String,
+}
+
+/// Rule that bundles matching updates into a single pull request
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Group {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patterns: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub update_types: Vec<String>,
+}
+
+impl Update {
+    fn weekly(package_ecosystem: &str, groups: IndexMap<String, Group>) -> Self {
+        Self {
+            package_ecosystem: package_ecosystem.to_string(),
+            directory: "/".to_string(),
+            schedule: Schedule { interval: "weekly".to_string() },
+            open_pull_requests_limit: 5,
+            labels: vec!["type: chore".to_string()],
+            groups,
+        }
+    }
+}
+
+/// One group per semver bump so majors can be reviewed apart from the rest
+fn semver_groups() -> IndexMap<String, Group> {
+    ["major", "minor", "patch"]
+        .into_iter()
+        .map(|update_type| {
+            let group = Group {
+                update_types: vec![update_type.to_string()],
+                ..Group::default()
+            };
+            (update_type.to_string(), group)
+        })
+        .collect()
+}
+
+impl Default for Dependabot {
+    fn default() -> Self {
+        let all_actions = Group { patterns: vec!["*".to_string()], ..Group::default() };
+        Self {
+            version: 2,
+            updates: vec![
+                Update::weekly("cargo", semver_groups()),
+                Update::weekly("npm", semver_groups()),
+                Update::weekly(
+                    "github-actions",
+                    IndexMap::from([("actions".to_string(), all_actions)]),
+                ),
+            ],
+        }
+    }
+}
+
+/// Renders the Dependabot configuration as YAML
+pub fn generate_dependabot_config() -> String {
+    let yaml = serde_yml::to_string(&Dependabot::default()).unwrap();
+    // serde_yml leaves a trailing space after keys that open a nested mapping
+    let yaml = yaml
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("{HEADER}\n{yaml}\n")
+}
+
+/// Writes `.github/dependabot.yml`, or fails in CI if the committed file is
+/// out of date
+pub fn write_dependabot_config() {
+    let root_dir = Command::new("git")
+        .args(["rev-parse", "--show-toplevel"])
+        .output()
+        .unwrap()
+        .stdout;
+    let path = PathBuf::from(String::from_utf8(root_dir).unwrap().trim())
+        .join(".github")
+        .join("dependabot.yml");
+
+    let content = generate_dependabot_config();
+    if std::fs::read_to_string(&path).is_ok_and(|prev| prev == content) {
+        println!("Dependabot config is up-to-date: {}", path.display());
+    } else if std::env::var("CI").is_ok() {
+        panic!("Dependabot config is outdated: {}", path.display());
+    } else {
+        std::fs::write(&path, content).unwrap();
+        println!("Updated dependabot config: {}", path.display());
+    }
+}
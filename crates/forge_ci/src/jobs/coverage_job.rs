@@ -0,0 +1,57 @@
+use derive_setters::Setters;
+use gh_workflow::*;
+
+use crate::steps::setup_protoc;
+
+/// Runs the test suite under `cargo-llvm-cov`, uploads the report to Codecov
+/// and fails when line coverage drops below the threshold
+#[derive(Clone, Setters)]
+#[setters(into)]
+pub struct CoverageJob {
+    /// Minimum percentage of covered lines
+    pub fail_under_lines: u8,
+}
+
+impl Default for CoverageJob {
+    fn default() -> Self {
+        Self { fail_under_lines: 60 }
+    }
+}
+
+impl CoverageJob {
+    pub fn into_job(self) -> Job {
+        self.into()
+    }
+}
+
+impl From<CoverageJob> for Job {
+    fn from(value: CoverageJob) -> Job {
+        Job::new("Build and Test")
+            .cond(Expression::new(
+                "github.event_name == 'pull_request' || github.ref == 'refs/heads/main'",
+            ))
+            .permissions(Permissions::default().contents(Level::Read))
+            .add_step(Step::new("Checkout Code").uses("actions", "checkout", "v6"))
+            .add_step(setup_protoc())
+            .add_step(Step::toolchain().add_stable())
+            .add_step(Step::new("Install cargo-llvm-cov").run("cargo install cargo-llvm-cov"))
+            .add_step(
+                Step::new("Generate coverage").run(
+                    "cargo llvm-cov --all-features --workspace --lcov --output-path lcov.info",
+                ),
+            )
+            .add_step(
+                Step::new("Upload coverage to Codecov")
+                    .uses("codecov", "codecov-action", "v5")
+                    .add_with(("files", "lcov.info"))
+                    .add_with(("token", "${{ secrets.CODECOV_TOKEN }}"))
+                    .add_with(("fail_ci_if_error", "false")),
+            )
+            // Checked after the upload so the report is available even when the
+            // threshold is missed
+            .add_step(Step::new("Check coverage threshold").run(format!(
+                "cargo llvm-cov report --fail-under-lines {}",
+                value.fail_under_lines
+            )))
+    }
+}
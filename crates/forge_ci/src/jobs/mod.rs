@@ -1,6 +1,7 @@
 //! Jobs for CI workflows
 
 mod bounty_job;
+mod coverage_job;
 mod draft_release_update_job;
 mod label_sync_job;
 mod lint;
@@ -9,8 +10,10 @@ mod release_draft;
 mod release_draft_pr;
 mod release_homebrew;
 mod release_npm;
+mod security_audit_job;
 
 pub use bounty_job::*;
+pub use coverage_job::*;
 pub use draft_release_update_job::*;
 pub use label_sync_job::*;
 pub use lint::*;
@@ -19,3 +22,4 @@ pub use release_draft::*;
 pub use release_draft_pr::*;
 pub use release_homebrew::*;
 pub use release_npm::*;
+pub use security_audit_job::*;
@@ -0,0 +1,45 @@
+use derive_setters::Setters;
+use gh_workflow::*;
+
+/// Checks dependencies for known vulnerabilities with `cargo audit`, failing
+/// only on high or critical advisories, and enforces the license, ban and
+/// source policies in `deny.toml` with `cargo deny check`
+#[derive(Clone, Default, Setters)]
+#[setters(strip_option, into)]
+pub struct SecurityAuditJob {
+    /// Advisory IDs that `cargo audit` should not fail on
+    pub ignore: Vec<String>,
+}
+
+impl SecurityAuditJob {
+    pub fn into_job(self) -> Job {
+        self.into()
+    }
+}
+
+impl From<SecurityAuditJob> for Job {
+    fn from(value: SecurityAuditJob) -> Job {
+        let audit = std::iter::once("./scripts/audit-severity.sh".to_string())
+            .chain(value.ignore.iter().map(|id| format!("--ignore {id}")))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Job::new("Security Audit")
+            .permissions(Permissions::default().contents(Level::Read))
+            .add_step(Step::new("Checkout Code").uses("actions", "checkout", "v6"))
+            .add_step(Step::toolchain().add_stable())
+            .add_step(
+                Step::new("Install cargo-audit and cargo-deny")
+                    .run("cargo install --locked cargo-audit cargo-deny"),
+            )
+            // Runs first so a vulnerable dependency fails the job before the
+            // slower policy checks
+            .add_step(Step::new("Audit dependencies").run(audit))
+            // Advisories are left to the audit step so that low severity
+            // ones do not fail the job here
+            .add_step(
+                Step::new("Check licenses, bans and sources")
+                    .run("cargo deny check licenses bans sources"),
+            )
+    }
+}
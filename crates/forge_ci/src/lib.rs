@@ -1,3 +1,4 @@
+pub mod dependabot;
 mod jobs;
 mod release_matrix;
 pub mod steps;
@@ -1,22 +1,13 @@
 use gh_workflow::generate::Generate;
 use gh_workflow::*;
 
-use crate::jobs::{self, ReleaseBuilderJob};
+use crate::dependabot::write_dependabot_config;
+use crate::jobs::{self, CoverageJob, ReleaseBuilderJob};
 use crate::steps::setup_protoc;
 
 /// Generate the main CI workflow
 pub fn generate_ci_workflow() {
-    // Create a basic build job for CI with coverage
-    let build_job = Job::new("Build and Test")
-        .permissions(Permissions::default().contents(Level::Read))
-        .add_step(Step::new("Checkout Code").uses("actions", "checkout", "v6"))
-        .add_step(setup_protoc())
-        .add_step(Step::toolchain().add_stable())
-        .add_step(Step::new("Install cargo-llvm-cov").run("cargo install cargo-llvm-cov"))
-        .add_step(
-            Step::new("Generate coverage")
-                .run("cargo llvm-cov --all-features --workspace --lcov --output-path lcov.info"),
-        );
+    let build_job = CoverageJob::default().into_job();
 
     // Create a performance test job to ensure zsh rprompt stays fast
     let perf_test_job = Job::new("zsh-rprompt-performance")
@@ -30,6 +21,51 @@ pub fn generate_ci_workflow() {
                 .run("./scripts/benchmark.sh --threshold 60 zsh rprompt"),
         );
 
+    // Fuzz the markdown repair pipeline for a minute to catch panics on odd
+    // streaming input
+    let fuzz_job = Job::new("fuzz-markdown-stream")
+        .name("Fuzz: markdown stream")
+        .permissions(Permissions::default().contents(Level::Read))
+        .add_step(Step::new("Checkout Code").uses("actions", "checkout", "v6"))
+        .add_step(Step::toolchain().add_nightly())
+        .add_step(Step::new("Install cargo-fuzz").run("cargo install cargo-fuzz"))
+        .add_step(Step::new("Fuzz repair_line").run(
+            "cargo fuzz run --fuzz-dir crates/forge_markdown_stream/fuzz repair_line -- -max_total_time=60",
+        ));
+
+    // Benchmark the markdown renderer against the base branch on the same
+    // runner and fail when it gets more than 10% slower. The comparison is
+    // skipped when the base branch has no benchmark to save a baseline from.
+    let bench_job = Job::new("markdown-stream-benchmarks")
+        .name("Performance: markdown stream")
+        .cond(Expression::new("github.event_name == 'pull_request'"))
+        .permissions(Permissions::default().contents(Level::Read))
+        .add_step(
+            Step::new("Checkout Code")
+                .uses("actions", "checkout", "v6")
+                .add_with(("fetch-depth", "0")),
+        )
+        .add_step(Step::toolchain().add_stable())
+        .add_step(
+            Step::new("Save base branch baseline")
+                .run(
+                    "git checkout ${{ github.event.pull_request.base.sha }}\nif [ ! -f crates/forge_markdown_stream/benches/render.rs ]; then\n  echo \"Base branch has no render benchmark, skipping comparison\"\n  echo \"saved=false\" >> $GITHUB_OUTPUT\n  exit 0\nfi\ncargo bench -p forge_markdown_stream --bench render -- --save-baseline main\necho \"saved=true\" >> $GITHUB_OUTPUT",
+                )
+                .id("baseline"),
+        )
+        .add_step(
+            Step::new("Compare against baseline")
+                .run(
+                    "git checkout ${{ github.sha }}\ncargo bench -p forge_markdown_stream --bench render -- --baseline-lenient main",
+                )
+                .if_condition(Expression::new("steps.baseline.outputs.saved == 'true'")),
+        )
+        .add_step(
+            Step::new("Check for regressions")
+                .run("./scripts/bench-regression.sh --threshold 10 target/criterion")
+                .if_condition(Expression::new("steps.baseline.outputs.saved == 'true'")),
+        );
+
     let draft_release_job = jobs::create_draft_release_job("build");
     let draft_release_pr_job = jobs::create_draft_release_pr_job();
     let events = Event::default()
@@ -73,10 +109,13 @@ pub fn generate_ci_workflow() {
         .add_env(("OPENROUTER_API_KEY", "${{secrets.OPENROUTER_API_KEY}}"))
         .add_job("build", build_job)
         .add_job("zsh_rprompt_perf", perf_test_job)
+        .add_job("fuzz_markdown_stream", fuzz_job)
+        .add_job("markdown_stream_bench", bench_job)
         .add_job("draft_release", draft_release_job)
         .add_job("draft_release_pr", draft_release_pr_job)
         .add_job("build_release", build_release_job)
         .add_job("build_release_pr", build_release_pr_job);
 
     Generate::new(workflow).name("ci.yml").generate().unwrap();
+    write_dependabot_config();
 }
@@ -6,6 +6,7 @@ mod ci;
 mod labels;
 mod release_drafter;
 mod release_publish;
+mod security;
 mod stale;
 
 pub use autofix::*;
@@ -14,4 +15,5 @@ pub use ci::*;
 pub use labels::*;
 pub use release_drafter::*;
 pub use release_publish::*;
+pub use security::*;
 pub use stale::*;
@@ -0,0 +1,28 @@
+use gh_workflow::generate::Generate;
+use gh_workflow::*;
+
+use crate::jobs::SecurityAuditJob;
+
+/// Generate the dependency security audit workflow
+pub fn generate_security_workflow() {
+    let events = Event::default()
+        .add_schedule(Schedule::new("0 0 * * *"))
+        .pull_request(
+            PullRequest::default()
+                .add_type(PullRequestType::Opened)
+                .add_type(PullRequestType::Synchronize)
+                .add_type(PullRequestType::Reopened)
+                .add_branch("main"),
+        );
+
+    let workflow = Workflow::default()
+        .name("Security Audit")
+        .on(events)
+        .concurrency(Concurrency::default().group("${{ github.workflow }}-${{ github.ref }}"))
+        .add_job("audit", SecurityAuditJob::default().into_job());
+
+    Generate::new(workflow)
+        .name("security.yml")
+        .generate()
+        .unwrap();
+}
@@ -1,4 +1,6 @@
+use forge_ci::dependabot::{Dependabot, generate_dependabot_config};
 use forge_ci::workflows as workflow;
+use pretty_assertions::assert_eq;
 
 #[test]
 fn generate() {
@@ -34,3 +36,48 @@ fn test_autofix_workflow() {
 fn test_bounty_workflow() {
     workflow::generate_bounty_workflow();
 }
+
+#[test]
+fn test_dependabot_config() {
+    let actual: Dependabot = serde_yml::from_str(&generate_dependabot_config()).unwrap();
+
+    let ecosystems = actual
+        .updates
+        .iter()
+        .map(|update| update.package_ecosystem.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ecosystems, vec!["cargo", "npm", "github-actions"]);
+    assert!(actual.updates.iter().all(|update| {
+        update.schedule.interval == "weekly" && update.open_pull_requests_limit == 5
+    }));
+}
+
+#[test]
+fn test_ci_workflow_reports_coverage() {
+    workflow::generate_ci_workflow();
+
+    let actual = std::fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../.github/workflows/ci.yml"
+    ))
+    .unwrap();
+
+    assert!(actual.contains("cargo llvm-cov --all-features --workspace --lcov"));
+    assert!(actual.contains("uses: codecov/codecov-action@v5"));
+    assert!(actual.contains("cargo llvm-cov report --fail-under-lines 60"));
+}
+
+#[test]
+fn test_security_workflow() {
+    workflow::generate_security_workflow();
+
+    let actual = std::fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../.github/workflows/security.yml"
+    ))
+    .unwrap();
+
+    assert!(actual.contains("cron: '0 0 * * *'"));
+    assert!(actual.contains("run: './scripts/audit-severity.sh'"));
+    assert!(actual.contains("run: cargo deny check licenses bans sources"));
+}
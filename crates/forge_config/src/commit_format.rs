@@ -0,0 +1,30 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The format enforced on messages generated by `forge commit`.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, fake::Dummy,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitFormat {
+    /// No format is enforced beyond the default prompt guidance
+    #[default]
+    Free,
+    /// Messages must follow the Conventional Commits specification
+    Conventional,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_commit_format_deserializes_snake_case() {
+        let fixture = r#""conventional""#;
+        let actual: CommitFormat = serde_json::from_str(fixture).unwrap();
+        let expected = CommitFormat::Conventional;
+        assert_eq!(actual, expected);
+    }
+}
@@ -39,6 +39,9 @@ pub struct Update {
     pub frequency: Option<UpdateFrequency>,
     /// Whether to automatically install updates without prompting
     pub auto_update: Option<bool>,
+    /// Version forge is pinned to. When set, the automatic update check is
+    /// skipped; `forge update` still upgrades explicitly
+    pub pin_version: Option<String>,
 }
 
 /// Configuration for automatic context compaction for all agents
@@ -72,8 +75,9 @@ pub struct Compact {
 
     /// Maximum percentage of the model context window used to derive the token
     /// threshold before triggering compaction. This is combined with
-    /// `token_threshold` by taking the lower value.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// `token_threshold` by taking the lower value. Also accepted as
+    /// `trigger_threshold`.
+    #[serde(skip_serializing_if = "Option::is_none", alias = "trigger_threshold")]
     pub token_threshold_percentage: Option<Percentage>,
 
     /// Maximum number of conversation turns before triggering compaction
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use derive_setters::Setters;
 use fake::Dummy;
@@ -9,7 +9,8 @@ use serde::{Deserialize, Serialize};
 use crate::reader::ConfigReader;
 use crate::writer::ConfigWriter;
 use crate::{
-    AutoDumpFormat, Compact, Decimal, HttpConfig, ModelConfig, ReasoningConfig, RetryConfig, Update,
+    AutoDumpFormat, CommitFormat, Compact, Decimal, HttpConfig, ModelConfig, ReasoningConfig,
+    RetryConfig, SshConfig, StorageBackend, Update,
 };
 
 /// Wire protocol a provider uses for chat completions.
@@ -21,6 +22,7 @@ pub enum ProviderResponseType {
     Bedrock,
     Google,
     OpenCode,
+    Cohere,
 }
 
 /// Category of a provider.
@@ -203,6 +205,15 @@ pub struct ForgeConfig {
     /// Time-to-live in seconds for the cached model API list.
     #[serde(default)]
     pub model_cache_ttl_secs: u64,
+    /// Serves repeated identical chat requests from an in-memory cache
+    /// instead of calling the provider again. Intended for evaluation and
+    /// testing runs; enable with `FORGE_CACHE_RESPONSES=1`.
+    #[serde(default)]
+    pub cache_responses: bool,
+    /// Time-to-live in seconds for cached chat responses when
+    /// `cache_responses` is enabled.
+    #[serde(default)]
+    pub response_cache_ttl_secs: u64,
     /// Default model and provider configuration used when not overridden by
     /// individual agents.    
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -219,6 +230,10 @@ pub struct ForgeConfig {
     /// generation.
     #[serde(default)]
     pub max_commit_count: usize,
+    /// Format enforced on generated commit messages; defaults to
+    /// [`CommitFormat::Free`] when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_format: Option<CommitFormat>,
     /// Model and provider configuration used for shell command suggestion
     /// generation.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -286,6 +301,23 @@ pub struct ForgeConfig {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub providers: Vec<ProviderEntry>,
 
+    /// Credential alias used for each provider, keyed by provider id (e.g.
+    /// `openai = "work"`).
+    ///
+    /// Providers without an entry use their unaliased credential.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub provider_aliases: HashMap<String, String>,
+
+    /// Where provider credentials are stored; defaults to
+    /// [`StorageBackend::File`] when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credential_storage: Option<StorageBackend>,
+
+    /// Remote host whose filesystem is used for files inside the working
+    /// directory; files are read and written locally when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssh: Option<SshConfig>,
+
     /// Currency symbol displayed in the shell rprompt next to the session cost
     /// (e.g. `"$"`, `"€"`, `"₹"`). Defaults to `"$"`.
     #[serde(default)]
@@ -353,6 +385,25 @@ impl ForgeConfig {
             .build()
     }
 
+    /// Reads configuration from the file at `path` instead of the global
+    /// config file, still layering defaults and environment variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::Error::NotFound`] if `path` does not exist, or an error
+    /// if the file cannot be read or deserialized.
+    pub fn read_from(path: &Path) -> crate::Result<ForgeConfig> {
+        if !path.is_file() {
+            return Err(crate::Error::NotFound(path.to_path_buf()));
+        }
+
+        ConfigReader::default()
+            .read_defaults()
+            .read_file(path)
+            .read_env()
+            .build()
+    }
+
     /// Writes the configuration to the user config file.
     ///
     /// # Errors
@@ -513,4 +564,14 @@ models = "http://example.com/v1/models"
 
         assert_eq!(actual.auto_install_vscode_extension, false);
     }
+
+    #[test]
+    fn test_read_from_missing_file() {
+        let fixture = PathBuf::from("/nonexistent/forge/.forge.toml");
+
+        let actual = ForgeConfig::read_from(&fixture).unwrap_err().to_string();
+
+        let expected = "Config file not found at /nonexistent/forge/.forge.toml";
+        assert_eq!(actual, expected);
+    }
 }
@@ -15,4 +15,16 @@ pub enum Error {
 
     #[error("JSON error: {0}")]
     Serde(#[from] serde_json::Error),
+
+    /// An explicitly requested config file does not exist.
+    #[error("Config file not found at {}", .0.display())]
+    NotFound(std::path::PathBuf),
+
+    /// A profile name is empty or contains path separators.
+    #[error("Invalid profile name '{0}'")]
+    InvalidProfileName(String),
+
+    /// The requested profile directory does not exist.
+    #[error("Profile '{0}' not found. Create it with `forge profile create {0}`")]
+    ProfileNotFound(String),
 }
@@ -0,0 +1,99 @@
+use regex::Regex;
+
+/// A regex matched against error messages, compiled once when the config is
+/// loaded.
+///
+/// Validation is enforced at deserialization time, so any config file with an
+/// invalid pattern is rejected with a descriptive error.
+#[derive(Debug, Clone)]
+pub struct ErrorPattern(Regex);
+
+impl ErrorPattern {
+    /// Compile `pattern` into an `ErrorPattern`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `pattern` is not a valid regex.
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Regex::new(pattern).map(Self)
+    }
+
+    /// Returns true if the pattern matches anywhere in `message`.
+    pub fn is_match(&self, message: &str) -> bool {
+        self.0.is_match(message)
+    }
+
+    /// Returns the pattern as written in the config.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl PartialEq for ErrorPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl schemars::JsonSchema for ErrorPattern {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        String::schema_name()
+    }
+
+    fn json_schema(r#gen: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        String::json_schema(r#gen)
+    }
+}
+
+impl fake::Dummy<fake::Faker> for ErrorPattern {
+    fn dummy_with_rng<R: fake::RngExt + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        use fake::Fake;
+        let text: String = config.fake_with_rng(rng);
+        Self::new(&regex::escape(&text)).unwrap()
+    }
+}
+
+impl serde::Serialize for ErrorPattern {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ErrorPattern {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Self::new(&pattern).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_error_pattern_matches_message() {
+        let fixture = ErrorPattern::new("(?i)rate limit").unwrap();
+        assert!(fixture.is_match("Rate limit exceeded"));
+        assert!(!fixture.is_match("context too long"));
+    }
+
+    #[test]
+    fn test_error_pattern_rejects_invalid_regex() {
+        let actual = serde_json::from_str::<ErrorPattern>(r#""rate (limit""#);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_error_pattern_serializes_as_written() {
+        let fixture = ErrorPattern::new("(?i)rate limit").unwrap();
+        let actual = serde_json::to_string(&fixture).unwrap();
+        let expected = r#""(?i)rate limit""#;
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,8 +1,10 @@
 mod auto_dump;
+mod commit_format;
 mod compact;
 mod config;
 mod decimal;
 mod error;
+mod error_pattern;
 mod http;
 mod legacy;
 mod model;
@@ -10,19 +12,25 @@ mod percentage;
 mod reader;
 mod reasoning;
 mod retry;
+mod ssh;
+mod storage_backend;
 mod writer;
 
 pub use auto_dump::*;
+pub use commit_format::*;
 pub use compact::*;
 pub use config::*;
 pub use decimal::*;
 pub use error::Error;
+pub use error_pattern::*;
 pub use http::*;
 pub use model::*;
 pub use percentage::*;
 pub use reader::*;
 pub use reasoning::*;
 pub use retry::*;
+pub use ssh::*;
+pub use storage_backend::*;
 pub use writer::*;
 
 /// A `Result` type alias for this crate's [`Error`] type.
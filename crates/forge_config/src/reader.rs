@@ -1,5 +1,5 @@
-use std::path::PathBuf;
-use std::sync::LazyLock;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, OnceLock};
 
 use config::ConfigBuilder;
 use config::builder::DefaultState;
@@ -33,6 +33,9 @@ static LOAD_DOT_ENV: LazyLock<()> = LazyLock::new(|| {
 /// Caches base-path resolution for the process lifetime.
 static BASE_PATH: LazyLock<PathBuf> = LazyLock::new(ConfigReader::resolve_base_path);
 
+/// Base path of the profile selected via [`ConfigReader::select_profile`].
+static PROFILE_BASE_PATH: OnceLock<PathBuf> = OnceLock::new();
+
 /// Merges [`ForgeConfig`] from layered sources using a builder pattern.
 #[derive(Default)]
 pub struct ConfigReader {
@@ -55,13 +58,56 @@ impl ConfigReader {
     /// Returns the base directory for all Forge config files.
     ///
     /// Resolution order:
+    /// 0. The profile directory chosen with [`Self::select_profile`], if any.
     /// 1. `FORGE_CONFIG` environment variable, if set.
     /// 2. `~/forge` (legacy path), if that directory exists, so users who have
     ///    not yet run `forge config migrate` continue to read from their
     ///    existing directory without disruption.
     /// 3. `~/.forge` as the default path.
     pub fn base_path() -> PathBuf {
-        BASE_PATH.clone()
+        PROFILE_BASE_PATH
+            .get()
+            .cloned()
+            .unwrap_or_else(|| BASE_PATH.clone())
+    }
+
+    /// Returns the directory holding named configuration profiles
+    /// (`~/.forge/profiles`).
+    pub fn profiles_path() -> PathBuf {
+        BASE_PATH.join("profiles")
+    }
+
+    /// Returns the directory of the profile called `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::Error::InvalidProfileName`] if `name` is empty or is
+    /// not a plain directory name.
+    pub fn profile_path(name: &str) -> crate::Result<PathBuf> {
+        let is_plain_name =
+            !name.is_empty() && name != "." && name != ".." && !name.contains(['/', '\\']);
+        if !is_plain_name {
+            return Err(crate::Error::InvalidProfileName(name.to_string()));
+        }
+        Ok(Self::profiles_path().join(name))
+    }
+
+    /// Makes the profile called `name` the base directory for all config and
+    /// credential files for the rest of the process.
+    ///
+    /// Must be called before any config is read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is invalid or the profile directory does
+    /// not exist.
+    pub fn select_profile(name: &str) -> crate::Result<PathBuf> {
+        let path = Self::profile_path(name)?;
+        if !path.is_dir() {
+            return Err(crate::Error::ProfileNotFound(name.to_string()));
+        }
+        let _ = PROFILE_BASE_PATH.set(path.clone());
+        Ok(path)
     }
 
     fn resolve_base_path() -> PathBuf {
@@ -140,6 +186,16 @@ impl ConfigReader {
         self
     }
 
+    /// Adds the TOML file at `path` as a required config source.
+    pub fn read_file(mut self, path: &Path) -> Self {
+        self.builder = self.builder.add_source(
+            config::File::from(path.to_path_buf())
+                .format(config::FileFormat::Toml)
+                .required(true),
+        );
+        self
+    }
+
     /// Reads `~/.forge/.config.json` (legacy format) and adds it as a source,
     /// silently skipping errors.
     pub fn read_legacy(self) -> Self {
@@ -228,6 +284,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_profile_path_is_nested_under_profiles() {
+        let actual = ConfigReader::profile_path("work").unwrap();
+        let expected = ConfigReader::profiles_path().join("work");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_profile_path_rejects_path_traversal() {
+        let actual = ConfigReader::profile_path("../work").is_err();
+        let expected = true;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_select_profile_requires_existing_directory() {
+        let actual = ConfigReader::select_profile("does-not-exist-profile")
+            .unwrap_err()
+            .to_string();
+        let expected = "Profile 'does-not-exist-profile' not found";
+        assert!(actual.starts_with(expected), "got: {actual}");
+    }
+
     #[test]
     fn test_read_parses_without_error() {
         let actual = ConfigReader::default().read_defaults().build();
@@ -2,10 +2,10 @@ use derive_setters::Setters;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::ErrorPattern;
+
 /// Configuration for retry mechanism.
-#[derive(
-    Default, Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, fake::Dummy, Setters,
-)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, fake::Dummy, Setters)]
 #[serde(rename_all = "snake_case")]
 #[setters(into)]
 pub struct RetryConfig {
@@ -23,6 +23,56 @@ pub struct RetryConfig {
     pub max_delay_secs: Option<u64>,
     /// Whether to suppress retry error logging and events
     pub suppress_errors: bool,
+    /// Magnitude of the random jitter added to each retry delay, as a
+    /// fraction of that delay. Spreads out retries from concurrent clients
+    /// that were rate-limited at the same time. Defaults to `0.5`.
+    #[serde(default = "default_jitter_factor")]
+    pub jitter_factor: f32,
+    /// Circuit breaker applied to each provider host; disabled when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    /// Regex patterns that a retryable error's message must match for it to
+    /// be retried. Every retryable error is retried when empty. An invalid
+    /// pattern is rejected when the config is loaded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on_specific_errors: Vec<ErrorPattern>,
+}
+
+fn default_jitter_factor() -> f32 {
+    0.5
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff_ms: 0,
+            min_delay_ms: 0,
+            backoff_factor: 0,
+            max_attempts: 0,
+            status_codes: vec![],
+            max_delay_secs: None,
+            suppress_errors: false,
+            jitter_factor: default_jitter_factor(),
+            circuit_breaker: None,
+            on_specific_errors: vec![],
+        }
+    }
+}
+
+/// Configuration for failing fast once a provider keeps returning errors.
+#[derive(
+    Default, Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, fake::Dummy, Setters,
+)]
+#[serde(rename_all = "snake_case")]
+#[setters(into)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures after which the circuit opens
+    pub failure_threshold: u32,
+    /// Window in seconds within which the failures must occur
+    pub window_secs: u64,
+    /// Seconds the circuit stays open before a single trial request is let
+    /// through
+    pub recovery_timeout_secs: u64,
 }
 
 #[cfg(test)]
@@ -41,8 +91,43 @@ mod tests {
             status_codes: vec![429, 500, 502, 503, 504, 408, 522, 524, 520, 529],
             max_delay_secs: None,
             suppress_errors: false,
+            jitter_factor: 0.5,
+            circuit_breaker: None,
+            on_specific_errors: vec![],
         };
         assert_eq!(config.initial_backoff_ms, 200);
         assert_eq!(config.suppress_errors, false);
     }
+
+    #[test]
+    fn test_retry_config_jitter_factor_defaults_when_omitted() {
+        let fixture = r#"{
+            "initial_backoff_ms": 200,
+            "min_delay_ms": 1000,
+            "backoff_factor": 2,
+            "max_attempts": 8,
+            "status_codes": [429],
+            "suppress_errors": false
+        }"#;
+
+        let actual = serde_json::from_str::<RetryConfig>(fixture)
+            .unwrap()
+            .jitter_factor;
+
+        let expected = RetryConfig::default().jitter_factor;
+        assert_eq!(actual, expected);
+        assert_eq!(actual, 0.5);
+    }
+
+    #[test]
+    fn test_retry_config_rejects_invalid_error_pattern() {
+        let fixture = "[retry]\non_specific_errors = [\"rate (limit\"]\n";
+
+        let actual = crate::ConfigReader::default()
+            .read_defaults()
+            .read_toml(fixture)
+            .build();
+
+        assert!(actual.is_err());
+    }
 }
@@ -0,0 +1,69 @@
+use std::path::PathBuf;
+
+use derive_setters::Setters;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Connection settings for operating on a remote workspace over SSH.
+///
+/// When configured, file tools read and write files inside the working
+/// directory through SFTP on the remote host instead of the local disk.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, fake::Dummy, Setters)]
+#[serde(rename_all = "snake_case")]
+#[setters(into)]
+pub struct SshConfig {
+    /// Hostname or IP address of the remote machine
+    pub host: String,
+    /// SSH port; defaults to `22`
+    #[serde(default = "default_port")]
+    pub port: u16,
+    /// User to authenticate as
+    pub user: String,
+    /// Private key used for authentication; the SSH agent is used when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub identity_file: Option<PathBuf>,
+    /// Directory on the remote host that mirrors the local working directory;
+    /// paths are used unchanged when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub remote_dir: Option<PathBuf>,
+    /// Trust a host that is missing from `~/.ssh/known_hosts` on first
+    /// connect and record its key there. Hosts whose key does not match the
+    /// recorded one are always rejected.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub accept_unknown_host: bool,
+}
+
+fn default_port() -> u16 {
+    22
+}
+
+impl SshConfig {
+    /// Creates a config for `user@host` on the default port
+    pub fn new(host: impl Into<String>, user: impl Into<String>) -> Self {
+        Self {
+            host: host.into(),
+            port: default_port(),
+            user: user.into(),
+            identity_file: None,
+            remote_dir: None,
+            accept_unknown_host: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_ssh_config_defaults_port() {
+        let fixture = r#"{"host": "dev.example.com", "user": "forge"}"#;
+        let actual: SshConfig = serde_json::from_str(fixture).unwrap();
+        let expected = SshConfig::new("dev.example.com", "forge");
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,32 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Where provider credentials are persisted.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, fake::Dummy,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageBackend {
+    /// A JSON file in the forge base directory readable only by the owner
+    #[default]
+    File,
+    /// The platform keychain (macOS Keychain, Windows Credential Manager or
+    /// the Secret Service on Linux). Credentials can't be read or saved
+    /// while it is unavailable or locked
+    Keychain,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_storage_backend_deserializes_snake_case() {
+        let fixture = r#""keychain""#;
+        let actual: StorageBackend = serde_json::from_str(fixture).unwrap();
+        let expected = StorageBackend::Keychain;
+        assert_eq!(actual, expected);
+    }
+}
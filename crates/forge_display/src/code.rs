@@ -163,6 +163,14 @@ impl CodeBlockParser {
         }
         rendered
     }
+
+    /// Restore code blocks into rendered output as unstyled text
+    pub fn restore_plain(&self, mut rendered: String) -> String {
+        for (i, block) in self.blocks.iter().enumerate() {
+            rendered = rendered.replace(&format!("\x00{i}\x00"), &block.code);
+        }
+        rendered
+    }
 }
 
 #[cfg(test)]
@@ -14,6 +14,9 @@ use crate::code::{CodeBlockParser, SyntaxHighlighter};
 pub struct MarkdownFormat {
     skin: MadSkin,
     max_consecutive_newlines: usize,
+    /// Leaves code blocks unhighlighted so the output has no ANSI codes
+    #[setters(skip)]
+    plain: bool,
     #[setters(skip)]
     highlighter: OnceLock<SyntaxHighlighter>,
 }
@@ -41,6 +44,18 @@ impl MarkdownFormat {
         Self {
             skin,
             max_consecutive_newlines: 2,
+            plain: false,
+            highlighter: OnceLock::new(),
+        }
+    }
+
+    /// Create a MarkdownFormat without any styling, for output that isn't
+    /// shown in a terminal (e.g. when stdout is piped)
+    pub fn plain() -> Self {
+        Self {
+            skin: MadSkin::no_style(),
+            max_consecutive_newlines: 2,
+            plain: true,
             highlighter: OnceLock::new(),
         }
     }
@@ -57,6 +72,9 @@ impl MarkdownFormat {
 
         // Render with termimad, then restore highlighted code
         let rendered = self.skin.term_text(processed.markdown()).to_string();
+        if self.plain {
+            return processed.restore_plain(rendered).trim().to_string();
+        }
         let highlighter = self.highlighter.get_or_init(SyntaxHighlighter::default);
         processed.restore(highlighter, rendered).trim().to_string()
     }
@@ -88,6 +106,20 @@ mod tests {
         assert!(!actual.is_empty());
     }
 
+    #[test]
+    fn test_render_plain_has_no_ansi_codes() {
+        let fixture = "# Heading\n\nSome **bold** and `code` text.\n\n```rust\nfn main() {}\n```";
+        let markdown = MarkdownFormat::plain();
+        let actual = markdown.render(fixture);
+
+        assert!(
+            !actual.contains('\x1b'),
+            "unexpected ANSI codes: {actual:?}"
+        );
+        assert!(actual.contains("bold"));
+        assert!(actual.contains("fn main() {}"));
+    }
+
     #[test]
     fn test_render_empty_markdown() {
         let fixture = "";
@@ -8,8 +8,9 @@ use serde::{Deserialize, Serialize};
 use strum_macros::{Display as StrumDisplay, EnumString};
 
 use crate::{
-    Compact, Error, EventContext, MaxTokens, Model, ModelId, ProviderId, Result, SystemContext,
-    Temperature, Template, ToolDefinition, ToolName, TopK, TopP,
+    Compact, ContextPruner, Error, EventContext, MaxTokens, MinP, Model, ModelId, ProviderId,
+    RepetitionPenalty, Result, SystemContext, Temperature, Template, ToolDefinition, ToolName,
+    TopK, TopP,
 };
 
 // Unique identifier for an agent
@@ -143,6 +144,10 @@ pub struct Agent {
     /// Configuration for automatic context compaction
     pub compact: Compact,
 
+    /// Strategy used to keep requests within the model's context window
+    #[serde(default)]
+    pub pruner: ContextPruner,
+
     /// A set of custom rules that the agent should follow
     pub custom_rules: Option<String>,
 
@@ -155,6 +160,12 @@ pub struct Agent {
     /// Top-k used for agent
     pub top_k: Option<TopK>,
 
+    /// Min-p used for agent
+    pub min_p: Option<MinP>,
+
+    /// Repetition penalty used for agent
+    pub repetition_penalty: Option<RepetitionPenalty>,
+
     /// Maximum number of tokens the model can generate
     pub max_tokens: Option<MaxTokens>,
 
@@ -198,10 +209,13 @@ impl Agent {
             tools: Default::default(),
             max_turns: Default::default(),
             compact: Compact::default(),
+            pruner: ContextPruner::default(),
             custom_rules: Default::default(),
             temperature: Default::default(),
             top_p: Default::default(),
             top_k: Default::default(),
+            min_p: Default::default(),
+            repetition_penalty: Default::default(),
             max_tokens: Default::default(),
             reasoning: Default::default(),
             max_tool_failure_per_turn: Default::default(),
@@ -312,6 +326,8 @@ mod tests {
             supports_parallel_tool_calls: Some(true),
             supports_reasoning: Some(true),
             input_modalities: vec![InputModality::Text],
+            cost_per_million_input_tokens: None,
+            cost_per_million_output_tokens: None,
         }
     }
 
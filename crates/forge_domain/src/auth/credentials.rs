@@ -12,20 +12,33 @@ pub struct AuthCredential {
     pub auth_details: AuthDetails,
     #[serde(skip_serializing_if = "HashMap::is_empty", default)]
     pub url_params: HashMap<URLParam, URLParamValue>,
+    /// Name distinguishing this credential from other accounts stored for the
+    /// same provider (e.g. `work`, `personal`)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub alias: Option<String>,
+    /// When the credential stops working and the user has to log in again.
+    /// Only set for OAuth credentials that cannot be refreshed.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub expires_at: Option<DateTime<Utc>>,
 }
+
 impl AuthCredential {
     pub fn new_api_key(id: ProviderId, api_key: ApiKey) -> Self {
         Self {
             id,
             auth_details: AuthDetails::ApiKey(api_key),
             url_params: HashMap::new(),
+            alias: None,
+            expires_at: None,
         }
     }
     pub fn new_oauth(id: ProviderId, tokens: OAuthTokens, config: OAuthConfig) -> Self {
         Self {
             id,
+            expires_at: tokens.credential_expiry(),
             auth_details: AuthDetails::OAuth { tokens, config },
             url_params: HashMap::new(),
+            alias: None,
         }
     }
     pub fn new_oauth_with_api_key(
@@ -36,8 +49,10 @@ impl AuthCredential {
     ) -> Self {
         Self {
             id,
+            expires_at: tokens.credential_expiry(),
             auth_details: AuthDetails::OAuthWithApiKey { tokens, api_key, config },
             url_params: HashMap::new(),
+            alias: None,
         }
     }
 
@@ -46,6 +61,8 @@ impl AuthCredential {
             id,
             auth_details: AuthDetails::AwsProfile(profile_name),
             url_params: HashMap::new(),
+            alias: None,
+            expires_at: None,
         }
     }
 
@@ -54,6 +71,8 @@ impl AuthCredential {
             id,
             auth_details: AuthDetails::GoogleAdc(access_token),
             url_params: HashMap::new(),
+            alias: None,
+            expires_at: None,
         }
     }
 
@@ -71,6 +90,13 @@ impl AuthCredential {
         }
     }
 
+    /// Checks if the credential expires within the next 24 hours, so the user
+    /// can be warned before they have to log in again
+    pub fn is_near_expiry(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| Utc::now() + chrono::Duration::hours(24) >= expires_at)
+    }
+
     /// Gets the OAuth config if this credential is OAuth-based
     pub fn oauth_config(&self) -> Option<&OAuthConfig> {
         match &self.auth_details {
@@ -143,8 +169,53 @@ impl OAuthTokens {
         now + buffer >= self.expires_at
     }
 
+    /// Returns when a credential holding these tokens expires. Tokens with a
+    /// refresh token are renewed transparently and never expire.
+    fn credential_expiry(&self) -> Option<DateTime<Utc>> {
+        self.refresh_token.is_none().then_some(self.expires_at)
+    }
+
     /// Checks if the token is currently expired
     pub fn is_expired(&self) -> bool {
         Utc::now() >= self.expires_at
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(refresh_token: Option<&str>, expires_in: chrono::Duration) -> AuthCredential {
+        let tokens = OAuthTokens::new("access", refresh_token, Utc::now() + expires_in);
+        let config = OAuthConfig {
+            auth_url: "https://example.com/auth".parse().unwrap(),
+            token_url: "https://example.com/token".parse().unwrap(),
+            client_id: "client".to_string().into(),
+            scopes: vec![],
+            redirect_uri: None,
+            use_pkce: false,
+            token_refresh_url: None,
+            custom_headers: None,
+            extra_auth_params: None,
+        };
+        AuthCredential::new_oauth(ProviderId::OPENAI, tokens, config)
+    }
+
+    #[test]
+    fn test_is_near_expiry_within_a_day() {
+        let actual = fixture(None, chrono::Duration::hours(1)).is_near_expiry();
+        assert!(actual);
+    }
+
+    #[test]
+    fn test_is_near_expiry_far_in_future() {
+        let actual = fixture(None, chrono::Duration::days(7)).is_near_expiry();
+        assert!(!actual);
+    }
+
+    #[test]
+    fn test_is_near_expiry_with_refresh_token() {
+        let actual = fixture(Some("refresh"), chrono::Duration::hours(1)).is_near_expiry();
+        assert!(!actual);
+    }
+}
@@ -1,5 +1,8 @@
+use std::collections::HashMap;
+
 use derive_setters::Setters;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::{ConversationId, Event};
 
@@ -8,10 +11,56 @@ use crate::{ConversationId, Event};
 pub struct ChatRequest {
     pub event: Event,
     pub conversation_id: ConversationId,
+    /// Caps the number of requests the agent may send to the provider while
+    /// handling this event, overriding the agent's `max_requests_per_turn`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_requests: Option<usize>,
+    /// Caller-defined values (e.g. trace or session IDs) used to correlate
+    /// this request with external systems. Forge never sends them to the
+    /// provider; they are recorded on the conversation and echoed back in
+    /// [`crate::ChatResponse::TaskComplete`]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, Value>,
 }
 
 impl ChatRequest {
     pub fn new(content: Event, conversation_id: ConversationId) -> Self {
-        Self { event: content, conversation_id }
+        Self {
+            event: content,
+            conversation_id,
+            max_requests: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Adds a caller-defined metadata entry, replacing any previous value
+    /// stored under `key`
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_with_metadata_adds_and_replaces_entries() {
+        let fixture = ChatRequest::new(Event::new("hi"), ConversationId::generate())
+            .with_metadata("trace_id", "abc")
+            .with_metadata("attempt", 1)
+            .with_metadata("attempt", 2);
+
+        let actual = fixture.metadata;
+
+        let expected = HashMap::from([
+            ("trace_id".to_string(), json!("abc")),
+            ("attempt".to_string(), json!(2)),
+        ]);
+        assert_eq!(actual, expected);
     }
 }
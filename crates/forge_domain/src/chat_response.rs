@@ -59,7 +59,11 @@ pub enum ChatResponse {
     TaskReasoning {
         content: String,
     },
-    TaskComplete,
+    /// The agent finished the task. Carries the caller-defined metadata of
+    /// the chat request that started it.
+    TaskComplete {
+        metadata: HashMap<String, serde_json::Value>,
+    },
     ToolCallStart {
         tool_call: ToolCallFull,
         notifier: Arc<Notify>,
@@ -72,6 +76,13 @@ pub enum ChatResponse {
     Interrupt {
         reason: InterruptionReason,
     },
+    /// The context was compacted automatically after crossing the agent's
+    /// compaction threshold. Reductions are percentages of the context before
+    /// compaction.
+    AutoCompacted {
+        token_reduction: f64,
+        message_reduction: f64,
+    },
 }
 
 impl ChatResponse {
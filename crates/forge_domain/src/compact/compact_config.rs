@@ -40,9 +40,11 @@ pub struct Compact {
 
     /// Maximum percentage of the model context window used to derive the token
     /// threshold before triggering compaction. This is combined with
-    /// `token_threshold` by taking the lower value.
+    /// `token_threshold` by taking the lower value. Also accepted as
+    /// `trigger_threshold`.
     #[serde(
         default,
+        alias = "trigger_threshold",
         skip_serializing_if = "Option::is_none",
         deserialize_with = "deserialize_optional_percentage"
     )]
@@ -1,9 +1,11 @@
 mod compact_config;
+mod pruner;
 mod result;
 mod strategy;
 mod summary;
 
 pub use compact_config::*;
+pub use pruner::*;
 pub use result::*;
 pub use strategy::*;
 pub use summary::*;
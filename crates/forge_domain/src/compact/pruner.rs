@@ -0,0 +1,140 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{Context, Role};
+
+/// Strategy used to keep a request within the model's context window before
+/// it is sent to the provider.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextPruner {
+    /// Rely on compaction alone; the context is never pruned before a request
+    #[default]
+    Compact,
+    /// Drop the oldest non-system messages until the context fits the budget
+    OldestFirst,
+    /// Summarize older messages first, then drop the oldest remaining
+    /// messages if the context still exceeds the budget
+    SummarizeAndPrune,
+}
+
+impl ContextPruner {
+    /// Fraction of the model's context window a request may occupy before
+    /// pruning kicks in.
+    pub const WINDOW_RATIO: f64 = 0.9;
+
+    /// Returns the token budget for a model with the given context window.
+    pub fn budget(context_window: u64) -> usize {
+        ((context_window as f64) * Self::WINDOW_RATIO).floor() as usize
+    }
+
+    /// Removes the oldest non-system messages from `context` until at least
+    /// `token_count - budget` tokens have been freed, returning the number of
+    /// removed messages.
+    ///
+    /// System messages and the most recent message are always kept. Tool
+    /// results left at the front of the history after their tool call was
+    /// dropped are removed as well, since providers reject orphaned results.
+    pub fn prune_oldest_first(context: &mut Context, token_count: usize, budget: usize) -> usize {
+        let mut excess = token_count.saturating_sub(budget);
+        let mut removed = 0;
+
+        while let Some(index) = context
+            .messages
+            .iter()
+            .position(|entry| !entry.has_role(Role::System))
+        {
+            if index + 1 >= context.messages.len() {
+                break;
+            }
+            if excess == 0 && !context.messages[index].has_tool_result() {
+                break;
+            }
+            let entry = context.messages.remove(index);
+            excess = excess.saturating_sub(entry.token_count_approx());
+            removed += 1;
+        }
+
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{ContextMessage, ToolCallId, ToolName, ToolResult};
+
+    fn contents(context: &Context) -> Vec<String> {
+        context
+            .messages
+            .iter()
+            .map(|entry| entry.content().unwrap_or_default().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_budget_is_ninety_percent_of_window() {
+        let actual = ContextPruner::budget(100_000);
+        let expected = 90_000;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_prune_oldest_first_removes_oldest_messages() {
+        let mut fixture = Context::default()
+            .add_message(ContextMessage::system("system"))
+            .add_message(ContextMessage::user("a".repeat(400), None))
+            .add_message(ContextMessage::assistant("b".repeat(400), None, None, None))
+            .add_message(ContextMessage::user("c".repeat(400), None))
+            .add_message(ContextMessage::user("latest", None));
+        let token_count = fixture.token_count_approx();
+
+        let actual =
+            ContextPruner::prune_oldest_first(&mut fixture, token_count, token_count - 150);
+
+        let expected = vec!["system".to_string(), "c".repeat(400), "latest".to_string()];
+        assert_eq!(actual, 2);
+        assert_eq!(contents(&fixture), expected);
+    }
+
+    #[test]
+    fn test_prune_oldest_first_within_budget_is_noop() {
+        let mut fixture = Context::default()
+            .add_message(ContextMessage::user("hello", None))
+            .add_message(ContextMessage::user("latest", None));
+        let expected = fixture.clone();
+
+        let actual = ContextPruner::prune_oldest_first(&mut fixture, 10, 100);
+
+        assert_eq!(actual, 0);
+        assert_eq!(fixture, expected);
+    }
+
+    #[test]
+    fn test_prune_oldest_first_drops_orphaned_tool_results() {
+        let mut fixture = Context::default()
+            .add_message(ContextMessage::assistant("a".repeat(40), None, None, None))
+            .add_message(ContextMessage::tool_result(
+                ToolResult::new(ToolName::new("read")).call_id(ToolCallId::new("1")),
+            ))
+            .add_message(ContextMessage::user("latest", None));
+
+        let actual = ContextPruner::prune_oldest_first(&mut fixture, 20, 15);
+
+        assert_eq!(actual, 2);
+        assert_eq!(contents(&fixture), vec!["latest".to_string()]);
+    }
+
+    #[test]
+    fn test_prune_oldest_first_keeps_latest_message() {
+        let mut fixture =
+            Context::default().add_message(ContextMessage::user("x".repeat(400), None));
+
+        let actual = ContextPruner::prune_oldest_first(&mut fixture, 100, 0);
+
+        assert_eq!(actual, 0);
+        assert_eq!(fixture.messages.len(), 1);
+    }
+}
@@ -14,6 +14,8 @@ fn is_false(value: &bool) -> bool {
     !value
 }
 
+use crate::min_p::MinP;
+use crate::repetition_penalty::RepetitionPenalty;
 use crate::temperature::Temperature;
 use crate::top_k::TopK;
 use crate::top_p::TopP;
@@ -101,6 +103,7 @@ impl ContextMessage {
                 .iter()
                 .map(|result| match result {
                     ToolValue::Text(text) => text.chars().count(),
+                    ToolValue::Json(json) => json.to_string().chars().count(),
                     _ => 0,
                 })
                 .sum(),
@@ -420,6 +423,10 @@ pub struct Context {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub top_k: Option<TopK>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_p: Option<MinP>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repetition_penalty: Option<RepetitionPenalty>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reasoning: Option<crate::ReasoningConfig>,
     /// Controls whether responses should be streamed. When `true`, responses
     /// are delivered incrementally as they're generated. When `false`, the
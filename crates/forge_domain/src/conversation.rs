@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::str::FromStr;
 
 use chrono::{DateTime, Utc};
@@ -19,6 +20,22 @@ impl ConversationId {
         Self(Uuid::new_v4())
     }
 
+    /// Generates a v4-formatted id that is fully determined by `seed`, so tests
+    /// can create stable conversation ids
+    pub fn generate_from_seed(seed: u64) -> Self {
+        // SplitMix64 spreads neighbouring seeds across all 128 bits
+        let mut state = seed;
+        let mut next = || {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+        let bits = (u128::from(next()) << 64) | u128::from(next());
+        Self(uuid::Builder::from_random_bytes(bits.to_be_bytes()).into_uuid())
+    }
+
     pub fn into_string(&self) -> String {
         self.0.to_string()
     }
@@ -53,11 +70,19 @@ pub struct Conversation {
 pub struct MetaData {
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
+    /// Caller-defined metadata accumulated from the chat requests sent on
+    /// this conversation. Later requests overwrite values with the same key.
+    #[serde(default)]
+    pub request_metadata: HashMap<String, serde_json::Value>,
 }
 
 impl MetaData {
     pub fn new(created_at: DateTime<Utc>) -> Self {
-        Self { created_at, updated_at: None }
+        Self {
+            created_at,
+            updated_at: None,
+            request_metadata: HashMap::new(),
+        }
     }
 }
 
@@ -92,8 +117,13 @@ impl Conversation {
     /// - If the Handlebars template registration fails
     /// - If the template rendering fails
     pub fn to_html(&self) -> String {
-        // Instead of using Handlebars, we now use our Element DSL
-        crate::conversation_html::render_conversation_html(self)
+        self.to_html_with_options(&crate::HtmlExportOptions::default())
+    }
+
+    /// Generates an HTML representation of the conversation using the given
+    /// export options
+    pub fn to_html_with_options(&self, options: &crate::HtmlExportOptions) -> String {
+        crate::conversation_html::render_conversation_html(self, options)
     }
 
     /// Generates an HTML representation with related agent conversations
@@ -101,7 +131,21 @@ impl Conversation {
     /// Creates a single HTML document containing the main conversation
     /// and all related agent conversations with anchor links for navigation.
     pub fn to_html_with_related(&self, related: &[Conversation]) -> String {
-        crate::conversation_html::render_conversation_html_with_related(self, related)
+        crate::conversation_html::render_conversation_html_with_related(
+            self,
+            related,
+            &crate::HtmlExportOptions::default(),
+        )
+    }
+
+    /// Exports the conversation as a PDF at `path` by rendering its HTML with
+    /// a headless Chromium or `wkhtmltopdf`
+    ///
+    /// # Errors
+    /// - [`Error::PdfRendererNotFound`] if no renderer is installed
+    /// - If the renderer fails or does not produce the output file
+    pub fn to_pdf(&self, path: &std::path::Path) -> anyhow::Result<()> {
+        crate::PdfRenderer::detect()?.render_html(&self.to_html(), path)
     }
 
     /// Returns a vector of user messages, selecting the first message from
@@ -209,6 +253,21 @@ mod tests {
     use super::*;
     use crate::{Context, ContextMessage, ToolOutput, ToolResult, ToolValue};
 
+    #[test]
+    fn test_generate_from_seed_is_deterministic() {
+        let actual = ConversationId::generate_from_seed(42);
+        let expected = ConversationId::generate_from_seed(42);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_generate_from_seed_differs_per_seed() {
+        let actual = ConversationId::generate_from_seed(42);
+        let other = ConversationId::generate_from_seed(43);
+        assert_ne!(actual, other);
+        assert_eq!(actual.0.get_version_num(), 4);
+    }
+
     #[test]
     fn test_related_conversation_ids_empty() {
         let conversation = Conversation::generate();
@@ -4,6 +4,28 @@ use serde_json::to_string_pretty;
 use crate::context::ContextMessage;
 use crate::conversation::Conversation;
 
+/// Default CDN base URL serving Prism.js and its themes and plugins
+pub const DEFAULT_PRISM_CDN: &str = "https://cdn.jsdelivr.net/npm/prismjs@1.29.0";
+
+/// Options controlling how a conversation is exported to HTML
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlExportOptions {
+    /// Embed the stylesheet for readable typography and message cards
+    pub include_styles: bool,
+    /// Base URL of the Prism.js CDN used to syntax highlight code blocks;
+    /// highlighting is disabled when `None`
+    pub prism_cdn: Option<String>,
+}
+
+impl Default for HtmlExportOptions {
+    fn default() -> Self {
+        Self {
+            include_styles: true,
+            prism_cdn: Some(DEFAULT_PRISM_CDN.to_string()),
+        }
+    }
+}
+
 /// Renders a conversation as an HTML document
 ///
 /// Creates a complete HTML page displaying the conversation's information
@@ -18,7 +40,11 @@ use crate::conversation::Conversation;
 /// # Arguments
 ///
 /// * `conversation` - The conversation to render
-pub fn render_conversation_html(conversation: &Conversation) -> String {
+/// * `options` - Controls embedded styles and syntax highlighting
+pub fn render_conversation_html(
+    conversation: &Conversation,
+    options: &HtmlExportOptions,
+) -> String {
     let c_title = format!(
         "Title: {}",
         conversation
@@ -28,17 +54,7 @@ pub fn render_conversation_html(conversation: &Conversation) -> String {
     );
     let html = Element::new("html")
         .attr("lang", "en")
-        .append(
-            Element::new("head")
-                .append(Element::new("meta").attr("charset", "UTF-8"))
-                .append(
-                    Element::new("meta")
-                        .attr("name", "viewport")
-                        .attr("content", "width=device-width, initial-scale=1.0"),
-                )
-                .append(Element::new("title").text(&c_title))
-                .append(Element::new("style").text(include_str!("conversation_style.css"))), // Includes tool-call-error styles
-        )
+        .append(create_head(&c_title, options))
         .append(
             Element::new("body")
                 // Combined Information Table
@@ -62,9 +78,11 @@ pub fn render_conversation_html(conversation: &Conversation) -> String {
 ///
 /// * `conversation` - The main conversation to render
 /// * `related` - Related agent conversations to include
+/// * `options` - Controls embedded styles and syntax highlighting
 pub fn render_conversation_html_with_related(
     conversation: &Conversation,
     related: &[Conversation],
+    options: &HtmlExportOptions,
 ) -> String {
     let c_title = format!(
         "Title: {}",
@@ -109,22 +127,123 @@ pub fn render_conversation_html_with_related(
 
     let html = Element::new("html")
         .attr("lang", "en")
-        .append(
-            Element::new("head")
-                .append(Element::new("meta").attr("charset", "UTF-8"))
-                .append(
-                    Element::new("meta")
-                        .attr("name", "viewport")
-                        .attr("content", "width=device-width, initial-scale=1.0"),
-                )
-                .append(Element::new("title").text(&c_title))
-                .append(Element::new("style").text(include_str!("conversation_style.css"))),
-        )
+        .append(create_head(&c_title, options))
         .append(body);
 
     format!("<!DOCTYPE html>\n{}", html.render())
 }
 
+/// Creates the document head with the optional stylesheet and Prism.js assets
+fn create_head(title: &str, options: &HtmlExportOptions) -> Element {
+    let mut head = Element::new("head")
+        .append(Element::new("meta").attr("charset", "UTF-8"))
+        .append(
+            Element::new("meta")
+                .attr("name", "viewport")
+                .attr("content", "width=device-width, initial-scale=1.0"),
+        )
+        .append(Element::new("title").text(title));
+
+    if options.include_styles {
+        // Includes tool-call-error styles
+        head = head.append(Element::new("style").text(include_str!("conversation_style.css")));
+    }
+
+    if let Some(cdn) = &options.prism_cdn {
+        let cdn = cdn.trim_end_matches('/');
+        head = head
+            .append(
+                Element::new("link")
+                    .attr("rel", "stylesheet")
+                    .attr("href", format!("{cdn}/themes/prism.min.css")),
+            )
+            .append(
+                Element::new("script").attr("src", format!("{cdn}/components/prism-core.min.js")),
+            )
+            .append(Element::new("script").attr(
+                "src",
+                format!("{cdn}/plugins/autoloader/prism-autoloader.min.js"),
+            ));
+    }
+
+    head
+}
+
+/// Renders message content, turning fenced code blocks into
+/// `<pre><code class="language-*">` elements that Prism.js can highlight
+fn create_message_content(content: &str) -> Vec<Element> {
+    if !content.contains("```") && !content.contains("~~~") {
+        return vec![Element::new("pre").text(content)];
+    }
+
+    let code_element = |language: &str, lines: &[&str]| {
+        let language = if language.is_empty() {
+            "plaintext"
+        } else {
+            language
+        };
+        Element::new("pre").append(
+            Element::new("code")
+                .attr("class", format!("language-{language}"))
+                .text(lines.join("\n")),
+        )
+    };
+
+    let mut elements = Vec::new();
+    let mut text: Vec<&str> = Vec::new();
+    // The opening fence character and length, the language and the code lines
+    let mut code: Option<(char, usize, &str, Vec<&str>)> = None;
+
+    for line in content.lines() {
+        match (&mut code, parse_fence(line)) {
+            // A fence only closes on the same character, at least as long as
+            // the opening one, with nothing after it
+            (Some((marker, len, language, lines)), Some((close, close_len, rest)))
+                if close == *marker && close_len >= *len && rest.trim().is_empty() =>
+            {
+                elements.push(code_element(language, lines));
+                code = None;
+            }
+            (Some((_, _, _, lines)), _) => lines.push(line),
+            (None, Some((marker, len, info))) => {
+                if !text.is_empty() {
+                    elements.push(Element::new("pre").text(text.join("\n")));
+                    text.clear();
+                }
+                // Info strings such as `rust,ignore` or `python {.numberLines}`
+                // start with the language
+                let language = info
+                    .trim_start()
+                    .split(|c: char| c == ',' || c == '{' || c.is_whitespace())
+                    .next()
+                    .unwrap_or_default();
+                code = Some((marker, len, language, Vec::new()));
+            }
+            (None, None) => text.push(line),
+        }
+    }
+
+    // An unterminated fence still renders its contents as code
+    if let Some((_, _, language, lines)) = &code {
+        elements.push(code_element(language, lines));
+    }
+    if !text.is_empty() {
+        elements.push(Element::new("pre").text(text.join("\n")));
+    }
+
+    elements
+}
+
+/// Parses `line` as a code fence of three or more backticks or tildes,
+/// returning the fence character, its length and the rest of the line
+fn parse_fence(line: &str) -> Option<(char, usize, &str)> {
+    let line = line.trim_start();
+    let marker = line.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let rest = line.trim_start_matches(marker);
+    let len = line.len() - rest.len();
+    (len >= 3).then_some((marker, len, rest))
+}
+
 /// Creates a table row with a label and value
 fn create_table_row(label: impl Into<String>, value: impl Into<String>) -> Element {
     Element::new("tr")
@@ -364,7 +483,7 @@ fn create_conversation_context_section(conversation: &Conversation) -> Element {
                         // Add main content
                         let message_elm = message_elm.append(
                             Element::new("div.main-content")
-                                .append(Element::new("pre").text(&content_message.content)),
+                                .append(create_message_content(&content_message.content)),
                         );
 
                         // Add tool calls if any
@@ -429,6 +548,10 @@ fn create_conversation_context_section(conversation: &Conversation) -> Element {
                                         crate::ToolValue::Image(image) => {
                                             Some(Element::new("img").attr("src", image.url()))
                                         }
+                                        crate::ToolValue::Json(json) => Some(
+                                            Element::new("div")
+                                                .append(Element::new("pre").text(json.to_string())),
+                                        ),
                                         crate::ToolValue::Empty => None,
                                         crate::ToolValue::AI { value, conversation_id } => {
                                             // Use anchor link to navigate within the same HTML
@@ -502,7 +625,7 @@ mod tests {
         let conversation: Conversation = json_fixture!("tests/fixtures/conversation.json").await;
 
         // Render the HTML
-        let html = render_conversation_html(&conversation);
+        let html = render_conversation_html(&conversation, &HtmlExportOptions::default());
 
         // Convert HTML string to bytes for binary snapshot
         let html_bytes = html.into_bytes();
@@ -510,4 +633,66 @@ mod tests {
         // Binary snapshot with exact .html extension
         insta::assert_binary_snapshot!("conversation.html", html_bytes);
     }
+
+    #[test]
+    fn test_render_conversation_html_highlights_code_blocks() {
+        let fixture = Conversation::generate().context(crate::Context::default().add_message(
+            ContextMessage::assistant(
+                "Try this:\n```rust\nfn main() {}\n```\nDone",
+                None,
+                None,
+                None,
+            ),
+        ));
+
+        let actual = render_conversation_html(&fixture, &HtmlExportOptions::default());
+
+        assert!(actual.contains("class=\"language-rust\""));
+        assert!(actual.contains(DEFAULT_PRISM_CDN));
+        assert!(actual.contains("fn main() {}"));
+    }
+
+    #[test]
+    fn test_create_message_content_takes_language_from_info_string() {
+        let fixture = "```rust,ignore\nfn a() {}\n```\n```python {.numberLines}\nb()\n```";
+
+        let actual = create_message_content(fixture)
+            .iter()
+            .map(Element::render)
+            .collect::<Vec<_>>();
+
+        let expected = vec![
+            "<pre>\n<code\n  class=\"language-rust\"\n>fn a() {}\n</code>\n</pre>".to_string(),
+            "<pre>\n<code\n  class=\"language-python\"\n>b()\n</code>\n</pre>".to_string(),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_create_message_content_closes_fence_on_matching_fence_only() {
+        let fixture = "````markdown\n```rust\nfn a() {}\n```\n~~~~\n````\nafter";
+
+        let actual = create_message_content(fixture)
+            .iter()
+            .map(Element::render)
+            .collect::<Vec<_>>();
+
+        let expected = vec![
+            "<pre>\n<code\n  class=\"language-markdown\"\n>```rust\nfn a() {}\n```\n~~~~\n</code>\n</pre>"
+                .to_string(),
+            "<pre>after</pre>".to_string(),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_render_conversation_html_without_styles_or_prism() {
+        let fixture = Conversation::generate();
+        let options = HtmlExportOptions { include_styles: false, prism_cdn: None };
+
+        let actual = render_conversation_html(&fixture, &options);
+
+        assert!(!actual.contains("<style>"));
+        assert!(!actual.contains("prism"));
+    }
 }
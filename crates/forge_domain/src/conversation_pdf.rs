@@ -0,0 +1,148 @@
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::Context as _;
+use bstr::ByteSlice;
+
+use crate::Error;
+
+/// Executables able to print HTML to PDF, in order of preference
+const CHROMIUM_PROGRAMS: &[&str] = &["chromium", "chromium-browser", "google-chrome"];
+const WKHTMLTOPDF_PROGRAM: &str = "wkhtmltopdf";
+
+/// External program used to convert an HTML document into a PDF
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PdfRenderer {
+    /// A headless Chromium-based browser using `--print-to-pdf`
+    Chromium(PathBuf),
+    /// The `wkhtmltopdf` command line tool
+    Wkhtmltopdf(PathBuf),
+}
+
+impl PdfRenderer {
+    /// Finds the first available renderer on the current `PATH`
+    ///
+    /// # Errors
+    /// Returns [`Error::PdfRendererNotFound`] if neither a Chromium-based
+    /// browser nor `wkhtmltopdf` is installed.
+    pub fn detect() -> crate::Result<Self> {
+        Self::detect_in(std::env::var_os("PATH").unwrap_or_default())
+    }
+
+    /// Finds the first available renderer in the given `PATH`-style list of
+    /// directories
+    pub fn detect_in(search_path: impl AsRef<OsStr>) -> crate::Result<Self> {
+        let find = |program: &str| {
+            std::env::split_paths(&search_path)
+                .map(|dir| dir.join(program))
+                .find(|candidate| candidate.is_file())
+        };
+
+        CHROMIUM_PROGRAMS
+            .iter()
+            .find_map(|program| find(program).map(Self::Chromium))
+            .or_else(|| find(WKHTMLTOPDF_PROGRAM).map(Self::Wkhtmltopdf))
+            .ok_or(Error::PdfRendererNotFound)
+    }
+
+    /// Renders the HTML file at `input` into a PDF written to `output`
+    pub fn render(&self, input: &Path, output: &Path) -> anyhow::Result<()> {
+        let mut command = match self {
+            Self::Chromium(program) => {
+                let mut command = Command::new(program);
+                command
+                    .arg("--headless")
+                    .arg("--disable-gpu")
+                    .arg("--no-pdf-header-footer")
+                    .arg(format!("--print-to-pdf={}", output.display()))
+                    .arg(input);
+                command
+            }
+            Self::Wkhtmltopdf(program) => {
+                let mut command = Command::new(program);
+                command.arg("--quiet").arg(input).arg(output);
+                command
+            }
+        };
+
+        let result = command
+            .output()
+            .with_context(|| format!("Failed to run PDF renderer: {self:?}"))?;
+        if !result.status.success() {
+            anyhow::bail!(
+                "PDF renderer exited with {}: {}",
+                result.status,
+                result.stderr.to_str_lossy().trim()
+            );
+        }
+        if !output.exists() {
+            anyhow::bail!("PDF renderer did not create {}", output.display());
+        }
+        Ok(())
+    }
+
+    /// Writes `html` to a temporary file and renders it into a PDF at
+    /// `output`
+    pub fn render_html(&self, html: &str, output: &Path) -> anyhow::Result<()> {
+        let input = std::env::temp_dir().join(format!("forge-{}.html", uuid::Uuid::new_v4()));
+        std::fs::write(&input, html)
+            .with_context(|| format!("Failed to write {}", input.display()))?;
+
+        // Chromium resolves relative output paths against its own working
+        // directory, so always hand it an absolute one
+        let output = std::path::absolute(output)?;
+        let result = self.render(&input, &output);
+        let _ = std::fs::remove_file(&input);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_detect_in_without_renderers() {
+        let fixture = tempfile::tempdir().unwrap();
+
+        let actual = PdfRenderer::detect_in(fixture.path());
+
+        assert!(matches!(actual, Err(Error::PdfRendererNotFound)));
+    }
+
+    #[test]
+    fn test_detect_in_prefers_chromium() {
+        let fixture = tempfile::tempdir().unwrap();
+        std::fs::write(fixture.path().join("wkhtmltopdf"), "").unwrap();
+        std::fs::write(fixture.path().join("chromium"), "").unwrap();
+
+        let actual = PdfRenderer::detect_in(fixture.path()).unwrap();
+
+        let expected = PdfRenderer::Chromium(fixture.path().join("chromium"));
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_render_html_with_fake_chromium() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let fixture = tempfile::tempdir().unwrap();
+        let program = fixture.path().join("chromium");
+        std::fs::write(
+            &program,
+            "#!/bin/sh\nfor arg in \"$@\"; do\n  case \"$arg\" in\n    --print-to-pdf=*) : > \"${arg#--print-to-pdf=}\" ;;\n  esac\ndone\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&program, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let output = fixture.path().join("conversation.pdf");
+
+        let renderer = PdfRenderer::detect_in(fixture.path()).unwrap();
+        renderer.render_html("<html></html>", &output).unwrap();
+
+        assert!(output.exists());
+    }
+}
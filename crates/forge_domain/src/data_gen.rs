@@ -26,3 +26,39 @@ pub struct DataGenerationParameters {
     /// Maximum number of concurrent LLM requests
     pub concurrency: usize,
 }
+
+/// A single prompt submitted as part of a message batch, read from one line
+/// of the batch input JSONL file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchRequest {
+    /// Caller supplied identifier used to match results to requests
+    pub id: String,
+
+    /// Prompt sent as the user message
+    pub prompt: String,
+}
+
+/// Outcome of a single request in a completed batch
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchResult {
+    /// Identifier of the originating [`BatchRequest`]
+    pub id: String,
+
+    /// Text generated by the model when the request succeeded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    /// Reason the request did not succeed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Processing status of a submitted batch
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchStatus {
+    /// The provider is still processing the batch
+    InProgress,
+
+    /// Processing finished; contains one result per request
+    Ended(Vec<BatchResult>),
+}
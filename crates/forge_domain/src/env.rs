@@ -5,7 +5,7 @@ use derive_more::Display;
 use derive_setters::Setters;
 use serde::{Deserialize, Serialize};
 
-use crate::{Effort, ModelConfig};
+use crate::{Effort, ModelConfig, ProviderId};
 
 /// All discrete mutations that can be applied to the application configuration.
 ///
@@ -29,6 +29,14 @@ pub enum ConfigOperation {
     SetSuggestConfig(ModelConfig),
     /// Set the reasoning effort level for all agents.
     SetReasoningEffort(Effort),
+    /// Select which stored credential alias is used for a provider.
+    ///
+    /// `None` switches the provider back to its unaliased credential.
+    SetProviderAlias(ProviderId, Option<String>),
+    /// Pin Forge to a version so automatic update checks are skipped.
+    ///
+    /// `None` removes the pin.
+    SetPinnedVersion(Option<String>),
 }
 
 const VERSION: &str = match option_env!("APP_VERSION") {
@@ -78,6 +86,11 @@ impl Environment {
     pub fn snapshot_path(&self) -> PathBuf {
         self.base_path.join("snapshots")
     }
+    /// Returns the path of the file holding context queued with
+    /// `forge context add` for the next message.
+    pub fn pending_context_path(&self) -> PathBuf {
+        self.base_path.join("pending_context.json")
+    }
     pub fn mcp_user_config(&self) -> PathBuf {
         self.base_path.join(".mcp.json")
     }
@@ -102,8 +102,15 @@ pub enum Error {
     #[error("Failed to sync {count} file(s)")]
     SyncFailed { count: usize },
 
+    #[error("Invalid exclude pattern: {0}")]
+    #[from(skip)]
+    InvalidExcludePattern(globset::Error),
+
     #[error("No default provider and model configured.")]
     NoDefaultSession,
+
+    #[error("No PDF renderer found. Install Chromium or wkhtmltopdf to export PDFs.")]
+    PdfRendererNotFound,
 }
 
 pub type Result<A> = std::result::Result<A, Error>;
@@ -41,7 +41,7 @@ impl<T: AsRef<str>> From<T> for EventValue {
 }
 
 // We'll use simple strings for JSON schema compatibility
-#[derive(Debug, Deserialize, Serialize, Clone, Setters)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Setters)]
 #[setters(into, strip_option)]
 pub struct Event {
     pub id: String,
@@ -52,6 +52,62 @@ pub struct Event {
     /// Contains additional context about the prompt that should typically be
     /// included after the `value` as a user message.
     pub additional_context: Option<String>,
+
+    /// Free-form key-value pairs attached by the caller
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, String>,
+}
+
+/// Fluent builder for [`Event`], created with [`Event::builder`]
+#[derive(Debug, Default, Clone)]
+pub struct EventBuilder {
+    value: Option<EventValue>,
+    attachments: Vec<Attachment>,
+    additional_context: Option<String>,
+    metadata: HashMap<String, String>,
+}
+
+impl EventBuilder {
+    /// Sets the event value, replacing any previously set value
+    pub fn value(mut self, value: impl Into<EventValue>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets the event value to a plain-text prompt
+    pub fn text(self, text: &str) -> Self {
+        self.value(EventValue::text(text))
+    }
+
+    /// Sets the additional context included after the value
+    pub fn context(mut self, context: &str) -> Self {
+        self.additional_context = Some(context.to_string());
+        self
+    }
+
+    /// Appends an attachment
+    pub fn attachment(mut self, attachment: Attachment) -> Self {
+        self.attachments.push(attachment);
+        self
+    }
+
+    /// Adds a metadata entry, overwriting any existing value for `key`
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Creates the event with a fresh id and the current timestamp
+    pub fn build(self) -> Event {
+        Event {
+            id: uuid::Uuid::new_v4().to_string(),
+            value: self.value,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            attachments: self.attachments,
+            additional_context: self.additional_context,
+            metadata: self.metadata,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
@@ -143,37 +199,56 @@ impl NamedTool for Event {
 }
 
 impl Event {
-    pub fn new<V: Into<EventValue>>(value: V) -> Self {
-        let id = uuid::Uuid::new_v4().to_string();
-        let timestamp = chrono::Utc::now().to_rfc3339();
+    /// Returns a builder for assembling an event step by step
+    pub fn builder() -> EventBuilder {
+        EventBuilder::default()
+    }
 
-        Self {
-            id,
-            value: Some(value.into()),
-            timestamp,
-            attachments: Vec::new(),
-            additional_context: None,
-        }
+    pub fn new<V: Into<EventValue>>(value: V) -> Self {
+        Self::builder().value(value).build()
     }
 
     pub fn empty() -> Self {
-        let id = uuid::Uuid::new_v4().to_string();
-        let timestamp = chrono::Utc::now().to_rfc3339();
-
-        Self {
-            id,
-            value: None,
-            timestamp,
-            attachments: Vec::new(),
-            additional_context: None,
-        }
+        Self::builder().build()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use pretty_assertions::assert_eq;
+
     use super::*;
 
+    #[test]
+    fn test_builder_sets_text_context_and_metadata() {
+        let actual = Event::builder()
+            .text("Fix the build")
+            .context("CI fails on main")
+            .metadata("source", "cli")
+            .build();
+
+        assert_eq!(actual.value, Some(EventValue::text("Fix the build")));
+        assert_eq!(
+            actual.additional_context,
+            Some("CI fails on main".to_string())
+        );
+        assert_eq!(
+            actual.metadata,
+            HashMap::from([("source".to_string(), "cli".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_new_matches_builder() {
+        let fixture = Event::builder().text("text").build();
+
+        let actual = Event::new("text")
+            .id(fixture.id.clone())
+            .timestamp(fixture.timestamp.clone());
+
+        assert_eq!(actual, fixture);
+    }
+
     #[test]
     fn test_into_feedback() {
         let event = EventContextValue::new("");
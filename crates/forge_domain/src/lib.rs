@@ -9,6 +9,7 @@ mod console;
 mod context;
 mod conversation;
 mod conversation_html;
+mod conversation_pdf;
 mod data_gen;
 mod env;
 mod error;
@@ -26,6 +27,7 @@ mod merge;
 mod message;
 mod message_pattern;
 mod migration;
+mod min_p;
 mod model;
 mod model_config;
 mod node;
@@ -33,6 +35,7 @@ mod point;
 mod policies;
 mod provider;
 mod reasoning;
+mod repetition_penalty;
 mod repo;
 mod result_stream_ext;
 mod session_metrics;
@@ -65,6 +68,7 @@ pub use console::*;
 pub use context::*;
 pub use conversation::*;
 pub use conversation_html::*;
+pub use conversation_pdf::*;
 pub use data_gen::*;
 pub use env::*;
 pub use error::*;
@@ -82,6 +86,7 @@ pub use mcp_servers::*;
 pub use message::*;
 pub use message_pattern::*;
 pub use migration::*;
+pub use min_p::*;
 pub use model::*;
 pub use model_config::*;
 pub use node::*;
@@ -89,6 +94,7 @@ pub use point::*;
 pub use policies::*;
 pub use provider::*;
 pub use reasoning::*;
+pub use repetition_penalty::*;
 pub use repo::*;
 pub use result_stream_ext::*;
 pub use session_metrics::*;
@@ -3,7 +3,7 @@ use derive_setters::Setters;
 use serde::{Deserialize, Serialize};
 use strum_macros::{EnumString, IntoStaticStr};
 
-use super::{ToolCall, ToolCallFull};
+use super::{ToolCall, ToolCallFull, ToolCallPart};
 use crate::TokenCount;
 use crate::reasoning::{Reasoning, ReasoningFull};
 
@@ -94,6 +94,9 @@ pub struct ChatCompletionMessage {
     /// Phase label for assistant messages (e.g. `Commentary` or `FinalAnswer`).
     /// Preserved from the response and replayed back on subsequent requests.
     pub phase: Option<MessagePhase>,
+    /// Source URLs the provider cited while generating the message (e.g.
+    /// Perplexity's online search results).
+    pub citations: Vec<url::Url>,
 }
 
 impl From<FinishReason> for ChatCompletionMessage {
@@ -132,6 +135,17 @@ impl Content {
     pub fn is_part(&self) -> bool {
         matches!(self, Content::Part(_))
     }
+
+    /// Appends `other` to this content. The result stays a part only when
+    /// both sides are parts.
+    pub fn concat(self, other: Content) -> Self {
+        let text = format!("{}{}", self.as_str(), other.as_str());
+        if self.is_part() && other.is_part() {
+            Content::part(text)
+        } else {
+            Content::full(text)
+        }
+    }
 }
 
 /// Used typically when streaming is enabled
@@ -209,6 +223,76 @@ impl ChatCompletionMessage {
         self.content = Some(Content::Full(ContentFull(content.to_string())));
         self
     }
+
+    /// Combines this message with the next fragment of the same streamed
+    /// response.
+    ///
+    /// Content and reasoning are concatenated, tool call parts are joined by
+    /// `call_id` (a part without an ID continues the previous call), the last
+    /// finish reason, signature and phase win, and usage is combined with
+    /// [`Usage::merge`] since providers report cumulative counts within a
+    /// stream.
+    pub fn merge(mut self, other: ChatCompletionMessage) -> Self {
+        self.content = concat_content(self.content, other.content);
+        self.reasoning = concat_content(self.reasoning, other.reasoning);
+        if let Some(details) = other.reasoning_details {
+            self.reasoning_details
+                .get_or_insert_with(Vec::new)
+                .extend(details);
+        }
+
+        for call in other.tool_calls {
+            match call {
+                ToolCall::Part(part) => self.merge_tool_call_part(part),
+                full => self.tool_calls.push(full),
+            }
+        }
+
+        self.thought_signature = other.thought_signature.or(self.thought_signature);
+        self.finish_reason = other.finish_reason.or(self.finish_reason);
+        self.phase = other.phase.or(self.phase);
+        self.usage = match (self.usage, other.usage) {
+            (Some(usage), Some(other)) => Some(usage.merge(&other)),
+            (usage, other) => usage.or(other),
+        };
+        self.citations.extend(other.citations);
+        self
+    }
+
+    fn merge_tool_call_part(&mut self, part: ToolCallPart) {
+        let mut parts = self
+            .tool_calls
+            .iter_mut()
+            .rev()
+            .filter_map(|call| match call {
+                ToolCall::Part(existing) => Some(existing),
+                ToolCall::Full(_) => None,
+            });
+        let existing = match &part.call_id {
+            Some(_) => parts.find(|existing| existing.call_id == part.call_id),
+            None => parts.next(),
+        };
+
+        match existing {
+            Some(existing) => {
+                existing.arguments_part.push_str(&part.arguments_part);
+                if existing.name.is_none() {
+                    existing.name = part.name;
+                }
+                if part.thought_signature.is_some() {
+                    existing.thought_signature = part.thought_signature;
+                }
+            }
+            _ => self.tool_calls.push(ToolCall::Part(part)),
+        }
+    }
+}
+
+fn concat_content(content: Option<Content>, other: Option<Content>) -> Option<Content> {
+    match (content, other) {
+        (Some(content), Some(other)) => Some(content.concat(other)),
+        (content, other) => content.or(other),
+    }
 }
 
 /// Represents a complete message from the LLM provider with all content
@@ -468,4 +552,105 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_merge_text_with_finish_reason() {
+        let fixture = ChatCompletionMessage::assistant(Content::part("Hello"));
+        let other = ChatCompletionMessage::assistant(Content::part(" world"))
+            .finish_reason(FinishReason::Stop);
+
+        let actual = fixture.merge(other);
+
+        let expected = ChatCompletionMessage::assistant(Content::part("Hello world"))
+            .finish_reason(FinishReason::Stop);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_merge_keeps_earlier_finish_reason() {
+        let fixture = ChatCompletionMessage::default().finish_reason(FinishReason::ToolCalls);
+        let other = ChatCompletionMessage::default();
+
+        let actual = fixture.merge(other).finish_reason;
+
+        assert_eq!(actual, Some(FinishReason::ToolCalls));
+    }
+
+    #[test]
+    fn test_merge_tool_call_parts_by_call_id() {
+        let fixture = ChatCompletionMessage::default().add_tool_call(
+            ToolCallPart::default()
+                .call_id(crate::ToolCallId::new("call_1"))
+                .name(crate::ToolName::new("read"))
+                .arguments_part(r#"{"path":"#),
+        );
+        let other = ChatCompletionMessage::default()
+            .add_tool_call(
+                ToolCallPart::default()
+                    .call_id(crate::ToolCallId::new("call_1"))
+                    .arguments_part(r#""a.txt"}"#),
+            )
+            .add_tool_call(
+                ToolCallPart::default()
+                    .call_id(crate::ToolCallId::new("call_2"))
+                    .name(crate::ToolName::new("shell"))
+                    .arguments_part("{}"),
+            );
+
+        let actual = fixture.merge(other).tool_calls;
+
+        let expected: Vec<ToolCall> = vec![
+            ToolCallPart::default()
+                .call_id(crate::ToolCallId::new("call_1"))
+                .name(crate::ToolName::new("read"))
+                .arguments_part(r#"{"path":"a.txt"}"#)
+                .into(),
+            ToolCallPart::default()
+                .call_id(crate::ToolCallId::new("call_2"))
+                .name(crate::ToolName::new("shell"))
+                .arguments_part("{}")
+                .into(),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_merge_tool_call_part_without_id_continues_last_call() {
+        let fixture = ChatCompletionMessage::default().add_tool_call(
+            ToolCallPart::default()
+                .call_id(crate::ToolCallId::new("call_1"))
+                .arguments_part("{\"a\":"),
+        );
+        let other = ChatCompletionMessage::default()
+            .add_tool_call(ToolCallPart::default().arguments_part("1}"));
+
+        let actual = fixture.merge(other).tool_calls;
+
+        let expected: Vec<ToolCall> = vec![
+            ToolCallPart::default()
+                .call_id(crate::ToolCallId::new("call_1"))
+                .arguments_part("{\"a\":1}")
+                .into(),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_merge_usage() {
+        let fixture = ChatCompletionMessage::default()
+            .usage(Usage { prompt_tokens: TokenCount::Actual(100), ..Default::default() });
+        let other = ChatCompletionMessage::default().usage(Usage {
+            completion_tokens: TokenCount::Actual(20),
+            ..Default::default()
+        });
+
+        let actual = fixture.merge(other).usage;
+
+        let expected = Usage {
+            prompt_tokens: TokenCount::Actual(100),
+            completion_tokens: TokenCount::Actual(20),
+            ..Default::default()
+        };
+        assert_eq!(actual, Some(expected));
+    }
 }
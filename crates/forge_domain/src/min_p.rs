@@ -0,0 +1,127 @@
+use std::fmt;
+use std::ops::Deref;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A newtype for min_p values with built-in validation
+///
+/// Min-p sampling discards tokens whose probability is below `min_p` times the
+/// probability of the most likely token:
+/// - Lower values (e.g., 0.01) keep more candidate tokens
+/// - Higher values (e.g., 0.2) make responses more focused
+/// - Valid range is 0.0 to 1.0
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, JsonSchema)]
+pub struct MinP(f32);
+
+impl MinP {
+    /// Creates a new MinP value, returning an error if outside the valid
+    /// range (0.0 to 1.0)
+    pub fn new(value: f32) -> Result<Self, String> {
+        if Self::is_valid(value) {
+            Ok(Self(value))
+        } else {
+            Err(format!("min_p must be between 0.0 and 1.0, got {value}"))
+        }
+    }
+
+    /// Creates a new MinP value without validation
+    ///
+    /// # Safety
+    /// This function should only be used when the value is known to be valid
+    pub fn new_unchecked(value: f32) -> Self {
+        debug_assert!(Self::is_valid(value), "invalid min_p: {value}");
+        Self(value)
+    }
+
+    /// Returns true if the min_p value is within the valid range (0.0 to 1.0)
+    pub fn is_valid(value: f32) -> bool {
+        (0.0..=1.0).contains(&value)
+    }
+
+    /// Returns the inner f32 value
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+}
+
+impl Deref for MinP {
+    type Target = f32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<MinP> for f32 {
+    fn from(min_p: MinP) -> Self {
+        min_p.0
+    }
+}
+
+impl fmt::Display for MinP {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for MinP {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Convert to string with fixed precision to avoid floating point issues
+        // and then parse back to ensure consistent serialization
+        let formatted = format!("{:.2}", self.0);
+        let value = formatted.parse::<f32>().unwrap();
+        serializer.serialize_f32(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for MinP {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        let value = f32::deserialize(deserializer)?;
+        if Self::is_valid(value) {
+            Ok(Self(value))
+        } else {
+            Err(Error::custom(format!(
+                "min_p must be between 0.0 and 1.0, got {value}"
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_min_p_creation() {
+        for value in [0.0, 0.05, 1.0] {
+            assert_eq!(MinP::new(value).unwrap().value(), value);
+        }
+
+        for value in [-0.1, 1.1] {
+            let actual = MinP::new(value).unwrap_err();
+            assert!(actual.contains("min_p must be between 0.0 and 1.0"));
+        }
+    }
+
+    #[test]
+    fn test_min_p_deserialization() {
+        let actual: MinP = serde_json::from_value(json!(0.05)).unwrap();
+        assert_eq!(actual.value(), 0.05);
+
+        let actual = serde_json::from_value::<MinP>(json!(1.5))
+            .unwrap_err()
+            .to_string();
+        assert!(actual.contains("min_p must be between 0.0 and 1.0"));
+    }
+}
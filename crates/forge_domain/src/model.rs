@@ -1,3 +1,6 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
 use derive_more::derive::Display;
 use derive_setters::Setters;
 use fake::Dummy;
@@ -5,9 +8,22 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumString;
 
+use crate::Usage;
+
 /// Represents input modalities that a model can accept
 #[derive(
-    Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, EnumString, JsonSchema, Dummy,
+    Debug,
+    Clone,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    EnumString,
+    JsonSchema,
+    Dummy,
 )]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase", ascii_case_insensitive)]
@@ -23,7 +39,7 @@ fn default_input_modalities() -> Vec<InputModality> {
     vec![InputModality::Text]
 }
 
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Setters, JsonSchema, Dummy)]
+#[derive(Clone, Debug, Deserialize, Serialize, Setters, JsonSchema, Dummy)]
 #[setters(strip_option)]
 pub struct Model {
     pub id: ModelId,
@@ -39,6 +55,74 @@ pub struct Model {
     /// Input modalities supported by the model (defaults to text-only)
     #[serde(default = "default_input_modalities")]
     pub input_modalities: Vec<InputModality>,
+    /// Price in USD per million prompt tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_per_million_input_tokens: Option<f64>,
+    /// Price in USD per million completion tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_per_million_output_tokens: Option<f64>,
+}
+
+/// Compares every field, pricing included, the same way as `Ord` so that
+/// equality is total even for NaN prices
+impl PartialEq for Model {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Model {}
+
+/// Hashes by id only, which is consistent with `Eq` because equal models share
+/// an id
+impl Hash for Model {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl PartialOrd for Model {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by id. Models that share an id fall back to the remaining fields,
+/// comparing prices with [`f64::total_cmp`].
+impl Ord for Model {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn cmp_price(a: Option<f64>, b: Option<f64>) -> Ordering {
+            match (a, b) {
+                (Some(a), Some(b)) => a.total_cmp(&b),
+                (a, b) => a.is_some().cmp(&b.is_some()),
+            }
+        }
+
+        self.id
+            .cmp(&other.id)
+            .then_with(|| self.name.cmp(&other.name))
+            .then_with(|| self.description.cmp(&other.description))
+            .then_with(|| self.context_length.cmp(&other.context_length))
+            .then_with(|| self.tools_supported.cmp(&other.tools_supported))
+            .then_with(|| {
+                self.supports_parallel_tool_calls
+                    .cmp(&other.supports_parallel_tool_calls)
+            })
+            .then_with(|| self.supports_reasoning.cmp(&other.supports_reasoning))
+            .then_with(|| self.input_modalities.cmp(&other.input_modalities))
+            .then_with(|| {
+                cmp_price(
+                    self.cost_per_million_input_tokens,
+                    other.cost_per_million_input_tokens,
+                )
+            })
+            .then_with(|| {
+                cmp_price(
+                    self.cost_per_million_output_tokens,
+                    other.cost_per_million_output_tokens,
+                )
+            })
+    }
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -52,7 +136,20 @@ impl Parameters {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Hash, Eq, Display, JsonSchema, Dummy)]
+#[derive(
+    Clone,
+    Debug,
+    Deserialize,
+    PartialEq,
+    Serialize,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    Display,
+    JsonSchema,
+    Dummy,
+)]
 #[serde(transparent)]
 pub struct ModelId(String);
 
@@ -75,8 +172,27 @@ impl Model {
             supports_parallel_tool_calls: None,
             supports_reasoning: None,
             input_modalities: default_input_modalities(),
+            cost_per_million_input_tokens: None,
+            cost_per_million_output_tokens: None,
         }
     }
+
+    /// Computes the USD cost of a completion with the given `usage` from the
+    /// model's per-million token rates.
+    ///
+    /// Returns `None` when the model has no pricing information.
+    pub fn cost_of(&self, usage: &Usage) -> Option<f64> {
+        if self.cost_per_million_input_tokens.is_none()
+            && self.cost_per_million_output_tokens.is_none()
+        {
+            return None;
+        }
+        let input_rate = self.cost_per_million_input_tokens.unwrap_or_default();
+        let output_rate = self.cost_per_million_output_tokens.unwrap_or_default();
+        let cost = *usage.prompt_tokens as f64 * input_rate
+            + *usage.completion_tokens as f64 * output_rate;
+        Some(cost / 1_000_000.0)
+    }
 }
 
 impl From<String> for ModelId {
@@ -104,3 +220,48 @@ impl std::str::FromStr for ModelId {
         Ok(ModelId(s.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashMap};
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_btree_set_orders_models_by_id() {
+        let fixture = BTreeSet::from([
+            Model::new("gpt-4o"),
+            Model::new("claude-sonnet-4"),
+            Model::new("gemini-2.5-pro"),
+        ]);
+
+        let actual = fixture
+            .iter()
+            .map(|model| model.id.as_str())
+            .collect::<Vec<_>>();
+
+        let expected = vec!["claude-sonnet-4", "gemini-2.5-pro", "gpt-4o"];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_hash_map_lookup_by_model() {
+        let model = Model::new("gpt-4o").context_length(128_000u64);
+        let fixture = HashMap::from([(model.clone(), "openai"), (Model::new("o3"), "openai")]);
+
+        let actual = fixture.get(&model);
+
+        assert_eq!(actual, Some(&"openai"));
+    }
+
+    #[test]
+    fn test_model_with_nan_price_equals_itself() {
+        let fixture = Model::new("gpt-4o").cost_per_million_input_tokens(f64::NAN);
+
+        let actual = fixture.clone();
+
+        assert_eq!(actual, fixture);
+    }
+}
@@ -1,5 +1,6 @@
 use derive_more::Display;
 use derive_setters::Setters;
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -146,6 +147,8 @@ pub struct SearchParams<'a> {
     pub use_case: String,
     pub starts_with: Option<String>,
     pub ends_with: Option<Vec<String>>,
+    /// Glob patterns for paths whose results are dropped after retrieval
+    pub exclude_paths: Vec<String>,
 }
 
 impl<'a> SearchParams<'a> {
@@ -157,8 +160,22 @@ impl<'a> SearchParams<'a> {
             use_case: use_case.to_string(),
             starts_with: None,
             ends_with: None,
+            exclude_paths: Vec::new(),
         }
     }
+
+    /// Compiles the `exclude_paths` patterns into a single matcher for
+    /// result paths
+    ///
+    /// # Errors
+    /// Returns an error if any pattern is not a valid glob
+    pub fn exclude_matcher(&self) -> crate::Result<GlobSet> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &self.exclude_paths {
+            builder.add(Glob::new(pattern).map_err(crate::Error::InvalidExcludePattern)?);
+        }
+        builder.build().map_err(crate::Error::InvalidExcludePattern)
+    }
 }
 
 pub type CodeSearchQuery<'a> = CodeBase<SearchParams<'a>>;
@@ -420,6 +437,18 @@ pub enum NodeData {
     Task(Task),
 }
 
+impl NodeData {
+    /// Returns the path of the file this node belongs to, if any
+    pub fn file_path(&self) -> Option<&str> {
+        match self {
+            NodeData::FileChunk(chunk) => Some(&chunk.file_path),
+            NodeData::File(file) => Some(&file.file_path),
+            NodeData::FileRef(file_ref) => Some(&file_ref.file_path),
+            NodeData::Note(_) | NodeData::Task(_) => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -456,6 +485,7 @@ mod tests {
             use_case: "find retry logic".to_string(),
             starts_with: None,
             ends_with: Some(vec![".rs".to_string()]),
+            exclude_paths: vec![],
         };
 
         assert_eq!(actual, expected);
@@ -483,6 +513,7 @@ mod tests {
                 ".ts".to_string(),
                 ".py".to_string(),
             ]),
+            exclude_paths: vec![],
         };
 
         assert_eq!(actual, expected);
@@ -499,8 +530,48 @@ mod tests {
             use_case: "authentication implementation".to_string(),
             starts_with: None,
             ends_with: None,
+            exclude_paths: vec![],
         };
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_search_params_exclude_matcher() {
+        let fixture = SearchParams::new("auth logic", "authentication implementation")
+            .exclude_paths(vec!["tests/**".to_string(), "*.generated.rs".to_string()]);
+
+        let actual = fixture.exclude_matcher().unwrap();
+
+        assert!(actual.is_match("tests/fixtures/auth.rs"));
+        assert!(actual.is_match("api.generated.rs"));
+        assert!(!actual.is_match("src/auth.rs"));
+    }
+
+    #[test]
+    fn test_search_params_exclude_matcher_rejects_invalid_pattern() {
+        let fixture = SearchParams::new("auth logic", "authentication implementation")
+            .exclude_paths(vec!["src/[auth".to_string()]);
+
+        let actual = fixture.exclude_matcher();
+
+        assert!(matches!(
+            actual,
+            Err(crate::Error::InvalidExcludePattern(_))
+        ));
+    }
+
+    #[test]
+    fn test_node_data_file_path() {
+        let fixture = NodeData::FileChunk(FileChunk {
+            file_path: "src/auth.rs".to_string(),
+            content: "fn login() {}".to_string(),
+            start_line: 1,
+            end_line: 1,
+        });
+        assert_eq!(fixture.file_path(), Some("src/auth.rs"));
+
+        let fixture = NodeData::Note(Note { content: "remember".to_string() });
+        assert_eq!(fixture.file_path(), None);
+    }
 }
@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::operation::PermissionOperation;
+use super::types::Permission;
+
+/// A single policy decision recorded in the policy audit log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyAuditEntry {
+    pub timestamp: DateTime<Utc>,
+    pub operation: String,
+    pub decision: Permission,
+    pub rule: Option<String>,
+}
+
+impl PolicyAuditEntry {
+    /// Creates an entry for `operation` timestamped with the current time
+    pub fn new(
+        operation: &PermissionOperation,
+        decision: Permission,
+        rule: Option<String>,
+    ) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            operation: operation.to_string(),
+            decision,
+            rule,
+        }
+    }
+
+    /// Serializes the entry as a single newline-terminated JSON line
+    pub fn to_json_line(&self) -> serde_json::Result<String> {
+        Ok(format!("{}\n", serde_json::to_string(self)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_to_json_line() {
+        let operation = PermissionOperation::Write {
+            path: PathBuf::from("Cargo.toml"),
+            cwd: PathBuf::from("/test/cwd"),
+            message: "Create/overwrite file: Cargo.toml".to_string(),
+        };
+        let fixture = PolicyAuditEntry::new(
+            &operation,
+            Permission::Deny,
+            Some("outside allowed paths".to_string()),
+        );
+
+        let actual: serde_json::Value =
+            serde_json::from_str(&fixture.to_json_line().unwrap()).unwrap();
+
+        assert_eq!(actual["operation"], "write Cargo.toml");
+        assert_eq!(actual["decision"], "deny");
+        assert_eq!(actual["rule"], "outside allowed paths");
+    }
+}
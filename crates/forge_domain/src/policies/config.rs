@@ -1,5 +1,6 @@
 use std::collections::BTreeSet;
 use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -7,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use super::operation::PermissionOperation;
 use super::policy::Policy;
 use super::types::Permission;
-use crate::Rule;
+use crate::{AllowedPathsRule, Rule};
 
 /// Collection of policies
 #[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -15,12 +16,21 @@ pub struct PolicyConfig {
     /// Set of policies to evaluate
     #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
     pub policies: BTreeSet<Policy>,
+    /// Append-only JSON lines file recording every policy decision
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit_log: Option<PathBuf>,
 }
 
 impl PolicyConfig {
     /// Create a new empty policies collection
     pub fn new() -> Self {
-        Self { policies: BTreeSet::new() }
+        Self { policies: BTreeSet::new(), audit_log: None }
+    }
+
+    /// Set the file policy decisions are recorded to
+    pub fn audit_log(mut self, path: impl Into<PathBuf>) -> Self {
+        self.audit_log = Some(path.into());
+        self
     }
 
     /// Add a policy to the collection
@@ -38,6 +48,22 @@ impl PolicyConfig {
             .collect()
     }
 
+    /// Returns the `allowed_paths` rules of top-level allow policies. Rules
+    /// nested under `not`, `all` or `any`, or attached to a deny or confirm
+    /// policy, do not restrict paths.
+    pub fn allowed_paths(&self) -> Vec<&AllowedPathsRule> {
+        self.policies
+            .iter()
+            .filter_map(|policy| match policy {
+                Policy::Simple {
+                    permission: Permission::Allow,
+                    rule: Rule::AllowedPaths(rule),
+                } => Some(rule),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Find all matching rules across all policies
     pub fn find_rules(&self, operation: &PermissionOperation) -> Vec<&Rule> {
         self.policies
@@ -25,6 +25,17 @@ impl<'a> PolicyEngine<'a> {
         self.evaluate_policies(operation)
     }
 
+    /// Describes the rule that decides the given operation, for audit logging
+    pub fn matching_rule(&self, operation: &PermissionOperation) -> Option<String> {
+        if self.is_outside_allowed_paths(operation) {
+            return Some("outside allowed paths".to_string());
+        }
+        self.policies
+            .find_rules(operation)
+            .first()
+            .map(|rule| rule.to_string())
+    }
+
     /// Internal helper function to evaluate policies for a given operation
     /// Returns permission result, defaults to Confirm if no policies match
     fn evaluate_policies(&self, operation: &PermissionOperation) -> Permission {
@@ -34,6 +45,10 @@ impl<'a> PolicyEngine<'a> {
             return Permission::Confirm;
         }
 
+        if self.is_outside_allowed_paths(operation) {
+            return Permission::Deny;
+        }
+
         let mut last_allow: Option<Permission> = None;
 
         // Evaluate all policies in order: workflow policies first, then extended
@@ -57,6 +72,19 @@ impl<'a> PolicyEngine<'a> {
         last_allow.unwrap_or(Permission::Confirm)
     }
 
+    /// Returns true when `allowed_paths` rules are configured and the path of
+    /// a read or write operation matches none of them
+    fn is_outside_allowed_paths(&self, operation: &PermissionOperation) -> bool {
+        if !matches!(
+            operation,
+            PermissionOperation::Read { .. } | PermissionOperation::Write { .. }
+        ) {
+            return false;
+        }
+        let rules = self.policies.allowed_paths();
+        !rules.is_empty() && !rules.iter().any(|rule| rule.allows(operation))
+    }
+
     /// Helper function to evaluate a set of policies
     /// Returns the first non-Allow result, or the last Allow result if all are
     /// Allow
@@ -91,7 +119,10 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use super::*;
-    use crate::{ExecuteRule, Fetch, Permission, Policy, PolicyConfig, ReadRule, Rule, WriteRule};
+    use crate::{
+        AllowedPathsRule, ExecuteRule, Fetch, Permission, Policy, PolicyConfig, ReadRule, Rule,
+        WriteRule,
+    };
 
     fn fixture_workflow_with_read_policy() -> PolicyConfig {
         PolicyConfig::new().add_policy(Policy::Simple {
@@ -201,4 +232,80 @@ mod tests {
 
         assert_eq!(actual, Permission::Allow);
     }
+
+    fn fixture_write(path: &str) -> PermissionOperation {
+        PermissionOperation::Write {
+            path: std::path::PathBuf::from(path),
+            cwd: std::path::PathBuf::from("/test/cwd"),
+            message: format!("Create/overwrite file: {path}"),
+        }
+    }
+
+    #[test]
+    fn test_policy_engine_denies_write_outside_allowed_paths() {
+        let fixture_workflow = PolicyConfig::new().add_policy(Policy::Simple {
+            permission: Permission::Allow,
+            rule: Rule::AllowedPaths(AllowedPathsRule { allowed_paths: "src/**/*.rs".to_string() }),
+        });
+        let fixture = PolicyEngine::new(&fixture_workflow);
+
+        let actual = (
+            fixture.can_perform(&fixture_write("Cargo.toml")),
+            fixture.can_perform(&fixture_write("src/main.rs")),
+            fixture.can_perform(&fixture_write("/test/cwd/src/lib.rs")),
+        );
+
+        let expected = (Permission::Deny, Permission::Allow, Permission::Allow);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_policy_engine_allowed_paths_ignores_execute() {
+        let fixture_workflow = PolicyConfig::new()
+            .add_policy(Policy::Simple {
+                permission: Permission::Allow,
+                rule: Rule::AllowedPaths(AllowedPathsRule { allowed_paths: "src/**".to_string() }),
+            })
+            .add_policy(Policy::Simple {
+                permission: Permission::Allow,
+                rule: Rule::Execute(ExecuteRule { command: "cargo *".to_string(), dir: None }),
+            });
+        let fixture = PolicyEngine::new(&fixture_workflow);
+        let operation = PermissionOperation::Execute {
+            command: "cargo build".to_string(),
+            cwd: std::path::PathBuf::from("/test/cwd"),
+        };
+
+        let actual = fixture.can_perform(&operation);
+
+        assert_eq!(actual, Permission::Allow);
+    }
+
+    #[test]
+    fn test_policy_engine_ignores_nested_and_denied_allowed_paths() {
+        let fixture_workflow = PolicyConfig::new()
+            .add_policy(Policy::Not {
+                not: Box::new(Policy::Simple {
+                    permission: Permission::Allow,
+                    rule: Rule::AllowedPaths(AllowedPathsRule {
+                        allowed_paths: "secrets/**".to_string(),
+                    }),
+                }),
+            })
+            .add_policy(Policy::Simple {
+                permission: Permission::Deny,
+                rule: Rule::AllowedPaths(AllowedPathsRule {
+                    allowed_paths: "target/**".to_string(),
+                }),
+            })
+            .add_policy(Policy::Simple {
+                permission: Permission::Allow,
+                rule: Rule::Write(WriteRule { write: "**/*".to_string(), dir: None }),
+            });
+        let fixture = PolicyEngine::new(&fixture_workflow);
+
+        let actual = fixture.can_perform(&fixture_write("src/main.rs"));
+
+        assert_eq!(actual, Permission::Allow);
+    }
 }
@@ -1,3 +1,4 @@
+mod audit;
 mod config;
 mod engine;
 mod operation;
@@ -5,6 +6,7 @@ mod policy;
 mod rule;
 mod types;
 
+pub use audit::*;
 pub use config::*;
 pub use engine::*;
 pub use operation::*;
@@ -1,3 +1,4 @@
+use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
 
 /// Operations that can be performed and need policy checking
@@ -24,3 +25,14 @@ pub enum PermissionOperation {
         message: String,
     },
 }
+
+impl Display for PermissionOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PermissionOperation::Write { path, .. } => write!(f, "write {}", path.display()),
+            PermissionOperation::Read { path, .. } => write!(f, "read {}", path.display()),
+            PermissionOperation::Execute { command, .. } => write!(f, "execute {command}"),
+            PermissionOperation::Fetch { url, .. } => write!(f, "fetch {url}"),
+        }
+    }
+}
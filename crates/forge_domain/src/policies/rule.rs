@@ -39,6 +39,32 @@ pub struct Fetch {
     pub dir: Option<String>,
 }
 
+/// Rule restricting file reads and writes to paths matching a glob pattern
+///
+/// Once any policy contains an `allowed_paths` rule, the policy engine denies
+/// reads and writes to paths that match none of the configured patterns.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema)]
+pub struct AllowedPathsRule {
+    pub allowed_paths: String,
+}
+
+impl AllowedPathsRule {
+    /// Check if the path of a read or write operation matches the pattern.
+    /// Paths are matched both as given and relative to the working directory.
+    pub fn allows(&self, operation: &PermissionOperation) -> bool {
+        match operation {
+            PermissionOperation::Write { path, cwd, message: _ }
+            | PermissionOperation::Read { path, cwd, message: _ } => {
+                match_pattern(&self.allowed_paths, path)
+                    || path
+                        .strip_prefix(cwd)
+                        .is_ok_and(|relative| match_pattern(&self.allowed_paths, relative))
+            }
+            _ => false,
+        }
+    }
+}
+
 /// Rules that define what operations are covered by a policy
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
@@ -51,6 +77,8 @@ pub enum Rule {
     Execute(ExecuteRule),
     /// Rule for network fetch operations with a URL pattern
     Fetch(Fetch),
+    /// Rule restricting file reads and writes to a glob pattern
+    AllowedPaths(AllowedPathsRule),
 }
 
 impl Rule {
@@ -94,6 +122,7 @@ impl Rule {
                 };
                 url_matches && dir_matches
             }
+            (Rule::AllowedPaths(rule), operation) => rule.allows(operation),
             _ => false,
         }
     }
@@ -150,6 +179,12 @@ impl Display for Fetch {
     }
 }
 
+impl Display for AllowedPathsRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "allowed paths '{}'", self.allowed_paths)
+    }
+}
+
 impl Display for Rule {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -157,6 +192,7 @@ impl Display for Rule {
             Rule::Read(rule) => write!(f, "{rule}"),
             Rule::Execute(rule) => write!(f, "{rule}"),
             Rule::Fetch(rule) => write!(f, "{rule}"),
+            Rule::AllowedPaths(rule) => write!(f, "{rule}"),
         }
     }
 }
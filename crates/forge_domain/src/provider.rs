@@ -83,6 +83,9 @@ impl ProviderId {
     pub const NVIDIA: ProviderId = ProviderId(Cow::Borrowed("nvidia"));
     pub const AMBIENT: ProviderId = ProviderId(Cow::Borrowed("ambient"));
     pub const NEURALWATT: ProviderId = ProviderId(Cow::Borrowed("neuralwatt"));
+    pub const COHERE: ProviderId = ProviderId(Cow::Borrowed("cohere"));
+    pub const TOGETHER_AI: ProviderId = ProviderId(Cow::Borrowed("together_ai"));
+    pub const PERPLEXITY: ProviderId = ProviderId(Cow::Borrowed("perplexity"));
 
     /// Returns all built-in provider IDs
     ///
@@ -125,6 +128,9 @@ impl ProviderId {
             ProviderId::NVIDIA,
             ProviderId::AMBIENT,
             ProviderId::NEURALWATT,
+            ProviderId::COHERE,
+            ProviderId::TOGETHER_AI,
+            ProviderId::PERPLEXITY,
         ]
     }
 
@@ -161,6 +167,7 @@ impl ProviderId {
             "nvidia" => "NVIDIA".to_string(),
             "ambient" => "Ambient".to_string(),
             "neuralwatt" => "Neuralwatt".to_string(),
+            "together_ai" => "TogetherAI".to_string(),
             _ => {
                 // For other providers, use UpperCamelCase conversion
                 use convert_case::{Case, Casing};
@@ -218,6 +225,9 @@ impl std::str::FromStr for ProviderId {
             "nvidia" => ProviderId::NVIDIA,
             "ambient" => ProviderId::AMBIENT,
             "neuralwatt" => ProviderId::NEURALWATT,
+            "cohere" => ProviderId::COHERE,
+            "together_ai" => ProviderId::TOGETHER_AI,
+            "perplexity" => ProviderId::PERPLEXITY,
             // For custom providers, use Cow::Owned to avoid memory leaks
             custom => ProviderId(Cow::Owned(custom.to_string())),
         };
@@ -239,6 +249,7 @@ pub enum ProviderResponse {
     Bedrock,
     Google,
     OpenCode,
+    Cohere,
 }
 
 /// Represents the source of models for a provider
@@ -392,6 +403,8 @@ mod test_helpers {
             id: provider_id,
             auth_details: AuthDetails::ApiKey(ApiKey::from(key.to_string())),
             url_params: HashMap::new(),
+            alias: None,
+            expires_at: None,
         })
     }
 
@@ -735,6 +748,32 @@ mod tests {
         assert!(built_in.contains(&ProviderId::NEURALWATT));
     }
 
+    #[test]
+    fn test_cohere_from_str() {
+        let actual = ProviderId::from_str("cohere").unwrap();
+        let expected = ProviderId::COHERE;
+        assert_eq!(actual, expected);
+        assert!(ProviderId::built_in_providers().contains(&expected));
+    }
+
+    #[test]
+    fn test_together_ai_from_str() {
+        let actual = ProviderId::from_str("together_ai").unwrap();
+        let expected = ProviderId::TOGETHER_AI;
+        assert_eq!(actual, expected);
+        assert_eq!(expected.to_string(), "TogetherAI");
+        assert!(ProviderId::built_in_providers().contains(&expected));
+    }
+
+    #[test]
+    fn test_perplexity_from_str() {
+        let actual = ProviderId::from_str("perplexity").unwrap();
+        let expected = ProviderId::PERPLEXITY;
+        assert_eq!(actual, expected);
+        assert_eq!(expected.to_string(), "Perplexity");
+        assert!(ProviderId::built_in_providers().contains(&expected));
+    }
+
     #[test]
     fn test_io_intelligence() {
         let fixture = "test_key";
@@ -749,6 +788,8 @@ mod tests {
                 id: ProviderId::IO_INTELLIGENCE,
                 auth_details: AuthDetails::ApiKey(ApiKey::from(fixture.to_string())),
                 url_params: HashMap::new(),
+                alias: None,
+                expires_at: None,
             }),
             auth_methods: vec![crate::AuthMethod::ApiKey],
             url_params: vec![],
@@ -773,6 +814,8 @@ mod tests {
                 id: ProviderId::XAI,
                 auth_details: AuthDetails::ApiKey(ApiKey::from(fixture.to_string())),
                 url_params: HashMap::new(),
+                alias: None,
+                expires_at: None,
             }),
             auth_methods: vec![crate::AuthMethod::ApiKey],
             url_params: vec![],
@@ -0,0 +1,130 @@
+use std::fmt;
+use std::ops::Deref;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A newtype for repetition_penalty values with built-in validation
+///
+/// Repetition penalty scales down the likelihood of tokens that already
+/// appeared in the prompt or the generated text:
+/// - 1.0 applies no penalty
+/// - Higher values (e.g., 1.2) discourage repeating the same phrases
+/// - Valid range is 0.0 to 2.0
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, JsonSchema)]
+pub struct RepetitionPenalty(f32);
+
+impl RepetitionPenalty {
+    /// Creates a new RepetitionPenalty value, returning an error if outside the
+    /// valid range (0.0 to 2.0)
+    pub fn new(value: f32) -> Result<Self, String> {
+        if Self::is_valid(value) {
+            Ok(Self(value))
+        } else {
+            Err(format!(
+                "repetition_penalty must be between 0.0 and 2.0, got {value}"
+            ))
+        }
+    }
+
+    /// Creates a new RepetitionPenalty value without validation
+    ///
+    /// # Safety
+    /// This function should only be used when the value is known to be valid
+    pub fn new_unchecked(value: f32) -> Self {
+        debug_assert!(Self::is_valid(value), "invalid repetition_penalty: {value}");
+        Self(value)
+    }
+
+    /// Returns true if the repetition_penalty value is within the valid range
+    /// (0.0 to 2.0)
+    pub fn is_valid(value: f32) -> bool {
+        (0.0..=2.0).contains(&value)
+    }
+
+    /// Returns the inner f32 value
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+}
+
+impl Deref for RepetitionPenalty {
+    type Target = f32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<RepetitionPenalty> for f32 {
+    fn from(repetition_penalty: RepetitionPenalty) -> Self {
+        repetition_penalty.0
+    }
+}
+
+impl fmt::Display for RepetitionPenalty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for RepetitionPenalty {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Convert to string with fixed precision to avoid floating point issues
+        // and then parse back to ensure consistent serialization
+        let formatted = format!("{:.2}", self.0);
+        let value = formatted.parse::<f32>().unwrap();
+        serializer.serialize_f32(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for RepetitionPenalty {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        let value = f32::deserialize(deserializer)?;
+        if Self::is_valid(value) {
+            Ok(Self(value))
+        } else {
+            Err(Error::custom(format!(
+                "repetition_penalty must be between 0.0 and 2.0, got {value}"
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_repetition_penalty_creation() {
+        for value in [0.0, 1.1, 2.0] {
+            assert_eq!(RepetitionPenalty::new(value).unwrap().value(), value);
+        }
+
+        for value in [-0.1, 2.1] {
+            let actual = RepetitionPenalty::new(value).unwrap_err();
+            assert!(actual.contains("repetition_penalty must be between 0.0 and 2.0"));
+        }
+    }
+
+    #[test]
+    fn test_repetition_penalty_deserialization() {
+        let actual: RepetitionPenalty = serde_json::from_value(json!(1.1)).unwrap();
+        assert_eq!(actual.value(), 1.1);
+
+        let actual = serde_json::from_value::<RepetitionPenalty>(json!(2.5))
+            .unwrap_err()
+            .to_string();
+        assert!(actual.contains("repetition_penalty must be between 0.0 and 2.0"));
+    }
+}
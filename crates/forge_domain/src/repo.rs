@@ -1,12 +1,13 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use url::Url;
 
 use crate::{
-    AnyProvider, AuthCredential, ChatCompletionMessage, Context, Conversation, ConversationId,
-    MigrationResult, Model, ModelId, Provider, ProviderId, ProviderTemplate, ResultStream,
-    SearchMatch, Skill, Snapshot, WorkspaceAuth, WorkspaceId,
+    AnyProvider, AuthCredential, BatchRequest, BatchStatus, ChatCompletionMessage, Context,
+    Conversation, ConversationId, MigrationResult, Model, ModelId, Provider, ProviderId,
+    ProviderTemplate, ResultStream, SearchMatch, Skill, Snapshot, WorkspaceAuth, WorkspaceId,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -15,6 +16,39 @@ pub struct TextPatchBlock {
     pub patched_text: String,
 }
 
+/// Repository for submitting prompts for asynchronous batch processing
+#[async_trait::async_trait]
+pub trait BatchRepository: Send + Sync {
+    /// Submits all `requests` as a single batch and returns the batch ID
+    ///
+    /// # Arguments
+    /// * `model_id` - Model used for every request in the batch
+    /// * `requests` - Prompts to process
+    /// * `provider` - Provider that processes the batch
+    ///
+    /// # Errors
+    /// Returns an error if the provider does not support batches or the
+    /// submission is rejected
+    async fn submit_batch(
+        &self,
+        model_id: &ModelId,
+        requests: Vec<BatchRequest>,
+        provider: Provider<Url>,
+    ) -> Result<String>;
+
+    /// Returns the processing status of a batch, including its results once
+    /// processing has ended
+    ///
+    /// # Arguments
+    /// * `batch_id` - ID returned by [`BatchRepository::submit_batch`]
+    /// * `provider` - Provider the batch was submitted to
+    ///
+    /// # Errors
+    /// Returns an error if the provider does not support batches or the batch
+    /// cannot be retrieved
+    async fn poll_batch(&self, batch_id: &str, provider: Provider<Url>) -> Result<BatchStatus>;
+}
+
 /// Repository for managing file snapshots
 ///
 /// This repository provides operations for creating and restoring file
@@ -38,6 +72,35 @@ pub trait SnapshotRepository: Send + Sync {
     /// # Errors
     /// Returns an error if no snapshot exists or restoration fails
     async fn undo_snapshot(&self, file_path: &Path) -> Result<()>;
+
+    /// Lists the snapshots that capture the content of each file before the
+    /// turn started at `turn_started_at` first modified it
+    ///
+    /// For every file the oldest snapshot taken at or after `turn_started_at`
+    /// is returned. Files without such a snapshot are skipped.
+    ///
+    /// # Arguments
+    /// * `file_paths` - Paths of the files touched during the turn
+    /// * `turn_started_at` - Time at which the turn started
+    ///
+    /// # Errors
+    /// Returns an error if the snapshot store cannot be read
+    async fn list_snapshots_for_turn(
+        &self,
+        file_paths: &[PathBuf],
+        turn_started_at: DateTime<Utc>,
+    ) -> Result<Vec<Snapshot>>;
+
+    /// Restores the file captured by `snapshot` and discards that snapshot
+    /// together with every newer snapshot of the same file
+    ///
+    /// # Arguments
+    /// * `snapshot` - The snapshot to restore
+    ///
+    /// # Errors
+    /// Returns an error if the snapshot content cannot be read or the file
+    /// cannot be written
+    async fn restore_snapshot(&self, snapshot: &Snapshot) -> Result<()>;
 }
 
 /// Repository for managing conversation persistence
@@ -55,6 +118,17 @@ pub trait ConversationRepository: Send + Sync {
     /// Returns an error if the operation fails
     async fn upsert_conversation(&self, conversation: Conversation) -> Result<()>;
 
+    /// Sets the title of a stored conversation without touching the rest of
+    /// the record
+    ///
+    /// # Arguments
+    /// * `conversation_id` - The ID of the conversation to rename
+    /// * `title` - The new title
+    ///
+    /// # Errors
+    /// Returns an error if the operation fails
+    async fn update_title(&self, conversation_id: &ConversationId, title: String) -> Result<()>;
+
     /// Retrieves a conversation by its ID
     ///
     /// # Arguments
@@ -112,6 +186,8 @@ pub trait ProviderRepository: Send + Sync {
     async fn get_provider(&self, id: ProviderId) -> anyhow::Result<ProviderTemplate>;
     async fn upsert_credential(&self, credential: AuthCredential) -> anyhow::Result<()>;
     async fn get_credential(&self, id: &ProviderId) -> anyhow::Result<Option<AuthCredential>>;
+    /// Returns every stored credential for the provider, one per alias
+    async fn get_credentials(&self, id: &ProviderId) -> anyhow::Result<Vec<AuthCredential>>;
     async fn remove_credential(&self, id: &ProviderId) -> anyhow::Result<()>;
     async fn migrate_env_credentials(&self) -> anyhow::Result<Option<MigrationResult>>;
 }
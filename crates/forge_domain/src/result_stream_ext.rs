@@ -198,6 +198,37 @@ impl ResultStreamExt<anyhow::Error> for crate::BoxStream<ChatCompletionMessage,
             }
         }
 
+        // Providers with online search (e.g. Perplexity) repeat the cited sources
+        // on every chunk, so they are de-duplicated and listed once at the end
+        let mut citations: Vec<&url::Url> = Vec::new();
+        for citation in messages.iter().flat_map(|m| &m.citations) {
+            if !citations.contains(&citation) {
+                citations.push(citation);
+            }
+        }
+        if !citations.is_empty() {
+            let sources = citations
+                .iter()
+                .enumerate()
+                .map(|(i, url)| format!("[{}] {url}", i + 1))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let sources = format!("\n\nSources:\n{sources}");
+
+            if let Some(ref sender) = sender {
+                // Ignore send errors - the receiver may have been dropped
+                let _ = sender
+                    .send(Ok(ChatResponse::TaskMessage {
+                        content: ChatResponseContent::Markdown {
+                            text: sources.clone(),
+                            partial: true,
+                        },
+                    }))
+                    .await;
+            }
+            content.push_str(&sources);
+        }
+
         // Extract all tool calls in a fully declarative way with combined sources
         // Start with complete tool calls (for non-streaming mode)
         let initial_tool_calls: Vec<ToolCallFull> = messages
@@ -1029,6 +1060,33 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[tokio::test]
+    async fn test_into_full_lists_citations_once() {
+        use crate::FinishReason;
+
+        let citations = vec![
+            url::Url::parse("https://example.com/a").unwrap(),
+            url::Url::parse("https://example.com/b").unwrap(),
+        ];
+        let messages = vec![
+            Ok(ChatCompletionMessage::default()
+                .content(Content::part("Rust 1.0 shipped in 2015 [1]"))
+                .citations(citations.clone())),
+            Ok(ChatCompletionMessage::default()
+                .content(Content::part(" [2]."))
+                .citations(citations)
+                .finish_reason(FinishReason::Stop)),
+        ];
+
+        let result_stream: BoxStream<ChatCompletionMessage, anyhow::Error> =
+            Box::pin(tokio_stream::iter(messages));
+
+        let actual = result_stream.into_full(false).await.unwrap().content;
+
+        let expected = "Rust 1.0 shipped in 2015 [1] [2].\n\nSources:\n[1] https://example.com/a\n[2] https://example.com/b";
+        assert_eq!(actual, expected);
+    }
+
     #[tokio::test]
     async fn test_into_full_with_finish_reason() {
         use crate::FinishReason;
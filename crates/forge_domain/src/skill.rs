@@ -4,6 +4,59 @@ use derive_setters::Setters;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::ToolName;
+
+/// Optional metadata declared in a skill's YAML front matter
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Setters, JsonSchema)]
+#[setters(strip_option, into)]
+#[serde(default)]
+pub struct SkillMetadata {
+    /// Version of the skill, e.g. `"1.0"`
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_scalar"
+    )]
+    pub version: Option<String>,
+
+    /// Tools the skill relies on to complete its workflow
+    #[serde(alias = "requires_tools", skip_serializing_if = "Vec::is_empty")]
+    pub required_tools: Vec<ToolName>,
+
+    /// Author of the skill
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_scalar"
+    )]
+    pub author: Option<String>,
+}
+
+/// Scalar front matter value that is accepted wherever a string is expected
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Scalar {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+/// Deserializes a YAML scalar into a string, so unquoted front matter values
+/// such as `version: 1.0` or `version: 2` are kept rather than rejected.
+fn deserialize_optional_scalar<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(
+        Option::<Scalar>::deserialize(deserializer)?.map(|value| match value {
+            Scalar::String(value) => value,
+            Scalar::Integer(value) => value.to_string(),
+            // `Debug` keeps the fractional part of whole floats, so `1.0` stays "1.0"
+            Scalar::Float(value) => format!("{value:?}"),
+            Scalar::Bool(value) => value.to_string(),
+        }),
+    )
+}
+
 /// Represents a reusable skill with a name, file path, and prompt content
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Setters, JsonSchema)]
 #[setters(strip_option, into)]
@@ -22,6 +75,10 @@ pub struct Skill {
 
     /// List of resource files in the skill directory
     pub resources: Vec<PathBuf>,
+
+    /// Metadata declared in the skill's front matter
+    #[serde(default)]
+    pub metadata: SkillMetadata,
 }
 
 impl Skill {
@@ -43,8 +100,18 @@ impl Skill {
             command: prompt.into(),
             description: description.into(),
             resources: Vec::new(),
+            metadata: SkillMetadata::default(),
         }
     }
+
+    /// Returns the required tools that are not in `available`
+    pub fn missing_tools<'a>(&'a self, available: &[ToolName]) -> Vec<&'a ToolName> {
+        self.metadata
+            .required_tools
+            .iter()
+            .filter(|tool| !available.contains(tool))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -99,4 +166,21 @@ mod tests {
             .path("/updated/path");
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_missing_tools() {
+        // Fixture
+        let fixture = Skill::new("test", "prompt", "desc").metadata(
+            SkillMetadata::default()
+                .required_tools(vec![ToolName::new("fs_read"), ToolName::new("shell")]),
+        );
+
+        // Act
+        let actual = fixture.missing_tools(&[ToolName::new("fs_read")]);
+
+        // Assert
+        let shell = ToolName::new("shell");
+        let expected = vec![&shell];
+        assert_eq!(actual, expected);
+    }
 }
@@ -6,6 +6,9 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Time format used in snapshot file names
+const SNAPSHOT_TIME_FORMAT: &str = "%Y-%m-%d_%H-%M-%S-%9f";
+
 /// A newtype for snapshot IDs, internally using UUID
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct SnapshotId(Uuid);
@@ -91,14 +94,32 @@ impl Snapshot {
         format!("{:x}", hasher.finish())
     }
 
+    /// Reconstructs a snapshot of `path` from the name of a stored snapshot
+    /// file, as produced by [`Snapshot::snapshot_path`]. Returns `None` when
+    /// the file name is not a snapshot file name.
+    pub fn from_file_name(path: impl Into<String>, file_name: &str) -> Option<Self> {
+        let stem = file_name.strip_suffix(".snap")?;
+        let datetime = chrono::NaiveDateTime::parse_from_str(stem, SNAPSHOT_TIME_FORMAT).ok()?;
+        let timestamp = datetime
+            .and_utc()
+            .signed_duration_since(chrono::DateTime::UNIX_EPOCH);
+
+        Some(Self {
+            id: SnapshotId::new(),
+            timestamp: timestamp.to_std().ok()?,
+            path: path.into(),
+        })
+    }
+
+    /// Returns the time at which the snapshot was taken
+    pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::<chrono::Utc>::from(UNIX_EPOCH + self.timestamp)
+    }
+
     /// Create a snapshot filename from a path and timestamp
     pub fn snapshot_path(&self, cwd: Option<PathBuf>) -> PathBuf {
-        // Convert Duration to SystemTime then to a formatted string
-        let datetime = UNIX_EPOCH + self.timestamp;
         // Format: YYYY-MM-DD_HH-MM-SS-nnnnnnnnn (including nanoseconds)
-        let formatted_time = chrono::DateTime::<chrono::Utc>::from(datetime)
-            .format("%Y-%m-%d_%H-%M-%S-%9f")
-            .to_string();
+        let formatted_time = self.created_at().format(SNAPSHOT_TIME_FORMAT).to_string();
 
         let filename = format!("{formatted_time}.snap");
         let path = PathBuf::from(self.path_hash()).join(PathBuf::from(filename));
@@ -126,6 +147,24 @@ mod tests {
         assert_eq!(snapshot.path, nonexistent_path.display().to_string());
     }
 
+    #[test]
+    fn test_from_file_name_round_trips_snapshot_path() {
+        let fixture = Snapshot::create(PathBuf::from("/tmp/file.txt")).unwrap();
+        let file_name = fixture.snapshot_path(None);
+        let file_name = file_name.file_name().unwrap().to_string_lossy();
+
+        let actual = Snapshot::from_file_name(fixture.path.clone(), &file_name).unwrap();
+
+        assert_eq!(actual.timestamp, fixture.timestamp);
+        assert_eq!(actual.path, fixture.path);
+    }
+
+    #[test]
+    fn test_from_file_name_rejects_other_files() {
+        let actual = Snapshot::from_file_name("/tmp/file.txt", "notes.txt").is_none();
+        assert!(actual);
+    }
+
     #[test]
     fn test_create_with_nonexistent_relative_path() {
         // Test with a non-existent relative path
@@ -2,8 +2,10 @@ mod choice;
 mod name;
 mod tool_definition;
 mod usage;
+mod validation;
 
 pub use choice::*;
 pub use name::*;
 pub use tool_definition::*;
 pub use usage::*;
+pub use validation::*;
@@ -0,0 +1,125 @@
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::ToolDefinition;
+
+/// A single problem found while validating tool call arguments against a
+/// tool's input schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// JSON pointer to the offending value (e.g. `/path`), empty for the root
+    pub path: String,
+    /// Human-readable description of what is wrong with the value
+    pub problem: String,
+}
+
+impl ValidationError {
+    /// Creates a new validation error for the value at `path`
+    pub fn new(path: impl Into<String>, problem: impl Into<String>) -> Self {
+        Self { path: path.into(), problem: problem.into() }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.problem)
+        } else {
+            write!(f, "{}: {}", self.path, self.problem)
+        }
+    }
+}
+
+impl ToolDefinition {
+    /// Validates `arguments` against this tool's input schema and reports
+    /// every problem found in one pass
+    ///
+    /// Returns an empty list when the arguments are valid, or a single error
+    /// at the root when the input schema itself is invalid.
+    pub fn validate_arguments(&self, arguments: &Value) -> Vec<ValidationError> {
+        let validator = match jsonschema::validator_for(self.input_schema.as_value()) {
+            Ok(validator) => validator,
+            Err(error) => {
+                return vec![ValidationError::new(
+                    "",
+                    format!("invalid input schema: {error}"),
+                )];
+            }
+        };
+        let mut errors: Vec<_> = validator
+            .iter_errors(arguments)
+            .map(|error| ValidationError::new(error.instance_path().as_str(), error.to_string()))
+            .collect();
+        errors.sort_by(|a, b| a.path.cmp(&b.path));
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use schemars::JsonSchema;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+    use crate::tool_schema_generator;
+
+    #[derive(Deserialize, JsonSchema)]
+    #[allow(dead_code)]
+    struct ReadInput {
+        path: String,
+        start_line: Option<u32>,
+        mode: Mode,
+    }
+
+    #[derive(Deserialize, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    #[allow(dead_code)]
+    enum Mode {
+        Text,
+        Binary,
+    }
+
+    fn fixture() -> ToolDefinition {
+        ToolDefinition::new("read")
+            .input_schema(tool_schema_generator().into_root_schema_for::<ReadInput>())
+    }
+
+    #[test]
+    fn test_validate_arguments_valid() {
+        let actual = fixture().validate_arguments(&json!({
+            "path": "src/main.rs",
+            "start_line": 10,
+            "mode": "text"
+        }));
+
+        let expected: Vec<ValidationError> = vec![];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_validate_arguments_missing_required_property() {
+        let actual = fixture().validate_arguments(&json!({"mode": "text"}));
+
+        let expected = vec![ValidationError::new("", r#""path" is a required property"#)];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_validate_arguments_reports_all_errors() {
+        let actual = fixture().validate_arguments(&json!({
+            "path": 42,
+            "start_line": "ten",
+            "mode": "hex"
+        }));
+
+        let expected = vec![
+            ValidationError::new("/mode", r#""hex" is not one of "text" or "binary""#),
+            ValidationError::new("/path", r#"42 is not of type "string""#),
+            ValidationError::new("/start_line", r#""ten" is not of types "integer", "null""#),
+        ];
+        assert_eq!(actual, expected);
+    }
+}
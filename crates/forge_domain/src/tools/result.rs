@@ -105,6 +105,10 @@ impl ToolOutput {
         ToolOutput { is_error: false, values: vec![ToolValue::Image(img)] }
     }
 
+    pub fn json(value: serde_json::Value) -> Self {
+        ToolOutput { is_error: false, values: vec![ToolValue::Json(value)] }
+    }
+
     pub fn combine_mut(&mut self, value: ToolOutput) {
         self.values.extend(value.values);
     }
@@ -141,6 +145,9 @@ pub enum ToolValue {
         conversation_id: ConversationId,
     },
     Image(Image),
+    /// Structured data, sent to providers that accept it as JSON rather than
+    /// as a serialized string
+    Json(serde_json::Value),
     #[default]
     Empty,
 }
@@ -158,6 +165,7 @@ impl ToolValue {
         match self {
             ToolValue::Text(text) => Some(text),
             ToolValue::Image(_) => None,
+            ToolValue::Json(_) => None,
             ToolValue::Empty => None,
             ToolValue::AI { value, .. } => Some(value),
         }
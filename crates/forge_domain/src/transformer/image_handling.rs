@@ -46,6 +46,7 @@ impl Transformer for ImageHandling {
                     images.push((id, image));
                 }
                 crate::ToolValue::Text(_) => {}
+                crate::ToolValue::Json(_) => {}
                 crate::ToolValue::Empty => {}
                 crate::ToolValue::AI { .. } => {}
             });
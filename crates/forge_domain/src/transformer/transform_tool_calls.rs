@@ -59,6 +59,9 @@ impl Transformer for TransformToolCalls {
                             crate::ToolValue::Image(image) => {
                                 new_messages.push(ContextMessage::Image(image).into());
                             }
+                            crate::ToolValue::Json(json) => new_messages.push(
+                                ContextMessage::user(json.to_string(), self.model.clone()).into(),
+                            ),
                             crate::ToolValue::Empty => {}
                             crate::ToolValue::AI { value, .. } => new_messages
                                 .push(ContextMessage::user(value, self.model.clone()).into()),
@@ -33,6 +33,7 @@ type EventStream = BoxStream<'static, Result<MessageEvent, EventStreamError<Reqw
 type EventStream = LocalBoxStream<'static, Result<MessageEvent, EventStreamError<ReqwestError>>>;
 
 type BoxedRetry = Box<dyn RetryPolicy + Send + Unpin + 'static>;
+type BoxedConnectObserver = Box<dyn FnMut(Result<(), &Error>) + Send + 'static>;
 
 /// The ready state of an [`EventSource`]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
@@ -61,7 +62,8 @@ pub struct EventSource {
     is_closed: bool,
     retry_policy: BoxedRetry,
     last_event_id: String,
-    last_retry: Option<(usize, Duration)>
+    last_retry: Option<(usize, Duration)>,
+    connect_observer: Option<BoxedConnectObserver>
 }
 }
 
@@ -82,6 +84,7 @@ impl EventSource {
             retry_policy: Box::new(DEFAULT_RETRY),
             last_event_id: String::new(),
             last_retry: None,
+            connect_observer: None,
         })
     }
 
@@ -100,6 +103,12 @@ impl EventSource {
         self.retry_policy = policy
     }
 
+    /// Set a callback that is told the outcome of every connection attempt:
+    /// `Ok` once the stream opens, or the error that kept it from opening
+    pub fn set_connect_observer(&mut self, observer: BoxedConnectObserver) {
+        self.connect_observer = Some(observer)
+    }
+
     /// Get the last event id
     pub fn last_event_id(&self) -> &str {
         &self.last_event_id
@@ -172,6 +181,12 @@ impl<'a> EventSourceProjection<'a> {
         Ok(())
     }
 
+    fn notify_connect(&mut self, outcome: Result<(), &Error>) {
+        if let Some(observer) = self.connect_observer.as_mut() {
+            observer(outcome)
+        }
+    }
+
     fn handle_response(&mut self, res: Response) {
         self.last_retry.take();
         let mut stream = res.bytes_stream().eventsource();
@@ -242,10 +257,12 @@ impl Stream for EventSource {
                     this.clear_fetch();
                     match check_response(res) {
                         Ok(res) => {
+                            this.notify_connect(Ok(()));
                             this.handle_response(res);
                             return Poll::Ready(Some(Ok(Event::Open)));
                         }
                         Err(err) => {
+                            this.notify_connect(Err(&err));
                             *this.is_closed = true;
                             return Poll::Ready(Some(Err(err)));
                         }
@@ -253,6 +270,7 @@ impl Stream for EventSource {
                 }
                 Poll::Ready(Err(err)) => {
                     let err = Error::Transport(err);
+                    this.notify_connect(Err(&err));
                     this.handle_error(&err);
                     return Poll::Ready(Some(Err(err)));
                 }
@@ -39,6 +39,13 @@ pub async fn is_binary<P: AsRef<std::path::Path>>(path: P) -> Result<bool> {
     Ok(is_binary_internal(&buffer, bytes_read))
 }
 
+/// Detects if content is binary by analyzing its leading bytes, the same way
+/// [`is_binary`] does for a file on disk
+pub fn is_binary_content(content: &[u8]) -> bool {
+    let buffer = &content[..content.len().min(512)];
+    is_binary_internal(buffer, buffer.len())
+}
+
 /// Detects encoding and binary status from a buffer
 fn is_binary_internal(buffer: &[u8], bytes_read: usize) -> bool {
     // Always first check for BOM to find out about encoding
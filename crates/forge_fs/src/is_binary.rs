@@ -27,13 +27,23 @@ impl crate::ForgeFS {
         let bytes_read = file.read(&mut sample).await?;
         sample.truncate(bytes_read);
 
+        Ok(Self::is_binary_content(&sample))
+    }
+
+    /// Checks if content is text by examining its leading bytes.
+    ///
+    /// Returns whether the content is text along with a description of the
+    /// detected file type.
+    pub(crate) fn is_binary_content(content: &[u8]) -> (bool, String) {
+        let sample = &content[..content.len().min(8192)];
+
         // Handle empty files
-        if bytes_read == 0 {
-            return Ok((true, "Empty file".into()));
+        if sample.is_empty() {
+            return (true, "Empty file".into());
         }
 
         // Get file type info
-        let is_text = match infer::get(&sample) {
+        let is_text = match infer::get(sample) {
             Some(info) => matches!(
                 info.matcher_type(),
                 infer::MatcherType::Text | infer::MatcherType::Doc
@@ -41,11 +51,11 @@ impl crate::ForgeFS {
             None => true, // Assume text if type can't be determined
         };
 
-        let description = infer::get(&sample)
+        let description = infer::get(sample)
             .map(|info| info.mime_type().to_string())
             .unwrap_or_else(|| "Text file (no specific format detected)".into());
 
-        Ok((is_text, description))
+        (is_text, description)
     }
 }
 
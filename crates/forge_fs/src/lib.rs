@@ -17,7 +17,7 @@ mod read;
 mod read_range;
 mod write;
 
-pub use crate::binary_detection::is_binary;
+pub use crate::binary_detection::{is_binary, is_binary_content};
 pub use crate::error::Error;
 
 /// ForgeFS provides a standardized interface for file system operations
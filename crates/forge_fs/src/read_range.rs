@@ -51,6 +51,33 @@ impl crate::ForgeFS {
         let content = tokio::fs::read(path_ref)
             .await
             .with_context(|| format!("Failed to read file content from {}", path_ref.display()))?;
+
+        Self::range_utf8(&content, start_line, end_line)
+    }
+
+    /// Extracts a specific range of lines from file content that was already
+    /// loaded, e.g. from a remote filesystem.
+    ///
+    /// Applies the same validation and returns the same [`FileInfo`] as
+    /// [`Self::read_range_utf8`].
+    pub fn range_utf8(
+        content: &[u8],
+        start_line: u64,
+        end_line: u64,
+    ) -> Result<(String, FileInfo)> {
+        if start_line > end_line {
+            return Err(Error::StartGreaterThanEnd { start: start_line, end: end_line }.into());
+        }
+
+        if start_line == 0 || end_line == 0 {
+            return Err(Error::IndexStartingWithZero { start: start_line, end: end_line }.into());
+        }
+
+        let (is_text, file_type) = Self::is_binary_content(content);
+        if !is_text {
+            return Err(Error::BinaryFileNotSupported(file_type).into());
+        }
+
         let content = content.to_str_lossy();
 
         // Hash the full file content so callers get a stable, whole-file hash
@@ -332,4 +359,17 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_range_utf8_from_bytes() -> Result<()> {
+        let content = b"Line 1\nLine 2\nLine 3";
+
+        let (result, info) = crate::ForgeFS::range_utf8(content, 2, 3)?;
+        assert_eq!(result, "Line 2\nLine 3");
+        assert_eq!(info.total_lines, 3);
+
+        assert!(crate::ForgeFS::range_utf8(content, 0, 2).is_err());
+        assert!(crate::ForgeFS::range_utf8(content, 4, 5).is_err());
+        Ok(())
+    }
 }
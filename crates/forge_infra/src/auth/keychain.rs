@@ -0,0 +1,140 @@
+//! Platform Keychain Storage
+//!
+//! Stores secrets through the `keyring` crate: the macOS Keychain, the
+//! Windows Credential Manager, and the Secret Service (GNOME Keyring,
+//! KWallet) on Linux. Platforms without a supported store, or a locked
+//! keychain, are reported as errors rather than read as empty.
+
+use anyhow::Context;
+use keyring::Entry;
+
+/// Service name every secret is stored under
+const SERVICE: &str = "forge";
+
+/// Characters stored per keychain entry. The Windows Credential Manager
+/// caps a secret at 2560 bytes of UTF-16, so longer secrets are split
+/// across `account`, `account.1`, `account.2` and so on. Other keychains
+/// take secrets of any size.
+const CHUNK_CHARS: usize = if cfg!(windows) { 640 } else { usize::MAX };
+
+/// Reads and writes secrets in the platform keychain
+#[derive(Debug, Default, Clone)]
+pub struct ForgeKeychain;
+
+impl ForgeKeychain {
+    /// Reads the secret stored for `account`, or `None` if there is none
+    ///
+    /// # Errors
+    /// Returns an error if the keychain is locked or the platform has no
+    /// supported keychain
+    pub async fn get_password(&self, account: &str) -> anyhow::Result<Option<String>> {
+        let account = account.to_string();
+        // Keychain access blocks, so it runs off the async runtime
+        tokio::task::spawn_blocking(move || read_chunks(&account))
+            .await?
+            .context("Failed to read from keychain")
+    }
+
+    /// Stores `password` for `account`, replacing any previous secret
+    ///
+    /// # Errors
+    /// Returns an error if the keychain is locked or the platform has no
+    /// supported keychain
+    pub async fn set_password(&self, account: &str, password: &str) -> anyhow::Result<()> {
+        let account = account.to_string();
+        let password = password.to_string();
+        tokio::task::spawn_blocking(move || write_chunks(&account, &password))
+            .await?
+            .context("Failed to write to keychain")
+    }
+}
+
+/// Reads every chunk of the secret for `account`. A chunk shorter than
+/// [`CHUNK_CHARS`] is the last one.
+fn read_chunks(account: &str) -> keyring::Result<Option<String>> {
+    let mut password = String::new();
+    for index in 0.. {
+        match Entry::new(SERVICE, &chunk_account(account, index))?.get_password() {
+            Ok(chunk) => {
+                let full = chunk.chars().count() == CHUNK_CHARS;
+                password.push_str(&chunk);
+                if !full {
+                    break;
+                }
+            }
+            Err(keyring::Error::NoEntry) if index == 0 => return Ok(None),
+            Err(keyring::Error::NoEntry) => break,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(Some(password))
+}
+
+/// Writes `password` for `account` in chunks, removing the chunk a previous,
+/// longer secret left after the last one when that one is full
+fn write_chunks(account: &str, password: &str) -> keyring::Result<()> {
+    let chunks = split_chunks(password, CHUNK_CHARS);
+    for (index, chunk) in chunks.iter().enumerate() {
+        Entry::new(SERVICE, &chunk_account(account, index))?.set_password(chunk)?;
+    }
+
+    if chunks
+        .last()
+        .is_some_and(|chunk| chunk.chars().count() == CHUNK_CHARS)
+    {
+        match Entry::new(SERVICE, &chunk_account(account, chunks.len()))?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+/// Keychain account holding chunk `index` of the secret for `account`
+fn chunk_account(account: &str, index: usize) -> String {
+    match index {
+        0 => account.to_string(),
+        index => format!("{account}.{index}"),
+    }
+}
+
+/// Splits `password` into chunks of at most `size` characters, keeping one
+/// empty chunk for an empty password
+fn split_chunks(password: &str, size: usize) -> Vec<String> {
+    let chars: Vec<char> = password.chars().collect();
+    if chars.is_empty() {
+        return vec![String::new()];
+    }
+    chars
+        .chunks(size)
+        .map(|chunk| chunk.iter().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_split_chunks() {
+        let actual = split_chunks("abcdé", 2);
+        let expected = vec!["ab".to_string(), "cd".to_string(), "é".to_string()];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_split_chunks_keeps_short_password_whole() {
+        let actual = split_chunks("{\"a\":1}", CHUNK_CHARS);
+        let expected = vec!["{\"a\":1}".to_string()];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_chunk_account() {
+        let actual = [0, 2].map(|index| chunk_account("credentials", index));
+        let expected = ["credentials".to_string(), "credentials.2".to_string()];
+        assert_eq!(actual, expected);
+    }
+}
@@ -3,9 +3,11 @@ mod mcp_token_storage;
 
 mod error;
 mod http;
+mod keychain;
 mod strategy;
 mod util;
 
+pub use keychain::*;
 pub(crate) use mcp_credentials::*;
 pub(crate) use mcp_token_storage::*;
 pub use strategy::*;
@@ -519,7 +519,8 @@ impl AuthStrategy for GoogleAdcStrategy {
             self.provider_id.clone(),
             ApiKey::from(access_token.token),
         )
-        .url_params(credential.url_params.clone()))
+        .url_params(credential.url_params.clone())
+        .alias(credential.alias.clone()))
     }
 }
 
@@ -783,7 +784,9 @@ async fn refresh_oauth_credential(
         AuthCredential::new_oauth(credential.id.clone(), new_tokens, config.clone())
     };
 
-    Ok(refreshed.url_params(credential.url_params.clone()))
+    Ok(refreshed
+        .url_params(credential.url_params.clone())
+        .alias(credential.alias.clone()))
 }
 
 /// Poll for OAuth tokens during device flow
@@ -64,6 +64,18 @@ fn apply_config_op(fc: &mut ForgeConfig, op: ConfigOperation) {
                 .get_or_insert_with(forge_config::ReasoningConfig::default);
             reasoning.effort = Some(config_effort);
         }
+        ConfigOperation::SetProviderAlias(provider, alias) => {
+            let provider = provider.as_ref().to_string();
+            match alias {
+                Some(alias) => fc.provider_aliases.insert(provider, alias),
+                None => fc.provider_aliases.remove(&provider),
+            };
+        }
+        ConfigOperation::SetPinnedVersion(version) => {
+            fc.updates
+                .get_or_insert_with(forge_config::Update::default)
+                .pin_version = version;
+        }
     }
 }
 
@@ -75,6 +87,7 @@ fn apply_config_op(fc: &mut ForgeConfig, op: ConfigOperation) {
 /// environment variable discovery via `.env` files and OS APIs.
 pub struct ForgeEnvironmentInfra {
     cwd: PathBuf,
+    base_path: Option<PathBuf>,
     cache: Arc<std::sync::Mutex<Option<ForgeConfig>>>,
 }
 
@@ -88,7 +101,22 @@ impl ForgeEnvironmentInfra {
     /// * `cwd` - The working directory path; used to resolve `.env` files
     /// * `config` - The pre-read [`ForgeConfig`] to seed the in-memory cache
     pub fn new(cwd: PathBuf, config: ForgeConfig) -> Self {
-        Self { cwd, cache: Arc::new(std::sync::Mutex::new(Some(config))) }
+        Self {
+            cwd,
+            base_path: None,
+            cache: Arc::new(std::sync::Mutex::new(Some(config))),
+        }
+    }
+
+    /// Returns a copy that stores Forge data under `base_path` instead of the
+    /// directory resolved by [`ConfigReader::base_path`]. The config cache is
+    /// shared with `self`.
+    pub fn with_base_path(&self, base_path: PathBuf) -> Self {
+        Self {
+            cwd: self.cwd.clone(),
+            base_path: Some(base_path),
+            cache: self.cache.clone(),
+        }
     }
 
     /// Returns the cached [`ForgeConfig`], re-reading from disk if the cache
@@ -125,7 +153,11 @@ impl EnvironmentInfra for ForgeEnvironmentInfra {
     }
 
     fn get_environment(&self) -> Environment {
-        to_environment(self.cwd.clone())
+        let mut environment = to_environment(self.cwd.clone());
+        if let Some(base_path) = &self.base_path {
+            environment.base_path = base_path.clone();
+        }
+        environment
     }
 
     fn get_config(&self) -> anyhow::Result<ForgeConfig> {
@@ -203,6 +235,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_environment_uses_base_path_override() {
+        let fixture =
+            ForgeEnvironmentInfra::new(PathBuf::from("/test/cwd"), ForgeConfig::default())
+                .with_base_path(PathBuf::from("/test/base"));
+        let actual = fixture.get_environment();
+        assert_eq!(actual.base_path, PathBuf::from("/test/base"));
+        assert_eq!(actual.cwd, PathBuf::from("/test/cwd"));
+    }
+
     #[test]
     fn test_apply_config_op_set_model() {
         use forge_domain::{ModelConfig as DomainModelConfig, ModelId, ProviderId};
@@ -271,4 +313,25 @@ mod tests {
         assert_eq!(actual_provider, Some("anthropic"));
         assert_eq!(actual_model, Some("claude-3-5-sonnet-20241022"));
     }
+
+    #[test]
+    fn test_apply_config_op_set_pinned_version_keeps_other_update_settings() {
+        let mut fixture = ForgeConfig {
+            updates: Some(forge_config::Update::default().auto_update(true)),
+            ..Default::default()
+        };
+
+        apply_config_op(
+            &mut fixture,
+            ConfigOperation::SetPinnedVersion(Some("1.2.3".to_string())),
+        );
+
+        let actual = fixture.updates;
+        let expected = Some(
+            forge_config::Update::default()
+                .auto_update(true)
+                .pin_version("1.2.3"),
+        );
+        assert_eq!(actual, expected);
+    }
 }
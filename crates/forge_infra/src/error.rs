@@ -2,4 +2,9 @@
 pub enum Error {
     #[error("Unsupported MCP response: {0}")]
     UnsupportedMcpResponse(&'static str),
+
+    #[error(
+        "Circuit open for {0}: the server kept failing, requests are rejected until it recovers"
+    )]
+    CircuitOpen(String),
 }
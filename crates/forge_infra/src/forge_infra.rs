@@ -6,17 +6,18 @@ use std::sync::Arc;
 use bytes::Bytes;
 use forge_app::{
     CommandInfra, DirectoryReaderInfra, EnvironmentInfra, FileDirectoryInfra, FileInfoInfra,
-    FileReaderInfra, FileRemoverInfra, FileWriterInfra, GrpcInfra, HttpInfra, McpServerInfra,
-    StrategyFactory, UserInfra, WalkerInfra,
+    FileReaderInfra, FileRemoverInfra, FileWriterInfra, GrpcInfra, HttpInfra, KeychainInfra,
+    McpServerInfra, StrategyFactory, UserInfra, WalkerInfra,
 };
 use forge_domain::{
     AuthMethod, CommandOutput, FileInfo as FileInfoData, McpServerConfig, ProviderId, URLParamSpec,
 };
 use forge_eventsource::EventSource;
+use futures::StreamExt;
 use reqwest::header::HeaderMap;
 use reqwest::{Response, Url};
 
-use crate::auth::{AnyAuthStrategy, ForgeAuthStrategyFactory};
+use crate::auth::{AnyAuthStrategy, ForgeAuthStrategyFactory, ForgeKeychain};
 use crate::console::StdConsoleWriter;
 use crate::env::{ForgeEnvironmentInfra, to_environment};
 use crate::executor::ForgeCommandExecutorService;
@@ -25,9 +26,11 @@ use crate::fs_meta::ForgeFileMetaService;
 use crate::fs_read::ForgeFileReadService;
 use crate::fs_read_dir::ForgeDirectoryReaderService;
 use crate::fs_remove::ForgeFileRemoveService;
+use crate::fs_s3::S3FileSystem;
+use crate::fs_ssh::SshFileSystem;
 use crate::fs_write::ForgeFileWriteService;
 use crate::grpc::ForgeGrpcClient;
-use crate::http::ForgeHttpInfra;
+use crate::http::{CircuitBreakerHttpClient, DeduplicatingHttpClient, ForgeHttpInfra};
 use crate::inquire::ForgeInquire;
 use crate::mcp_client::ForgeMcpClient;
 use crate::mcp_server::ForgeMcpServer;
@@ -48,10 +51,22 @@ pub struct ForgeInfra {
     inquire_service: Arc<ForgeInquire>,
     mcp_server: ForgeMcpServer,
     walker_service: Arc<ForgeWalkerService>,
-    http_service: Arc<ForgeHttpInfra<ForgeFileWriteService>>,
+    http_service: Arc<
+        DeduplicatingHttpClient<CircuitBreakerHttpClient<ForgeHttpInfra<ForgeFileWriteService>>>,
+    >,
     strategy_factory: Arc<ForgeAuthStrategyFactory>,
     grpc_client: Arc<ForgeGrpcClient>,
     output_printer: Arc<StdConsoleWriter>,
+    keychain: Arc<ForgeKeychain>,
+    ssh_file_system: Option<Arc<SshFileSystem>>,
+    s3_file_system: Arc<S3FileSystem>,
+}
+
+/// Filesystem backend that serves a given path
+enum Backend<'a> {
+    Local,
+    Ssh(&'a SshFileSystem),
+    S3(&'a S3FileSystem),
 }
 
 impl ForgeInfra {
@@ -66,14 +81,18 @@ impl ForgeInfra {
     /// * `services_url` - Pre-validated URL for the gRPC workspace server
     pub fn new(cwd: PathBuf, config: forge_config::ForgeConfig) -> Self {
         let env = to_environment(cwd.clone());
-        let config_infra = Arc::new(ForgeEnvironmentInfra::new(cwd, config.clone()));
+        let config_infra = Arc::new(ForgeEnvironmentInfra::new(cwd.clone(), config.clone()));
         let file_write_service = Arc::new(ForgeFileWriteService::new());
         let config = config_infra.cached_config().unwrap_or(config);
 
-        let http_service = Arc::new(ForgeHttpInfra::new(
-            config.clone(),
-            file_write_service.clone(),
-        ));
+        let circuit_breaker = config
+            .retry
+            .as_ref()
+            .and_then(|retry| retry.circuit_breaker.clone());
+        let http_service = Arc::new(DeduplicatingHttpClient::new(CircuitBreakerHttpClient::new(
+            ForgeHttpInfra::new(config.clone(), file_write_service.clone()),
+            circuit_breaker,
+        )));
         let file_read_service = Arc::new(ForgeFileReadService::new());
         let file_meta_service = Arc::new(ForgeFileMetaService);
         let directory_reader_service = Arc::new(ForgeDirectoryReaderService::new(
@@ -84,6 +103,10 @@ impl ForgeInfra {
         ));
         let grpc_client = Arc::new(ForgeGrpcClient::new(config.services_url.clone()));
         let output_printer = Arc::new(StdConsoleWriter::default());
+        let ssh_file_system = config
+            .ssh
+            .clone()
+            .map(|ssh| Arc::new(SshFileSystem::new(ssh, cwd)));
 
         Self {
             file_read_service,
@@ -104,6 +127,42 @@ impl ForgeInfra {
             http_service,
             grpc_client,
             output_printer,
+            keychain: Arc::new(ForgeKeychain),
+            ssh_file_system,
+            s3_file_system: Arc::new(S3FileSystem::new()),
+        }
+    }
+
+    /// Stores conversations, credentials and other Forge data under
+    /// `base_path` instead of the configured base directory
+    pub fn with_base_path(mut self, base_path: PathBuf) -> Self {
+        self.config_infra = Arc::new(self.config_infra.with_base_path(base_path));
+        self
+    }
+
+    /// Serves files inside the working directory from a remote host over SFTP
+    /// instead of the local disk
+    pub fn with_ssh_file_system(mut self, file_system: SshFileSystem) -> Self {
+        self.ssh_file_system = Some(Arc::new(file_system));
+        self
+    }
+
+    /// Serves `s3://` paths with `file_system` instead of a client configured
+    /// from the AWS environment
+    pub fn with_s3_file_system(mut self, file_system: S3FileSystem) -> Self {
+        self.s3_file_system = Arc::new(file_system);
+        self
+    }
+
+    /// Picks the backend for `path`: `s3://` URLs go to S3, workspace paths to
+    /// the SSH host when one is configured, and everything else to local disk
+    fn backend(&self, path: &Path) -> Backend<'_> {
+        if S3FileSystem::is_s3_path(path) {
+            return Backend::S3(&self.s3_file_system);
+        }
+        match self.ssh_file_system.as_deref() {
+            Some(fs) if fs.is_remote(path) => Backend::Ssh(fs),
+            _ => Backend::Local,
         }
     }
 }
@@ -150,7 +209,11 @@ impl EnvironmentInfra for ForgeInfra {
 #[async_trait::async_trait]
 impl FileReaderInfra for ForgeInfra {
     async fn read_utf8(&self, path: &Path) -> anyhow::Result<String> {
-        self.file_read_service.read_utf8(path).await
+        match self.backend(path) {
+            Backend::S3(fs) => fs.read_utf8(path).await,
+            Backend::Ssh(fs) => fs.read_utf8(path).await,
+            Backend::Local => self.file_read_service.read_utf8(path).await,
+        }
     }
 
     fn read_batch_utf8(
@@ -158,11 +221,30 @@ impl FileReaderInfra for ForgeInfra {
         batch_size: usize,
         paths: Vec<PathBuf>,
     ) -> impl futures::Stream<Item = (PathBuf, anyhow::Result<String>)> + Send {
-        self.file_read_service.read_batch_utf8(batch_size, paths)
+        let local_only = self.ssh_file_system.is_none()
+            && !paths.iter().any(|path| S3FileSystem::is_s3_path(path));
+        if local_only {
+            self.file_read_service
+                .read_batch_utf8(batch_size, paths)
+                .left_stream()
+        } else {
+            // Each path is routed on its own, so batches may mix local and remote files
+            futures::stream::iter(paths)
+                .map(move |path| async move {
+                    let result = self.read_utf8(&path).await;
+                    (path, result)
+                })
+                .buffered(batch_size.max(1))
+                .right_stream()
+        }
     }
 
     async fn read(&self, path: &Path) -> anyhow::Result<Vec<u8>> {
-        self.file_read_service.read(path).await
+        match self.backend(path) {
+            Backend::S3(fs) => fs.read(path).await,
+            Backend::Ssh(fs) => fs.read(path).await,
+            Backend::Local => self.file_read_service.read(path).await,
+        }
     }
 
     async fn range_read_utf8(
@@ -171,20 +253,34 @@ impl FileReaderInfra for ForgeInfra {
         start_line: u64,
         end_line: u64,
     ) -> anyhow::Result<(String, FileInfoData)> {
-        self.file_read_service
-            .range_read_utf8(path, start_line, end_line)
-            .await
+        match self.backend(path) {
+            Backend::S3(fs) => fs.range_read_utf8(path, start_line, end_line).await,
+            Backend::Ssh(fs) => fs.range_read_utf8(path, start_line, end_line).await,
+            Backend::Local => {
+                self.file_read_service
+                    .range_read_utf8(path, start_line, end_line)
+                    .await
+            }
+        }
     }
 }
 
 #[async_trait::async_trait]
 impl FileWriterInfra for ForgeInfra {
     async fn write(&self, path: &Path, contents: Bytes) -> anyhow::Result<()> {
-        self.file_write_service.write(path, contents).await
+        match self.backend(path) {
+            Backend::S3(fs) => fs.write(path, contents).await,
+            Backend::Ssh(fs) => fs.write(path, contents).await,
+            Backend::Local => self.file_write_service.write(path, contents).await,
+        }
     }
 
     async fn append(&self, path: &Path, contents: Bytes) -> anyhow::Result<()> {
-        self.file_write_service.append(path, contents).await
+        match self.backend(path) {
+            Backend::S3(fs) => fs.append(path, contents).await,
+            Backend::Ssh(fs) => fs.append(path, contents).await,
+            Backend::Local => self.file_write_service.append(path, contents).await,
+        }
     }
 
     async fn write_temp(&self, prefix: &str, ext: &str, content: &str) -> anyhow::Result<PathBuf> {
@@ -197,32 +293,56 @@ impl FileWriterInfra for ForgeInfra {
 #[async_trait::async_trait]
 impl FileInfoInfra for ForgeInfra {
     async fn is_binary(&self, path: &Path) -> anyhow::Result<bool> {
-        self.file_meta_service.is_binary(path).await
+        match self.backend(path) {
+            Backend::S3(fs) => fs.is_binary(path).await,
+            Backend::Ssh(fs) => fs.is_binary(path).await,
+            Backend::Local => self.file_meta_service.is_binary(path).await,
+        }
     }
 
     async fn is_file(&self, path: &Path) -> anyhow::Result<bool> {
-        self.file_meta_service.is_file(path).await
+        match self.backend(path) {
+            Backend::S3(fs) => fs.is_file(path).await,
+            Backend::Ssh(fs) => fs.is_file(path).await,
+            Backend::Local => self.file_meta_service.is_file(path).await,
+        }
     }
 
     async fn exists(&self, path: &Path) -> anyhow::Result<bool> {
-        self.file_meta_service.exists(path).await
+        match self.backend(path) {
+            Backend::S3(fs) => fs.exists(path).await,
+            Backend::Ssh(fs) => fs.exists(path).await,
+            Backend::Local => self.file_meta_service.exists(path).await,
+        }
     }
 
     async fn file_size(&self, path: &Path) -> anyhow::Result<u64> {
-        self.file_meta_service.file_size(path).await
+        match self.backend(path) {
+            Backend::S3(fs) => fs.file_size(path).await,
+            Backend::Ssh(fs) => fs.file_size(path).await,
+            Backend::Local => self.file_meta_service.file_size(path).await,
+        }
     }
 }
 #[async_trait::async_trait]
 impl FileRemoverInfra for ForgeInfra {
     async fn remove(&self, path: &Path) -> anyhow::Result<()> {
-        self.file_remove_service.remove(path).await
+        match self.backend(path) {
+            Backend::S3(fs) => fs.remove(path).await,
+            Backend::Ssh(fs) => fs.remove(path).await,
+            Backend::Local => self.file_remove_service.remove(path).await,
+        }
     }
 }
 
 #[async_trait::async_trait]
 impl FileDirectoryInfra for ForgeInfra {
     async fn create_dirs(&self, path: &Path) -> anyhow::Result<()> {
-        self.create_dirs_service.create_dirs(path).await
+        match self.backend(path) {
+            Backend::S3(fs) => fs.create_dirs(path).await,
+            Backend::Ssh(fs) => fs.create_dirs(path).await,
+            Backend::Local => self.create_dirs_service.create_dirs(path).await,
+        }
     }
 }
 
@@ -330,9 +450,15 @@ impl DirectoryReaderInfra for ForgeInfra {
         &self,
         directory: &Path,
     ) -> anyhow::Result<Vec<(PathBuf, bool)>> {
-        self.directory_reader_service
-            .list_directory_entries(directory)
-            .await
+        match self.backend(directory) {
+            Backend::S3(fs) => fs.list_directory_entries(directory).await,
+            Backend::Ssh(fs) => fs.list_directory_entries(directory).await,
+            Backend::Local => {
+                self.directory_reader_service
+                    .list_directory_entries(directory)
+                    .await
+            }
+        }
     }
 
     async fn read_directory_files(
@@ -340,9 +466,15 @@ impl DirectoryReaderInfra for ForgeInfra {
         directory: &Path,
         pattern: Option<&str>,
     ) -> anyhow::Result<Vec<(PathBuf, String)>> {
-        self.directory_reader_service
-            .read_directory_files(directory, pattern)
-            .await
+        match self.backend(directory) {
+            Backend::S3(fs) => fs.read_directory_files(directory, pattern).await,
+            Backend::Ssh(fs) => fs.read_directory_files(directory, pattern).await,
+            Backend::Local => {
+                self.directory_reader_service
+                    .read_directory_files(directory, pattern)
+                    .await
+            }
+        }
     }
 }
 
@@ -359,6 +491,17 @@ impl StrategyFactory for ForgeInfra {
     }
 }
 
+#[async_trait::async_trait]
+impl KeychainInfra for ForgeInfra {
+    async fn get_password(&self, account: &str) -> anyhow::Result<Option<String>> {
+        self.keychain.get_password(account).await
+    }
+
+    async fn set_password(&self, account: &str, password: &str) -> anyhow::Result<()> {
+        self.keychain.set_password(account, password).await
+    }
+}
+
 impl GrpcInfra for ForgeInfra {
     fn channel(&self) -> anyhow::Result<tonic::transport::Channel> {
         self.grpc_client.channel()
@@ -0,0 +1,384 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use aws_sdk_s3::Client;
+use bstr::ByteSlice;
+use bytes::Bytes;
+use forge_app::{
+    DirectoryReaderInfra, FileDirectoryInfra, FileInfoInfra, FileReaderInfra, FileRemoverInfra,
+    FileWriterInfra,
+};
+use forge_fs::ForgeFS;
+use futures::{StreamExt, stream};
+use glob::Pattern;
+use tokio::sync::OnceCell;
+
+use crate::fs_write::ForgeFileWriteService;
+
+const S3_SCHEME: &str = "s3://";
+
+/// Read-only filesystem backend for objects stored in Amazon S3
+///
+/// Paths take the form `s3://bucket/key`. Objects are fetched with
+/// `GetObject`/`HeadObject` and "directories" are listed with
+/// `ListObjectsV2` using `/` as the delimiter. The client is built lazily from
+/// the standard AWS environment (credentials, profile and region) on first
+/// use. Writes are rejected.
+pub struct S3FileSystem {
+    client: OnceCell<Client>,
+}
+
+impl Default for S3FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl S3FileSystem {
+    /// Creates a backend that configures its client from the AWS environment
+    pub fn new() -> Self {
+        Self { client: OnceCell::new() }
+    }
+
+    /// Creates a backend that uses a preconfigured client
+    pub fn with_client(client: Client) -> Self {
+        Self { client: OnceCell::new_with(Some(client)) }
+    }
+
+    /// Returns true if `path` is an `s3://` URL
+    pub fn is_s3_path(path: &Path) -> bool {
+        path.to_str()
+            .is_some_and(|path| path.starts_with(S3_SCHEME))
+    }
+
+    /// Splits an `s3://bucket/key` path into its bucket and key
+    fn split(path: &Path) -> Result<(String, String)> {
+        let location = path
+            .to_str()
+            .and_then(|path| path.strip_prefix(S3_SCHEME))
+            .with_context(|| format!("{} is not an s3:// path", path.display()))?;
+        let (bucket, key) = location.split_once('/').unwrap_or((location, ""));
+        if bucket.is_empty() {
+            bail!("{} does not name a bucket", path.display());
+        }
+        Ok((bucket.to_string(), key.to_string()))
+    }
+
+    async fn client(&self) -> &Client {
+        self.client
+            .get_or_init(|| async { Client::new(&aws_config::load_from_env().await) })
+            .await
+    }
+
+    /// Fetches an object, optionally limited to an HTTP byte `range`
+    async fn get_object(&self, path: &Path, range: Option<&str>) -> Result<Vec<u8>> {
+        let (bucket, key) = Self::split(path)?;
+        let output = self
+            .client()
+            .await
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            .set_range(range.map(str::to_string))
+            .send()
+            .await
+            .with_context(|| format!("Failed to read object {}", path.display()))?;
+        let body = output
+            .body
+            .collect()
+            .await
+            .with_context(|| format!("Failed to read object {}", path.display()))?;
+        Ok(body.into_bytes().to_vec())
+    }
+
+    fn read_only(path: &Path) -> Result<()> {
+        bail!("S3 objects are read-only: {}", path.display())
+    }
+}
+
+#[async_trait::async_trait]
+impl FileReaderInfra for S3FileSystem {
+    async fn read_utf8(&self, path: &Path) -> Result<String> {
+        self.read(path)
+            .await
+            .map(|bytes| bytes.to_str_lossy().to_string())
+    }
+
+    fn read_batch_utf8(
+        &self,
+        batch_size: usize,
+        paths: Vec<PathBuf>,
+    ) -> impl futures::Stream<Item = (PathBuf, anyhow::Result<String>)> + Send {
+        stream::iter(paths)
+            .map(move |path| async move {
+                let result = self.read_utf8(&path).await;
+                (path, result)
+            })
+            .buffered(batch_size.max(1))
+    }
+
+    async fn read(&self, path: &Path) -> Result<Vec<u8>> {
+        self.get_object(path, None).await
+    }
+
+    async fn range_read_utf8(
+        &self,
+        path: &Path,
+        start_line: u64,
+        end_line: u64,
+    ) -> Result<(String, forge_domain::FileInfo)> {
+        let contents = self.read(path).await?;
+        ForgeFS::range_utf8(&contents, start_line, end_line)
+    }
+}
+
+#[async_trait::async_trait]
+impl FileWriterInfra for S3FileSystem {
+    async fn write(&self, path: &Path, _contents: Bytes) -> Result<()> {
+        Self::read_only(path)
+    }
+
+    async fn append(&self, path: &Path, _contents: Bytes) -> Result<()> {
+        Self::read_only(path)
+    }
+
+    async fn write_temp(&self, prefix: &str, ext: &str, content: &str) -> Result<PathBuf> {
+        ForgeFileWriteService::new()
+            .write_temp(prefix, ext, content)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl FileInfoInfra for S3FileSystem {
+    async fn is_binary(&self, path: &Path) -> Result<bool> {
+        let sample = self.get_object(path, Some("bytes=0-511")).await?;
+        Ok(forge_fs::is_binary_content(&sample))
+    }
+
+    async fn is_file(&self, path: &Path) -> Result<bool> {
+        let (bucket, key) = Self::split(path)?;
+        if key.is_empty() {
+            return Ok(false);
+        }
+        let head = self
+            .client()
+            .await
+            .head_object()
+            .bucket(bucket)
+            .key(key)
+            .send()
+            .await;
+        Ok(head.is_ok())
+    }
+
+    async fn exists(&self, path: &Path) -> Result<bool> {
+        if self.is_file(path).await? {
+            return Ok(true);
+        }
+        Ok(!self.list_directory_entries(path).await?.is_empty())
+    }
+
+    async fn file_size(&self, path: &Path) -> Result<u64> {
+        let (bucket, key) = Self::split(path)?;
+        let head = self
+            .client()
+            .await
+            .head_object()
+            .bucket(bucket)
+            .key(key)
+            .send()
+            .await
+            .with_context(|| format!("Failed to get metadata for {}", path.display()))?;
+        Ok(head.content_length.unwrap_or_default().max(0) as u64)
+    }
+}
+
+#[async_trait::async_trait]
+impl FileRemoverInfra for S3FileSystem {
+    async fn remove(&self, path: &Path) -> Result<()> {
+        Self::read_only(path)
+    }
+}
+
+#[async_trait::async_trait]
+impl FileDirectoryInfra for S3FileSystem {
+    async fn create_dirs(&self, path: &Path) -> Result<()> {
+        Self::read_only(path)
+    }
+}
+
+#[async_trait::async_trait]
+impl DirectoryReaderInfra for S3FileSystem {
+    async fn list_directory_entries(&self, directory: &Path) -> Result<Vec<(PathBuf, bool)>> {
+        let (bucket, key) = Self::split(directory)?;
+        let prefix = match key.trim_end_matches('/') {
+            "" => String::new(),
+            key => format!("{key}/"),
+        };
+
+        let mut pages = self
+            .client()
+            .await
+            .list_objects_v2()
+            .bucket(&bucket)
+            .prefix(&prefix)
+            .delimiter("/")
+            .into_paginator()
+            .send();
+
+        let to_path = |key: &str| PathBuf::from(format!("{S3_SCHEME}{bucket}/{key}"));
+        let mut entries = Vec::new();
+        while let Some(page) = pages.next().await {
+            let page =
+                page.with_context(|| format!("Failed to list directory {}", directory.display()))?;
+            entries.extend(
+                page.common_prefixes()
+                    .iter()
+                    .filter_map(|common| common.prefix())
+                    .map(|dir| (to_path(dir.trim_end_matches('/')), true)),
+            );
+            entries.extend(
+                page.contents()
+                    .iter()
+                    .filter_map(|object| object.key())
+                    .filter(|key| *key != prefix)
+                    .map(|key| (to_path(key), false)),
+            );
+        }
+        Ok(entries)
+    }
+
+    async fn read_directory_files(
+        &self,
+        directory: &Path,
+        pattern: Option<&str>,
+    ) -> Result<Vec<(PathBuf, String)>> {
+        let glob_pattern = pattern.map(Pattern::new).transpose()?;
+
+        let files = self
+            .list_directory_entries(directory)
+            .await?
+            .into_iter()
+            .filter(|(path, is_dir)| {
+                !is_dir
+                    && glob_pattern.as_ref().is_none_or(|pattern| {
+                        path.file_name()
+                            .is_some_and(|name| pattern.matches(&name.to_string_lossy()))
+                    })
+            })
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::new();
+        for path in files {
+            let content = self.read_utf8(&path).await?;
+            results.push((path, content));
+        }
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aws_sdk_s3::config::{Credentials, Region};
+    use mockito::Matcher;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn fixture(endpoint: String) -> S3FileSystem {
+        let config = aws_sdk_s3::Config::builder()
+            .region(Region::new("us-east-1"))
+            .endpoint_url(endpoint)
+            .force_path_style(true)
+            .credentials_provider(Credentials::new("key", "secret", None, None, "test"))
+            .build();
+        S3FileSystem::with_client(Client::from_conf(config))
+    }
+
+    #[test]
+    fn test_is_s3_path() {
+        assert!(S3FileSystem::is_s3_path(Path::new("s3://bucket/key.txt")));
+        assert!(!S3FileSystem::is_s3_path(Path::new("/home/me/s3://x")));
+        assert!(!S3FileSystem::is_s3_path(Path::new("relative/path")));
+    }
+
+    #[test]
+    fn test_split_bucket_and_key() {
+        let actual = S3FileSystem::split(Path::new("s3://bucket/dir/key.txt")).unwrap();
+        let expected = ("bucket".to_string(), "dir/key.txt".to_string());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_split_rejects_missing_bucket() {
+        assert!(S3FileSystem::split(Path::new("s3:///key.txt")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_range_read_utf8_from_get_object() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/bucket/docs/notes.txt")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body("first\nsecond\nthird\n")
+            .create_async()
+            .await;
+        let fs = fixture(server.url());
+
+        let (actual, info) = fs
+            .range_read_utf8(Path::new("s3://bucket/docs/notes.txt"), 2, 3)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(actual, "second\nthird");
+        assert_eq!(info.total_lines, 3);
+    }
+
+    #[tokio::test]
+    async fn test_list_directory_entries() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", Matcher::Regex("^/bucket/?$".into()))
+            .match_query(Matcher::UrlEncoded("prefix".into(), "docs/".into()))
+            .with_status(200)
+            .with_body(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Name>bucket</Name>
+  <Prefix>docs/</Prefix>
+  <Delimiter>/</Delimiter>
+  <KeyCount>2</KeyCount>
+  <IsTruncated>false</IsTruncated>
+  <Contents><Key>docs/notes.txt</Key><Size>19</Size></Contents>
+  <CommonPrefixes><Prefix>docs/images/</Prefix></CommonPrefixes>
+</ListBucketResult>"#,
+            )
+            .create_async()
+            .await;
+        let fs = fixture(server.url());
+
+        let actual = fs
+            .list_directory_entries(Path::new("s3://bucket/docs"))
+            .await
+            .unwrap();
+
+        let expected = vec![
+            (PathBuf::from("s3://bucket/docs/images"), true),
+            (PathBuf::from("s3://bucket/docs/notes.txt"), false),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_write_is_rejected() {
+        let fs = S3FileSystem::new();
+        let actual = fs
+            .write(Path::new("s3://bucket/key.txt"), Bytes::from("x"))
+            .await;
+        assert!(actual.is_err());
+    }
+}
@@ -0,0 +1,750 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use bstr::ByteSlice;
+use bytes::Bytes;
+use forge_app::{
+    DirectoryReaderInfra, FileDirectoryInfra, FileInfoInfra, FileReaderInfra, FileRemoverInfra,
+    FileWriterInfra,
+};
+use forge_config::SshConfig;
+use forge_fs::ForgeFS;
+use futures::{StreamExt, stream};
+use glob::Pattern;
+use ssh2::{
+    CheckResult, FileStat, KnownHostFileKind, KnownHosts, OpenFlags, OpenType, Session, Sftp,
+};
+use tokio::sync::OnceCell;
+
+use crate::fs_write::ForgeFileWriteService;
+
+/// Blocking file operations on the remote host
+///
+/// [`Sftp`] is the real transport; [`SshFileSystem`] is generic over it so
+/// that its behaviour can be tested without an SSH server.
+pub trait SftpTransport: Send + Sync + 'static {
+    /// Opens a session to the host described by `config`
+    fn connect(config: &SshConfig) -> Result<Self>
+    where
+        Self: Sized;
+
+    fn stat(&self, path: &Path) -> Result<FileStat>;
+
+    fn mkdir(&self, path: &Path) -> Result<()>;
+
+    /// Reads at most `limit` bytes from the start of a file
+    fn read(&self, path: &Path, limit: u64) -> Result<Vec<u8>>;
+
+    /// Writes `contents` to a file, creating it if needed. Existing contents
+    /// are kept when `append` is set and truncated otherwise.
+    fn write(&self, path: &Path, contents: &[u8], append: bool) -> Result<()>;
+
+    fn unlink(&self, path: &Path) -> Result<()>;
+
+    fn readdir(&self, path: &Path) -> Result<Vec<(PathBuf, FileStat)>>;
+}
+
+impl SftpTransport for Sftp {
+    fn connect(config: &SshConfig) -> Result<Self> {
+        let address = format!("{}:{}", config.host, config.port);
+        let tcp = TcpStream::connect(&address)
+            .with_context(|| format!("Failed to connect to {address}"))?;
+
+        let mut session = Session::new()?;
+        session.set_tcp_stream(tcp);
+        session
+            .handshake()
+            .with_context(|| format!("SSH handshake with {address} failed"))?;
+        verify_host_key(&session, config)?;
+
+        match &config.identity_file {
+            Some(identity_file) => {
+                session.userauth_pubkey_file(&config.user, None, identity_file, None)
+            }
+            None => session.userauth_agent(&config.user),
+        }
+        .with_context(|| format!("SSH authentication as {} failed", config.user))?;
+
+        Ok(session.sftp()?)
+    }
+
+    fn stat(&self, path: &Path) -> Result<FileStat> {
+        Ok(Sftp::stat(self, path)?)
+    }
+
+    fn mkdir(&self, path: &Path) -> Result<()> {
+        Ok(Sftp::mkdir(self, path, 0o755)?)
+    }
+
+    fn read(&self, path: &Path, limit: u64) -> Result<Vec<u8>> {
+        let mut contents = Vec::new();
+        self.open(path)?.take(limit).read_to_end(&mut contents)?;
+        Ok(contents)
+    }
+
+    fn write(&self, path: &Path, contents: &[u8], append: bool) -> Result<()> {
+        let mode = if append {
+            OpenFlags::APPEND
+        } else {
+            OpenFlags::TRUNCATE
+        };
+        let flags = OpenFlags::WRITE | OpenFlags::CREATE | mode;
+        let mut file = self.open_mode(path, flags, 0o644, OpenType::File)?;
+        file.write_all(contents)?;
+        Ok(())
+    }
+
+    fn unlink(&self, path: &Path) -> Result<()> {
+        Ok(Sftp::unlink(self, path)?)
+    }
+
+    fn readdir(&self, path: &Path) -> Result<Vec<(PathBuf, FileStat)>> {
+        Ok(Sftp::readdir(self, path)?)
+    }
+}
+
+/// Checks the key the server presented during the handshake against
+/// `~/.ssh/known_hosts`, recording it there when the host is unknown and
+/// `accept_unknown_host` is set
+fn verify_host_key(session: &Session, config: &SshConfig) -> Result<()> {
+    let (key, key_type) = session
+        .host_key()
+        .context("SSH server did not present a host key")?;
+    let path = dirs::home_dir()
+        .context("Failed to locate the home directory")?
+        .join(".ssh")
+        .join("known_hosts");
+
+    let mut known_hosts = session.known_hosts()?;
+    if path.exists() {
+        known_hosts
+            .read_file(&path, KnownHostFileKind::OpenSSH)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+    }
+
+    if check_host_key(&known_hosts, config, key)? {
+        let host = match config.port {
+            22 => config.host.clone(),
+            port => format!("[{}]:{port}", config.host),
+        };
+        known_hosts.add(&host, key, "added by forge", key_type.into())?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        known_hosts
+            .write_file(&path, KnownHostFileKind::OpenSSH)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Returns whether `key` should be recorded as the key of a newly trusted
+/// host, or an error if the host must not be trusted
+fn check_host_key(known_hosts: &KnownHosts, config: &SshConfig, key: &[u8]) -> Result<bool> {
+    let address = format!("{}:{}", config.host, config.port);
+    match known_hosts.check_port(&config.host, config.port, key) {
+        CheckResult::Match => Ok(false),
+        CheckResult::Mismatch => anyhow::bail!(
+            "Host key for {address} does not match the one in ~/.ssh/known_hosts; the connection may be intercepted"
+        ),
+        CheckResult::NotFound if config.accept_unknown_host => Ok(true),
+        CheckResult::NotFound => anyhow::bail!(
+            "Host {address} is not in ~/.ssh/known_hosts; connect once with ssh to trust it or set accept_unknown_host"
+        ),
+        CheckResult::Failure => anyhow::bail!("Failed to check the host key for {address}"),
+    }
+}
+
+/// Filesystem backend that reads and writes files on a remote host over SFTP
+///
+/// Paths are given in terms of the local working directory and are mapped
+/// onto the remote host with [`SshFileSystem::remote_path`]. The SSH session
+/// is opened lazily on the first operation and reused afterwards.
+pub struct SshFileSystem<T = Sftp> {
+    config: SshConfig,
+    local_root: PathBuf,
+    sftp: OnceCell<Arc<T>>,
+}
+
+impl<T: SftpTransport> SshFileSystem<T> {
+    /// Creates a backend for the workspace rooted at `local_root`
+    pub fn new(config: SshConfig, local_root: PathBuf) -> Self {
+        Self { config, local_root, sftp: OnceCell::new() }
+    }
+
+    /// Returns true if `path` lies inside the workspace and is therefore
+    /// served from the remote host
+    pub fn is_remote(&self, path: &Path) -> bool {
+        path.starts_with(&self.local_root)
+    }
+
+    /// Maps a local workspace path to the corresponding path on the remote
+    /// host
+    pub fn remote_path(&self, path: &Path) -> PathBuf {
+        match (&self.config.remote_dir, path.strip_prefix(&self.local_root)) {
+            (Some(remote_dir), Ok(relative)) => remote_dir.join(relative),
+            _ => path.to_path_buf(),
+        }
+    }
+
+    /// Maps a path on the remote host back to the local workspace path
+    fn local_path(&self, path: &Path) -> PathBuf {
+        match &self.config.remote_dir {
+            Some(remote_dir) => path
+                .strip_prefix(remote_dir)
+                .map(|relative| self.local_root.join(relative))
+                .unwrap_or_else(|_| path.to_path_buf()),
+            None => path.to_path_buf(),
+        }
+    }
+
+    async fn sftp(&self) -> Result<Arc<T>> {
+        self.sftp
+            .get_or_try_init(|| async {
+                let config = self.config.clone();
+                let sftp = tokio::task::spawn_blocking(move || T::connect(&config)).await??;
+                Ok(Arc::new(sftp))
+            })
+            .await
+            .cloned()
+    }
+
+    /// Runs a blocking SFTP operation on the remote counterpart of `path`
+    async fn with_sftp<R, F>(&self, path: &Path, op: F) -> Result<R>
+    where
+        R: Send + 'static,
+        F: FnOnce(&T, &Path) -> Result<R> + Send + 'static,
+    {
+        let sftp = self.sftp().await?;
+        let remote = self.remote_path(path);
+        tokio::task::spawn_blocking(move || op(&sftp, &remote)).await?
+    }
+
+    fn create_dir_all(sftp: &T, path: &Path) -> Result<()> {
+        let missing = path
+            .ancestors()
+            .take_while(|dir| !dir.as_os_str().is_empty() && sftp.stat(dir).is_err())
+            .collect::<Vec<_>>();
+
+        for dir in missing.into_iter().rev() {
+            sftp.mkdir(dir)
+                .with_context(|| format!("Failed to create directory {}", dir.display()))?;
+        }
+        Ok(())
+    }
+
+    /// Reads at most `limit` bytes from the start of a remote file
+    fn read_file(sftp: &T, path: &Path, limit: u64) -> Result<Vec<u8>> {
+        sftp.read(path, limit)
+            .with_context(|| format!("Failed to read file {}", path.display()))
+    }
+
+    fn write_file(sftp: &T, path: &Path, contents: &[u8], append: bool) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            Self::create_dir_all(sftp, parent)?;
+        }
+        sftp.write(path, contents, append)
+            .with_context(|| format!("Failed to write file {}", path.display()))
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: SftpTransport> FileReaderInfra for SshFileSystem<T> {
+    async fn read_utf8(&self, path: &Path) -> Result<String> {
+        self.read(path)
+            .await
+            .map(|bytes| bytes.to_str_lossy().to_string())
+    }
+
+    fn read_batch_utf8(
+        &self,
+        batch_size: usize,
+        paths: Vec<PathBuf>,
+    ) -> impl futures::Stream<Item = (PathBuf, anyhow::Result<String>)> + Send {
+        let batches: Vec<Vec<PathBuf>> = paths
+            .chunks(batch_size)
+            .map(|chunk| chunk.to_vec())
+            .collect();
+
+        stream::iter(batches)
+            .then(move |batch| async move {
+                let futures = batch.into_iter().map(|path| async move {
+                    let result = self.read_utf8(&path).await;
+                    (path, result)
+                });
+
+                futures::future::join_all(futures).await
+            })
+            .flat_map(stream::iter)
+    }
+
+    async fn read(&self, path: &Path) -> Result<Vec<u8>> {
+        self.with_sftp(path, |sftp, path| Self::read_file(sftp, path, u64::MAX))
+            .await
+    }
+
+    async fn range_read_utf8(
+        &self,
+        path: &Path,
+        start_line: u64,
+        end_line: u64,
+    ) -> Result<(String, forge_domain::FileInfo)> {
+        let contents = self.read(path).await?;
+        ForgeFS::range_utf8(&contents, start_line, end_line)
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: SftpTransport> FileWriterInfra for SshFileSystem<T> {
+    async fn write(&self, path: &Path, contents: Bytes) -> Result<()> {
+        self.with_sftp(path, move |sftp, path| {
+            Self::write_file(sftp, path, &contents, false)
+        })
+        .await
+    }
+
+    async fn append(&self, path: &Path, contents: Bytes) -> Result<()> {
+        self.with_sftp(path, move |sftp, path| {
+            Self::write_file(sftp, path, &contents, true)
+        })
+        .await
+    }
+
+    /// Temporary files are scratch space for Forge itself, so they are kept on
+    /// the local disk
+    async fn write_temp(&self, prefix: &str, ext: &str, content: &str) -> Result<PathBuf> {
+        ForgeFileWriteService::new()
+            .write_temp(prefix, ext, content)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: SftpTransport> FileInfoInfra for SshFileSystem<T> {
+    async fn is_binary(&self, path: &Path) -> Result<bool> {
+        self.with_sftp(path, |sftp, path| {
+            let sample = Self::read_file(sftp, path, 512)?;
+            Ok(forge_fs::is_binary_content(&sample))
+        })
+        .await
+    }
+
+    async fn is_file(&self, path: &Path) -> Result<bool> {
+        self.with_sftp(path, |sftp, path| {
+            Ok(sftp.stat(path).is_ok_and(|stat| stat.is_file()))
+        })
+        .await
+    }
+
+    async fn exists(&self, path: &Path) -> Result<bool> {
+        self.with_sftp(path, |sftp, path| Ok(sftp.stat(path).is_ok()))
+            .await
+    }
+
+    async fn file_size(&self, path: &Path) -> Result<u64> {
+        self.with_sftp(path, |sftp, path| {
+            let stat = sftp
+                .stat(path)
+                .with_context(|| format!("Failed to get metadata for {}", path.display()))?;
+            Ok(stat.size.unwrap_or_default())
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: SftpTransport> FileRemoverInfra for SshFileSystem<T> {
+    async fn remove(&self, path: &Path) -> Result<()> {
+        self.with_sftp(path, |sftp, path| {
+            sftp.unlink(path)
+                .with_context(|| format!("Failed to remove file {}", path.display()))
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: SftpTransport> FileDirectoryInfra for SshFileSystem<T> {
+    async fn create_dirs(&self, path: &Path) -> Result<()> {
+        self.with_sftp(path, Self::create_dir_all).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: SftpTransport> DirectoryReaderInfra for SshFileSystem<T> {
+    async fn list_directory_entries(&self, directory: &Path) -> Result<Vec<(PathBuf, bool)>> {
+        let entries = self
+            .with_sftp(directory, |sftp, directory| {
+                if !sftp.stat(directory).is_ok_and(|stat| stat.is_dir()) {
+                    return Ok(vec![]);
+                }
+                sftp.readdir(directory)
+                    .with_context(|| format!("Failed to read directory {}", directory.display()))
+            })
+            .await?;
+
+        Ok(entries
+            .into_iter()
+            .map(|(path, stat)| (self.local_path(&path), stat.is_dir()))
+            .collect())
+    }
+
+    async fn read_directory_files(
+        &self,
+        directory: &Path,
+        pattern: Option<&str>,
+    ) -> Result<Vec<(PathBuf, String)>> {
+        let glob_pattern = pattern.map(Pattern::new).transpose()?;
+
+        let files = self
+            .list_directory_entries(directory)
+            .await?
+            .into_iter()
+            .filter(|(path, is_dir)| {
+                !is_dir
+                    && glob_pattern.as_ref().is_none_or(|pattern| {
+                        path.file_name()
+                            .is_some_and(|name| pattern.matches(&name.to_string_lossy()))
+                    })
+            })
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::new();
+        for path in files {
+            let content = self.read_utf8(&path).await?;
+            results.push((path, content));
+        }
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    use anyhow::bail;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// In-memory remote host. Directories map to `None` and files to their
+    /// contents; only `/` exists when a session is opened.
+    #[derive(Default)]
+    struct MockSftp {
+        entries: Mutex<BTreeMap<PathBuf, Option<Vec<u8>>>>,
+    }
+
+    impl MockSftp {
+        fn file_stat(entry: &Option<Vec<u8>>) -> FileStat {
+            let (perm, size) = match entry {
+                Some(contents) => (0o100644, contents.len() as u64),
+                None => (0o040755, 0),
+            };
+            FileStat {
+                size: Some(size),
+                uid: None,
+                gid: None,
+                perm: Some(perm),
+                atime: None,
+                mtime: None,
+            }
+        }
+
+        fn contents(&self, path: &str) -> Option<String> {
+            self.entries
+                .lock()
+                .unwrap()
+                .get(Path::new(path))
+                .map(|entry| String::from_utf8(entry.clone().unwrap_or_default()).unwrap())
+        }
+
+        fn is_dir(&self, path: &Path) -> bool {
+            matches!(self.entries.lock().unwrap().get(path), Some(None))
+        }
+    }
+
+    impl SftpTransport for MockSftp {
+        fn connect(_config: &SshConfig) -> Result<Self> {
+            let sftp = Self::default();
+            sftp.entries
+                .lock()
+                .unwrap()
+                .insert(PathBuf::from("/"), None);
+            Ok(sftp)
+        }
+
+        fn stat(&self, path: &Path) -> Result<FileStat> {
+            match self.entries.lock().unwrap().get(path) {
+                Some(entry) => Ok(Self::file_stat(entry)),
+                None => bail!("No such file"),
+            }
+        }
+
+        fn mkdir(&self, path: &Path) -> Result<()> {
+            if !path.parent().is_some_and(|parent| self.is_dir(parent)) {
+                bail!("No such file");
+            }
+            self.entries
+                .lock()
+                .unwrap()
+                .insert(path.to_path_buf(), None);
+            Ok(())
+        }
+
+        fn read(&self, path: &Path, limit: u64) -> Result<Vec<u8>> {
+            match self.entries.lock().unwrap().get(path) {
+                Some(Some(contents)) => Ok(contents.iter().copied().take(limit as usize).collect()),
+                _ => bail!("No such file"),
+            }
+        }
+
+        fn write(&self, path: &Path, contents: &[u8], append: bool) -> Result<()> {
+            if !path.parent().is_some_and(|parent| self.is_dir(parent)) {
+                bail!("No such file");
+            }
+            let mut entries = self.entries.lock().unwrap();
+            let file = entries
+                .entry(path.to_path_buf())
+                .or_insert_with(|| Some(Vec::new()))
+                .get_or_insert_default();
+            if !append {
+                file.clear();
+            }
+            file.extend_from_slice(contents);
+            Ok(())
+        }
+
+        fn unlink(&self, path: &Path) -> Result<()> {
+            match self.entries.lock().unwrap().remove(path) {
+                Some(Some(_)) => Ok(()),
+                _ => bail!("No such file"),
+            }
+        }
+
+        fn readdir(&self, path: &Path) -> Result<Vec<(PathBuf, FileStat)>> {
+            Ok(self
+                .entries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(entry, _)| entry.parent() == Some(path))
+                .map(|(entry, contents)| (entry.clone(), Self::file_stat(contents)))
+                .collect())
+        }
+    }
+
+    fn fixture(remote_dir: Option<&str>) -> SshFileSystem<MockSftp> {
+        let mut config = SshConfig::new("localhost", "forge");
+        config.remote_dir = remote_dir.map(PathBuf::from);
+        SshFileSystem::new(config, PathBuf::from("/home/me/project"))
+    }
+
+    #[test]
+    fn test_remote_path_without_remote_dir() {
+        let fs = fixture(None);
+        let actual = fs.remote_path(Path::new("/home/me/project/src/main.rs"));
+        let expected = PathBuf::from("/home/me/project/src/main.rs");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_remote_path_maps_workspace_into_remote_dir() {
+        let fs = fixture(Some("/srv/project"));
+        let actual = fs.remote_path(Path::new("/home/me/project/src/main.rs"));
+        let expected = PathBuf::from("/srv/project/src/main.rs");
+        assert_eq!(actual, expected);
+
+        let actual = fs.local_path(&expected);
+        let expected = PathBuf::from("/home/me/project/src/main.rs");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_is_remote_only_for_workspace_paths() {
+        let fs = fixture(Some("/srv/project"));
+        assert!(fs.is_remote(Path::new("/home/me/project/Cargo.toml")));
+        assert!(!fs.is_remote(Path::new("/home/me/.forge/.forge.toml")));
+    }
+
+    #[tokio::test]
+    async fn test_write_and_append_create_files_under_remote_dir() -> Result<()> {
+        let fs = fixture(Some("/srv/project"));
+        let path = Path::new("/home/me/project/nested/hello.txt");
+
+        fs.write(path, Bytes::from("hello\n")).await?;
+        fs.append(path, Bytes::from("world\n")).await?;
+
+        let actual = fs.sftp().await?.contents("/srv/project/nested/hello.txt");
+        let expected = Some("hello\nworld\n".to_string());
+        assert_eq!(actual, expected);
+        assert_eq!(fs.read_utf8(path).await?, "hello\nworld\n");
+        assert!(fs.is_file(path).await?);
+        assert_eq!(fs.file_size(path).await?, 12);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_truncates_existing_file() -> Result<()> {
+        let fs = fixture(None);
+        let path = Path::new("/home/me/project/notes.txt");
+
+        fs.write(path, Bytes::from("first draft")).await?;
+        fs.write(path, Bytes::from("final")).await?;
+
+        let actual = fs.read_utf8(path).await?;
+        let expected = "final";
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_directory_entries_maps_back_to_local_paths() -> Result<()> {
+        let fs = fixture(Some("/srv/project"));
+        fs.write(Path::new("/home/me/project/a.rs"), Bytes::from("a"))
+            .await?;
+        fs.create_dirs(Path::new("/home/me/project/src")).await?;
+
+        let actual = fs
+            .list_directory_entries(Path::new("/home/me/project"))
+            .await?;
+        let expected = vec![
+            (PathBuf::from("/home/me/project/a.rs"), false),
+            (PathBuf::from("/home/me/project/src"), true),
+        ];
+        assert_eq!(actual, expected);
+
+        let actual = fs
+            .list_directory_entries(Path::new("/home/me/project/missing"))
+            .await?;
+        assert_eq!(actual, vec![]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_directory_files_filters_by_pattern() -> Result<()> {
+        let fs = fixture(Some("/srv/project"));
+        fs.write(Path::new("/home/me/project/a.md"), Bytes::from("# A"))
+            .await?;
+        fs.write(Path::new("/home/me/project/b.rs"), Bytes::from("fn b() {}"))
+            .await?;
+
+        let actual = fs
+            .read_directory_files(Path::new("/home/me/project"), Some("*.md"))
+            .await?;
+        let expected = vec![(PathBuf::from("/home/me/project/a.md"), "# A".to_string())];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_remove_deletes_remote_file() -> Result<()> {
+        let fs = fixture(Some("/srv/project"));
+        let path = Path::new("/home/me/project/old.txt");
+        fs.write(path, Bytes::from("stale")).await?;
+
+        fs.remove(path).await?;
+
+        assert!(!fs.exists(path).await?);
+        assert!(fs.remove(path).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_missing_file_reports_remote_path() {
+        let fs = fixture(Some("/srv/project"));
+
+        let actual = fs
+            .read(Path::new("/home/me/project/missing.txt"))
+            .await
+            .unwrap_err()
+            .to_string();
+
+        let expected = "Failed to read file /srv/project/missing.txt";
+        assert_eq!(actual, expected);
+    }
+
+    /// known_hosts entry for `localhost` holding a real ed25519 key
+    const KNOWN_HOST: &str = "localhost ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOMqqnkVzrm0SdG6UOoqKLsabgH5C9okWi0dh2l9GKJl";
+
+    fn known_hosts() -> KnownHosts {
+        let mut known_hosts = Session::new().unwrap().known_hosts().unwrap();
+        known_hosts
+            .read_str(KNOWN_HOST, KnownHostFileKind::OpenSSH)
+            .unwrap();
+        known_hosts
+    }
+
+    #[test]
+    fn test_check_host_key_rejects_mismatched_key() {
+        let config = SshConfig::new("localhost", "forge");
+
+        let actual = check_host_key(&known_hosts(), &config, b"attacker key")
+            .unwrap_err()
+            .to_string();
+
+        assert!(actual.contains("does not match"), "{actual}");
+    }
+
+    #[test]
+    fn test_check_host_key_accepts_recorded_key() {
+        use base64::Engine;
+        let config = SshConfig::new("localhost", "forge");
+        let key = base64::engine::general_purpose::STANDARD
+            .decode(KNOWN_HOST.rsplit(' ').next().unwrap())
+            .unwrap();
+
+        let actual = check_host_key(&known_hosts(), &config, &key).unwrap();
+
+        assert!(!actual);
+    }
+
+    #[test]
+    fn test_check_host_key_unknown_host_requires_opt_in() {
+        let config = SshConfig::new("dev.example.com", "forge");
+        assert!(check_host_key(&known_hosts(), &config, b"key").is_err());
+
+        let config = config.accept_unknown_host(true);
+        let actual = check_host_key(&known_hosts(), &config, b"key").unwrap();
+        assert!(actual);
+    }
+
+    /// Runs against a real SSH server, e.g. a local `openssh-server`:
+    ///
+    /// ```sh
+    /// FORGE_TEST_SSH_HOST=localhost FORGE_TEST_SSH_USER=$USER \
+    ///   FORGE_TEST_SSH_KEY=~/.ssh/id_ed25519 \
+    ///   cargo test -p forge_infra test_sftp_round_trip -- --ignored
+    /// ```
+    #[tokio::test]
+    #[ignore = "requires an SSH server"]
+    async fn test_sftp_round_trip() -> Result<()> {
+        let env = |key: &str| std::env::var(key).unwrap();
+        let mut config = SshConfig::new(env("FORGE_TEST_SSH_HOST"), env("FORGE_TEST_SSH_USER"));
+        config.identity_file = std::env::var("FORGE_TEST_SSH_KEY").ok().map(PathBuf::from);
+        let root = tempfile::tempdir()?;
+        let fs = SshFileSystem::<Sftp>::new(config, root.path().to_path_buf());
+        let path = root.path().join("nested/hello.txt");
+
+        fs.write(&path, Bytes::from("hello\n")).await?;
+        fs.append(&path, Bytes::from("world\n")).await?;
+
+        let actual = fs.read_utf8(&path).await?;
+        assert_eq!(actual, "hello\nworld\n");
+        assert!(fs.is_file(&path).await?);
+        assert_eq!(fs.file_size(&path).await?, 12);
+
+        let actual = fs
+            .list_directory_entries(&root.path().join("nested"))
+            .await?;
+        assert_eq!(actual, vec![(path.clone(), false)]);
+
+        fs.remove(&path).await?;
+        assert!(!fs.exists(&path).await?);
+        Ok(())
+    }
+}
@@ -5,14 +5,20 @@ use std::time::Duration;
 
 use anyhow::Context;
 use bytes::Bytes;
+use dashmap::DashMap;
+use dashmap::mapref::entry::Entry;
 use forge_app::HttpInfra;
-use forge_config::{ForgeConfig, TlsBackend, TlsVersion};
+use forge_config::{CircuitBreakerConfig, ForgeConfig, TlsBackend, TlsVersion};
 use forge_eventsource::{EventSource, RequestBuilderExt};
 use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue};
 use reqwest::redirect::Policy;
 use reqwest::{Certificate, Client, Response, StatusCode, Url};
+use tokio::sync::broadcast;
+use tokio::time::Instant;
 use tracing::{debug, warn};
 
+use crate::error::Error;
+
 const VERSION: &str = match option_env!("APP_VERSION") {
     None => env!("CARGO_PKG_VERSION"),
     Some(v) => v,
@@ -307,6 +313,403 @@ impl<F: forge_app::FileWriterInfra + 'static> HttpInfra for ForgeHttpInfra<F> {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CircuitState {
+    /// Requests flow normally while failures are counted
+    Closed {
+        failures: u32,
+        window_start: Instant,
+    },
+    /// Requests are rejected until `until`
+    Open { until: Instant },
+    /// A single trial request is in flight; others are rejected
+    HalfOpen,
+}
+
+/// Classic three-state circuit breaker guarding requests to one host.
+///
+/// After `failure_threshold` consecutive failures within `window` the circuit
+/// opens and requests fail immediately with [`Error::CircuitOpen`]. Once
+/// `recovery_timeout` has elapsed a single trial request is let through; its
+/// success closes the circuit again while its failure reopens it.
+pub struct CircuitBreaker {
+    name: String,
+    failure_threshold: u32,
+    window: Duration,
+    recovery_timeout: Duration,
+    state: std::sync::Mutex<CircuitState>,
+}
+
+impl CircuitBreaker {
+    pub fn new(name: impl Into<String>, config: &CircuitBreakerConfig) -> Self {
+        Self {
+            name: name.into(),
+            failure_threshold: config.failure_threshold.max(1),
+            window: Duration::from_secs(config.window_secs),
+            recovery_timeout: Duration::from_secs(config.recovery_timeout_secs),
+            state: std::sync::Mutex::new(CircuitState::Closed {
+                failures: 0,
+                window_start: Instant::now(),
+            }),
+        }
+    }
+
+    /// Checks whether a request may be sent, moving an open circuit to
+    /// half-open once the recovery timeout has elapsed. The outcome of the
+    /// request must be recorded on the returned [`CircuitPermit`].
+    pub fn acquire(self: &Arc<Self>) -> Result<CircuitPermit, Error> {
+        let mut state = self.state.lock().unwrap();
+        match *state {
+            CircuitState::Closed { .. } => {
+                Ok(CircuitPermit { breaker: self.clone(), trial: false })
+            }
+            CircuitState::Open { until } if Instant::now() >= until => {
+                *state = CircuitState::HalfOpen;
+                Ok(CircuitPermit { breaker: self.clone(), trial: true })
+            }
+            CircuitState::Open { .. } | CircuitState::HalfOpen => {
+                Err(Error::CircuitOpen(self.name.clone()))
+            }
+        }
+    }
+
+    /// Records a successful request, closing the circuit
+    pub fn record_success(&self) {
+        *self.state.lock().unwrap() =
+            CircuitState::Closed { failures: 0, window_start: Instant::now() };
+    }
+
+    /// Records a failed request, opening the circuit once the failure
+    /// threshold is reached
+    pub fn record_failure(&self) {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        let failures = match *state {
+            CircuitState::Closed { failures, window_start }
+                if now.duration_since(window_start) <= self.window =>
+            {
+                *state = CircuitState::Closed { failures: failures + 1, window_start };
+                failures + 1
+            }
+            CircuitState::Closed { .. } => {
+                *state = CircuitState::Closed { failures: 1, window_start: now };
+                1
+            }
+            // The trial request failed, so the server hasn't recovered yet
+            CircuitState::HalfOpen | CircuitState::Open { .. } => self.failure_threshold,
+        };
+        if failures >= self.failure_threshold {
+            *state = CircuitState::Open { until: now + self.recovery_timeout };
+        }
+    }
+
+    /// Reopens a half-open circuit whose trial request never finished, so the
+    /// next request becomes the trial instead of being rejected forever
+    fn abandon_trial(&self) {
+        let mut state = self.state.lock().unwrap();
+        if *state == CircuitState::HalfOpen {
+            *state = CircuitState::Open { until: Instant::now() };
+        }
+    }
+}
+
+/// Permission to send one request through a [`CircuitBreaker`].
+///
+/// Dropping the permit of a trial request without recording its outcome,
+/// e.g. because the request future was cancelled, reopens the circuit.
+#[must_use]
+pub struct CircuitPermit {
+    breaker: Arc<CircuitBreaker>,
+    trial: bool,
+}
+
+impl CircuitPermit {
+    /// Records a successful request, closing the circuit
+    pub fn success(mut self) {
+        self.trial = false;
+        self.breaker.record_success();
+    }
+
+    /// Records a failed request, opening the circuit once the failure
+    /// threshold is reached
+    pub fn failure(mut self) {
+        self.trial = false;
+        self.breaker.record_failure();
+    }
+}
+
+impl Drop for CircuitPermit {
+    fn drop(&mut self) {
+        if self.trial {
+            self.breaker.abandon_trial();
+        }
+    }
+}
+
+/// Returns true if the error means the server is unhealthy: it could not be
+/// reached, or it answered with a 5xx status. Client errors such as 4xx
+/// responses say nothing about the server's health.
+fn is_server_failure(error: &anyhow::Error) -> bool {
+    match error.downcast_ref::<forge_app::dto::openai::Error>() {
+        Some(forge_app::dto::openai::Error::InvalidStatusCode(status)) => *status >= 500,
+        _ => error.chain().any(|cause| cause.is::<reqwest::Error>()),
+    }
+}
+
+/// Same as [`is_server_failure`] for the errors an [`EventSource`] reports
+/// while connecting
+fn is_eventsource_server_failure(error: &forge_eventsource::Error) -> bool {
+    match error {
+        forge_eventsource::Error::InvalidStatusCode(status, _) => status.is_server_error(),
+        forge_eventsource::Error::Transport(_) => true,
+        _ => false,
+    }
+}
+
+/// Wraps an [`HttpInfra`] with a [`CircuitBreaker`] per host, so a provider
+/// that keeps failing is rejected immediately instead of being hammered with
+/// retries, while other providers are unaffected. Event streams count towards
+/// the same breaker as plain requests. Passes every request through unchanged
+/// when no [`CircuitBreakerConfig`] is set.
+pub struct CircuitBreakerHttpClient<H> {
+    inner: H,
+    config: Option<CircuitBreakerConfig>,
+    breakers: DashMap<String, Arc<CircuitBreaker>>,
+}
+
+impl<H: HttpInfra> CircuitBreakerHttpClient<H> {
+    pub fn new(inner: H, config: Option<CircuitBreakerConfig>) -> Self {
+        Self { inner, config, breakers: DashMap::new() }
+    }
+
+    fn breaker(&self, url: &Url) -> Option<Arc<CircuitBreaker>> {
+        let config = self.config.as_ref()?;
+        let host = url.host_str().unwrap_or_default().to_string();
+        Some(
+            self.breakers
+                .entry(host.clone())
+                .or_insert_with(|| Arc::new(CircuitBreaker::new(host, config)))
+                .clone(),
+        )
+    }
+
+    async fn guarded<T, Fut>(&self, url: &Url, request: Fut) -> anyhow::Result<T>
+    where
+        Fut: Future<Output = anyhow::Result<T>>,
+    {
+        let Some(breaker) = self.breaker(url) else {
+            return request.await;
+        };
+        let permit = breaker.acquire()?;
+        let result = request.await;
+        match &result {
+            Err(error) if is_server_failure(error) => permit.failure(),
+            _ => permit.success(),
+        }
+        result
+    }
+}
+
+#[async_trait::async_trait]
+impl<H: HttpInfra> HttpInfra for CircuitBreakerHttpClient<H> {
+    async fn http_get(&self, url: &Url, headers: Option<HeaderMap>) -> anyhow::Result<Response> {
+        self.guarded(url, self.inner.http_get(url, headers)).await
+    }
+
+    async fn http_post(
+        &self,
+        url: &Url,
+        headers: Option<HeaderMap>,
+        body: Bytes,
+    ) -> anyhow::Result<Response> {
+        self.guarded(url, self.inner.http_post(url, headers, body))
+            .await
+    }
+
+    async fn http_delete(&self, url: &Url) -> anyhow::Result<Response> {
+        self.guarded(url, self.inner.http_delete(url)).await
+    }
+
+    async fn http_eventsource(
+        &self,
+        url: &Url,
+        headers: Option<HeaderMap>,
+        body: Bytes,
+    ) -> anyhow::Result<EventSource> {
+        let Some(breaker) = self.breaker(url) else {
+            return self.inner.http_eventsource(url, headers, body).await;
+        };
+        let mut permit = Some(breaker.acquire()?);
+        let mut source = self.inner.http_eventsource(url, headers, body).await?;
+        // The stream connects lazily, so the outcome is recorded once it opens
+        // or fails to. Reconnects are recorded directly on the breaker since
+        // the permit only covers the first attempt.
+        source.set_connect_observer(Box::new(move |outcome| {
+            let failed = outcome.is_err_and(is_eventsource_server_failure);
+            match (permit.take(), failed) {
+                (Some(permit), true) => permit.failure(),
+                (Some(permit), false) => permit.success(),
+                (None, true) => breaker.record_failure(),
+                (None, false) => breaker.record_success(),
+            }
+        }));
+        Ok(source)
+    }
+}
+
+/// Identifies GET requests that can share a single response
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct RequestKey {
+    url: String,
+    headers: Vec<(String, Vec<u8>)>,
+}
+
+impl RequestKey {
+    fn new(url: &Url, headers: Option<&HeaderMap>) -> Self {
+        let mut headers: Vec<_> = headers
+            .into_iter()
+            .flatten()
+            .map(|(name, value)| (name.as_str().to_string(), value.as_bytes().to_vec()))
+            .collect();
+        headers.sort();
+        Self { url: url.to_string(), headers }
+    }
+}
+
+/// A fully read response that can be handed to every coalesced caller
+#[derive(Clone)]
+struct SharedResponse {
+    status: StatusCode,
+    version: reqwest::Version,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl SharedResponse {
+    async fn read(response: Response) -> anyhow::Result<Self> {
+        Ok(Self {
+            status: response.status(),
+            version: response.version(),
+            headers: response.headers().clone(),
+            body: response.bytes().await?,
+        })
+    }
+
+    fn to_response(&self) -> Response {
+        let mut response = http::Response::new(self.body.clone());
+        *response.status_mut() = self.status;
+        *response.version_mut() = self.version;
+        *response.headers_mut() = self.headers.clone();
+        Response::from(response)
+    }
+}
+
+type InFlight = DashMap<RequestKey, Arc<broadcast::Sender<SharedResponse>>>;
+
+/// Removes the in-flight entry when the leading request finishes or is
+/// cancelled, so waiting callers are never left hanging
+struct InFlightGuard<'a> {
+    in_flight: &'a InFlight,
+    key: RequestKey,
+}
+
+impl InFlightGuard<'_> {
+    /// Ends the in-flight request and returns the channel to notify waiting
+    /// callers on
+    fn finish(self) -> Option<Arc<broadcast::Sender<SharedResponse>>> {
+        self.in_flight.remove(&self.key).map(|(_, sender)| sender)
+    }
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.in_flight.remove(&self.key);
+    }
+}
+
+/// Wraps an [`HttpInfra`] and coalesces concurrent identical GET requests
+/// into a single underlying request.
+///
+/// The first caller performs the request and broadcasts the response to every
+/// caller that asked for the same URL with the same headers while it was in
+/// flight. If the leading request fails, waiting callers issue their own
+/// request so each of them observes the original error. POST, DELETE and
+/// streaming requests are never coalesced.
+pub struct DeduplicatingHttpClient<H> {
+    inner: H,
+    in_flight: InFlight,
+}
+
+impl<H: HttpInfra> DeduplicatingHttpClient<H> {
+    pub fn new(inner: H) -> Self {
+        Self { inner, in_flight: DashMap::new() }
+    }
+
+    async fn get(&self, url: &Url, headers: Option<HeaderMap>) -> anyhow::Result<Response> {
+        let key = RequestKey::new(url, headers.as_ref());
+
+        // Subscribing while holding the entry guarantees the broadcast can't be
+        // missed: the leader removes the entry before sending
+        let mut receiver = match self.in_flight.entry(key.clone()) {
+            Entry::Occupied(entry) => Some(entry.get().subscribe()),
+            Entry::Vacant(entry) => {
+                entry.insert(Arc::new(broadcast::channel(1).0));
+                None
+            }
+        };
+
+        if let Some(receiver) = receiver.as_mut() {
+            return match receiver.recv().await {
+                Ok(shared) => Ok(shared.to_response()),
+                Err(_) => self.inner.http_get(url, headers).await,
+            };
+        }
+
+        let guard = InFlightGuard { in_flight: &self.in_flight, key };
+        let result = match self.inner.http_get(url, headers).await {
+            Ok(response) => SharedResponse::read(response).await,
+            Err(error) => Err(error),
+        };
+
+        let sender = guard.finish();
+        let shared = result?;
+        if let Some(sender) = sender {
+            // No receivers simply means nobody else asked for this request
+            let _ = sender.send(shared.clone());
+        }
+        Ok(shared.to_response())
+    }
+}
+
+#[async_trait::async_trait]
+impl<H: HttpInfra> HttpInfra for DeduplicatingHttpClient<H> {
+    async fn http_get(&self, url: &Url, headers: Option<HeaderMap>) -> anyhow::Result<Response> {
+        self.get(url, headers).await
+    }
+
+    async fn http_post(
+        &self,
+        url: &Url,
+        headers: Option<HeaderMap>,
+        body: Bytes,
+    ) -> anyhow::Result<Response> {
+        self.inner.http_post(url, headers, body).await
+    }
+
+    async fn http_delete(&self, url: &Url) -> anyhow::Result<Response> {
+        self.inner.http_delete(url).await
+    }
+
+    async fn http_eventsource(
+        &self,
+        url: &Url,
+        headers: Option<HeaderMap>,
+        body: Bytes,
+    ) -> anyhow::Result<EventSource> {
+        self.inner.http_eventsource(url, headers, body).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -315,6 +718,7 @@ mod tests {
     use fake::{Fake, Faker};
     use forge_app::FileWriterInfra;
     use forge_config::ForgeConfig;
+    use futures::StreamExt;
     use tokio::sync::Mutex;
 
     use super::*;
@@ -552,4 +956,317 @@ mod tests {
             Some(&HeaderValue::from_static("application/json"))
         );
     }
+
+    struct CountingHttp {
+        requests: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl HttpInfra for CountingHttp {
+        async fn http_get(
+            &self,
+            _url: &Url,
+            _headers: Option<HeaderMap>,
+        ) -> anyhow::Result<Response> {
+            self.requests
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok(Response::from(http::Response::new(Bytes::from("models"))))
+        }
+
+        async fn http_post(
+            &self,
+            _url: &Url,
+            _headers: Option<HeaderMap>,
+            _body: Bytes,
+        ) -> anyhow::Result<Response> {
+            self.requests
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(Response::from(http::Response::new(Bytes::new())))
+        }
+
+        async fn http_delete(&self, _url: &Url) -> anyhow::Result<Response> {
+            unimplemented!()
+        }
+
+        async fn http_eventsource(
+            &self,
+            _url: &Url,
+            _headers: Option<HeaderMap>,
+            _body: Bytes,
+        ) -> anyhow::Result<EventSource> {
+            unimplemented!()
+        }
+    }
+
+    fn dedup_fixture() -> DeduplicatingHttpClient<CountingHttp> {
+        DeduplicatingHttpClient::new(CountingHttp { requests: Default::default() })
+    }
+
+    #[tokio::test]
+    async fn test_deduplicating_client_coalesces_concurrent_gets() {
+        let fixture = dedup_fixture();
+        let url = Url::parse("https://api.test.com/models").unwrap();
+
+        let responses = futures::future::join_all(
+            (0..5).map(|_| async { fixture.http_get(&url, None).await.unwrap().text().await }),
+        )
+        .await;
+
+        let actual = fixture
+            .inner
+            .requests
+            .load(std::sync::atomic::Ordering::SeqCst);
+        assert_eq!(actual, 1);
+        for response in responses {
+            assert_eq!(response.unwrap(), "models");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deduplicating_client_never_coalesces_posts() {
+        let fixture = dedup_fixture();
+        let url = Url::parse("https://api.test.com/messages").unwrap();
+
+        futures::future::join_all((0..5).map(|_| fixture.http_post(&url, None, Bytes::from("{}"))))
+            .await;
+
+        let actual = fixture
+            .inner
+            .requests
+            .load(std::sync::atomic::Ordering::SeqCst);
+        assert_eq!(actual, 5);
+    }
+
+    /// Answers every request with the configured status code
+    struct StatusHttp {
+        status: std::sync::atomic::AtomicU16,
+        requests: std::sync::atomic::AtomicUsize,
+    }
+
+    impl StatusHttp {
+        fn respond(&self) -> anyhow::Result<Response> {
+            self.requests
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let status = self.status.load(std::sync::atomic::Ordering::SeqCst);
+            if status >= 400 {
+                return Err(forge_app::dto::openai::Error::InvalidStatusCode(status).into());
+            }
+            Ok(Response::from(http::Response::new(Bytes::new())))
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl HttpInfra for StatusHttp {
+        async fn http_get(
+            &self,
+            _url: &Url,
+            _headers: Option<HeaderMap>,
+        ) -> anyhow::Result<Response> {
+            self.respond()
+        }
+
+        async fn http_post(
+            &self,
+            _url: &Url,
+            _headers: Option<HeaderMap>,
+            _body: Bytes,
+        ) -> anyhow::Result<Response> {
+            self.respond()
+        }
+
+        async fn http_delete(&self, _url: &Url) -> anyhow::Result<Response> {
+            self.respond()
+        }
+
+        async fn http_eventsource(
+            &self,
+            url: &Url,
+            _headers: Option<HeaderMap>,
+            _body: Bytes,
+        ) -> anyhow::Result<EventSource> {
+            self.requests
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(Client::new().post(url.clone()).eventsource()?)
+        }
+    }
+
+    fn breaker_fixture(status: u16) -> CircuitBreakerHttpClient<StatusHttp> {
+        CircuitBreakerHttpClient::new(
+            StatusHttp { status: status.into(), requests: Default::default() },
+            Some(CircuitBreakerConfig {
+                failure_threshold: 5,
+                window_secs: 60,
+                recovery_timeout_secs: 30,
+            }),
+        )
+    }
+
+    fn requests(fixture: &CircuitBreakerHttpClient<StatusHttp>) -> usize {
+        fixture
+            .inner
+            .requests
+            .load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_circuit_breaker_opens_after_threshold() {
+        let fixture = breaker_fixture(503);
+        let url = Url::parse("https://api.test.com/messages").unwrap();
+
+        for _ in 0..5 {
+            let _ = fixture.http_post(&url, None, Bytes::new()).await;
+        }
+        let actual = fixture
+            .http_post(&url, None, Bytes::new())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            actual.downcast_ref::<Error>(),
+            Some(Error::CircuitOpen(host)) if host == "api.test.com"
+        ));
+        assert_eq!(requests(&fixture), 5);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_circuit_breaker_closes_after_successful_trial() {
+        let fixture = breaker_fixture(503);
+        let url = Url::parse("https://api.test.com/messages").unwrap();
+        for _ in 0..5 {
+            let _ = fixture.http_post(&url, None, Bytes::new()).await;
+        }
+
+        tokio::time::advance(Duration::from_secs(31)).await;
+        fixture
+            .inner
+            .status
+            .store(200, std::sync::atomic::Ordering::SeqCst);
+        fixture.http_post(&url, None, Bytes::new()).await.unwrap();
+        fixture.http_post(&url, None, Bytes::new()).await.unwrap();
+
+        assert_eq!(requests(&fixture), 7);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_circuit_breaker_reopens_after_failed_trial() {
+        let fixture = breaker_fixture(503);
+        let url = Url::parse("https://api.test.com/messages").unwrap();
+        for _ in 0..5 {
+            let _ = fixture.http_post(&url, None, Bytes::new()).await;
+        }
+
+        tokio::time::advance(Duration::from_secs(31)).await;
+        let _ = fixture.http_post(&url, None, Bytes::new()).await;
+        let actual = fixture.http_post(&url, None, Bytes::new()).await;
+
+        assert!(actual.is_err());
+        assert_eq!(requests(&fixture), 6);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_circuit_breaker_ignores_client_errors() {
+        let fixture = breaker_fixture(404);
+        let url = Url::parse("https://api.test.com/models").unwrap();
+
+        for _ in 0..10 {
+            let _ = fixture.http_get(&url, None).await;
+        }
+
+        assert_eq!(requests(&fixture), 10);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_circuit_breaker_resets_failures_outside_window() {
+        let fixture = breaker_fixture(503);
+        let url = Url::parse("https://api.test.com/messages").unwrap();
+
+        for _ in 0..4 {
+            let _ = fixture.http_post(&url, None, Bytes::new()).await;
+        }
+        tokio::time::advance(Duration::from_secs(61)).await;
+        for _ in 0..4 {
+            let _ = fixture.http_post(&url, None, Bytes::new()).await;
+        }
+
+        assert_eq!(requests(&fixture), 8);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_circuit_breaker_unopened_eventsource_releases_trial() {
+        let fixture = breaker_fixture(503);
+        let url = Url::parse("https://api.test.com/messages").unwrap();
+        for _ in 0..5 {
+            let _ = fixture.http_post(&url, None, Bytes::new()).await;
+        }
+
+        tokio::time::advance(Duration::from_secs(31)).await;
+        fixture
+            .http_eventsource(&url, None, Bytes::new())
+            .await
+            .unwrap();
+        fixture
+            .inner
+            .status
+            .store(200, std::sync::atomic::Ordering::SeqCst);
+        fixture.http_post(&url, None, Bytes::new()).await.unwrap();
+        fixture.http_post(&url, None, Bytes::new()).await.unwrap();
+
+        assert_eq!(requests(&fixture), 8);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_records_eventsource_failures() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/messages")
+            .with_status(503)
+            .expect(5)
+            .create_async()
+            .await;
+        let fixture = breaker_fixture(200);
+        let url = Url::parse(&format!("{}/messages", server.url())).unwrap();
+
+        for _ in 0..5 {
+            let mut source = fixture
+                .http_eventsource(&url, None, Bytes::new())
+                .await
+                .unwrap();
+            let event = source.next().await.unwrap();
+            assert!(event.is_err());
+        }
+        let actual = fixture
+            .http_eventsource(&url, None, Bytes::new())
+            .await
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            actual.downcast_ref::<Error>(),
+            Some(Error::CircuitOpen(host)) if host == "127.0.0.1"
+        ));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_circuit_breaker_cancelled_trial_allows_next_request() {
+        let fixture = breaker_fixture(503);
+        let url = Url::parse("https://api.test.com/messages").unwrap();
+        for _ in 0..5 {
+            let _ = fixture.http_post(&url, None, Bytes::new()).await;
+        }
+
+        tokio::time::advance(Duration::from_secs(31)).await;
+        let trial = fixture.guarded(&url, std::future::pending::<anyhow::Result<()>>());
+        let cancelled = tokio::time::timeout(Duration::from_secs(1), trial).await;
+        fixture
+            .inner
+            .status
+            .store(200, std::sync::atomic::Ordering::SeqCst);
+        let actual = fixture.http_post(&url, None, Bytes::new()).await;
+
+        assert!(cancelled.is_err());
+        assert!(actual.is_ok());
+        assert_eq!(requests(&fixture), 6);
+    }
 }
@@ -9,6 +9,8 @@ mod fs_meta;
 mod fs_read;
 mod fs_read_dir;
 mod fs_remove;
+mod fs_s3;
+mod fs_ssh;
 mod fs_write;
 mod grpc;
 mod http;
@@ -22,6 +24,8 @@ pub use console::StdConsoleWriter;
 pub use env::ForgeEnvironmentInfra;
 pub use executor::ForgeCommandExecutorService;
 pub use forge_infra::*;
+pub use fs_s3::S3FileSystem;
+pub use fs_ssh::SshFileSystem;
 pub use http::sanitize_headers;
 pub use kv_storage::CacacheStorage;
 pub use mcp_client::*;
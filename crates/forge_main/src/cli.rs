@@ -7,6 +7,7 @@
 
 use std::path::PathBuf;
 
+use anyhow::Context;
 use clap::{Parser, Subcommand, ValueEnum};
 use forge_domain::{AgentId, ConversationId, Effort, ModelId, ProviderId};
 
@@ -58,6 +59,17 @@ pub struct Cli {
     #[arg(long, alias = "aid")]
     pub agent: Option<AgentId>,
 
+    /// Path to a config file to use instead of the global `.forge.toml`.
+    #[arg(long = "config", value_name = "PATH")]
+    pub workflow: Option<PathBuf>,
+
+    /// Named configuration profile to use for this session.
+    ///
+    /// Reads config and credentials from `~/.forge/profiles/<name>/` instead
+    /// of the global config directory.
+    #[arg(long)]
+    pub profile: Option<String>,
+
     /// Top-level subcommands.
     #[command(subcommand)]
     pub subcommands: Option<TopLevelCommand>,
@@ -65,6 +77,28 @@ pub struct Cli {
     /// Event to dispatch to the workflow in JSON format.
     #[arg(long, short = 'e')]
     pub event: Option<String>,
+
+    /// Maximum number of agent turns allowed for each prompt.
+    ///
+    /// When the limit is reached the agent is interrupted and forge exits
+    /// without asking whether to continue. Useful in CI and scripts to stop
+    /// runaway agents.
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_turns: Option<u64>,
+
+    /// Pin forge to the given version, skipping the automatic update check.
+    ///
+    /// Overrides `updates.pin_version` from the config for this session.
+    /// `forge update` still upgrades when run explicitly.
+    #[arg(long, value_name = "VERSION")]
+    pub pin_version: Option<String>,
+
+    /// Also write structured JSON logs to this file, one event per line.
+    ///
+    /// Intended for log aggregation systems; the regular logs are still
+    /// written as usual.
+    #[arg(long, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
 }
 
 impl Cli {
@@ -155,6 +189,62 @@ pub enum TopLevelCommand {
 
     /// Interactive fuzzy item picker.
     Select(SelectCommandGroup),
+
+    /// Manage named configuration profiles.
+    Profile(ProfileCommandGroup),
+
+    /// Queue files to be attached to the next message.
+    Context(ContextCommandGroup),
+
+    /// Undo the file modifications made during the last agent turn.
+    Rollback {
+        /// Conversation to roll back; defaults to the most recent one.
+        #[arg(long, alias = "cid")]
+        conversation_id: Option<ConversationId>,
+
+        /// List the files that would be restored without restoring them.
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+/// Command group for named configuration profiles.
+#[derive(Parser, Debug, Clone)]
+pub struct ProfileCommandGroup {
+    #[command(subcommand)]
+    pub command: ProfileCommand,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ProfileCommand {
+    /// List available profiles.
+    List,
+
+    /// Create a new empty profile.
+    Create {
+        /// Name of the profile to create.
+        name: String,
+    },
+}
+
+/// Command group for context queued for the next message.
+#[derive(Parser, Debug, Clone)]
+pub struct ContextCommandGroup {
+    #[command(subcommand)]
+    pub command: ContextCommand,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ContextCommand {
+    /// Attach files to the next message.
+    Add {
+        /// Files to attach.
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+    },
+
+    /// Remove all pending files.
+    Clear,
 }
 
 /// Command group for the `forge select` interactive picker.
@@ -303,6 +393,17 @@ pub enum AgentCommand {
     /// List available agents.
     #[command(alias = "ls")]
     List,
+
+    /// Show details about an agent.
+    Inspect {
+        /// ID of the agent to inspect.
+        id: AgentId,
+
+        /// Print the fully rendered system prompt the agent starts a
+        /// conversation with.
+        #[arg(long)]
+        system_prompt: bool,
+    },
 }
 
 /// Command group for workspace management.
@@ -361,6 +462,11 @@ pub enum WorkspaceCommand {
         /// Filter results to files ending with this suffix.
         #[arg(long)]
         ends_with: Option<String>,
+
+        /// Exclude results from paths matching this glob pattern. Can be
+        /// repeated.
+        #[arg(long = "exclude")]
+        exclude: Vec<String>,
     },
 
     /// Show workspace information for an indexed directory.
@@ -609,6 +715,25 @@ impl From<Scope> for forge_domain::Scope {
     }
 }
 
+/// Format enforced on generated commit messages.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+#[clap(rename_all = "lower")]
+pub enum CommitScope {
+    /// No format is enforced.
+    Free,
+    /// Messages must follow the Conventional Commits specification.
+    Conventional,
+}
+
+impl From<CommitScope> for forge_config::CommitFormat {
+    fn from(value: CommitScope) -> Self {
+        match value {
+            CommitScope::Free => forge_config::CommitFormat::Free,
+            CommitScope::Conventional => forge_config::CommitFormat::Conventional,
+        }
+    }
+}
+
 /// Transport protocol for communication.
 #[derive(Copy, Clone, Debug, ValueEnum)]
 #[clap(rename_all = "lower")]
@@ -646,6 +771,9 @@ pub enum ConfigCommand {
 
     /// Migrate the legacy ~/forge directory to ~/.forge.
     Migrate,
+
+    /// Check the configuration for errors without running anything.
+    Validate,
 }
 
 /// Arguments for `forge config set`.
@@ -691,6 +819,38 @@ pub enum ConfigSetField {
         /// Effort level: none, minimal, low, medium, high, xhigh, max.
         effort: Effort,
     },
+    /// Select which stored credential is used for a provider.
+    ProviderAlias {
+        /// Assignment in the form PROVIDER=ALIAS (e.g. openai=work). Leave
+        /// the alias empty to use the provider's unaliased credential.
+        assignment: ProviderAlias,
+    },
+}
+
+/// A `PROVIDER=ALIAS` assignment selecting a provider's credential.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderAlias {
+    pub provider: ProviderId,
+    /// `None` selects the provider's unaliased credential.
+    pub alias: Option<String>,
+}
+
+impl std::str::FromStr for ProviderAlias {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (provider, alias) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected PROVIDER=ALIAS, found '{s}'"))?;
+        let provider = provider.trim();
+        if provider.is_empty() {
+            return Err(format!("missing provider in '{s}'"));
+        }
+        let alias = Some(alias.trim())
+            .filter(|alias| !alias.is_empty())
+            .map(str::to_string);
+        Ok(Self { provider: ProviderId::from(provider.to_string()), alias })
+    }
 }
 
 /// Type-safe subcommands for `forge config get`.
@@ -726,14 +886,18 @@ pub enum ConversationCommand {
     /// Create a new conversation.
     New,
 
-    /// Export conversation as JSON or HTML.
+    /// Export conversation as JSON, HTML or PDF.
     Dump {
         /// Conversation ID to export.
         id: ConversationId,
 
         /// Export as HTML instead of JSON.
-        #[arg(long)]
+        #[arg(long, conflicts_with = "pdf")]
         html: bool,
+
+        /// Export as PDF using a headless Chromium or wkhtmltopdf.
+        #[arg(long)]
+        pdf: bool,
     },
 
     /// Compact conversation to reduce token usage.
@@ -825,6 +989,11 @@ pub enum ProviderCommand {
     Login {
         /// Provider name to authenticate with.
         provider: Option<ProviderId>,
+
+        /// Store the credential under this alias and make it the active one,
+        /// keeping other accounts for the same provider.
+        #[arg(long)]
+        alias: Option<String>,
     },
 
     /// Remove provider credentials.
@@ -859,6 +1028,21 @@ pub struct CommitCommandGroup {
     #[arg(long = "max-diff", default_value = "100000", value_parser = clap::builder::RangedI64ValueParser::<usize>::new().range(5000..))]
     pub max_diff_size: Option<usize>,
 
+    /// Format the generated commit message must follow
+    ///
+    /// Overrides `commit_format` from the configuration. With `conventional`
+    /// the command fails when the generated message does not follow the
+    /// Conventional Commits format.
+    #[arg(long)]
+    pub scope: Option<CommitScope>,
+
+    /// GPG-sign the commit
+    ///
+    /// Passes `-S` to `git commit`. Requires a signing key configured via
+    /// `git config user.signingkey`.
+    #[arg(long)]
+    pub sign: bool,
+
     /// Git diff content (used internally for piped input)
     ///
     /// This field is populated when diff content is piped to the commit
@@ -877,14 +1061,18 @@ pub struct CommitCommandGroup {
 
 /// Group of Data-related commands
 #[derive(Parser, Debug, Clone)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct DataCommandGroup {
+    #[command(subcommand)]
+    pub command: Option<DataCommand>,
+
     /// Path to JSONL file to process
-    #[arg(long)]
-    pub input: String,
+    #[arg(long, required = true)]
+    pub input: Option<String>,
 
     /// Path to JSON schema file for LLM tool definition
-    #[arg(long)]
-    pub schema: String,
+    #[arg(long, required = true)]
+    pub schema: Option<String>,
 
     /// Path to Handlebars template file for system prompt
     #[arg(long)]
@@ -899,15 +1087,36 @@ pub struct DataCommandGroup {
     pub concurrency: usize,
 }
 
-impl From<DataCommandGroup> for forge_domain::DataGenerationParameters {
-    fn from(value: DataCommandGroup) -> Self {
-        Self {
-            input: value.input.into(),
-            schema: value.schema.into(),
+/// Offline batch processing commands.
+#[derive(Subcommand, Debug, Clone)]
+pub enum DataCommand {
+    /// Submit every prompt in a JSONL file as a single provider batch.
+    BatchSubmit {
+        /// JSONL file where each line is `{"id": "...", "prompt": "..."}`.
+        input_file: PathBuf,
+
+        /// File the batch ID is written to.
+        output_file: PathBuf,
+    },
+
+    /// Wait for a batch to finish and print its results as JSONL.
+    BatchPoll {
+        /// ID of the batch returned by `batch-submit`.
+        batch_id: String,
+    },
+}
+
+impl TryFrom<DataCommandGroup> for forge_domain::DataGenerationParameters {
+    type Error = anyhow::Error;
+
+    fn try_from(value: DataCommandGroup) -> anyhow::Result<Self> {
+        Ok(Self {
+            input: value.input.context("--input is required")?.into(),
+            schema: value.schema.context("--schema is required")?.into(),
             system_prompt: value.system_prompt.map(Into::into),
             user_prompt: value.user_prompt.map(Into::into),
             concurrency: value.concurrency,
-        }
+        })
     }
 }
 
@@ -924,6 +1133,10 @@ pub struct UpdateArgs {
     /// Skip the confirmation prompt when applying updates.
     #[arg(long, default_value_t = false)]
     pub no_confirm: bool,
+
+    /// Remove the pinned version so automatic update checks resume.
+    #[arg(long, default_value_t = false)]
+    pub unpin: bool,
 }
 
 /// Arguments for the `forge logs` command.
@@ -959,13 +1172,14 @@ mod tests {
         use std::path::PathBuf;
 
         let fixture = DataCommandGroup {
-            input: "path/to/input.jsonl".to_string(),
-            schema: "path/to/schema.json".to_string(),
+            command: None,
+            input: Some("path/to/input.jsonl".to_string()),
+            schema: Some("path/to/schema.json".to_string()),
             system_prompt: Some("system prompt".to_string()),
             user_prompt: None,
             concurrency: 5,
         };
-        let actual: forge_domain::DataGenerationParameters = fixture.into();
+        let actual: forge_domain::DataGenerationParameters = fixture.try_into().unwrap();
         let expected = forge_domain::DataGenerationParameters {
             input: PathBuf::from("path/to/input.jsonl"),
             schema: PathBuf::from("path/to/schema.json"),
@@ -976,6 +1190,58 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_data_batch_submit() {
+        let fixture = Cli::parse_from(["forge", "data", "batch-submit", "in.jsonl", "batch.txt"]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Data(group)) => group.command,
+            _ => None,
+        };
+        let expected = Some((PathBuf::from("in.jsonl"), PathBuf::from("batch.txt")));
+        let actual = match actual {
+            Some(DataCommand::BatchSubmit { input_file, output_file }) => {
+                Some((input_file, output_file))
+            }
+            _ => None,
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_data_without_subcommand_still_generates() {
+        let fixture =
+            Cli::parse_from(["forge", "data", "--input", "in.jsonl", "--schema", "s.json"]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Data(group)) => {
+                group.command.is_none() && group.input.as_deref() == Some("in.jsonl")
+            }
+            _ => false,
+        };
+        assert!(actual);
+    }
+
+    #[test]
+    fn test_max_turns() {
+        let fixture = Cli::parse_from(["forge", "-p", "hello", "--max-turns", "2"]);
+        let actual = fixture.max_turns;
+        let expected = Some(2);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_max_turns_rejects_zero() {
+        let actual = Cli::try_parse_from(["forge", "-p", "hello", "--max-turns", "0"]);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_pin_version() {
+        let fixture = Cli::parse_from(["forge", "--pin-version", "1.2.3"]);
+        let actual = fixture.pin_version;
+        let expected = Some("1.2.3".to_string());
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_commit_default_max_diff_size() {
         let fixture = Cli::parse_from(["forge", "commit", "--preview"]);
@@ -987,6 +1253,28 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_commit_scope_conventional() {
+        let fixture = Cli::parse_from(["forge", "commit", "--scope", "conventional"]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Commit(commit)) => commit.scope,
+            _ => panic!("Expected Commit command"),
+        };
+        let expected = Some(CommitScope::Conventional);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_commit_sign() {
+        let fixture = Cli::parse_from(["forge", "commit", "--sign"]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Commit(commit)) => commit.sign,
+            _ => panic!("Expected Commit command"),
+        };
+        let expected = true;
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_commit_custom_max_diff_size() {
         let fixture = Cli::parse_from(["forge", "commit", "--preview", "--max-diff", "50000"]);
@@ -1027,6 +1315,69 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_config_flag_sets_workflow_path() {
+        let fixture = Cli::parse_from(["forge", "--config", "/tmp/test.toml"]);
+        let actual = fixture.workflow;
+        let expected = Some(PathBuf::from("/tmp/test.toml"));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_profile_flag() {
+        let fixture = Cli::parse_from(["forge", "--profile", "work", "list", "model"]);
+        let actual = fixture.profile;
+        let expected = Some("work".to_string());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_profile_create() {
+        let fixture = Cli::parse_from(["forge", "profile", "create", "work"]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Profile(group)) => match group.command {
+                ProfileCommand::Create { name } => Some(name),
+                _ => None,
+            },
+            _ => None,
+        };
+        let expected = Some("work".to_string());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_context_add_multiple_files() {
+        let fixture = Cli::parse_from(["forge", "context", "add", "a.rs", "b.rs"]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Context(group)) => match group.command {
+                ContextCommand::Add { paths } => paths,
+                ContextCommand::Clear => Vec::new(),
+            },
+            _ => Vec::new(),
+        };
+        let expected = vec![PathBuf::from("a.rs"), PathBuf::from("b.rs")];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_context_add_requires_paths() {
+        let actual = Cli::try_parse_from(["forge", "context", "add"]).is_err();
+        assert!(actual);
+    }
+
+    #[test]
+    fn test_rollback_dry_run() {
+        let fixture = Cli::parse_from(["forge", "rollback", "--dry-run"]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Rollback { conversation_id, dry_run }) => {
+                Some((conversation_id, dry_run))
+            }
+            _ => None,
+        };
+        let expected = Some((None, true));
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_config_list() {
         let fixture = Cli::parse_from(["forge", "config", "list"]);
@@ -1038,6 +1389,19 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_config_validate() {
+        let fixture = Cli::parse_from(["forge", "config", "validate"]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Config(config)) => {
+                matches!(config.command, ConfigCommand::Validate)
+            }
+            _ => false,
+        };
+        let expected = true;
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_config_get_specific_field() {
         let fixture = Cli::parse_from(["forge", "config", "get", "model"]);
@@ -1080,6 +1444,47 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_config_set_provider_alias() {
+        let fixture = Cli::parse_from(["forge", "config", "set", "provider-alias", "openai=work"]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Config(config)) => match config.command {
+                ConfigCommand::Set(args) => match args.field {
+                    ConfigSetField::ProviderAlias { assignment } => Some(assignment),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        };
+        let expected = Some(ProviderAlias {
+            provider: ProviderId::OPENAI,
+            alias: Some("work".to_string()),
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_provider_alias_without_alias_selects_default() {
+        let actual: ProviderAlias = "openai=".parse().unwrap();
+        let expected = ProviderAlias { provider: ProviderId::OPENAI, alias: None };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_provider_login_with_alias() {
+        let fixture = Cli::parse_from(["forge", "provider", "login", "openai", "--alias", "work"]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Provider(group)) => match group.command {
+                ProviderCommand::Login { alias, .. } => alias,
+                _ => None,
+            },
+            _ => None,
+        };
+        let expected = Some("work".to_string());
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_conversation_list() {
         let fixture = Cli::parse_from(["forge", "conversation", "list"]);
@@ -1139,7 +1544,7 @@ mod tests {
         ]);
         let (id, html) = match fixture.subcommands {
             Some(TopLevelCommand::Conversation(conversation)) => match conversation.command {
-                ConversationCommand::Dump { id, html } => (id, html),
+                ConversationCommand::Dump { id, html, .. } => (id, html),
                 _ => (ConversationId::default(), true),
             },
             _ => (ConversationId::default(), true),
@@ -1151,6 +1556,26 @@ mod tests {
         assert_eq!(html, false); // JSON is default
     }
 
+    #[test]
+    fn test_conversation_dump_pdf_with_id() {
+        let fixture = Cli::parse_from([
+            "forge",
+            "conversation",
+            "dump",
+            "550e8400-e29b-41d4-a716-446655440002",
+            "--pdf",
+        ]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Conversation(conversation)) => match conversation.command {
+                ConversationCommand::Dump { html, pdf, .. } => (html, pdf),
+                _ => (true, false),
+            },
+            _ => (true, false),
+        };
+        let expected = (false, true);
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_conversation_dump_html_with_id() {
         let fixture = Cli::parse_from([
@@ -1162,7 +1587,7 @@ mod tests {
         ]);
         let (id, html) = match fixture.subcommands {
             Some(TopLevelCommand::Conversation(conversation)) => match conversation.command {
-                ConversationCommand::Dump { id, html } => (id, html),
+                ConversationCommand::Dump { id, html, .. } => (id, html),
                 _ => (ConversationId::default(), false),
             },
             _ => (ConversationId::default(), false),
@@ -1474,6 +1899,20 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_agent_inspect_with_system_prompt() {
+        let fixture = Cli::parse_from(["forge", "agent", "inspect", "forge", "--system-prompt"]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Agent(agent)) => match agent.command {
+                AgentCommand::Inspect { id, system_prompt } => Some((id, system_prompt)),
+                _ => None,
+            },
+            _ => None,
+        };
+        let expected = Some((AgentId::new("forge"), true));
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_mcp_list_with_porcelain() {
         let fixture = Cli::parse_from(["forge", "mcp", "list", "--porcelain"]);
@@ -1585,7 +2024,7 @@ mod tests {
         ]);
         let (id, html) = match fixture.subcommands {
             Some(TopLevelCommand::Conversation(conversation)) => match conversation.command {
-                ConversationCommand::Dump { id, html } => (id, html),
+                ConversationCommand::Dump { id, html, .. } => (id, html),
                 _ => (ConversationId::default(), true),
             },
             _ => (ConversationId::default(), true),
@@ -1711,6 +2150,39 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_workspace_query_with_excludes() {
+        let fixture = Cli::parse_from([
+            "forge",
+            "workspace",
+            "query",
+            "--use-case",
+            "find retry logic",
+            "--exclude",
+            "tests/**",
+            "--exclude",
+            "*.generated.rs",
+            "retry",
+        ]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Workspace(group)) => match group.command {
+                WorkspaceCommand::Query { exclude, .. } => exclude,
+                _ => panic!("Expected Query command"),
+            },
+            _ => panic!("Expected Workspace command"),
+        };
+        let expected = vec!["tests/**".to_string(), "*.generated.rs".to_string()];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_log_file_flag() {
+        let fixture = Cli::parse_from(["forge", "--log-file", "/tmp/forge.log"]);
+        let actual = fixture.log_file;
+        let expected = Some(PathBuf::from("/tmp/forge.log"));
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_commit_with_custom_text() {
         let fixture = Cli::parse_from(["forge", "commit", "fix", "typo", "in", "readme"]);
@@ -2025,6 +2497,16 @@ mod tests {
         assert!(actual);
     }
 
+    #[test]
+    fn test_update_with_unpin() {
+        let fixture = Cli::parse_from(["forge", "update", "--unpin"]);
+        let actual = match fixture.subcommands {
+            Some(TopLevelCommand::Update(args)) => args.unpin,
+            _ => panic!("Expected Update command"),
+        };
+        assert!(actual);
+    }
+
     #[test]
     fn test_update_without_no_confirm() {
         let fixture = Cli::parse_from(["forge", "update"]);
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use forge_api::Conversation;
 use forge_domain::ConversationId;
 use forge_select::{ForgeWidget, PreviewLayout, PreviewPlacement, SelectRow};
@@ -39,11 +39,42 @@ impl ConversationSelector {
             return Ok(None);
         }
 
-        // Build Info structure for display
-        let now = Utc::now();
+        let rows = Self::conversation_rows(&valid_conversations, Utc::now());
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        // Build a lookup map from UUID to Conversation for the result
+        let conv_map: std::collections::HashMap<String, Conversation> = valid_conversations
+            .into_iter()
+            .map(|c| (c.id.to_string(), c.clone()))
+            .collect();
+
+        let preview_command =
+            "CLICOLOR_FORCE=1 forge conversation info {1}; echo; CLICOLOR_FORCE=1 forge conversation show {1}"
+                .to_string();
+
+        let selected_uuid = tokio::task::spawn_blocking(move || -> Result<Option<String>> {
+            Ok(ForgeWidget::select_rows("Conversation", rows)
+                .query(query)
+                .header_lines(1_usize)
+                .help_message(Some("Type to filter conversations".to_string()))
+                .preview(Some(preview_command))
+                .preview_layout(PreviewLayout { placement: PreviewPlacement::Bottom, percent: 60 })
+                .prompt()?
+                .map(|row| row.raw))
+        })
+        .await??;
+
+        Ok(selected_uuid.and_then(|uuid| conv_map.get(&uuid).cloned()))
+    }
+
+    /// Builds the selector rows for `conversations`: a header row followed by
+    /// one row per conversation showing its title and last update time.
+    fn conversation_rows(conversations: &[&Conversation], now: DateTime<Utc>) -> Vec<SelectRow> {
         let mut info = Info::new();
 
-        for conv in &valid_conversations {
+        for conv in conversations {
             let title = conv
                 .title
                 .as_deref()
@@ -76,14 +107,12 @@ impl ConversationSelector {
         let porcelain_str = porcelain_output.to_string();
 
         let all_lines: Vec<&str> = porcelain_str.lines().collect();
-        if all_lines.is_empty() {
-            return Ok(None);
-        }
 
         // Build SelectRow items for the shared Rust selector UI.
         // Each row stores the UUID in `fields[0]` so that `{1}` in the preview
         // command resolves to the conversation ID. The `raw` field is what gets
-        // returned on selection (the UUID).
+        // returned on selection (the UUID). Rows are searchable by both the
+        // displayed title and the conversation ID.
         let mut rows: Vec<SelectRow> = Vec::with_capacity(all_lines.len());
 
         // Header row (non-selectable via header_lines=1)
@@ -93,39 +122,18 @@ impl ConversationSelector {
 
         // Data rows: each maps to a conversation
         for (i, line) in all_lines.iter().skip(1).enumerate() {
-            if let Some(conv) = valid_conversations.get(i) {
+            if let Some(conv) = conversations.get(i) {
                 let uuid = conv.id.to_string();
                 rows.push(SelectRow {
                     raw: uuid.clone(),
                     display: line.to_string(),
-                    search: line.to_string(),
+                    search: format!("{line} {uuid}"),
                     fields: vec![uuid],
                 });
             }
         }
 
-        // Build a lookup map from UUID to Conversation for the result
-        let conv_map: std::collections::HashMap<String, Conversation> = valid_conversations
-            .into_iter()
-            .map(|c| (c.id.to_string(), c.clone()))
-            .collect();
-
-        let preview_command =
-            "CLICOLOR_FORCE=1 forge conversation info {1}; echo; CLICOLOR_FORCE=1 forge conversation show {1}"
-                .to_string();
-
-        let selected_uuid = tokio::task::spawn_blocking(move || -> Result<Option<String>> {
-            Ok(ForgeWidget::select_rows("Conversation", rows)
-                .query(query)
-                .header_lines(1_usize)
-                .preview(Some(preview_command))
-                .preview_layout(PreviewLayout { placement: PreviewPlacement::Bottom, percent: 60 })
-                .prompt()?
-                .map(|row| row.raw))
-        })
-        .await??;
-
-        Ok(selected_uuid.and_then(|uuid| conv_map.get(&uuid).cloned()))
+        rows
     }
 }
 
@@ -145,7 +153,7 @@ mod tests {
             title: title.map(|t| t.to_string()),
             context: None,
             metrics: Metrics::default().started_at(now),
-            metadata: MetaData { created_at: now, updated_at: Some(now) },
+            metadata: MetaData { updated_at: Some(now), ..MetaData::new(now) },
         }
     }
 
@@ -174,6 +182,45 @@ mod tests {
         assert_eq!(conversations.len(), 2);
     }
 
+    #[test]
+    fn test_conversation_rows_filter_by_partial_title() {
+        let titles = [
+            "Fix login redirect",
+            "Add dark mode",
+            "Refactor payment service",
+            "Update README",
+            "Login page styling",
+            "Bump dependencies",
+            "Investigate flaky test",
+            "Write release notes",
+            "Remove dead code",
+            "Improve error messages",
+        ];
+        let conversations: Vec<Conversation> = titles
+            .iter()
+            .enumerate()
+            .map(|(i, title)| {
+                create_test_conversation(
+                    &format!("550e8400-e29b-41d4-a716-4466554400{i:02}"),
+                    Some(title),
+                )
+            })
+            .collect();
+        let fixture: Vec<&Conversation> = conversations.iter().collect();
+        let rows = ConversationSelector::conversation_rows(&fixture, Utc::now());
+
+        let actual = forge_select::filter_rows(rows.into_iter().skip(1).collect(), "login")
+            .into_iter()
+            .map(|row| row.raw)
+            .collect::<std::collections::BTreeSet<_>>();
+
+        let expected = std::collections::BTreeSet::from([
+            "550e8400-e29b-41d4-a716-446655440000".to_string(),
+            "550e8400-e29b-41d4-a716-446655440004".to_string(),
+        ]);
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_select_conversation_without_titles() {
         let conversations = [
@@ -25,6 +25,48 @@ use crate::prompt::ForgePrompt;
 
 const HISTORY_CAPACITY: usize = 1024 * 1024;
 
+/// Editors tried in order when no editor is configured in the environment
+const FALLBACK_EDITORS: &[&str] = &["nano", "vi"];
+
+/// Resolves the external editor command used for multi-line input and config
+/// editing.
+///
+/// Follows the usual Unix convention: `$FORGE_EDITOR` overrides everything,
+/// then `$VISUAL`, then `$EDITOR`, falling back to the first of `nano` or
+/// `vi` found on `PATH`. The returned command may include flags, e.g.
+/// `code --wait`.
+pub fn resolve_editor() -> String {
+    let editor = resolve_editor_with(
+        |key| std::env::var(key).ok(),
+        |program| {
+            std::env::var_os("PATH").is_some_and(|path| {
+                std::env::split_paths(&path).any(|dir| dir.join(program).is_file())
+            })
+        },
+    );
+    tracing::debug!(editor = %editor, "Resolved external editor");
+    editor
+}
+
+fn resolve_editor_with(
+    var: impl Fn(&str) -> Option<String>,
+    is_installed: impl Fn(&str) -> bool,
+) -> String {
+    ["FORGE_EDITOR", "VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(&var)
+        .map(|editor| editor.trim().to_string())
+        .find(|editor| !editor.is_empty())
+        .unwrap_or_else(|| {
+            FALLBACK_EDITORS
+                .iter()
+                .copied()
+                .find(|program| is_installed(program))
+                .unwrap_or(FALLBACK_EDITORS[0])
+                .to_string()
+        })
+}
+
 /// Interactive terminal editor used by the Forge prompt.
 pub struct ForgeEditor {
     editor: Editor<ForgeHelper, DefaultHistory>,
@@ -273,6 +315,48 @@ mod tests {
 
     use super::*;
 
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: std::collections::HashMap<String, String> = vars
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        move |key| vars.get(key).cloned()
+    }
+
+    #[test]
+    fn test_resolve_editor_prefers_visual_over_editor() {
+        let fixture = env(&[("VISUAL", "code --wait")]);
+
+        let actual = resolve_editor_with(fixture, |_| true);
+
+        let expected = "code --wait";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_resolve_editor_forge_editor_overrides_visual() {
+        let fixture = env(&[
+            ("FORGE_EDITOR", "hx"),
+            ("VISUAL", "code --wait"),
+            ("EDITOR", "vim"),
+        ]);
+
+        let actual = resolve_editor_with(fixture, |_| true);
+
+        let expected = "hx";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_resolve_editor_falls_back_to_installed_editor() {
+        let fixture = env(&[("VISUAL", "  ")]);
+
+        let actual = resolve_editor_with(fixture, |program| program == "vi");
+
+        let expected = "vi";
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_normalize_result_wraps_existing_pasted_path() {
         let fixture = "/usr/bin/env".to_string();
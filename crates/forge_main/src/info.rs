@@ -233,6 +233,66 @@ impl Info {
         self.sections.extend(other.into().sections);
         self
     }
+
+    /// Combines `other` into this info without repeating section titles.
+    ///
+    /// Items under a title that already exists are appended to that section,
+    /// replacing any item with the same key. Sections with new titles are
+    /// appended at the end, and items in `other` that precede its first title
+    /// join the last section of this info.
+    pub fn merge(mut self, other: impl Into<Info>) -> Self {
+        let mut insert_at = self.sections.len();
+        let mut section_start = self
+            .sections
+            .iter()
+            .rposition(|section| matches!(section, Section::Title(_)))
+            .map_or(0, |index| index + 1);
+
+        for section in other.into().sections {
+            match section {
+                Section::Title(title) => {
+                    let existing = self.sections.iter().position(
+                        |section| matches!(section, Section::Title(existing) if *existing == title),
+                    );
+                    match existing {
+                        Some(index) => {
+                            section_start = index + 1;
+                            insert_at = self.section_end(section_start);
+                        }
+                        None => {
+                            self.sections.push(Section::Title(title));
+                            section_start = self.sections.len();
+                            insert_at = section_start;
+                        }
+                    }
+                }
+                item => {
+                    let duplicate = item.key().and_then(|key| {
+                        self.sections[section_start..insert_at]
+                            .iter()
+                            .position(|section| section.key() == Some(key))
+                    });
+                    match duplicate {
+                        Some(offset) => self.sections[section_start + offset] = item,
+                        None => {
+                            self.sections.insert(insert_at, item);
+                            insert_at += 1;
+                        }
+                    }
+                }
+            }
+        }
+        self
+    }
+
+    /// Returns the index just past the last item of the section whose items
+    /// begin at `start`.
+    fn section_end(&self, start: usize) -> usize {
+        self.sections[start..]
+            .iter()
+            .position(|section| matches!(section, Section::Title(_)))
+            .map_or(self.sections.len(), |offset| start + offset)
+    }
 }
 
 pub trait IntoInfoValue {
@@ -1273,4 +1333,36 @@ mod tests {
         assert!(!expected_display.contains("file1.rs"));
         assert!(!expected_display.contains("file2.rs"));
     }
+
+    #[test]
+    fn test_merge_combines_sections_with_the_same_title() {
+        use super::Section;
+
+        let fixture = super::Info::new()
+            .add_title("AGENT")
+            .add_key_value("Name", "forge")
+            .add_key_value("Model", "gpt-4")
+            .add_title("USER")
+            .add_key_value("Email", "user@example.com");
+        let other = super::Info::new()
+            .add_title("AGENT")
+            .add_key_value("Model", "claude")
+            .add_key_value("Provider", "anthropic")
+            .add_title("USAGE")
+            .add_key_value("Tokens", "1000");
+
+        let actual = fixture.merge(other);
+
+        let expected = vec![
+            Section::Title("AGENT".to_string()),
+            Section::Items(Some("name".to_string()), "forge".to_string()),
+            Section::Items(Some("model".to_string()), "claude".to_string()),
+            Section::Items(Some("provider".to_string()), "anthropic".to_string()),
+            Section::Title("USER".to_string()),
+            Section::Items(Some("email".to_string()), "user@example.com".to_string()),
+            Section::Title("USAGE".to_string()),
+            Section::Items(Some("tokens".to_string()), "1000".to_string()),
+        ];
+        assert_eq!(actual.sections(), expected.as_slice());
+    }
 }
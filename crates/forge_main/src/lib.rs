@@ -14,6 +14,7 @@ mod oauth_callback;
 mod porcelain;
 mod prompt;
 mod sandbox;
+mod shutdown;
 mod state;
 mod stream_renderer;
 mod sync_display;
@@ -31,6 +32,7 @@ use std::sync::LazyLock;
 
 pub use cli::{Cli, ListCommand, ListCommandGroup, TopLevelCommand};
 pub use sandbox::Sandbox;
+pub use sync_display::{SyncProgressDisplay, into_sse_stream};
 pub use title_display::*;
 pub use ui::UI;
 
@@ -102,10 +102,22 @@ async fn run() -> Result<()> {
         }
     }
 
+    // Point all config and credential lookups at the selected profile before
+    // anything reads from the config directory.
+    if let Some(profile) = &cli.profile {
+        forge_config::ConfigReader::select_profile(profile)?;
+    }
+
     // Read and validate configuration at startup so any errors are surfaced
     // immediately rather than silently falling back to defaults at runtime.
-    let config =
-        ForgeConfig::read().context("Failed to read Forge configuration from .forge.toml")?;
+    let config = match &cli.workflow {
+        Some(path) => ForgeConfig::read_from(path).with_context(|| {
+            format!("Failed to read Forge configuration from {}", path.display())
+        })?,
+        None => {
+            ForgeConfig::read().context("Failed to read Forge configuration from .forge.toml")?
+        }
+    };
 
     // Handle worktree creation if specified
     let cwd: PathBuf = match (&cli.sandbox, &cli.directory) {
@@ -1223,6 +1223,8 @@ mod tests {
             supports_parallel_tool_calls: None,
             supports_reasoning: None,
             input_modalities: vec![InputModality::Text],
+            cost_per_million_input_tokens: None,
+            cost_per_million_output_tokens: None,
         }
     }
 
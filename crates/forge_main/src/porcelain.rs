@@ -117,6 +117,35 @@ impl Porcelain {
         )
     }
 
+    /// Keeps only the rows whose value in column `col` satisfies `predicate`
+    ///
+    /// Preserves the header row (first row). Missing cells are passed to the
+    /// predicate as an empty string.
+    ///
+    /// # Example
+    /// ```ignore
+    /// porcelain.filter_rows(0, |v| v.starts_with("claude"))
+    /// ```
+    #[allow(unused)]
+    pub fn filter_rows<F>(self, col: usize, predicate: F) -> Self
+    where
+        F: Fn(&str) -> bool,
+    {
+        let mut rows = self.0.into_iter();
+        let header = rows.next();
+
+        Porcelain(
+            header
+                .into_iter()
+                .chain(
+                    rows.filter(|row| {
+                        predicate(row.get(col).and_then(Option::as_deref).unwrap_or(""))
+                    }),
+                )
+                .collect(),
+        )
+    }
+
     /// Sorts rows based on multiple columns
     ///
     /// Preserves the header row (first row) and sorts all subsequent rows.
@@ -171,6 +200,38 @@ impl Porcelain {
         Porcelain(rows)
     }
 
+    /// Sorts rows lexicographically by the value in column `col`
+    ///
+    /// Preserves the header row (first row). Rows with a missing value are
+    /// kept after all others regardless of `descending`, and rows with equal
+    /// values keep their relative order.
+    ///
+    /// # Example
+    /// ```ignore
+    /// porcelain.sort_by_col(0, false) // Sort by column 0, A to Z
+    /// ```
+    #[allow(unused)]
+    pub fn sort_by_col(self, col: usize, descending: bool) -> Self {
+        let mut rows = self.0;
+        if rows.len() < 2 {
+            return Porcelain(rows);
+        }
+
+        rows[1..].sort_by(|a, b| {
+            let a_val = a.get(col).and_then(Option::as_ref);
+            let b_val = b.get(col).and_then(Option::as_ref);
+            match (a_val, b_val) {
+                (Some(a), Some(b)) if descending => b.cmp(a),
+                (Some(a), Some(b)) => a.cmp(b),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            }
+        });
+
+        Porcelain(rows)
+    }
+
     /// Applies case transformation to specified columns
     ///
     /// # Arguments
@@ -982,6 +1043,30 @@ mod tests {
         assert_eq!(actual, expected)
     }
 
+    #[test]
+    fn test_filter_rows() {
+        let fixture = Porcelain(vec![
+            vec![Some(headers::ID.into()), Some("provider".into())],
+            vec![Some("claude-sonnet".into()), Some("anthropic".into())],
+            vec![Some("gpt-4o".into()), Some("openai".into())],
+            vec![Some("claude-opus".into()), Some("anthropic".into())],
+            vec![Some("gemini-pro".into()), Some("google".into())],
+            vec![None, Some("unknown".into())],
+        ]);
+
+        let actual = fixture
+            .filter_rows(0, |v| v.starts_with("claude"))
+            .into_rows();
+
+        let expected = vec![
+            vec![Some(headers::ID.into()), Some("provider".into())],
+            vec![Some("claude-sonnet".into()), Some("anthropic".into())],
+            vec![Some("claude-opus".into()), Some("anthropic".into())],
+        ];
+
+        assert_eq!(actual, expected)
+    }
+
     #[test]
     fn test_sort_by_single_col() {
         let fixture = Porcelain(vec![
@@ -1158,6 +1243,48 @@ mod tests {
         assert_eq!(actual, expected)
     }
 
+    #[test]
+    fn test_sort_by_col_ascending() {
+        let fixture = Porcelain(vec![
+            vec![Some("name".into()), Some("age".into())],
+            vec![Some("Charlie".into()), Some("35".into())],
+            vec![Some("Alice".into()), Some("30".into())],
+            vec![Some("Bob".into()), Some("25".into())],
+        ]);
+
+        let actual = fixture.sort_by_col(0, false).into_rows();
+
+        let expected = vec![
+            vec![Some("name".into()), Some("age".into())],
+            vec![Some("Alice".into()), Some("30".into())],
+            vec![Some("Bob".into()), Some("25".into())],
+            vec![Some("Charlie".into()), Some("35".into())],
+        ];
+
+        assert_eq!(actual, expected)
+    }
+
+    #[test]
+    fn test_sort_by_col_descending_keeps_none_last() {
+        let fixture = Porcelain(vec![
+            vec![Some("name".into()), Some("age".into())],
+            vec![None, Some("40".into())],
+            vec![Some("Alice".into()), Some("30".into())],
+            vec![Some("Charlie".into()), Some("35".into())],
+        ]);
+
+        let actual = fixture.sort_by_col(0, true).into_rows();
+
+        let expected = vec![
+            vec![Some("name".into()), Some("age".into())],
+            vec![Some("Charlie".into()), Some("35".into())],
+            vec![Some("Alice".into()), Some("30".into())],
+            vec![None, Some("40".into())],
+        ];
+
+        assert_eq!(actual, expected)
+    }
+
     #[test]
     fn test_set_headers_basic() {
         let fixture = Porcelain(vec![
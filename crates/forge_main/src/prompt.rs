@@ -25,6 +25,10 @@ const MODEL_SYMBOL: &str = "\u{ec19}";
 /// integration render identically on equivalent terminals.
 const WIDE_TERMINAL_THRESHOLD: usize = 100;
 
+/// Maximum number of characters of the conversation title shown in the right
+/// prompt before it is truncated with an ellipsis.
+const MAX_TITLE_CHARS: usize = 32;
+
 /// Very Specialized Prompt for the Agent Chat
 #[derive(Clone, Setters)]
 #[setters(strip_option, borrow_self)]
@@ -37,6 +41,14 @@ pub struct ForgePrompt {
     /// rendered to the right of the model when set. `Effort::None` is
     /// suppressed (see [`ForgePrompt::render_prompt_right`]).
     pub reasoning_effort: Option<Effort>,
+    /// Title of the active conversation. Titles are generated in the
+    /// background, so this is picked up on the first prompt after the title
+    /// has been saved.
+    pub title: Option<String>,
+    /// Tokens currently held in the context window alongside the model's
+    /// maximum context length, rendered as `[used/max]` and coloured by how
+    /// full the window is.
+    pub context_tokens: Option<(usize, usize)>,
     pub git_branch: Option<String>,
 }
 
@@ -51,6 +63,8 @@ impl ForgePrompt {
             agent_id,
             model: None,
             reasoning_effort: None,
+            title: None,
+            context_tokens: None,
             git_branch,
         }
     }
@@ -114,7 +128,7 @@ impl ForgePrompt {
     }
 
     pub fn render_prompt_right(&self) -> Cow<'_, str> {
-        // Right prompt layout: agent · tokens · cost · model
+        // Right prompt layout: title · agent · tokens · cost · model
         // Active (tokens > 0): bright white for agent/tokens, green for cost
         // Inactive (no tokens): all segments dimmed
 
@@ -128,6 +142,20 @@ impl ForgePrompt {
         };
         let mut result = String::with_capacity(64);
 
+        // Conversation title, dimmed and truncated so it never crowds out the
+        // other segments
+        if let Some(title) = self.title.as_deref() {
+            write!(
+                result,
+                " {}",
+                Style::new()
+                    .italic()
+                    .fg(Color::DarkGray)
+                    .paint(truncate_title(title))
+            )
+            .unwrap();
+        }
+
         // Agent name with nerd font symbol
         let agent_str = format!(
             "{AGENT_SYMBOL} {}",
@@ -157,11 +185,28 @@ impl ForgePrompt {
             .unwrap();
         }
 
+        // Context window usage (only shown when active and the model's
+        // context length is known)
+        if let Some((used, max)) = self.context_tokens
+            && active
+            && max > 0
+        {
+            let context_str = format!("[{}/{}]", compact_number(used), compact_number(max));
+            write!(
+                result,
+                " {}",
+                Style::new()
+                    .fg(context_usage_color(used, max))
+                    .paint(&context_str)
+            )
+            .unwrap();
+        }
+
         // Cost (only shown when active)
         if let Some(cost) = self.usage.as_ref().and_then(|u| u.cost)
             && active
         {
-            let cost_str = format!("\u{f155}{cost:.2}");
+            let cost_str = format!("\u{f155}{cost:.4}");
             write!(
                 result,
                 " {}",
@@ -222,6 +267,45 @@ fn term_width() -> usize {
         .unwrap_or(80)
 }
 
+/// Shortens `title` to [`MAX_TITLE_CHARS`] characters, appending an ellipsis
+/// when it was cut.
+fn truncate_title(title: &str) -> String {
+    if title.chars().count() <= MAX_TITLE_CHARS {
+        return title.to_string();
+    }
+    let mut truncated: String = title.chars().take(MAX_TITLE_CHARS - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Humanizes `n` without a redundant trailing `.0` (e.g. `128k` rather than
+/// `128.0k`) so the context window segment stays short.
+fn compact_number(n: usize) -> String {
+    let humanized = humanize_number(n);
+    ["k", "M", "B"]
+        .iter()
+        .find_map(|suffix| {
+            humanized
+                .strip_suffix(suffix)
+                .and_then(|number| number.strip_suffix(".0"))
+                .map(|whole| format!("{whole}{suffix}"))
+        })
+        .unwrap_or(humanized)
+}
+
+/// Picks the colour for the context window segment: green below 50% usage,
+/// yellow up to 80% and red beyond that.
+fn context_usage_color(used: usize, max: usize) -> Color {
+    let ratio = used as f64 / max as f64;
+    if ratio < 0.5 {
+        Color::Green
+    } else if ratio <= 0.8 {
+        Color::Yellow
+    } else {
+        Color::Red
+    }
+}
+
 /// Formats an [`Effort`] as its uppercase label, collapsing to the first three
 /// characters on narrow terminals (< [`WIDE_TERMINAL_THRESHOLD`] columns).
 fn effort_label(effort: &Effort, width: usize) -> String {
@@ -250,6 +334,8 @@ mod tests {
                 agent_id: AgentId::default(),
                 model: None,
                 reasoning_effort: None,
+                title: None,
+                context_tokens: None,
                 git_branch: None,
             }
         }
@@ -344,6 +430,53 @@ mod tests {
         assert!(actual.contains(AGENT_SYMBOL));
     }
 
+    #[test]
+    fn test_render_prompt_right_with_context_tokens() {
+        let usage = Usage {
+            total_tokens: forge_api::TokenCount::Actual(30_000),
+            ..Default::default()
+        };
+        let mut prompt = ForgePrompt::default();
+        let _ = prompt.usage(usage).context_tokens((30_000, 128_000));
+
+        let actual = prompt.render_prompt_right();
+        let expected = Style::new()
+            .fg(Color::Green)
+            .paint("[30k/128k]")
+            .to_string();
+        assert!(actual.contains(&expected));
+    }
+
+    #[test]
+    fn test_render_prompt_right_context_tokens_hidden_when_inactive() {
+        let mut prompt = ForgePrompt::default();
+        let _ = prompt.context_tokens((0, 128_000));
+
+        let actual = prompt.render_prompt_right();
+        assert!(!actual.contains("128k"));
+    }
+
+    #[test]
+    fn test_compact_number() {
+        let actual = [999, 1_200, 30_000, 128_000, 1_000_000]
+            .map(compact_number)
+            .to_vec();
+        let expected = vec!["999", "1.2k", "30k", "128k", "1M"];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_context_usage_color() {
+        let actual = [
+            context_usage_color(30, 100),
+            context_usage_color(50, 100),
+            context_usage_color(80, 100),
+            context_usage_color(81, 100),
+        ];
+        let expected = [Color::Green, Color::Yellow, Color::Yellow, Color::Red];
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_render_prompt_history_search_indicator_passing() {
         let history_search = PromptHistorySearch {
@@ -452,4 +585,23 @@ mod tests {
             "MEDIUM"
         );
     }
+
+    #[test]
+    fn test_render_prompt_right_with_title() {
+        let mut prompt = ForgePrompt::default();
+        let _ = prompt.title("Fix login bug".to_string());
+
+        let actual = prompt.render_prompt_right();
+
+        assert!(actual.contains("Fix login bug"));
+    }
+
+    #[test]
+    fn test_truncate_title() {
+        let fixture = "a".repeat(40);
+        let actual = truncate_title(&fixture);
+        let expected = format!("{}…", "a".repeat(31));
+        assert_eq!(actual, expected);
+        assert_eq!(truncate_title("short"), "short");
+    }
 }
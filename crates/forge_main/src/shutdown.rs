@@ -0,0 +1,83 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tokio::sync::Notify;
+
+/// Graceful handling of `SIGTERM` as sent by Docker, Kubernetes or systemd.
+///
+/// Installing the handler replaces the default "terminate immediately"
+/// behaviour. While an operation awaits [`Shutdown::requested`] the signal is
+/// delivered to it so the caller can persist state and return normally, after
+/// which [`Shutdown::is_requested`] tells the rest of the program to wind
+/// down. When nothing is waiting, e.g. while sitting at the prompt, there is
+/// no state to save and the process exits straight away with the
+/// conventional status.
+///
+/// On Windows there is no `SIGTERM`; Ctrl+C remains the only signal and
+/// [`Shutdown::requested`] never resolves.
+#[derive(Clone, Default)]
+pub struct Shutdown {
+    notify: Arc<Notify>,
+    waiting: Arc<AtomicBool>,
+    received: Arc<AtomicBool>,
+}
+
+impl Shutdown {
+    /// Exit status used when terminating outside of an operation (128 +
+    /// `SIGTERM`)
+    #[cfg(unix)]
+    const EXIT_CODE: i32 = 143;
+
+    /// Registers the `SIGTERM` handler. Must be called from within a Tokio
+    /// runtime.
+    pub fn install() -> std::io::Result<Self> {
+        let shutdown = Self::default();
+
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{SignalKind, signal};
+
+            let mut sigterm = signal(SignalKind::terminate())?;
+            let handle = shutdown.clone();
+            tokio::spawn(async move {
+                while sigterm.recv().await.is_some() {
+                    if handle.waiting.load(Ordering::SeqCst) {
+                        handle.received.store(true, Ordering::SeqCst);
+                        handle.notify.notify_one();
+                    } else {
+                        std::process::exit(Self::EXIT_CODE);
+                    }
+                }
+            });
+        }
+
+        Ok(shutdown)
+    }
+
+    /// Resolves once `SIGTERM` is received while this future is pending
+    pub async fn requested(&self) {
+        let _waiting = WaitingGuard::new(&self.waiting);
+        self.notify.notified().await;
+    }
+
+    /// Returns true once `SIGTERM` has been delivered to an operation
+    pub fn is_requested(&self) -> bool {
+        self.received.load(Ordering::SeqCst)
+    }
+}
+
+/// Marks the shutdown as observed for as long as a caller is waiting on it
+struct WaitingGuard<'a>(&'a AtomicBool);
+
+impl<'a> WaitingGuard<'a> {
+    fn new(flag: &'a AtomicBool) -> Self {
+        flag.store(true, Ordering::SeqCst);
+        Self(flag)
+    }
+}
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
@@ -1,7 +1,9 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use anyhow::Context as _;
 use derive_setters::Setters;
 use forge_api::{ConversationId, Environment};
+use serde::{Deserialize, Serialize};
 
 //TODO: UIState and ForgePrompt seem like the same thing and can be merged
 /// State information for the UI
@@ -17,3 +19,154 @@ impl UIState {
         Self { cwd: env.cwd, conversation_id: Default::default() }
     }
 }
+
+/// Files queued with `forge context add` that are attached to the next
+/// message and then cleared. Persisted as JSON so it survives between CLI
+/// invocations.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingContext {
+    pub files: Vec<PathBuf>,
+}
+
+impl PendingContext {
+    /// Loads the pending context from `path`, returning an empty context when
+    /// the file does not exist.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse pending context at {}", path.display())),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Writes the pending context to `path`, removing the file when there is
+    /// nothing pending.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if self.files.is_empty() {
+            return match std::fs::remove_file(path) {
+                Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error.into()),
+                _ => Ok(()),
+            };
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Queues `paths`, skipping files that are already pending, and returns
+    /// the number of files newly queued. Every path must point to a readable
+    /// text file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the files cannot be read.
+    pub fn add(&mut self, paths: &[PathBuf]) -> anyhow::Result<usize> {
+        let pending = self.files.len();
+        for path in paths {
+            let path = path
+                .canonicalize()
+                .with_context(|| format!("Failed to resolve {}", path.display()))?;
+            std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            if !self.files.contains(&path) {
+                self.files.push(path);
+            }
+        }
+        Ok(self.files.len() - pending)
+    }
+
+    /// Reads every pending file and renders them as additional context for
+    /// the next message, or `None` when nothing is pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the files can no longer be read.
+    pub fn render(&self) -> anyhow::Result<Option<String>> {
+        if self.files.is_empty() {
+            return Ok(None);
+        }
+        let files = self
+            .files
+            .iter()
+            .map(|path| {
+                let content = std::fs::read_to_string(path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                Ok(format!(
+                    "<file path=\"{}\">\n{}\n</file>",
+                    path.display(),
+                    content.trim_end()
+                ))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(Some(files.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_pending_context_round_trip_and_render() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().canonicalize().unwrap();
+        let a = dir.join("a.rs");
+        let b = dir.join("b.rs");
+        std::fs::write(&a, "fn a() {}\n").unwrap();
+        std::fs::write(&b, "fn b() {}\n").unwrap();
+        let store = dir.join("pending_context.json");
+
+        let mut fixture = PendingContext::load(&store).unwrap();
+        let added = fixture.add(&[a.clone(), b.clone(), a.clone()]).unwrap();
+        fixture.save(&store).unwrap();
+        assert_eq!(added, 2);
+
+        let actual = PendingContext::load(&store).unwrap().render().unwrap();
+        let expected = Some(format!(
+            "<file path=\"{}\">\nfn a() {{}}\n</file>\n<file path=\"{}\">\nfn b() {{}}\n</file>",
+            a.display(),
+            b.display()
+        ));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_pending_context_add_counts_only_new_files() {
+        let temp = TempDir::new().unwrap();
+        let a = temp.path().join("a.rs");
+        std::fs::write(&a, "fn a() {}\n").unwrap();
+        let mut fixture = PendingContext::default();
+        fixture.add(std::slice::from_ref(&a)).unwrap();
+
+        let actual = fixture.add(&[a.clone(), a]).unwrap();
+
+        let expected = 0;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_pending_context_save_empty_removes_file() {
+        let temp = TempDir::new().unwrap();
+        let store = temp.path().join("pending_context.json");
+        std::fs::write(&store, r#"{"files":["/tmp/a.rs"]}"#).unwrap();
+
+        PendingContext::default().save(&store).unwrap();
+
+        let actual = PendingContext::load(&store).unwrap();
+        let expected = PendingContext::default();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_pending_context_add_missing_file_fails() {
+        let mut fixture = PendingContext::default();
+        let actual = fixture.add(&[PathBuf::from("/does/not/exist.rs")]).is_err();
+        assert!(actual);
+    }
+}
@@ -109,13 +109,21 @@ pub struct StreamingWriter<P: ConsoleWriter + 'static> {
     active: Option<ActiveRenderer<P>>,
     spinner: SharedSpinner<P>,
     printer: Arc<P>,
+    plain: bool,
 }
 
 impl<P: ConsoleWriter + 'static> StreamingWriter<P> {
     /// Creates a new stream writer with the given shared spinner and output
     /// printer.
     pub fn new(spinner: SharedSpinner<P>, printer: Arc<P>) -> Self {
-        Self { active: None, spinner, printer }
+        Self { active: None, spinner, printer, plain: false }
+    }
+
+    /// Renders without colors or text attributes, for output that isn't
+    /// shown in a terminal.
+    pub fn plain(mut self, plain: bool) -> Self {
+        self.plain = plain;
+        self
     }
 
     /// Writes markdown content with normal styling.
@@ -137,6 +145,7 @@ impl<P: ConsoleWriter + 'static> StreamingWriter<P> {
     }
 
     fn write_styled(&mut self, text: &str, style: Style) -> Result<()> {
+        let style = if self.plain { Style::Normal } else { style };
         self.ensure_renderer(style)?;
         if let Some(ref mut active) = self.active {
             active.push(text)?;
@@ -157,7 +166,11 @@ impl<P: ConsoleWriter + 'static> StreamingWriter<P> {
                 printer: self.printer.clone(),
                 style: new_style,
             };
-            let renderer = StreamdownRenderer::new(writer, term_width());
+            let renderer = if self.plain {
+                StreamdownRenderer::plain(writer, term_width())
+            } else {
+                StreamdownRenderer::new(writer, term_width())
+            };
             self.active = Some(ActiveRenderer { renderer, style: new_style });
         }
         Ok(())
@@ -1,4 +1,5 @@
 use forge_domain::SyncProgress;
+use futures::{Stream, StreamExt};
 
 /// Extensions for formatting `SyncProgress` events as human-readable strings.
 ///
@@ -72,6 +73,29 @@ impl SyncProgressDisplay for SyncProgress {
     }
 }
 
+/// Formats progress events as Server-Sent Events so a web or desktop front end
+/// can show the same status messages as the terminal.
+///
+/// Each event with a user-facing [`SyncProgressDisplay::message`] becomes one
+/// SSE event terminated by a blank line; internal events are skipped.
+pub fn into_sse_stream<S>(events: S) -> impl Stream<Item = String>
+where
+    S: Stream<Item = SyncProgress>,
+{
+    events.filter_map(|event| async move { event.message().map(|message| sse_event(&message)) })
+}
+
+/// Formats `message` as a single SSE event, writing one `data:` line per line
+/// of the message so multi-line messages are not split into separate fields.
+fn sse_event(message: &str) -> String {
+    let mut event: String = message
+        .split('\n')
+        .map(|line| format!("data: {}\n", line.trim_end_matches('\r')))
+        .collect();
+    event.push('\n');
+    event
+}
+
 /// Returns "file" or "files" based on count.
 fn pluralize(count: usize) -> &'static str {
     if count == 1 { "file" } else { "files" }
@@ -174,4 +198,30 @@ mod tests {
         assert_eq!(pluralize(2), "files");
         assert_eq!(pluralize(100), "files");
     }
+
+    #[tokio::test]
+    async fn test_into_sse_stream() {
+        let fixture = futures::stream::iter([
+            SyncProgress::Starting,
+            SyncProgress::FilesDiscovered { count: 10 },
+            SyncProgress::Syncing { current: 1, total: 2 },
+            SyncProgress::Completed { uploaded_files: 2, total_files: 2, failed_files: 0 },
+        ]);
+
+        let actual: Vec<_> = into_sse_stream(fixture).collect().await;
+
+        let expected = vec![
+            "data: Initializing sync\n\n".to_string(),
+            "data: Syncing 1/2 files\n\n".to_string(),
+            "data: Sync completed successfully [2/2 files updated]\n\n".to_string(),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_sse_event_writes_one_data_line_per_message_line() {
+        let actual = sse_event("Sync failed\r\nretrying\n");
+        let expected = "data: Sync failed\ndata: retrying\ndata: \n\n";
+        assert_eq!(actual, expected);
+    }
 }
@@ -1,4 +1,5 @@
 use std::collections::{HashMap, HashSet};
+use std::io::IsTerminal;
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -11,14 +12,15 @@ use convert_case::{Case, Casing};
 use forge_api::{
     API, AgentId, AnyProvider, ApiKeyRequest, AuthContextRequest, AuthContextResponse, ChatRequest,
     ChatResponse, CodeRequest, ConfigOperation, Conversation, ConversationId, DeviceCodeRequest,
-    Event, InterruptionReason, ModelId, Provider, ProviderId, TextMessage, UserPrompt,
+    Event, InterruptionReason, ModelId, Provider, ProviderId, TextMessage, Usage, UserPrompt,
 };
 use forge_app::utils::{format_display_path, truncate_key};
 use forge_app::{CommitResult, ToolResolver};
 use forge_config::ForgeConfig;
 use forge_display::MarkdownFormat;
 use forge_domain::{
-    AuthMethod, ChatResponseContent, ConsoleWriter, ContextMessage, Role, TitleFormat, UserCommand,
+    AuthMethod, BatchRequest, BatchStatus, ChatResponseContent, ConsoleWriter, ContextMessage,
+    Role, TitleFormat, UserCommand,
 };
 use forge_fs::ForgeFS;
 use forge_select::{ForgeWidget, SelectRow};
@@ -28,6 +30,7 @@ use forge_walker::Walker;
 use futures::future;
 use strum::IntoEnumIterator;
 use tokio_stream::StreamExt;
+use tokio_util::sync::CancellationToken;
 use url::Url;
 
 use crate::cli::{
@@ -43,7 +46,8 @@ use crate::input::Console;
 use crate::model::{AppCommand, ForgeCommandManager};
 use crate::porcelain::Porcelain;
 use crate::prompt::ForgePrompt;
-use crate::state::UIState;
+use crate::shutdown::Shutdown;
+use crate::state::{PendingContext, UIState};
 use crate::stream_renderer::{SharedSpinner, StreamingWriter};
 use crate::sync_display::SyncProgressDisplay;
 use crate::title_display::TitleDisplayExt;
@@ -56,6 +60,9 @@ use crate::{TRACKER, banner, tracker};
 // File-specific constants
 const MISSING_AGENT_TITLE: &str = "<missing agent.title>";
 
+/// Delay between status checks while waiting for a batch to finish
+const BATCH_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
 /// Conversation dump format used by the /dump command
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 struct ConversationDump {
@@ -63,6 +70,24 @@ struct ConversationDump {
     related_conversations: Vec<Conversation>,
 }
 
+/// Parses batch input JSONL, where each non-empty line is
+/// `{"id": "...", "prompt": "..."}`.
+fn parse_batch_requests(content: &str) -> Result<Vec<BatchRequest>> {
+    let requests = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("Invalid batch request on line {}", index + 1))
+        })
+        .collect::<Result<Vec<BatchRequest>>>()?;
+    if requests.is_empty() {
+        anyhow::bail!("Batch input contains no requests");
+    }
+    Ok(requests)
+}
+
 /// Formats an MCP server config for display, redacting sensitive information.
 /// Returns the command/URL string only.
 fn format_mcp_server(server: &forge_domain::McpServerConfig) -> String {
@@ -104,6 +129,8 @@ fn format_mcp_headers(server: &forge_domain::McpServerConfig) -> Option<String>
 
 pub struct UI<A: ConsoleWriter, F: Fn(ForgeConfig) -> A> {
     markdown: MarkdownFormat,
+    /// Whether stdout is not a terminal, in which case output is unstyled
+    plain_output: bool,
     state: UIState,
     api: Arc<F::Output>,
     new_api: Arc<F>,
@@ -112,6 +139,7 @@ pub struct UI<A: ConsoleWriter, F: Fn(ForgeConfig) -> A> {
     cli: Cli,
     spinner: SharedSpinner<A>,
     config: ForgeConfig,
+    shutdown: Shutdown,
     #[allow(dead_code)] // The guard is kept alive by being held in the struct
     _guard: forge_tracker::Guard,
 }
@@ -205,7 +233,11 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
 
     // Handle creating a new conversation
     async fn on_new(&mut self) -> Result<()> {
-        let config = forge_config::ForgeConfig::read().unwrap_or_default();
+        let config = match &self.cli.workflow {
+            Some(path) => forge_config::ForgeConfig::read_from(path),
+            None => forge_config::ForgeConfig::read(),
+        }
+        .unwrap_or_default();
         self.config = config.clone();
         self.api = Arc::new((self.new_api)(config));
         self.init_state(false).await?;
@@ -277,6 +309,9 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
         let env = api.environment();
         let command = Arc::new(ForgeCommandManager::default());
         let spinner = SharedSpinner::new(SpinnerManager::new(api.clone()));
+        let log_file = cli.log_file.clone();
+        // Piped output is consumed by other tools, so skip ANSI styling
+        let plain_output = !std::io::stdout().is_terminal();
         Ok(Self {
             state: UIState::new(env.clone()),
             api,
@@ -289,9 +324,15 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             cli,
             command,
             spinner,
-            markdown: MarkdownFormat::new(),
+            markdown: if plain_output {
+                MarkdownFormat::plain()
+            } else {
+                MarkdownFormat::new()
+            },
+            plain_output,
             config,
-            _guard: forge_tracker::init_tracing(env.log_path(), TRACKER.clone())?,
+            shutdown: Shutdown::default(),
+            _guard: forge_tracker::init_tracing(env.log_path(), log_file, TRACKER.clone())?,
         })
     }
 
@@ -300,21 +341,18 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
         // Use the last message's usage for token count (context window size),
         // but replace cost with the accumulated session cost so the cost
         // shown reflects the total spend rather than just the last request.
-        let usage = if let Some(conversation_id) = &self.state.conversation_id {
-            self.api
-                .conversation(conversation_id)
-                .await
-                .ok()
-                .flatten()
-                .and_then(|conv| {
-                    conv.usage().map(|mut u| {
-                        u.cost = conv.accumulated_cost();
-                        u
-                    })
-                })
+        let conversation = if let Some(conversation_id) = &self.state.conversation_id {
+            self.api.conversation(conversation_id).await.ok().flatten()
         } else {
             None
         };
+        let usage = conversation.as_ref().and_then(|conv| {
+            conv.usage().map(|mut u| {
+                u.cost = conv.accumulated_cost();
+                u
+            })
+        });
+        let title = conversation.and_then(|conv| conv.title);
 
         // Prompt the user for input
         let agent_id = self.api.get_active_agent().await.unwrap_or_default();
@@ -322,6 +360,7 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             .get_agent_model(self.api.get_active_agent().await)
             .await;
         let reasoning_effort = self.api.get_reasoning_effort().await.ok().flatten();
+        let context_tokens = self.context_tokens(usage.as_ref(), model.as_ref()).await;
         let mut forge_prompt = ForgePrompt::new(self.state.cwd.clone(), agent_id);
         if let Some(u) = usage {
             forge_prompt.usage(u);
@@ -332,9 +371,39 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
         if let Some(e) = reasoning_effort {
             forge_prompt.reasoning_effort(e);
         }
+        if let Some(title) = title {
+            forge_prompt.title(title);
+        }
+        if let Some(context_tokens) = context_tokens {
+            forge_prompt.context_tokens(context_tokens);
+        }
         self.console.prompt(&mut forge_prompt).await
     }
 
+    /// Pairs the tokens in the current context window with the active
+    /// model's context length. Returns `None` before the first request or when
+    /// the model does not advertise a context length.
+    async fn context_tokens(
+        &self,
+        usage: Option<&Usage>,
+        model: Option<&ModelId>,
+    ) -> Option<(usize, usize)> {
+        let used = *usage?.total_tokens;
+        let model = model?;
+        if used == 0 {
+            return None;
+        }
+        let max = self
+            .api
+            .get_models()
+            .await
+            .ok()?
+            .into_iter()
+            .find(|m| &m.id == model)?
+            .context_length?;
+        Some((used, max as usize))
+    }
+
     pub async fn run(&mut self) {
         match self.run_inner().await {
             Ok(_) => {}
@@ -366,6 +435,7 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
 
         self.trace_user();
         self.hydrate_caches();
+        self.watch_agents();
         self.init_conversation().await?;
 
         // Check for dispatch flag first
@@ -373,6 +443,8 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             return self.handle_dispatch(dispatch_json).await;
         }
 
+        self.shutdown = Shutdown::install()?;
+
         // Handle direct prompt or piped input if provided (raw text messages)
         let input = self.cli.prompt.clone().or(self.cli.piped_input.clone());
         if let Some(input) = input {
@@ -422,6 +494,9 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                     }
 
                     self.spinner.stop(None)?;
+                    if self.shutdown.is_requested() {
+                        return Ok(());
+                    }
                 }
                 Err(error) => {
                     tracker::error(&error);
@@ -457,6 +532,25 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
         });
     }
 
+    // Reload custom agents in the background while they are being edited
+    fn watch_agents(&self) {
+        let api = self.api.clone();
+        let spinner = self.spinner.clone();
+        tokio::spawn(async move {
+            match api.watch_agents().await {
+                Ok(mut reloaded) => {
+                    while let Some(agent_id) = reloaded.next().await {
+                        let title = TitleFormat::info(format!("Agent reloaded: {agent_id}"));
+                        if let Err(error) = spinner.write_ln(title.display()) {
+                            tracing::warn!(%error, "Failed to report agent reload");
+                        }
+                    }
+                }
+                Err(error) => tracing::warn!(%error, "Failed to watch agent files"),
+            }
+        });
+    }
+
     async fn handle_generate_conversation_id(&mut self) -> Result<()> {
         let conversation_id = forge_domain::ConversationId::generate();
         println!("{}", conversation_id.into_string());
@@ -470,6 +564,10 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                     crate::cli::AgentCommand::List => {
                         self.on_show_agents(agent_group.porcelain, false).await?;
                     }
+                    crate::cli::AgentCommand::Inspect { id, system_prompt } => {
+                        self.on_inspect_agent(id, system_prompt, agent_group.porcelain)
+                            .await?;
+                    }
                 }
                 return Ok(());
             }
@@ -757,9 +855,11 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                         use_case,
                         starts_with,
                         ends_with,
+                        exclude,
                     } => {
-                        let mut params =
-                            forge_domain::SearchParams::new(&query, &use_case).limit(limit);
+                        let mut params = forge_domain::SearchParams::new(&query, &use_case)
+                            .limit(limit)
+                            .exclude_paths(exclude);
                         if let Some(k) = top_k {
                             params = params.top_k(k);
                         }
@@ -800,12 +900,21 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                 }
                 return Ok(());
             }
-            TopLevelCommand::Data(data_command_group) => {
-                let mut stream = self.api.generate_data(data_command_group.into()).await?;
-                while let Some(data) = stream.next().await {
-                    self.writeln(data?)?;
+            TopLevelCommand::Data(data_command_group) => match data_command_group.command.clone() {
+                Some(command) => {
+                    self.handle_data_batch_command(command).await?;
+                    return Ok(());
                 }
-            }
+                None => {
+                    let mut stream = self
+                        .api
+                        .generate_data(data_command_group.try_into()?)
+                        .await?;
+                    while let Some(data) = stream.next().await {
+                        self.writeln(data?)?;
+                    }
+                }
+            },
             TopLevelCommand::Vscode(vscode_command) => {
                 match vscode_command {
                     crate::cli::VscodeCommand::InstallExtension => {
@@ -814,9 +923,27 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                 }
                 return Ok(());
             }
+            TopLevelCommand::Profile(profile_group) => {
+                self.handle_profile_command(profile_group.command)?;
+                return Ok(());
+            }
+            TopLevelCommand::Context(context_group) => {
+                self.handle_context_command(context_group.command)?;
+                return Ok(());
+            }
+            TopLevelCommand::Rollback { conversation_id, dry_run } => {
+                self.handle_rollback(conversation_id, dry_run).await?;
+                return Ok(());
+            }
             TopLevelCommand::Update(args) => {
+                if args.unpin {
+                    self.api
+                        .update_config(vec![ConfigOperation::SetPinnedVersion(None)])
+                        .await?;
+                    self.writeln_title(TitleFormat::info("Removed pinned version"))?;
+                }
                 let update = forge_config::Update::default().auto_update(args.no_confirm);
-                on_update(self.api.clone(), Some(&update)).await;
+                on_update(self.api.clone(), Some(&update), true).await;
                 return Ok(());
             }
             TopLevelCommand::Setup => {
@@ -920,14 +1047,18 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             ConversationCommand::New => {
                 self.handle_generate_conversation_id().await?;
             }
-            ConversationCommand::Dump { id, html } => {
+            ConversationCommand::Dump { id, html, pdf } => {
                 self.validate_conversation_exists(&id).await?;
 
                 let original_id = self.state.conversation_id;
                 self.state.conversation_id = Some(id);
 
                 self.spinner.start(Some("Dumping"))?;
-                self.on_dump(html).await?;
+                if pdf {
+                    self.on_dump_pdf().await?;
+                } else {
+                    self.on_dump(html).await?;
+                }
 
                 self.state.conversation_id = original_id;
             }
@@ -1155,8 +1286,8 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
         use crate::cli::ProviderCommand;
 
         match provider_group.command {
-            ProviderCommand::Login { provider } => {
-                self.handle_provider_login(provider.as_ref()).await?;
+            ProviderCommand::Login { provider, alias } => {
+                self.handle_provider_login(provider.as_ref(), alias).await?;
             }
             ProviderCommand::Logout { provider } => {
                 self.handle_provider_logout(provider.as_ref()).await?;
@@ -1173,6 +1304,7 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
     async fn handle_provider_login(
         &mut self,
         provider_id: Option<&ProviderId>,
+        alias: Option<String>,
     ) -> anyhow::Result<()> {
         // Get the provider to login to
         let any_provider = if let Some(id) = provider_id {
@@ -1191,20 +1323,55 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             }
         };
 
+        // Activate the alias first so the new credential is stored under it,
+        // restoring the previous alias unless the login succeeds
+        let provider_key = any_provider.id().as_ref().to_string();
+        let previous_alias = self.config.provider_aliases.get(&provider_key).cloned();
+        let alias_changed = alias.is_some() && alias != previous_alias;
+        if alias_changed {
+            self.set_provider_alias(any_provider.id(), alias).await?;
+        }
+
         // For login, always configure (even if already configured) to allow
         // re-authentication
         let provider = match self
             .configure_provider(any_provider.id(), any_provider.auth_methods().to_vec())
-            .await?
+            .await
         {
-            Some(provider) => provider,
-            None => return Ok(()),
+            Ok(Some(provider)) => provider,
+            result => {
+                if alias_changed {
+                    self.set_provider_alias(any_provider.id(), previous_alias)
+                        .await?;
+                }
+                return result.map(|_| ());
+            }
         };
 
         // Set as default and handle model selection
         self.finalize_provider_activation(provider, None).await
     }
 
+    /// Activates `alias` as the provider's credential alias, or clears it
+    async fn set_provider_alias(
+        &mut self,
+        provider_id: ProviderId,
+        alias: Option<String>,
+    ) -> anyhow::Result<()> {
+        let provider_key = provider_id.as_ref().to_string();
+        self.api
+            .update_config(vec![ConfigOperation::SetProviderAlias(
+                provider_id,
+                alias.clone(),
+            )])
+            .await?;
+        match alias {
+            Some(alias) => self.config.provider_aliases.insert(provider_key, alias),
+            None => self.config.provider_aliases.remove(&provider_key),
+        };
+        Ok(())
+    }
+
     async fn handle_provider_logout(
         &mut self,
         provider_id: Option<&ProviderId>,
@@ -1276,6 +1443,8 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                 commit_group.max_diff_size,
                 commit_group.diff,
                 additional_context,
+                commit_group.scope.map(Into::into),
+                commit_group.sign,
             )
             .await;
 
@@ -1303,50 +1472,83 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
         }
 
         let mut info = Info::new();
-
         for agent in agents.iter() {
-            let id = agent.id.as_str().to_string();
-            let title = agent
-                .title
-                .as_deref()
-                .map(|title| title.lines().collect::<Vec<_>>().join(" "));
+            info = self.add_agent_info(info, agent).await;
+        }
 
-            // Get provider and model for this agent
-            let provider_name = match self.get_provider(Some(agent.id.clone())).await {
-                Ok(p) => p.id.to_string(),
-                Err(e) => format!("Error: [{}]", e),
-            };
+        Ok(info)
+    }
 
-            let model_name = agent.model.as_str().to_string();
+    /// Appends a section describing `agent` to `info`
+    async fn add_agent_info(&self, info: Info, agent: &forge_domain::Agent) -> Info {
+        let id = agent.id.as_str().to_string();
+        let title = agent
+            .title
+            .as_deref()
+            .map(|title| title.lines().collect::<Vec<_>>().join(" "));
+
+        // Get provider and model for this agent
+        let provider_name = match self.get_provider(Some(agent.id.clone())).await {
+            Ok(p) => p.id.to_string(),
+            Err(e) => format!("Error: [{}]", e),
+        };
 
-            let reasoning = if agent
-                .reasoning
-                .as_ref()
-                .and_then(|a| a.enabled)
-                .unwrap_or_default()
-            {
-                status::YES
-            } else {
-                status::NO
-            };
+        let model_name = agent.model.as_str().to_string();
 
-            let location = agent
-                .path
-                .as_ref()
-                .map(|s| s.to_string())
-                .unwrap_or_else(|| markers::BUILT_IN.to_string());
+        let reasoning = if agent
+            .reasoning
+            .as_ref()
+            .and_then(|a| a.enabled)
+            .unwrap_or_default()
+        {
+            status::YES
+        } else {
+            status::NO
+        };
 
-            info = info
-                .add_title(id.to_case(Case::UpperSnake))
-                .add_key_value("Id", id)
-                .add_key_value("Title", title)
-                .add_key_value("Location", location)
-                .add_key_value("Provider", provider_name)
-                .add_key_value("Model", model_name)
-                .add_key_value("Reasoning Enabled", reasoning);
+        let location = agent
+            .path
+            .as_ref()
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| markers::BUILT_IN.to_string());
+
+        info.add_title(id.to_case(Case::UpperSnake))
+            .add_key_value("Id", id)
+            .add_key_value("Title", title)
+            .add_key_value("Location", location)
+            .add_key_value("Provider", provider_name)
+            .add_key_value("Model", model_name)
+            .add_key_value("Reasoning Enabled", reasoning)
+    }
+
+    async fn on_inspect_agent(
+        &mut self,
+        id: AgentId,
+        system_prompt: bool,
+        porcelain: bool,
+    ) -> anyhow::Result<()> {
+        if system_prompt {
+            let prompt = self.api.get_agent_system_prompt(id).await?;
+            self.writeln(prompt)?;
+            return Ok(());
         }
 
-        Ok(info)
+        let agent = self
+            .api
+            .get_agents()
+            .await?
+            .into_iter()
+            .find(|agent| agent.id == id)
+            .ok_or_else(|| anyhow::anyhow!("Agent '{id}' not found"))?;
+        let info = self.add_agent_info(Info::new(), &agent).await;
+
+        if porcelain {
+            self.writeln(Porcelain::from(&info).into_long().skip(1))?;
+        } else {
+            self.writeln(info)?;
+        }
+
+        Ok(())
     }
 
     async fn on_show_agents(&mut self, porcelain: bool, custom: bool) -> anyhow::Result<()> {
@@ -1407,6 +1609,10 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             if configured {
                 info = info.add_key_value("logged in", status::YES);
             };
+            let aliases = self.api.get_provider_aliases(&provider.id()).await?;
+            if !aliases.is_empty() {
+                info = info.add_key_value("aliases", aliases.join(", "));
+            }
         }
 
         if porcelain {
@@ -1621,6 +1827,10 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                 info = info.add_key_value("path", format_display_path(&path, &env.cwd));
             }
 
+            if let Some(version) = skill.metadata.version {
+                info = info.add_key_value("version", version);
+            }
+
             info = info.add_key_value("description", skill.description);
         }
 
@@ -2247,7 +2457,7 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                 self.on_show_tools(agent_id, false).await?;
             }
             AppCommand::Update => {
-                on_update(self.api.clone(), None).await;
+                on_update(self.api.clone(), None, true).await;
             }
             AppCommand::Exit => {
                 return Ok(true);
@@ -2267,6 +2477,8 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                 let args = CommitCommandGroup {
                     preview: false,
                     max_diff_size: max_diff_size.or(Some(100_000)),
+                    scope: None,
+                    sign: false,
                     diff: None,
                     text: Vec::new(),
                 };
@@ -2283,7 +2495,7 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                 }
             }
             AppCommand::Login => {
-                self.handle_provider_login(None).await?;
+                self.handle_provider_login(None, None).await?;
             }
             AppCommand::Logout => {
                 return self.handle_provider_logout(None).await;
@@ -2350,6 +2562,8 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                 let args = CommitCommandGroup {
                     preview: true,
                     max_diff_size: Some(100_000),
+                    scope: None,
+                    sign: false,
                     diff: None,
                     text: Vec::new(),
                 };
@@ -2523,9 +2737,7 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             std::fs::File::create(&config_path)?;
         }
 
-        let editor = std::env::var("FORGE_EDITOR")
-            .or_else(|_| std::env::var("EDITOR"))
-            .unwrap_or_else(|_| "nano".to_string());
+        let editor = crate::editor::resolve_editor();
         let editor_binary = editor
             .split_whitespace()
             .next()
@@ -2537,7 +2749,7 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             .status()
             .map_err(|e| {
                 anyhow::anyhow!(
-                    "Failed to open editor '{}': {}. Set FORGE_EDITOR or EDITOR.",
+                    "Failed to open editor '{}': {}. Set FORGE_EDITOR, VISUAL or EDITOR.",
                     editor_binary,
                     e
                 )
@@ -2563,9 +2775,7 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
     async fn on_edit_buffer(&mut self, initial: Option<String>) -> anyhow::Result<()> {
         use std::io::Write as _;
 
-        let editor = std::env::var("FORGE_EDITOR")
-            .or_else(|_| std::env::var("EDITOR"))
-            .unwrap_or_else(|_| "nano".to_string());
+        let editor = crate::editor::resolve_editor();
 
         // Split the editor string into binary + pre-configured flags
         // (e.g. "code --wait" → binary="code", extra_args=["--wait"])
@@ -2591,7 +2801,7 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             .status()
             .map_err(|e| {
                 anyhow::anyhow!(
-                    "Failed to open editor '{}': {}. Set FORGE_EDITOR or EDITOR.",
+                    "Failed to open editor '{}': {}. Set FORGE_EDITOR, VISUAL or EDITOR.",
                     editor_binary,
                     e
                 )
@@ -3917,8 +4127,26 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             self.api
                 .set_active_agent(active_agent.clone().unwrap_or_default())
                 .await?;
+            // Persist a pin requested on the command line so later sessions
+            // keep skipping the update check
+            if let Some(version) = self.cli.pin_version.clone() {
+                self.api
+                    .update_config(vec![ConfigOperation::SetPinnedVersion(Some(
+                        version.clone(),
+                    ))])
+                    .await?;
+                self.config
+                    .updates
+                    .get_or_insert_with(Default::default)
+                    .pin_version = Some(version);
+            }
             // only call on_update if this is the first initialization
-            on_update(self.api.clone(), self.config.updates.as_ref()).await;
+            on_update(
+                self.api.clone(),
+                self.config.updates.as_ref(),
+                self.cli.is_interactive(),
+            )
+            .await;
             // Apply the MCP trust gate. Servers are NOT connected here —
             // connections remain lazy and happen on first tool use.
             self.api.init_mcp().await?;
@@ -3993,19 +4221,59 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             event = event.additional_context(piped);
         }
 
+        // Attach files queued with `forge context add`
+        let pending_path = self.api.environment().pending_context_path();
+        let pending = PendingContext::load(&pending_path)?.render()?;
+        let has_pending = pending.is_some();
+        if let Some(files) = pending {
+            let context = match event.additional_context.take() {
+                Some(existing) => format!("{existing}\n\n{files}"),
+                None => files,
+            };
+            event = event.additional_context(context);
+        }
+
         // Create the chat request with the event
-        let chat = ChatRequest::new(event, conversation_id);
+        let mut chat = ChatRequest::new(event, conversation_id);
+        if let Some(max_turns) = self.cli.max_turns {
+            chat = chat.max_requests(max_turns as usize);
+        }
 
-        self.on_chat(chat).await
+        self.on_chat(chat).await?;
+
+        // Pending files are only cleared once they were sent successfully
+        if has_pending {
+            PendingContext::default().save(&pending_path)?;
+        }
+        Ok(())
     }
 
     async fn on_chat(&mut self, chat: ChatRequest) -> Result<()> {
-        let mut stream = self.api.chat(chat).await?;
+        let cancel = CancellationToken::new();
+        let mut stream = self.api.chat(chat, cancel.clone()).await?;
 
         // Always use streaming content writer
-        let mut writer = StreamingWriter::new(self.spinner.clone(), self.api.clone());
+        let mut writer =
+            StreamingWriter::new(self.spinner.clone(), self.api.clone()).plain(self.plain_output);
 
-        while let Some(message) = stream.next().await {
+        // On SIGTERM the turn is cancelled rather than dropped, and the stream
+        // is drained so the conversation is saved before the process exits
+        let shutdown = self.shutdown.clone();
+        let terminated = shutdown.requested();
+        tokio::pin!(terminated);
+
+        loop {
+            let message = tokio::select! {
+                message = stream.next() => message,
+                _ = &mut terminated, if !cancel.is_cancelled() => {
+                    tracing::info!("Received SIGTERM, saving conversation before exiting");
+                    cancel.cancel();
+                    continue;
+                }
+            };
+            let Some(message) = message else {
+                break;
+            };
             match message {
                 Ok(message) => self.handle_chat_response(message, &mut writer).await?,
                 Err(err) => {
@@ -4048,6 +4316,46 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             .collect()
     }
 
+    /// Exports the current conversation, including related agent
+    /// conversations, as a PDF file
+    async fn on_dump_pdf(&mut self) -> Result<()> {
+        let Some(conversation_id) = self.state.conversation_id else {
+            return Ok(());
+        };
+        let Some(conversation) = self.api.conversation(&conversation_id).await? else {
+            return Ok(());
+        };
+
+        let timestamp = chrono::Local::now().format("%Y-%m-%d_%H-%M-%S");
+        let related_conversations = self.fetch_related_conversations(&conversation).await;
+        let html_content = if related_conversations.is_empty() {
+            conversation.to_html()
+        } else {
+            conversation.to_html_with_related(&related_conversations)
+        };
+
+        let path = format!("{timestamp}-dump.pdf");
+        let output = PathBuf::from(&path);
+        tokio::task::spawn_blocking(move || {
+            forge_domain::PdfRenderer::detect()?.render_html(&html_content, &output)
+        })
+        .await??;
+
+        let subtitle = if related_conversations.is_empty() {
+            path.to_string()
+        } else {
+            format!("{} (+ {} related)", path, related_conversations.len())
+        };
+        self.writeln_title(
+            TitleFormat::action("Conversation PDF dump created".to_string()).sub_title(subtitle),
+        )?;
+
+        if self.config.auto_open_dump {
+            open::that(path.as_str()).ok();
+        }
+        Ok(())
+    }
+
     /// Modified version of handle_dump that supports HTML format
     async fn on_dump(&mut self, html: bool) -> Result<()> {
         if let Some(conversation_id) = self.state.conversation_id {
@@ -4201,7 +4509,7 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                 writer.finish()?;
                 self.spinner.stop(None)?;
 
-                let title = match reason {
+                let title = match &reason {
                     InterruptionReason::MaxRequestPerTurnLimitReached { limit } => {
                         format!("Maximum request ({limit}) per turn achieved")
                     }
@@ -4211,7 +4519,17 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                 };
 
                 self.writeln_title(TitleFormat::action(title))?;
-                let continued = self.should_continue().await?;
+                // An explicit `--max-turns` is a hard stop, so don't offer to
+                // continue past it
+                let continued = if self.cli.max_turns.is_some()
+                    && matches!(
+                        reason,
+                        InterruptionReason::MaxRequestPerTurnLimitReached { .. }
+                    ) {
+                    false
+                } else {
+                    self.should_continue().await?
+                };
                 if !continued && let Some(conversation_id) = self.state.conversation_id {
                     self.writeln_title(
                         TitleFormat::debug("Finished").sub_title(conversation_id.into_string()),
@@ -4221,7 +4539,13 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             ChatResponse::TaskReasoning { content } => {
                 writer.write_dimmed(&content)?;
             }
-            ChatResponse::TaskComplete => {
+            ChatResponse::AutoCompacted { token_reduction, message_reduction } => {
+                writer.finish()?;
+                self.writeln_title(TitleFormat::action(format!(
+                    "Context auto-compacted: size reduced by {token_reduction:.1}% (tokens), {message_reduction:.1}% (messages)"
+                )))?;
+            }
+            ChatResponse::TaskComplete { .. } => {
                 writer.finish()?;
                 if let Some(conversation_id) = self.state.conversation_id {
                     self.writeln_title(
@@ -4396,7 +4720,7 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
         };
 
         if let Ok(Some(user_usage)) = self.api.user_usage().await {
-            info = info.extend(Info::from(&user_usage));
+            info = info.merge(Info::from(&user_usage));
         }
 
         self.writeln(info)?;
@@ -4434,10 +4758,167 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
             crate::cli::ConfigCommand::Migrate => {
                 self.handle_config_migrate()?;
             }
+            crate::cli::ConfigCommand::Validate => {
+                self.handle_config_validate()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Handle profile command
+    fn handle_profile_command(&mut self, command: crate::cli::ProfileCommand) -> Result<()> {
+        match command {
+            crate::cli::ProfileCommand::List => {
+                let path = forge_config::ConfigReader::profiles_path();
+                let mut names = match std::fs::read_dir(&path) {
+                    Ok(entries) => entries
+                        .filter_map(|entry| entry.ok())
+                        .filter(|entry| entry.path().is_dir())
+                        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                        .collect::<Vec<_>>(),
+                    Err(_) => Vec::new(),
+                };
+                names.sort();
+                if names.is_empty() {
+                    self.writeln_title(TitleFormat::info("No profiles found"))?;
+                }
+                for name in names {
+                    self.writeln(name)?;
+                }
+            }
+            crate::cli::ProfileCommand::Create { name } => {
+                let path = forge_config::ConfigReader::profile_path(&name)?;
+                if path.exists() {
+                    anyhow::bail!("Profile '{name}' already exists at {}", path.display());
+                }
+                std::fs::create_dir_all(&path)?;
+                std::fs::write(path.join(".forge.toml"), "")?;
+                self.writeln_title(TitleFormat::info(format!(
+                    "Created profile '{name}' at {}",
+                    path.display()
+                )))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Submit a batch of prompts, or wait for a submitted batch to finish and
+    /// print its results as JSONL.
+    async fn handle_data_batch_command(&mut self, command: crate::cli::DataCommand) -> Result<()> {
+        match command {
+            crate::cli::DataCommand::BatchSubmit { input_file, output_file } => {
+                let content = tokio::fs::read_to_string(&input_file)
+                    .await
+                    .with_context(|| format!("Failed to read {}", input_file.display()))?;
+                let requests = parse_batch_requests(&content)?;
+                let count = requests.len();
+
+                self.spinner.start(Some("Submitting batch"))?;
+                let batch_id = self.api.submit_batch(requests).await;
+                self.spinner.stop(None)?;
+                let batch_id = batch_id?;
+
+                tokio::fs::write(&output_file, &batch_id)
+                    .await
+                    .with_context(|| format!("Failed to write {}", output_file.display()))?;
+                self.writeln_title(TitleFormat::info(format!(
+                    "Submitted {count} prompt(s) as batch {batch_id}"
+                )))?;
+            }
+            crate::cli::DataCommand::BatchPoll { batch_id } => {
+                self.spinner.start(Some("Waiting for batch"))?;
+                let results = loop {
+                    match self.api.poll_batch(&batch_id).await {
+                        Ok(BatchStatus::Ended(results)) => break Ok(results),
+                        Ok(BatchStatus::InProgress) => {
+                            tokio::time::sleep(BATCH_POLL_INTERVAL).await
+                        }
+                        Err(error) => break Err(error),
+                    }
+                };
+                self.spinner.stop(None)?;
+                for result in results? {
+                    self.writeln(serde_json::to_string(&result)?)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Queue files for, or clear files from, the next message.
+    fn handle_context_command(&mut self, command: crate::cli::ContextCommand) -> Result<()> {
+        let path = self.api.environment().pending_context_path();
+        match command {
+            crate::cli::ContextCommand::Add { paths } => {
+                let mut pending = PendingContext::load(&path)?;
+                let added = pending.add(&paths)?;
+                pending.save(&path)?;
+                self.writeln_title(TitleFormat::info(format!(
+                    "Added {added} file(s) to context"
+                )))?;
+            }
+            crate::cli::ContextCommand::Clear => {
+                PendingContext::default().save(&path)?;
+                self.writeln_title(TitleFormat::info("Cleared pending context"))?;
+            }
         }
         Ok(())
     }
 
+    /// Restore the files modified during the last turn of a conversation,
+    /// defaulting to the most recent conversation.
+    async fn handle_rollback(
+        &mut self,
+        conversation_id: Option<ConversationId>,
+        dry_run: bool,
+    ) -> Result<()> {
+        let conversation_id = match conversation_id {
+            Some(id) => id,
+            None => self
+                .api
+                .last_conversation()
+                .await?
+                .map(|conversation| conversation.id)
+                .context("No conversation found to roll back")?,
+        };
+
+        let mut paths = self.api.rollback(&conversation_id, dry_run).await?;
+        paths.sort();
+
+        if paths.is_empty() {
+            self.writeln_title(TitleFormat::info("No file modifications to roll back"))?;
+            return Ok(());
+        }
+
+        let title = if dry_run {
+            format!("{} file(s) would be restored", paths.len())
+        } else {
+            format!("Restored {} file(s)", paths.len())
+        };
+        self.writeln_title(TitleFormat::info(title))?;
+        for path in paths {
+            self.writeln(path.display().to_string())?;
+        }
+        Ok(())
+    }
+
+    /// Validate the loaded configuration, printing every problem found.
+    ///
+    /// Errors if any problem is found so the process exits with a non-zero
+    /// status.
+    fn handle_config_validate(&mut self) -> Result<()> {
+        let errors = forge_app::validate_config(&self.config);
+        if errors.is_empty() {
+            self.writeln_title(TitleFormat::info("Configuration is valid"))?;
+            return Ok(());
+        }
+
+        for error in &errors {
+            self.writeln(error.to_string())?;
+        }
+        anyhow::bail!("Found {} configuration error(s)", errors.len())
+    }
+
     /// Rename `~/forge` to `~/.forge`.
     ///
     /// Errors if the legacy directory does not exist, if the new directory
@@ -4524,6 +5005,23 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
                         .sub_title("is now the reasoning effort"),
                 )?;
             }
+            ConfigSetField::ProviderAlias { assignment } => {
+                let provider = assignment.provider;
+                let alias = assignment
+                    .alias
+                    .clone()
+                    .unwrap_or_else(|| "default".to_string());
+                self.api
+                    .update_config(vec![ConfigOperation::SetProviderAlias(
+                        provider.clone(),
+                        assignment.alias,
+                    )])
+                    .await?;
+                self.writeln_title(
+                    TitleFormat::action(alias)
+                        .sub_title(format!("is now the credential for provider '{provider}'")),
+                )?;
+            }
         }
 
         Ok(())
@@ -4755,7 +5253,7 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
         }
 
         let mut stream = self.api.sync_workspace(path.clone()).await?;
-        let mut progress_bar = ProgressBarManager::default();
+        let mut progress_bar = ProgressBarManager::default().with_eta(true);
 
         while let Some(event) = stream.next().await {
             match event {
@@ -5174,7 +5672,7 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
     }
 
     /// Silently install VS Code extension if in VS Code and extension not
-    /// installed.
+    /// installed, or update it when the installed version is outdated.
     /// NOTE: This is a non-cancellable and a slow task. We should only run this
     /// if the user has provided a prompt because that is guaranteed to run for
     /// at least a few seconds.
@@ -5182,6 +5680,10 @@ impl<A: API + ConsoleWriter + 'static, F: Fn(ForgeConfig) -> A + Send + Sync> UI
         tokio::task::spawn_blocking(|| {
             if crate::vscode::should_install_extension() {
                 let _ = crate::vscode::install_extension();
+            } else if crate::vscode::is_vscode_terminal()
+                && crate::vscode::should_update_extension()
+            {
+                let _ = crate::vscode::update_extension();
             }
         });
     }
@@ -5193,4 +5695,27 @@ mod tests {
     // ForgeSelect::confirm is not easily mockable in the current
     // architecture. The functionality is tested through integration tests
     // instead.
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_batch_requests() {
+        let fixture = "{\"id\":\"1\",\"prompt\":\"a\"}\n\n{\"id\":\"2\",\"prompt\":\"b\"}\n";
+        let actual = parse_batch_requests(fixture).unwrap();
+        let expected = vec![
+            BatchRequest { id: "1".to_string(), prompt: "a".to_string() },
+            BatchRequest { id: "2".to_string(), prompt: "b".to_string() },
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_batch_requests_reports_line() {
+        let fixture = "{\"id\":\"1\",\"prompt\":\"a\"}\nnot json";
+        let actual = parse_batch_requests(fixture).unwrap_err().to_string();
+        let expected = "Invalid batch request on line 2";
+        assert_eq!(actual, expected);
+    }
 }
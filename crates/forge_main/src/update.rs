@@ -5,6 +5,7 @@ use forge_api::API;
 use forge_config::{Update, UpdateFrequency};
 use forge_select::ForgeWidget;
 use forge_tracker::VERSION;
+use update_informer::http_client::{DefaultHttpClient, HttpClient};
 use update_informer::{Check, Version, registry};
 
 /// Runs the official installation script to update Forge, failing silently.
@@ -69,32 +70,73 @@ fn should_check_for_updates(frequency: &UpdateFrequency) -> bool {
     !matches!(frequency, UpdateFrequency::Never)
 }
 
-/// Checks if there is an update available
-pub async fn on_update(api: Arc<impl API>, update: Option<&Update>) {
-    let update = update.cloned().unwrap_or_default();
-    let frequency = update.frequency.unwrap_or_default();
+/// Returns the version updates are pinned to, ignoring blank values
+fn pinned_version(update: &Update) -> Option<&str> {
+    update
+        .pin_version
+        .as_deref()
+        .map(str::trim)
+        .filter(|version| !version.is_empty())
+}
 
-    if !should_check_for_updates(&frequency) {
-        return;
+/// Describes a skipped update check, noting when the running version is not
+/// the pinned one
+fn pin_message(pinned: &str, current: &str) -> String {
+    if pinned.trim_start_matches('v') == current.trim_start_matches('v') {
+        format!("Forge is pinned to {pinned}, skipping update check")
+    } else {
+        format!("Forge {current} does not match pinned version {pinned}, skipping update check")
     }
+}
 
+/// Checks if there is an update available. The message shown for a pinned
+/// version goes to stderr, and only when `interactive`, so it never mixes
+/// with the output of a one-shot prompt.
+pub async fn on_update(api: Arc<impl API>, update: Option<&Update>, interactive: bool) {
+    let update = update.cloned().unwrap_or_default();
     let auto_update = update.auto_update.unwrap_or_default();
 
+    if let Some(version) = check_for_update(&update, interactive, DefaultHttpClient {})
+        && (auto_update || confirm_update(version).await)
+    {
+        execute_update_command(api, auto_update).await;
+    }
+}
+
+/// Looks up the latest release with `client`, returning `None` when there is
+/// no newer one or the check is skipped
+fn check_for_update<C: HttpClient>(
+    update: &Update,
+    interactive: bool,
+    client: C,
+) -> Option<Version> {
+    // A pinned version skips the check entirely, so no request is made
+    if let Some(version) = pinned_version(update) {
+        if interactive {
+            eprintln!("{}", pin_message(version, VERSION).dimmed());
+        }
+        return None;
+    }
+
+    let frequency = update.frequency.clone().unwrap_or_default();
+
+    if !should_check_for_updates(&frequency) {
+        return None;
+    }
+
     // Check if version is development version, in which case we skip the update
     // check
     if VERSION.contains("dev") || VERSION == "0.1.0" {
         // Skip update for development version 0.1.0
-        return;
+        return None;
     }
 
-    let informer = update_informer::new(registry::GitHub, "tailcallhq/forgecode", VERSION)
-        .interval(frequency.into());
-
-    if let Some(version) = informer.check_version().ok().flatten()
-        && (auto_update || confirm_update(version).await)
-    {
-        execute_update_command(api, auto_update).await;
-    }
+    update_informer::new(registry::GitHub, "tailcallhq/forgecode", VERSION)
+        .interval(frequency.into())
+        .http_client(client)
+        .check_version()
+        .ok()
+        .flatten()
 }
 
 /// Sends an event to the tracker when an update fails
@@ -106,7 +148,12 @@ async fn send_update_failure_event(error_msg: &str) -> anyhow::Result<()> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
     use pretty_assertions::assert_eq;
+    use serde::de::DeserializeOwned;
+    use update_informer::http_client::HeaderMap;
 
     use super::*;
 
@@ -119,4 +166,66 @@ mod tests {
         let expected = false;
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_pinned_version() {
+        let fixture = Update::default().pin_version("1.2.3");
+
+        let actual = pinned_version(&fixture);
+
+        let expected = Some("1.2.3");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_pinned_version_ignores_blank_value() {
+        let fixture = Update::default().pin_version("  ");
+
+        let actual = pinned_version(&fixture);
+
+        let expected = None;
+        assert_eq!(actual, expected);
+    }
+
+    static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+    /// Counts requests instead of sending them.
+    struct CountingHttpClient;
+
+    impl HttpClient for CountingHttpClient {
+        fn get<T: DeserializeOwned>(
+            _url: &str,
+            _timeout: Duration,
+            _headers: HeaderMap,
+        ) -> update_informer::Result<T> {
+            REQUESTS.fetch_add(1, Ordering::SeqCst);
+            Err("unexpected request".into())
+        }
+    }
+
+    #[test]
+    fn test_pinned_version_skips_update_request() {
+        let fixture = Update::default()
+            .pin_version("1.2.3")
+            .frequency(UpdateFrequency::Always);
+
+        let actual = check_for_update(&fixture, false, CountingHttpClient);
+
+        assert!(actual.is_none());
+        assert_eq!(REQUESTS.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_pin_message_for_running_pinned_version() {
+        let actual = pin_message("v1.2.3", "1.2.3");
+        let expected = "Forge is pinned to v1.2.3, skipping update check";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_pin_message_for_mismatched_version() {
+        let actual = pin_message("1.2.3", "1.4.0");
+        let expected = "Forge 1.4.0 does not match pinned version 1.2.3, skipping update check";
+        assert_eq!(actual, expected);
+    }
 }
@@ -2,6 +2,14 @@
 
 use std::process::Command;
 
+/// Marketplace identifier of the Forge VS Code extension
+const EXTENSION_ID: &str = "ForgeCode.forge-vscode";
+
+/// Oldest extension version this release of Forge works with; older installs
+/// are updated automatically. The extension is versioned separately from
+/// Forge, so bump this only when Forge starts relying on a newer extension.
+const MIN_EXTENSION_VERSION: &str = "1.0.0";
+
 /// Checks if running in VS Code terminal
 ///
 /// Detects VS Code by checking for environment variables that are set
@@ -25,9 +33,7 @@ pub fn is_extension_installed() -> bool {
         && output.status.success()
         && let Ok(extensions) = String::from_utf8(output.stdout)
     {
-        return extensions
-            .lines()
-            .any(|line| line.trim() == "ForgeCode.forge-vscode");
+        return extensions.lines().any(|line| line.trim() == EXTENSION_ID);
     }
     false
 }
@@ -39,13 +45,77 @@ pub fn is_extension_installed() -> bool {
 pub fn install_extension() -> Result<bool, std::io::Error> {
     let output = Command::new("code")
         .arg("--install-extension")
-        .arg("ForgeCode.forge-vscode")
+        .arg(EXTENSION_ID)
         .arg("--force")
         .output()?;
 
     Ok(output.status.success())
 }
 
+/// Returns the installed version of the Forge extension reported by
+/// `<program> --list-extensions --show-versions`, if any
+fn installed_extension_version(program: &str) -> Option<String> {
+    let output = Command::new(program)
+        .arg("--list-extensions")
+        .arg("--show-versions")
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8(output.stdout)
+        .ok()?
+        .lines()
+        .find_map(|line| {
+            line.trim()
+                .split_once('@')
+                .filter(|(id, _)| *id == EXTENSION_ID)
+                .map(|(_, version)| version.to_string())
+        })
+}
+
+/// Returns true if the dotted `installed` version is older than `expected`.
+/// Missing or non-numeric components are treated as zero.
+fn is_older_version(installed: &str, expected: &str) -> bool {
+    let parse = |version: &str| -> Vec<u64> {
+        version
+            .split('.')
+            .map(|part| part.parse().unwrap_or_default())
+            .collect()
+    };
+    let (installed, expected) = (parse(installed), parse(expected));
+    let len = installed.len().max(expected.len());
+    let component = |parts: &[u64], index: usize| parts.get(index).copied().unwrap_or_default();
+
+    (0..len)
+        .map(|index| (component(&installed, index), component(&expected, index)))
+        .find(|(installed, expected)| installed != expected)
+        .is_some_and(|(installed, expected)| installed < expected)
+}
+
+fn should_update_extension_with(program: &str, minimum: &str) -> bool {
+    installed_extension_version(program).is_some_and(|version| is_older_version(&version, minimum))
+}
+
+/// Returns true if an installed Forge extension is older than the minimum
+/// version this release works with
+pub fn should_update_extension() -> bool {
+    if cfg!(windows) {
+        return false;
+    }
+
+    should_update_extension_with("code", MIN_EXTENSION_VERSION)
+}
+
+/// Updates the installed Forge VS Code extension to the latest version
+///
+/// Returns Ok(true) if the update was successful, Ok(false) if it failed,
+/// or Err if the command couldn't be executed.
+pub fn update_extension() -> Result<bool, std::io::Error> {
+    install_extension()
+}
+
 /// Returns true if we should install the extension
 ///
 /// This will return true only when:
@@ -66,6 +136,8 @@ mod tests {
     use std::ffi::OsString;
     use std::sync::{LazyLock, Mutex, MutexGuard};
 
+    use pretty_assertions::assert_eq;
+
     use super::*;
 
     static ENV_MUTEX: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
@@ -136,6 +208,57 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_is_older_version() {
+        let actual = (
+            is_older_version("0.9.3", "1.0.0"),
+            is_older_version("1.0", "1.0.1"),
+            is_older_version("1.0.0", "1.0.0"),
+            is_older_version("1.2.0", "1.0.9"),
+        );
+        let expected = (true, true, false, false);
+        assert_eq!(actual, expected);
+    }
+
+    /// Writes a fake `code` program listing the Forge extension at `version`.
+    #[cfg(unix)]
+    fn fixture_code_program(dir: &std::path::Path, version: &str) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let program = dir.join("code");
+        std::fs::write(
+            &program,
+            format!(
+                "#!/bin/sh\necho 'rust-lang.rust-analyzer@0.3.2'\necho 'ForgeCode.forge-vscode@{version}'\n"
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&program, std::fs::Permissions::from_mode(0o755)).unwrap();
+        program
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_should_update_extension_with_older_version_installed() {
+        let fixture = tempfile::tempdir().unwrap();
+        let program = fixture_code_program(fixture.path(), "0.1.0");
+
+        let actual = should_update_extension_with(program.to_str().unwrap(), "1.0.0");
+
+        assert!(actual);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_should_not_update_extension_newer_than_minimum() {
+        let fixture = tempfile::tempdir().unwrap();
+        let program = fixture_code_program(fixture.path(), "2.3.0");
+
+        let actual = should_update_extension_with(program.to_str().unwrap(), MIN_EXTENSION_VERSION);
+
+        assert!(!actual);
+    }
+
     #[test]
     fn test_should_install_when_in_vscode() {
         with_env_var("TERM_PROGRAM", "vscode", || {
@@ -0,0 +1,69 @@
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+const PROFILE_CONFIG: &str = r#"
+[session]
+provider_id = "profile_provider"
+model_id = "profile-model"
+
+[[providers]]
+id = "profile_provider"
+url = "http://127.0.0.1:1/chat/completions"
+response_type = "OpenAI"
+
+[[providers.models]]
+id = "profile-model"
+tools_supported = true
+"#;
+
+/// Runs the forge binary with `args`, keeping its home and config directory
+/// inside `dir`.
+fn forge(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_forge"))
+        .args(args)
+        .current_dir(dir)
+        .env_clear()
+        .env("PATH", std::env::var_os("PATH").unwrap_or_default())
+        .env("HOME", dir.join("home"))
+        .env("FORGE_CONFIG", dir.join(".forge"))
+        .env("NO_COLOR", "1")
+        .stdin(Stdio::null())
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_profile_flag_uses_profile_configuration() {
+    let fixture = tempfile::tempdir().unwrap();
+    let profile = fixture.path().join(".forge/profiles/test-profile");
+    std::fs::create_dir_all(&profile).unwrap();
+    std::fs::create_dir_all(fixture.path().join("home")).unwrap();
+    std::fs::write(profile.join(".forge.toml"), PROFILE_CONFIG).unwrap();
+    std::fs::write(
+        profile.join(".credentials.json"),
+        r#"[{"id": "profile_provider", "auth_details": {"api_key": "test-key"}}]"#,
+    )
+    .unwrap();
+
+    let actual = forge(
+        fixture.path(),
+        &["--profile", "test-profile", "list", "model"],
+    );
+
+    let stdout = String::from_utf8(actual.stdout.clone()).unwrap();
+    assert!(actual.status.success(), "{actual:?}");
+    assert!(stdout.contains("profile-model"), "{stdout}");
+    assert!(stdout.contains("profile_provider"), "{stdout}");
+}
+
+#[test]
+fn test_profile_flag_rejects_missing_profile() {
+    let fixture = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(fixture.path().join(".forge/profiles")).unwrap();
+
+    let actual = forge(fixture.path(), &["--profile", "missing", "list", "model"]);
+
+    let stderr = String::from_utf8(actual.stderr.clone()).unwrap();
+    assert!(!actual.status.success());
+    assert!(stderr.contains("Profile 'missing' not found"), "{stderr}");
+}
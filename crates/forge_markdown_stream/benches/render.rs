@@ -0,0 +1,78 @@
+//! Rendering throughput benchmarks for the streaming markdown renderer.
+//!
+//! Run with `cargo bench -p forge_markdown_stream --bench render`. Each
+//! scenario streams its tokens into a fresh renderer. To compare against an
+//! earlier revision, save a baseline there with `-- --save-baseline main`, run
+//! the benches again with `-- --baseline main`, and check the result with
+//! `scripts/bench-regression.sh`.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use forge_markdown_stream::{StreamdownRenderer, Theme};
+
+/// 100 tokens of plain prose, split the way a model streams words
+fn plain_text_tokens() -> Vec<String> {
+    (0..100)
+        .map(|i| match i % 20 {
+            19 => "sentence.\n".to_string(),
+            _ => format!("word{i} "),
+        })
+        .collect()
+}
+
+/// A 50-row table streamed one cell at a time
+fn table_tokens() -> Vec<String> {
+    let mut tokens = vec![
+        "| Name | Status | Notes |\n".to_string(),
+        "|------|--------|-------|\n".to_string(),
+    ];
+    for row in 0..50 {
+        tokens.push(format!("| item {row} "));
+        tokens.push(format!(
+            "| **{}** ",
+            if row % 2 == 0 { "done" } else { "todo" }
+        ));
+        tokens.push(format!("| a note about row {row} with `code` |\n"));
+    }
+    tokens.push("\n".to_string());
+    tokens
+}
+
+/// A 200-line Rust code block streamed one line at a time
+fn code_block_tokens() -> Vec<String> {
+    let mut tokens = vec!["```rust\n".to_string()];
+    for line in 0..200 {
+        tokens.push(format!(
+            "    let value_{line} = compute(\"input {line}\", {line}).unwrap_or_default();\n"
+        ));
+    }
+    tokens.push("```\n".to_string());
+    tokens
+}
+
+fn render(tokens: &[String]) -> usize {
+    let mut output = Vec::new();
+    let mut renderer = StreamdownRenderer::with_theme(&mut output, 100, Theme::dark());
+    for token in tokens {
+        renderer.push(black_box(token)).unwrap();
+    }
+    renderer.finish().unwrap();
+    output.len()
+}
+
+fn bench_render(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render");
+    for (name, tokens) in [
+        ("plain_text", plain_text_tokens()),
+        ("table", table_tokens()),
+        ("code_block", code_block_tokens()),
+    ] {
+        group.throughput(Throughput::Elements(tokens.len() as u64));
+        group.bench_function(name, |b| b.iter(|| render(&tokens)));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_render);
+criterion_main!(benches);
@@ -0,0 +1,57 @@
+//! Feeds arbitrary streaming tokens through `repair_line`, the parser and the
+//! renderer. Any panic is a bug.
+//!
+//! Run with `cargo +nightly fuzz run repair_line` from the crate directory.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use forge_markdown_stream::{Parser, StreamdownRenderer, repair_line};
+use libfuzzer_sys::fuzz_target;
+use streamdown_core::{BlockType, Code, ParseState};
+
+/// The subset of parser state `repair_line` looks at
+#[derive(Arbitrary, Debug)]
+enum StateInput {
+    Text,
+    Code { language: Option<String> },
+    Think,
+}
+
+impl StateInput {
+    fn into_state(self) -> ParseState {
+        let mut state = ParseState::new();
+        match self {
+            Self::Text => {}
+            Self::Code { language } => state.enter_code_block(Code::Backtick, language),
+            Self::Think => state.enter_block(BlockType::Think),
+        }
+        state
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    state: StateInput,
+    line: String,
+    tokens: Vec<String>,
+    width: u8,
+}
+
+fuzz_target!(|input: Input| {
+    // A single line against an arbitrary state, then through the parser
+    let state = input.state.into_state();
+    let mut parser = Parser::new();
+    for repaired in repair_line(&input.line, &state) {
+        let _ = parser.parse_line(&repaired);
+    }
+    let _ = parser.finalize();
+
+    // The whole pipeline with tokens split however the model sends them
+    let mut output = Vec::new();
+    let mut renderer = StreamdownRenderer::new(&mut output, usize::from(input.width));
+    for token in &input.tokens {
+        let _ = renderer.push(token);
+    }
+    let _ = renderer.finish();
+});
@@ -6,10 +6,15 @@ use syntect::highlighting::ThemeSet;
 use syntect::parsing::SyntaxSet;
 use syntect::util::as_24_bit_terminal_escaped;
 
+use crate::theme::Theme;
 use crate::utils::{ThemeMode, detect_theme_mode};
 
 const RESET: &str = "\x1b[0m";
 
+/// Code block language holding LaTeX math, used for ```` ```math ```` fences
+/// and for `$$` blocks rewritten by [`crate::repair_line`].
+pub const MATH_LANGUAGE: &str = "math";
+
 /// Code block highlighter using syntect.
 pub struct CodeHighlighter {
     syntax_set: SyntaxSet,
@@ -55,21 +60,28 @@ impl CodeHighlighter {
 
     /// Render a code line with margin, wrapping if needed.
     ///
-    /// Returns multiple lines if the code exceeds the available width.
+    /// Returns multiple lines if the code exceeds the available width. Lines
+    /// are left unhighlighted when the theme has `no_color` set.
     pub fn render_code_line(
         &self,
         line: &str,
         language: Option<&str>,
         margin: &str,
         width: usize,
+        theme: &Theme,
     ) -> Vec<String> {
         // Use code_wrap with pretty_broken=true for line wrapping
         let (indent, wrapped_lines) = code_wrap(line, width, true);
 
+        let reset = if theme.no_color { "" } else { RESET };
         let mut result = Vec::new();
 
         for (i, code_line) in wrapped_lines.iter().enumerate() {
-            let highlighted = self.highlight_line(code_line, language);
+            let highlighted = if theme.no_color {
+                code_line.to_string()
+            } else {
+                self.highlight_line(code_line, language)
+            };
 
             // Add continuation indent for wrapped lines
             let line_indent = if i == 0 {
@@ -78,17 +90,44 @@ impl CodeHighlighter {
                 &"  ".repeat(indent.min(4) / 2 + 1)
             };
 
-            result.push(format!("{}{}{}{}", margin, line_indent, highlighted, RESET));
+            result.push(format!("{}{}{}{}", margin, line_indent, highlighted, reset));
         }
 
         if result.is_empty() {
-            result.push(format!("{}{}", margin, RESET));
+            result.push(format!("{}{}", margin, reset));
         }
 
         result
     }
 }
 
+/// Render the top border of a math block.
+///
+/// LaTeX can't be typeset in a terminal, so math blocks show their raw source
+/// in a labelled box where it stays easy to copy.
+pub fn render_math_header(margin: &str, theme: &Theme) -> String {
+    format!(
+        "{}{}",
+        margin,
+        theme.code_block_lang.apply("┌─ [Math: LaTeX] ─")
+    )
+}
+
+/// Render a line of LaTeX source inside a math block, wrapping if needed.
+pub fn render_math_line(line: &str, margin: &str, width: usize, theme: &Theme) -> Vec<String> {
+    let border = theme.code_block_lang.apply("│");
+    let (_, wrapped_lines) = code_wrap(line, width.saturating_sub(2), true);
+    wrapped_lines
+        .iter()
+        .map(|math| format!("{}{} {}", margin, border, theme.math_block.apply(math)))
+        .collect()
+}
+
+/// Render the bottom border of a math block.
+pub fn render_math_footer(margin: &str, theme: &Theme) -> String {
+    format!("{}{}", margin, theme.code_block_lang.apply("└"))
+}
+
 #[cfg(test)]
 mod tests {
     use streamdown_render::code::code_wrap;
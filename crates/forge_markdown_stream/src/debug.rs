@@ -0,0 +1,136 @@
+//! Snapshot of the streaming parser's state for debugging.
+
+use std::fmt;
+
+use streamdown_core::BlockType;
+use streamdown_parser::Parser;
+
+/// Which kind of block the parser is currently inside.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParserState {
+    /// Regular paragraph text, not inside any block.
+    Text,
+    /// Inside a fenced or indented code block.
+    InCodeBlock,
+    /// Inside a table.
+    InTable,
+    /// Inside a blockquote.
+    InBlockquote,
+    /// Inside a `<think>` block.
+    InThink,
+}
+
+impl fmt::Display for ParserState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Text => "text",
+            Self::InCodeBlock => "in code block",
+            Self::InTable => "in table",
+            Self::InBlockquote => "in blockquote",
+            Self::InThink => "in think block",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Parser and renderer state at a point in the stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParserDebugInfo {
+    /// Block the parser is currently inside.
+    pub state: ParserState,
+    /// Text received since the last newline, not yet parsed.
+    pub line_buffer: String,
+    /// Level of the most recently rendered heading.
+    pub heading_level: Option<u8>,
+    /// Language of the open code fence.
+    pub code_language: Option<String>,
+    /// Nesting depth of the open list, 0 outside of lists.
+    pub list_depth: usize,
+}
+
+impl ParserDebugInfo {
+    pub(crate) fn new(parser: &Parser, line_buffer: &str, heading_level: Option<u8>) -> Self {
+        let parse_state = parser.state();
+        let state = if parse_state.is_in_code() {
+            ParserState::InCodeBlock
+        } else if parse_state.is_in_table() {
+            ParserState::InTable
+        } else {
+            match parse_state.block_type {
+                Some(BlockType::Quote) => ParserState::InBlockquote,
+                Some(BlockType::Think) => ParserState::InThink,
+                None => ParserState::Text,
+            }
+        };
+
+        Self {
+            state,
+            line_buffer: line_buffer.to_string(),
+            heading_level,
+            code_language: parse_state.code_language.clone(),
+            list_depth: parse_state.list_depth(),
+        }
+    }
+}
+
+impl fmt::Display for ParserDebugInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "state: {}", self.state)?;
+        writeln!(f, "line buffer: {:?}", self.line_buffer)?;
+        match self.heading_level {
+            Some(level) => writeln!(f, "heading level: {level}")?,
+            None => writeln!(f, "heading level: none")?,
+        }
+        writeln!(
+            f,
+            "code language: {}",
+            self.code_language.as_deref().unwrap_or("none")
+        )?;
+        write!(f, "list depth: {}", self.list_depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::StreamdownRenderer;
+
+    #[test]
+    fn test_debug_state_inside_open_code_fence() {
+        let mut fixture = StreamdownRenderer::new(Vec::new(), 80);
+        fixture
+            .push("## Setup\n```rust\nfn main() {\nlet x")
+            .unwrap();
+
+        let actual = fixture.debug_state();
+
+        let expected = ParserDebugInfo {
+            state: ParserState::InCodeBlock,
+            line_buffer: "let x".to_string(),
+            heading_level: Some(2),
+            code_language: Some("rust".to_string()),
+            list_depth: 0,
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_debug_state_display() {
+        let mut fixture = StreamdownRenderer::new(Vec::new(), 80);
+        fixture.push("- one\n  - two\n").unwrap();
+
+        let actual = fixture.debug_state().to_string();
+
+        let expected = [
+            "state: text",
+            "line buffer: \"\"",
+            "heading level: none",
+            "code language: none",
+            "list depth: 2",
+        ]
+        .join("\n");
+        assert_eq!(actual, expected);
+    }
+}
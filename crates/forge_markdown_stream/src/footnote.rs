@@ -0,0 +1,276 @@
+//! Footnotes: references are numbered in order of appearance and definitions
+//! are collected while streaming, to be shown after the rest of the document.
+//!
+//! A line referencing a label that has no definition yet is held back, along
+//! with every line after it, until the definition arrives. References still
+//! undefined when the document ends are shown as written.
+
+use crate::repair::code_span_len;
+
+/// Footnote labels, definitions and held lines seen so far.
+#[derive(Default)]
+pub struct Footnotes {
+    /// Labels in order of appearance, a label's number is its index plus one
+    labels: Vec<String>,
+    /// Definition label and text in order of arrival
+    definitions: Vec<(String, String)>,
+    /// Index of the definition that indented lines continue
+    continued: Option<usize>,
+    /// Lines held back until their references are defined
+    held: Vec<String>,
+    /// Labels referenced by held lines that have no definition yet
+    missing: Vec<String>,
+}
+
+impl Footnotes {
+    /// Collect a `[^label]: text` definition, or an indented line continuing
+    /// one. Any other line is held while it or an earlier held line references
+    /// an undefined label. Returns the lines ready to render, with their
+    /// `[^label]` references rewritten to `[^n]`, which the parser shows as
+    /// superscripts.
+    pub fn process(&mut self, line: &str) -> Vec<String> {
+        if let Some(index) = self.continued
+            && line.starts_with(['\t', ' '])
+            && !line.trim().is_empty()
+        {
+            let definition = &mut self.definitions[index].1;
+            definition.push(' ');
+            definition.push_str(line.trim());
+            return Vec::new();
+        }
+        self.continued = None;
+
+        if let Some((label, text)) = parse_definition(line) {
+            let index = match self
+                .definitions
+                .iter()
+                .position(|(known, _)| known == label)
+            {
+                Some(index) => {
+                    self.definitions[index].1 = text.to_string();
+                    index
+                }
+                None => {
+                    self.definitions.push((label.to_string(), text.to_string()));
+                    self.definitions.len() - 1
+                }
+            };
+            self.continued = Some(index);
+            self.missing.retain(|missing| missing != label);
+        } else {
+            for label in self.undefined_references(line) {
+                if !self.missing.contains(&label) {
+                    self.missing.push(label);
+                }
+            }
+            self.held.push(line.to_string());
+        }
+
+        if self.missing.is_empty() {
+            self.release()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Whether lines are being held for a definition yet to arrive.
+    pub fn is_holding(&self) -> bool {
+        !self.held.is_empty()
+    }
+
+    /// Return every held line, leaving references that never got a
+    /// definition as written. To be called once the document ends.
+    pub fn release(&mut self) -> Vec<String> {
+        self.missing.clear();
+        std::mem::take(&mut self.held)
+            .iter()
+            .map(|line| self.renumber(line))
+            .collect()
+    }
+
+    /// Take the definitions collected so far as `(number, text)` pairs in
+    /// order. Their references are left as written.
+    pub fn take_definitions(&mut self) -> Vec<(usize, String)> {
+        self.continued = None;
+        let mut definitions: Vec<_> = std::mem::take(&mut self.definitions)
+            .into_iter()
+            .map(|(label, text)| (self.number(&label), text))
+            .collect();
+        definitions.sort_by_key(|(number, _)| *number);
+        definitions
+    }
+
+    /// Number of the footnote with `label`, assigning the next one if new.
+    fn number(&mut self, label: &str) -> usize {
+        match self.labels.iter().position(|known| known == label) {
+            Some(index) => index + 1,
+            None => {
+                self.labels.push(label.to_string());
+                self.labels.len()
+            }
+        }
+    }
+
+    fn is_defined(&self, label: &str) -> bool {
+        self.definitions.iter().any(|(known, _)| known == label)
+    }
+
+    /// Labels referenced in `line` that have no definition yet.
+    fn undefined_references(&self, line: &str) -> Vec<String> {
+        let mut undefined = Vec::new();
+        map_references(line, |label| {
+            if !self.is_defined(label) {
+                undefined.push(label.to_string());
+            }
+            format!("[^{label}]")
+        });
+        undefined
+    }
+
+    /// Rewrite every defined `[^label]` reference to `[^n]`. The parser would
+    /// still show an undefined numeric label as a superscript, so its bracket
+    /// is written as an entity that the theme decodes back.
+    fn renumber(&mut self, line: &str) -> String {
+        map_references(line, |label| {
+            if self.is_defined(label) {
+                format!("[^{}]", self.number(label))
+            } else if label.chars().all(|c| c.is_ascii_digit()) {
+                format!("&#91;^{label}]")
+            } else {
+                format!("[^{label}]")
+            }
+        })
+    }
+}
+
+/// Replace every `[^label]` reference outside code spans in `line` with the
+/// result of `f` on its label.
+fn map_references(line: &str, mut f: impl FnMut(&str) -> String) -> String {
+    if !line.contains("[^") {
+        return line.to_string();
+    }
+
+    let mut result = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        let len = match c {
+            '`' => code_span_len(rest),
+            '[' => match reference_len(rest) {
+                Some((label, len)) => {
+                    result.push_str(&f(label));
+                    rest = rest.get(len..).unwrap_or_default();
+                    continue;
+                }
+                None => 1,
+            },
+            _ => c.len_utf8(),
+        };
+        let (head, tail) = rest.split_at(len);
+        result.push_str(head);
+        rest = tail;
+    }
+    result
+}
+
+/// Split a `[^label]: text` definition line into its label and text.
+fn parse_definition(line: &str) -> Option<(&str, &str)> {
+    let (label, len) = reference_len(line)?;
+    let text = line.get(len..)?.strip_prefix(':')?;
+    Some((label, text.trim()))
+}
+
+/// Label and byte length of the `[^label]` reference at the start of `text`.
+/// Labels can't be empty or contain whitespace.
+fn reference_len(text: &str) -> Option<(&str, usize)> {
+    let body = text.strip_prefix("[^")?;
+    let close = body.find(']')?;
+    let label = body.get(..close)?;
+    let valid = !label.is_empty() && !label.contains(|c: char| c.is_whitespace() || c == '[');
+    valid.then_some((label, close + 3))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_references_numbered_in_order_of_appearance() {
+        let mut fixture = Footnotes::default();
+        fixture.process("[^1]: One");
+        fixture.process("[^note]: Note");
+        let actual = fixture.process("See[^note] and[^1], again[^note] but not `[^2]` or [^ x]");
+        let expected = vec!["See[^1] and[^2], again[^1] but not `[^2]` or [^ x]".to_string()];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_definitions_collected_with_continuation() {
+        let mut fixture = Footnotes::default();
+        let actual = [
+            "Text[^b]",
+            "[^a]: First",
+            "[^b]: Second",
+            "    continued",
+            "After",
+        ]
+        .map(|line| fixture.process(line));
+
+        assert_eq!(
+            actual,
+            [
+                vec![],
+                vec![],
+                vec!["Text[^1]".to_string()],
+                vec![],
+                vec!["After".to_string()]
+            ]
+        );
+        assert_eq!(
+            fixture.take_definitions(),
+            vec![
+                (1, "Second continued".to_string()),
+                (2, "First".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lines_held_until_reference_defined() {
+        let mut fixture = Footnotes::default();
+        let actual =
+            ["Text[^a]", "", "More", "[^a]: Note", "After"].map(|line| fixture.process(line));
+
+        assert_eq!(
+            actual,
+            [
+                vec![],
+                vec![],
+                vec![],
+                vec!["Text[^1]".to_string(), String::new(), "More".to_string()],
+                vec!["After".to_string()]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_undefined_reference_released_as_written() {
+        let mut fixture = Footnotes::default();
+        fixture.process("missing[^9] and[^note] here");
+        let actual = fixture.release();
+        let expected = vec!["missing&#91;^9] and[^note] here".to_string()];
+
+        assert_eq!(actual, expected);
+        assert_eq!(fixture.take_definitions(), vec![]);
+    }
+
+    #[test]
+    fn test_indented_line_without_definition_is_kept() {
+        let mut fixture = Footnotes::default();
+        let actual = fixture.process("    code");
+
+        assert_eq!(actual, vec!["    code".to_string()]);
+    }
+}
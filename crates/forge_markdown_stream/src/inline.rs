@@ -11,45 +11,406 @@ pub fn render_inline_content<S: InlineStyler>(content: &str, styler: &S) -> Stri
 
 /// Render inline elements to a string using a styler.
 pub fn render_inline_elements<S: InlineStyler>(elements: &[InlineElement], styler: &S) -> String {
-    let mut result = String::new();
-    for element in elements {
-        match element {
+    let mut spans = InlineSpans::default();
+    elements
+        .iter()
+        .map(|element| match element {
             InlineElement::Text(text) => {
-                result.push_str(&styler.text(text));
+                spans.render_marked(text, styler, |text| render_text(text, styler))
             }
             InlineElement::Bold(text) => {
-                result.push_str(&styler.bold(text));
+                spans.render_marked(text, styler, |text| styler.bold(&convert_scripts(text)))
             }
             InlineElement::Italic(text) => {
-                result.push_str(&styler.italic(text));
+                spans.render_marked(text, styler, |text| styler.italic(&convert_scripts(text)))
             }
-            InlineElement::BoldItalic(text) => {
-                result.push_str(&styler.bold_italic(text));
+            InlineElement::BoldItalic(text) => spans.render_marked(text, styler, |text| {
+                styler.bold_italic(&convert_scripts(text))
+            }),
+            InlineElement::Strikeout(text) => spans.render_marked(text, styler, |text| {
+                styler.strikethrough(&convert_scripts(text))
+            }),
+            InlineElement::Underline(text) => spans.render_marked(text, styler, |text| {
+                styler.underline(&convert_scripts(text))
+            }),
+            InlineElement::Code(text) if is_inline_math(text) => {
+                spans.apply(&styler.math(text), styler)
             }
-            InlineElement::Strikeout(text) => {
-                result.push_str(&styler.strikethrough(text));
+            InlineElement::Code(text) => spans.apply(&styler.code(text), styler),
+            InlineElement::Link { text, url } => {
+                spans.apply(&styler.link(&convert_scripts(text), url), styler)
             }
-            InlineElement::Underline(text) => {
-                result.push_str(&styler.underline(text));
+            InlineElement::Image { alt, url } => {
+                spans.apply(&styler.image(&convert_scripts(alt), url), styler)
             }
-            InlineElement::Code(text) => {
-                result.push_str(&styler.code(text));
+            InlineElement::Footnote(text) => spans.apply(&styler.footnote(text), styler),
+        })
+        .collect()
+}
+
+/// Strikethrough and highlight spans marked by [`crate::repair_line`], which
+/// the parser doesn't know about and so can reach across several elements.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InlineSpans {
+    struck: bool,
+    highlighted: bool,
+}
+
+impl InlineSpans {
+    /// Render the content of an element, which may open or close spans with
+    /// [`STRIKETHROUGH_MARKER`] and [`HIGHLIGHT_MARKER`]. Each piece between
+    /// markers is rendered with `render` and then styled with the open spans.
+    pub fn render_marked<S: InlineStyler>(
+        &mut self,
+        text: &str,
+        styler: &S,
+        render: impl Fn(&str) -> String,
+    ) -> String {
+        let mut result = String::new();
+        let mut rest = text;
+        loop {
+            let (piece, marker) = match rest.find([STRIKETHROUGH_MARKER, HIGHLIGHT_MARKER]) {
+                Some(offset) => (
+                    rest.get(..offset).unwrap_or_default(),
+                    rest.get(offset..).and_then(|tail| tail.chars().next()),
+                ),
+                None => (rest, None),
+            };
+            if !piece.is_empty() {
+                result.push_str(&self.apply(&render(piece), styler));
             }
-            InlineElement::Link { text, url } => {
-                result.push_str(&styler.link(text, url));
+
+            let Some(marker) = marker else {
+                break;
+            };
+            if marker == STRIKETHROUGH_MARKER {
+                self.struck = !self.struck;
+            } else {
+                self.highlighted = !self.highlighted;
             }
-            InlineElement::Image { alt, url } => {
-                result.push_str(&styler.image(alt, url));
+            rest = rest
+                .get(piece.len() + marker.len_utf8()..)
+                .unwrap_or_default();
+        }
+        result
+    }
+
+    /// Style an already rendered element with the spans open around it. The
+    /// styler decodes entities again, so the `&` left over from the first pass
+    /// is escaped to survive it.
+    pub fn apply<S: InlineStyler>(&self, rendered: &str, styler: &S) -> String {
+        let mut result = rendered.to_string();
+        if self.highlighted {
+            result = styler.highlight(&result.replace('&', "&amp;"));
+        }
+        if self.struck {
+            result = styler.strikethrough(&result.replace('&', "&amp;"));
+        }
+        result
+    }
+}
+
+/// Longest entity name worth looking up, e.g. `#x10FFFF` or `hellip`
+const MAX_ENTITY_LEN: usize = 10;
+
+/// Decode HTML entities such as `&amp;`, `&#160;` and `&#x00A0;` in a single
+/// pass, so decoded text is never decoded again (`&amp;lt;` becomes `&lt;`).
+/// Unknown or malformed entities are kept as written.
+pub fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        let (head, tail) = rest.split_at(start);
+        result.push_str(head);
+
+        let decoded = tail
+            .get(1..)
+            .and_then(|entity| {
+                entity
+                    .find(';')
+                    .filter(|end| *end <= MAX_ENTITY_LEN)
+                    .map(|end| (entity, end))
+            })
+            .and_then(|(entity, end)| Some((decode_entity(entity.get(..end)?)?, end)));
+
+        match decoded {
+            Some((decoded, end)) => {
+                result.push_str(decoded.as_str());
+                rest = tail.get(end + 2..).unwrap_or_default();
             }
-            InlineElement::Footnote(text) => {
-                result.push_str(&styler.footnote(text));
+            None => {
+                result.push('&');
+                rest = tail.get(1..).unwrap_or_default();
             }
         }
     }
 
+    result.push_str(rest);
     result
 }
 
+/// Decode a single entity name (without `&` and `;`).
+fn decode_entity(name: &str) -> Option<String> {
+    if let Some(number) = name.strip_prefix('#') {
+        let codepoint = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(codepoint).map(String::from);
+    }
+
+    let decoded = match name {
+        "amp" => "&",
+        "lt" => "<",
+        "gt" => ">",
+        "quot" => "\"",
+        "apos" => "'",
+        "nbsp" => " ",
+        "copy" => "©",
+        "trade" => "™",
+        "reg" => "®",
+        "mdash" => "—",
+        "ndash" => "–",
+        "hellip" => "…",
+        "larr" => "←",
+        "rarr" => "→",
+        "uarr" => "↑",
+        "darr" => "↓",
+        "times" => "×",
+        "divide" => "÷",
+        "plusmn" => "±",
+        "ne" => "≠",
+        "le" => "≤",
+        "ge" => "≥",
+        "infin" => "∞",
+        "euro" => "€",
+        "pound" => "£",
+        "yen" => "¥",
+        "cent" => "¢",
+        "deg" => "°",
+        "para" => "¶",
+        "sect" => "§",
+        "bull" => "•",
+        "middot" => "·",
+        "laquo" => "«",
+        "raquo" => "»",
+        "dagger" => "†",
+        "Dagger" => "‡",
+        "permil" => "‰",
+        "prime" => "′",
+        "Prime" => "″",
+        _ => return None,
+    };
+    Some(decoded.to_string())
+}
+
+/// Stands in for the `~` delimiters of a subscript, which the parser would
+/// otherwise drop. Set by [`crate::repair_line`].
+pub const SUBSCRIPT_MARKER: char = '\u{E000}';
+
+/// Stands in for a `~~` that is never closed, which the parser would
+/// otherwise treat as a strikethrough to the end of the line. Set by
+/// [`crate::repair_line`].
+pub const TILDES_MARKER: char = '\u{E001}';
+
+/// Stands in for the `~~` delimiters of a strikethrough holding other inline
+/// markup, whose strikethrough the parser would otherwise drop. Set by
+/// [`crate::repair_line`].
+pub const STRIKETHROUGH_MARKER: char = '\u{E002}';
+
+/// Stands in for the `==` delimiters of a highlight, which the parser doesn't
+/// know about. Set by [`crate::repair_line`].
+pub const HIGHLIGHT_MARKER: char = '\u{E003}';
+
+/// A piece of plain text split by its superscript and subscript spans.
+enum Script<'a> {
+    Text(&'a str),
+    Superscript(&'a str),
+    Subscript(&'a str),
+}
+
+/// Split text into plain, `^superscript^` and marked subscript pieces.
+///
+/// A superscript must be non-empty and contain no whitespace, so a lone `^`
+/// (as in `x^2 + y^2`) stays literal text.
+fn split_scripts(text: &str) -> Vec<Script<'_>> {
+    let mut scripts = Vec::new();
+    let mut plain_start = 0;
+    let mut search = 0;
+
+    while let Some(offset) = text
+        .get(search..)
+        .and_then(|tail| tail.find(['^', SUBSCRIPT_MARKER]))
+    {
+        let open = search + offset;
+        let marker = text
+            .get(open..)
+            .and_then(|tail| tail.chars().next())
+            .unwrap_or('^');
+        let body = text.get(open + marker.len_utf8()..).unwrap_or_default();
+        let inner = body.find(marker).and_then(|close| body.get(..close));
+
+        match inner {
+            Some(inner) if !inner.is_empty() && !inner.contains(char::is_whitespace) => {
+                scripts.push(Script::Text(
+                    text.get(plain_start..open).unwrap_or_default(),
+                ));
+                scripts.push(if marker == '^' {
+                    Script::Superscript(inner)
+                } else {
+                    Script::Subscript(inner)
+                });
+                search = open + inner.len() + marker.len_utf8() * 2;
+                plain_start = search;
+            }
+            _ => search = open + marker.len_utf8(),
+        }
+    }
+
+    scripts.push(Script::Text(text.get(plain_start..).unwrap_or_default()));
+    scripts
+}
+
+/// Render plain text, styling its `^superscript^` and `~subscript~` spans.
+pub fn render_text<S: InlineStyler>(text: &str, styler: &S) -> String {
+    split_scripts(text)
+        .into_iter()
+        .map(|script| match script {
+            Script::Text(text) => styler.text(&text.replace(TILDES_MARKER, "~~")),
+            Script::Superscript(text) => styler.superscript(text),
+            Script::Subscript(text) => styler.subscript(text),
+        })
+        .collect()
+}
+
+/// Convert the superscript and subscript spans of emphasized text in place,
+/// since emphasis styles can't nest another style.
+pub fn convert_scripts(text: &str) -> String {
+    split_scripts(text)
+        .into_iter()
+        .map(|script| match script {
+            Script::Text(text) => text.replace(TILDES_MARKER, "~~"),
+            Script::Superscript(text) => superscript_or_label(text),
+            Script::Subscript(text) => subscript_or_label(text),
+        })
+        .collect()
+}
+
+/// Unicode superscript form of the text, or a `[sup:text]` label if some
+/// character has none.
+pub fn superscript_or_label(text: &str) -> String {
+    to_superscript(text).unwrap_or_else(|| format!("[sup:{}]", text))
+}
+
+/// Unicode subscript form of the text, or a `[sub:text]` label if some
+/// character has none.
+pub fn subscript_or_label(text: &str) -> String {
+    to_subscript(text).unwrap_or_else(|| format!("[sub:{}]", text))
+}
+
+/// Convert text to Unicode superscript characters, or `None` if any
+/// character has no superscript form.
+fn to_superscript(text: &str) -> Option<String> {
+    text.chars()
+        .map(|c| {
+            Some(match c {
+                '0' => '⁰',
+                '1' => '¹',
+                '2' => '²',
+                '3' => '³',
+                '4' => '⁴',
+                '5' => '⁵',
+                '6' => '⁶',
+                '7' => '⁷',
+                '8' => '⁸',
+                '9' => '⁹',
+                '+' => '⁺',
+                '-' => '⁻',
+                '=' => '⁼',
+                '(' => '⁽',
+                ')' => '⁾',
+                'a' => 'ᵃ',
+                'b' => 'ᵇ',
+                'c' => 'ᶜ',
+                'd' => 'ᵈ',
+                'e' => 'ᵉ',
+                'f' => 'ᶠ',
+                'g' => 'ᵍ',
+                'h' => 'ʰ',
+                'i' => 'ⁱ',
+                'j' => 'ʲ',
+                'k' => 'ᵏ',
+                'l' => 'ˡ',
+                'm' => 'ᵐ',
+                'n' => 'ⁿ',
+                'o' => 'ᵒ',
+                'p' => 'ᵖ',
+                'r' => 'ʳ',
+                's' => 'ˢ',
+                't' => 'ᵗ',
+                'u' => 'ᵘ',
+                'v' => 'ᵛ',
+                'w' => 'ʷ',
+                'x' => 'ˣ',
+                'y' => 'ʸ',
+                'z' => 'ᶻ',
+                _ => return None,
+            })
+        })
+        .collect()
+}
+
+/// Convert text to Unicode subscript characters, or `None` if any character
+/// has no subscript form.
+fn to_subscript(text: &str) -> Option<String> {
+    text.chars()
+        .map(|c| {
+            Some(match c {
+                '0' => '₀',
+                '1' => '₁',
+                '2' => '₂',
+                '3' => '₃',
+                '4' => '₄',
+                '5' => '₅',
+                '6' => '₆',
+                '7' => '₇',
+                '8' => '₈',
+                '9' => '₉',
+                '+' => '₊',
+                '-' => '₋',
+                '=' => '₌',
+                '(' => '₍',
+                ')' => '₎',
+                'a' => 'ₐ',
+                'e' => 'ₑ',
+                'h' => 'ₕ',
+                'i' => 'ᵢ',
+                'j' => 'ⱼ',
+                'k' => 'ₖ',
+                'l' => 'ₗ',
+                'm' => 'ₘ',
+                'n' => 'ₙ',
+                'o' => 'ₒ',
+                'p' => 'ₚ',
+                'r' => 'ᵣ',
+                's' => 'ₛ',
+                't' => 'ₜ',
+                'u' => 'ᵤ',
+                'v' => 'ᵥ',
+                'x' => 'ₓ',
+                _ => return None,
+            })
+        })
+        .collect()
+}
+
+/// Returns true if an inline code span holds `$...$` math, which
+/// [`crate::repair_line`] wraps in backticks to shield it from inline parsing.
+pub fn is_inline_math(code: &str) -> bool {
+    code.len() > 2 && code.starts_with('$') && code.ends_with('$')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,6 +450,50 @@ mod tests {
         insta::assert_snapshot!(render("~~struck~~"), @"<s>struck</s>");
     }
 
+    fn render_repaired(content: &str) -> String {
+        let state = streamdown_core::ParseState::default();
+        crate::repair_line(content, &state)
+            .iter()
+            .map(|line| render(line))
+            .collect()
+    }
+
+    #[test]
+    fn test_strikethrough_with_nested_bold() {
+        insta::assert_snapshot!(
+            render_repaired("~~**bold strikethrough**~~"),
+            @"<s><b>bold strikethrough</b></s>"
+        );
+    }
+
+    #[test]
+    fn test_strikethrough_with_nested_code_and_text() {
+        insta::assert_snapshot!(
+            render_repaired("x ~~a `b` &amp; c~~ y"),
+            @"x <s>a </s><s><code>b</code></s><s> & c</s> y"
+        );
+    }
+
+    #[test]
+    fn test_unclosed_strikethrough_is_literal() {
+        insta::assert_snapshot!(render_repaired("a ~~b"), @"a ~~b");
+    }
+
+    #[test]
+    fn test_highlight() {
+        insta::assert_snapshot!(render_repaired("a ==b== c"), @"a <mark>b</mark> c");
+    }
+
+    #[test]
+    fn test_highlight_inside_bold() {
+        insta::assert_snapshot!(render_repaired("**==x==**"), @"<mark><b>x</b></mark>");
+    }
+
+    #[test]
+    fn test_unclosed_highlight_is_literal() {
+        insta::assert_snapshot!(render_repaired("a ==b"), @"a ==b");
+    }
+
     #[test]
     fn test_code() {
         insta::assert_snapshot!(render("`code`"), @"<code>code</code>");
@@ -104,6 +509,11 @@ mod tests {
         insta::assert_snapshot!(render("[click](https://example.com)"), @r#"<a href="https://example.com">click</a>"#);
     }
 
+    #[test]
+    fn test_link_text_with_subscript() {
+        insta::assert_snapshot!(render("[H\u{E000}2\u{E000}O](https://x.dev)"), @r#"<a href="https://x.dev">H₂O</a>"#);
+    }
+
     #[test]
     fn test_image() {
         insta::assert_snapshot!(render("![alt](image.png)"), @r#"<img alt="alt" src="image.png"/>"#);
@@ -149,6 +559,55 @@ mod tests {
         insta::assert_snapshot!(render("hello   world"), @"hello   world");
     }
 
+    #[test]
+    fn test_inline_math() {
+        insta::assert_snapshot!(render("energy `$E = mc^2$` and `$`"), @"energy <math>$E = mc^2$</math> and <code>$</code>");
+    }
+
+    #[test]
+    fn test_superscript_and_subscript() {
+        insta::assert_snapshot!(render("H\u{E000}2\u{E000}O and E=mc^2^"), @"H<sub>2</sub>O and E=mc<sup>2</sup>");
+    }
+
+    #[test]
+    fn test_lone_carets_stay_text() {
+        insta::assert_snapshot!(render("x^2 + y^2 and ^ q ^"), @"x^2 + y^2 and ^ q ^");
+    }
+
+    #[test]
+    fn test_scripts_in_emphasis() {
+        insta::assert_snapshot!(render("**H\u{E000}2\u{E000}O at 10^q^**"), @"<b>H₂O at 10[sup:q]</b>");
+    }
+
+    #[test]
+    fn test_script_labels_without_unicode_form() {
+        assert_eq!(superscript_or_label("2n"), "²ⁿ");
+        assert_eq!(superscript_or_label("q"), "[sup:q]");
+        assert_eq!(subscript_or_label("b"), "[sub:b]");
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("AT&amp;T &lt;noreply@example.com&gt; &quot;&apos;"),
+            r#"AT&T <noreply@example.com> "'"#
+        );
+        assert_eq!(decode_entities("&#160;&#x00A0;&#X41;"), "\u{a0}\u{a0}A");
+    }
+
+    #[test]
+    fn test_decode_entities_only_once() {
+        assert_eq!(decode_entities("&amp;lt; &amp;#169;"), "&lt; &#169;");
+    }
+
+    #[test]
+    fn test_decode_entities_keeps_unknown_and_malformed() {
+        assert_eq!(
+            decode_entities("R&D &bogus; &#xZZ; &#169; & ;"),
+            "R&D &bogus; &#xZZ; © & ;"
+        );
+    }
+
     #[test]
     fn test_image_empty_alt() {
         insta::assert_snapshot!(render("![](image.png)"), @r#"<img alt="" src="image.png"/>"#);
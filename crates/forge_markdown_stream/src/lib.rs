@@ -24,9 +24,13 @@
 //! ```
 
 mod code;
+#[cfg(any(test, feature = "debug"))]
+mod debug;
+mod footnote;
 mod heading;
 mod inline;
 mod list;
+mod middleware;
 mod renderer;
 mod repair;
 mod style;
@@ -36,10 +40,15 @@ mod utils;
 
 use std::io::{self, Write};
 
+#[cfg(any(test, feature = "debug"))]
+pub use debug::{ParserDebugInfo, ParserState};
+pub use middleware::{CodeBlock, CodeBlockExtractor, RenderMiddleware};
 pub use renderer::Renderer;
-pub use repair::repair_line;
-pub use streamdown_parser::Parser;
+pub use repair::{RepairAction, RepairResult, repair_line, repair_line_with_report};
+pub use streamdown_parser::{ParseEvent, Parser};
+pub use table::TableOptions;
 pub use theme::{Style, Theme};
+use utils::no_color_requested;
 
 /// Streaming markdown renderer for terminal output.
 ///
@@ -56,11 +65,21 @@ pub struct StreamdownRenderer<W: Write> {
 
 impl<W: Write> StreamdownRenderer<W> {
     /// Create a new renderer with the given writer and terminal width.
+    ///
+    /// Renders without ANSI escape sequences, like
+    /// [`StreamdownRenderer::plain`], when the `NO_COLOR` environment variable
+    /// is set.
     pub fn new(writer: W, width: usize) -> Self {
-        Self {
-            parser: Parser::new(),
-            renderer: Renderer::new(writer, width),
-            line_buffer: String::new(),
+        Self::with_no_color(writer, width, no_color_requested())
+    }
+
+    /// Create a new renderer with the default theme, or without ANSI escape
+    /// sequences when `no_color` is set.
+    pub fn with_no_color(writer: W, width: usize, no_color: bool) -> Self {
+        if no_color {
+            Self::plain(writer, width)
+        } else {
+            Self::with_theme(writer, width, Theme::default())
         }
     }
 
@@ -73,6 +92,24 @@ impl<W: Write> StreamdownRenderer<W> {
         }
     }
 
+    /// Create a new renderer that writes no ANSI escape sequences, for output
+    /// piped to files or log collectors.
+    pub fn plain(writer: W, width: usize) -> Self {
+        Self::with_theme(writer, width, Theme::plain())
+    }
+
+    /// Run `middleware` over every parse event, in order, before rendering.
+    pub fn with_middleware(mut self, middleware: Vec<Box<dyn RenderMiddleware>>) -> Self {
+        self.renderer = self.renderer.with_middleware(middleware);
+        self
+    }
+
+    /// Render tables with `options`, e.g. to cap column widths.
+    pub fn with_table_options(mut self, options: TableOptions) -> Self {
+        self.renderer = self.renderer.with_table_options(options);
+        self
+    }
+
     /// Push a token to the renderer.
     ///
     /// Tokens are buffered until a complete line is received, then rendered.
@@ -80,53 +117,96 @@ impl<W: Write> StreamdownRenderer<W> {
         self.line_buffer.push_str(token);
 
         while let Some(pos) = self.line_buffer.find('\n') {
-            let line = self.line_buffer.get(..pos).unwrap_or("").to_string();
-
-            for repaired in repair_line(&line, self.parser.state()) {
-                for event in self.parser.parse_line(&repaired) {
-                    self.renderer.render_event(&event)?;
-                }
-            }
+            // CRLF streams would otherwise leave `\r` in the rendered line
+            let line = self.line_buffer.get(..pos).unwrap_or("");
+            let line = line.strip_suffix('\r').unwrap_or(line).to_string();
+            self.renderer.render_line(&mut self.parser, &line)?;
 
             self.line_buffer = self.line_buffer.get(pos + 1..).unwrap_or("").to_string();
         }
         Ok(())
     }
 
+    /// Current parser and renderer state, for debugging streams that render
+    /// unexpectedly.
+    #[cfg(any(test, feature = "debug"))]
+    pub fn debug_state(&self) -> ParserDebugInfo {
+        ParserDebugInfo::new(
+            &self.parser,
+            &self.line_buffer,
+            self.renderer.heading_level(),
+        )
+    }
+
+    /// Render a complete document and finish, for text that is available
+    /// upfront such as a completed response.
+    pub fn render_all(mut self, markdown: &str) -> io::Result<()> {
+        self.push(markdown)?;
+        self.finish()
+    }
+
     /// Finish rendering, flushing any remaining buffered content.
-    /// Returns the underlying writer.
     pub fn finish(mut self) -> io::Result<()> {
+        self.flush_remaining()
+    }
+
+    fn flush_remaining(&mut self) -> io::Result<()> {
         if !self.line_buffer.is_empty() {
-            for repaired in repair_line(&self.line_buffer, self.parser.state()) {
-                for event in self.parser.parse_line(&repaired) {
-                    self.renderer.render_event(&event)?;
-                }
-            }
+            self.renderer
+                .render_line(&mut self.parser, &self.line_buffer)?;
+            self.line_buffer.clear();
         }
+        self.renderer
+            .release_footnote_references(&mut self.parser)?;
         for event in self.parser.finalize() {
-            self.renderer.render_event(&event)?;
+            self.renderer.handle_event(&event)?;
         }
-        Ok(())
+        self.renderer.render_footnotes()
+    }
+}
+
+impl StreamdownRenderer<Vec<u8>> {
+    /// Create a renderer that collects its output in memory, to be read back
+    /// with [`StreamdownRenderer::finish_into_string`].
+    pub fn to_string_renderer(width: usize) -> Self {
+        Self::new(Vec::new(), width)
+    }
+
+    /// Finish rendering and return everything written so far as a `String`.
+    pub fn finish_into_string(mut self) -> io::Result<String> {
+        self.flush_remaining()?;
+        String::from_utf8(self.renderer.into_writer())
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
     }
 }
 
+/// Render a complete markdown document to a string with the default theme.
+///
+/// The result contains the same ANSI styling as streamed output.
+pub fn render_to_string(markdown: &str, width: usize) -> String {
+    render_to_string_with_theme(markdown, width, Theme::default())
+}
+
+/// Render a complete markdown document to a string with a custom theme.
+pub fn render_to_string_with_theme(markdown: &str, width: usize, theme: Theme) -> String {
+    let mut renderer = StreamdownRenderer::with_theme(Vec::new(), width, theme);
+    // Writing into a Vec can't fail and the renderer only ever writes `str` data
+    let _ = renderer.push(markdown);
+    renderer.finish_into_string().unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
+    use unicode_width::UnicodeWidthStr;
 
-    use super::StreamdownRenderer;
+    use super::{
+        StreamdownRenderer, TableOptions, Theme, render_to_string, render_to_string_with_theme,
+    };
 
     fn fixture_rendered_output(markdown: &str, width: usize) -> String {
-        let mut output = Vec::new();
-        let mut fixture = StreamdownRenderer::new(&mut output, width);
-        fixture.push(markdown).unwrap();
-        fixture.finish().unwrap();
-
-        let actual = strip_ansi_escapes::strip(output);
-        String::from_utf8(actual)
-            .unwrap()
-            .trim_matches('\n')
-            .to_string()
+        let actual = strip_ansi_escapes::strip_str(render_to_string(markdown, width));
+        actual.trim_matches('\n').to_string()
     }
 
     fn fixture_rendered_output_from_chunks(chunks: &[&str], width: usize) -> String {
@@ -230,4 +310,552 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_streaming_renderer_renders_display_math_as_raw_latex_block() {
+        let fixture = [
+            "$$\\int_0^",
+            "\\infty e^{-x} dx = 1$$\n",
+            "so $a_1 * b_1$ costs $5\n",
+        ];
+        let actual = fixture_rendered_output_from_chunks(&fixture, 80);
+        let expected = concat!(
+            "┌─ [Math: LaTeX] ─\n",
+            "│ \\int_0^\\infty e^{-x} dx = 1\n",
+            "└\n",
+            "so $a_1 * b_1$ costs $5"
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_streaming_renderer_renders_superscript_and_subscript() {
+        let fixture = "H~2~O and E=mc^2^, but x^q^\n";
+        let actual = fixture_rendered_output(fixture, 80);
+        let expected = "H₂O and E=mc², but x[sup:q]";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_streaming_renderer_decodes_html_entities() {
+        let fixture = "AT&amp;T &lt;noreply@example.com&gt; &amp;lt; &#38;&#x26; &#160;|&nbsp;| &#xZZ; &#169;\n";
+        let actual = fixture_rendered_output(fixture, 80);
+        let expected = "AT&T <noreply@example.com> &lt; && \u{a0}| | &#xZZ; ©";
+
+        assert_eq!(actual, expected);
+    }
+
+    fn fixture_rendered_output_with_theme(markdown: &str, theme: Theme) -> String {
+        let actual =
+            strip_ansi_escapes::strip_str(render_to_string_with_theme(markdown, 80, theme));
+        actual.trim_matches('\n').to_string()
+    }
+
+    #[test]
+    fn test_render_to_string_keeps_ansi_styling() {
+        // `colored` drops its codes without a TTY, underline is written directly
+        let actual = render_to_string("**bold** and __underline__\n", 80);
+
+        assert!(actual.contains("\x1b[4munderline\x1b[24m"));
+        assert_eq!(
+            strip_ansi_escapes::strip_str(&actual).trim_matches('\n'),
+            "bold and underline"
+        );
+    }
+
+    fn fixture_plain_output(markdown: &str) -> String {
+        let mut output = Vec::new();
+        StreamdownRenderer::plain(&mut output, 80)
+            .render_all(markdown)
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_push_strips_carriage_return_from_crlf_lines() {
+        let mut fixture = StreamdownRenderer::to_string_renderer(80);
+        fixture.push("## Hello\r\n").unwrap();
+        fixture.push("world\r\n").unwrap();
+
+        let actual = fixture.finish_into_string().unwrap();
+
+        assert!(!actual.contains('\r'));
+        assert_eq!(
+            strip_ansi_escapes::strip_str(&actual).trim_matches('\n'),
+            "## Hello\nworld"
+        );
+    }
+
+    #[test]
+    fn test_finish_into_string_returns_rendered_chunks() {
+        let mut fixture = StreamdownRenderer::to_string_renderer(80);
+        fixture.push("## Ti").unwrap();
+        fixture.push("tle\nSome **bold** text").unwrap();
+
+        let actual = strip_ansi_escapes::strip_str(fixture.finish_into_string().unwrap());
+        let expected = "## Title\nSome bold text";
+
+        assert_eq!(actual.trim_matches('\n'), expected);
+    }
+
+    #[test]
+    fn test_renderer_with_no_color_writes_no_escapes() {
+        let mut output = Vec::new();
+        StreamdownRenderer::with_no_color(&mut output, 80, true)
+            .render_all("__underline__ [link](https://forgecode.dev)\n```rust\nfn main() {}\n```\n")
+            .unwrap();
+
+        let actual = String::from_utf8(output).unwrap();
+        assert!(!actual.contains('\x1b'), "escape in {actual:?}");
+    }
+
+    #[test]
+    fn test_renderer_with_color_writes_escapes() {
+        let mut output = Vec::new();
+        StreamdownRenderer::with_no_color(&mut output, 80, false)
+            .render_all("__underline__\n")
+            .unwrap();
+
+        let actual = String::from_utf8(output).unwrap();
+        assert!(actual.contains('\x1b'), "no escape in {actual:?}");
+    }
+
+    #[test]
+    fn test_plain_renderer_writes_no_ansi() {
+        let actual = fixture_plain_output("**bold**\n");
+
+        assert!(!actual.contains("\x1b["));
+        assert_eq!(actual.trim_matches('\n'), "bold");
+    }
+
+    #[test]
+    fn test_plain_renderer_skips_underline_links_and_highlighting() {
+        let actual = fixture_plain_output(concat!(
+            "__underline__ and [Forge](https://forgecode.dev)\n",
+            "\n",
+            "```rust\n",
+            "fn main() {}\n",
+            "```\n",
+        ));
+
+        assert!(!actual.contains('\x1b'));
+        assert!(actual.contains("underline and Forge (https://forgecode.dev)"));
+        assert!(actual.contains("fn main() {}"));
+    }
+
+    #[test]
+    fn test_task_list_mixed_checkboxes() {
+        let fixture = "- [x] done\n- [ ] todo\n- plain\n";
+        let actual = fixture_rendered_output_with_theme(fixture, Theme::plain());
+        let expected = "• ☑ done\n• ☐ todo\n• plain";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_task_list_nested() {
+        let fixture = "- [ ] parent\n  - [x] child\n  - [ ] sibling\n";
+        let actual = fixture_rendered_output_with_theme(fixture, Theme::plain());
+        let expected = "• ☐ parent\n    ◦ ☑ child\n    ◦ ☐ sibling";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_task_list_inside_blockquote() {
+        let fixture = "> - [x] **done**\n> - [ ] todo\n> - [y] not a task\n";
+        let actual = fixture_rendered_output_with_theme(fixture, Theme::plain());
+        let expected = "│ - ☑ done\n│ - ☐ todo\n│ - [y] not a task";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_task_list_nerd_font_glyphs() {
+        let fixture = "- [x] done\n- [ ] todo\n";
+        let actual = fixture_rendered_output_with_theme(fixture, Theme::plain().nerd_font_tasks());
+        let expected = format!(
+            "• {} done\n• {} todo",
+            crate::theme::NERD_FONT_TASK_CHECKED,
+            crate::theme::NERD_FONT_TASK_UNCHECKED
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    fn fixture_strikethrough_theme() -> Theme {
+        Theme { strikethrough_fallback: true, ..Theme::plain() }
+    }
+
+    #[test]
+    fn test_strikethrough_fallback() {
+        let actual = fixture_rendered_output_with_theme(
+            "Plain ~~struck~~ text\n",
+            fixture_strikethrough_theme(),
+        );
+        let expected = "Plain --struck-- text";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_strikethrough_keeps_nested_markup() {
+        let fixture = concat!(
+            "~~**bold strikethrough**~~ and ~~a *b* `c`~~\n",
+            "- ~~**done**~~ item\n",
+        );
+        let actual = fixture_rendered_output_with_theme(fixture, fixture_strikethrough_theme());
+        let expected = "--bold strikethrough-- and --a ----b---- ----c--\n• --done-- item";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_unclosed_strikethrough_stays_literal() {
+        let fixture = "Not ~~closed **here**\n- item ~~open\n";
+        let actual = fixture_rendered_output_with_theme(fixture, fixture_strikethrough_theme());
+        let expected = "Not ~~closed here\n• item ~~open";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_highlight_fallback() {
+        let theme = Theme { highlight_fallback: true, ..Theme::plain() };
+        let actual =
+            fixture_rendered_output_with_theme("Some ==marked== and **==bold==** text\n", theme);
+        let expected = "Some >>marked<< and >>bold<< text";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_highlight_across_push_boundary() {
+        let theme = Theme { highlight_fallback: true, ..Theme::plain() };
+        let mut output = Vec::new();
+        let mut renderer = StreamdownRenderer::with_theme(&mut output, 80, theme);
+        renderer.push("Text ==hi").unwrap();
+        renderer.push("gh== done\n").unwrap();
+        renderer.finish().unwrap();
+
+        let actual = String::from_utf8(output).unwrap();
+        let expected = "Text >>high<< done";
+
+        assert_eq!(actual.trim(), expected);
+    }
+
+    #[test]
+    fn test_table_max_column_width_truncates_cells() {
+        let long = "x".repeat(100);
+        let fixture =
+            format!("| Key | Value | Note |\n|-----|-------|------|\n| a | {long} | {long} |\n");
+        let options = TableOptions { max_column_width: Some(12) };
+        let mut output = Vec::new();
+        StreamdownRenderer::plain(&mut output, 40)
+            .with_table_options(options)
+            .render_all(&fixture)
+            .unwrap();
+        let actual = String::from_utf8(output).unwrap();
+
+        assert!(actual.contains(&format!("{}…", "x".repeat(11))));
+        assert!(!actual.contains(&"x".repeat(12)));
+        for line in actual.lines() {
+            assert!(UnicodeWidthStr::width(line) <= 40, "too wide: {line:?}");
+        }
+    }
+
+    #[test]
+    fn test_footnotes_shown_after_document() {
+        let mut output = Vec::new();
+        let mut fixture = StreamdownRenderer::plain(&mut output, 80);
+        for chunk in [
+            "Named[^no",
+            "te] and numbered[^1].\n\n[^1]: Numbered **de",
+            "finition**.\n[^note]: Named\n    continued.\n",
+            "Last line.",
+        ] {
+            fixture.push(chunk).unwrap();
+        }
+        fixture.finish().unwrap();
+
+        let actual = String::from_utf8(output).unwrap();
+        let expected = concat!(
+            "Named¹ and numbered².\n",
+            "\n",
+            "Last line.\n",
+            "\n",
+            "#### Footnotes\n",
+            "¹ Named continued.\n",
+            "² Numbered definition.",
+        );
+
+        assert_eq!(actual.trim_matches('\n'), expected);
+    }
+
+    #[test]
+    fn test_undefined_footnote_reference_shown_as_written() {
+        let actual = fixture_rendered_output("See missing[^9] here.\n\nNext line.\n", 80);
+        let expected = "See missing[^9] here.\n\nNext line.";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_footnotes_section_follows_single_blank_line() {
+        let actual = fixture_rendered_output("Text[^a].\n\n[^a]: Note.\n", 80);
+        let expected = "Text¹.\n\n#### Footnotes\n¹ Note.";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_streaming_renderer_shows_link_url() {
+        let theme = Theme { show_link_url: true, ..Theme::dark() };
+        let actual = fixture_rendered_output_with_theme("[Forge](https://forgecode.dev)\n", theme);
+        let expected = "Forge (https://forgecode.dev)";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_streaming_renderer_converts_scripts_in_link_text() {
+        let actual = fixture_rendered_output("see [H~2~O](https://x.dev) link\n", 80);
+        let expected = "see H₂O (https://x.dev) link";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_streaming_renderer_hides_link_url() {
+        let theme = Theme { show_link_url: false, ..Theme::dark() };
+        let actual = fixture_rendered_output_with_theme("[Forge](https://forgecode.dev)\n", theme);
+        let expected = "Forge";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_streaming_renderer_truncates_long_link_url() {
+        let theme = Theme { max_link_url_length: Some(16), ..Theme::dark() };
+        let actual =
+            fixture_rendered_output_with_theme("[Docs](https://forgecode.dev/docs/intro)\n", theme);
+        let expected = "Docs (https://forgeco…)";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_streaming_renderer_aligns_table_columns() {
+        let actual = fixture_rendered_output_from_chunks(
+            &[
+                "| Label | Count |\n",
+                "| :---: | ---: |\n",
+                "| a | 7 |\n",
+                "| long label | 1234 |\n",
+                "\n",
+            ],
+            80,
+        );
+        let expected = [
+            "┌────────────┬───────┐",
+            "│   Label    │ Count │",
+            "├────────────┼───────┤",
+            "│     a      │     7 │",
+            "├────────────┼───────┤",
+            "│ long label │  1234 │",
+            "└────────────┴───────┘",
+        ]
+        .join("\n");
+
+        assert_eq!(actual, expected);
+    }
+
+    /// Streams `markdown` one character at a time, like a slow model would,
+    /// and returns the output without ANSI codes. Colors depend on whether
+    /// stdout is a terminal, so only the layout is compared.
+    fn fixture_streamed_output(markdown: &str, theme: Theme) -> String {
+        let mut output = Vec::new();
+        let mut fixture = StreamdownRenderer::with_theme(&mut output, 60, theme);
+        for token in markdown.chars() {
+            fixture.push(&token.to_string()).unwrap();
+        }
+        fixture.finish().unwrap();
+
+        strip_ansi_escapes::strip_str(String::from_utf8(output).unwrap())
+    }
+
+    /// Snapshots `markdown` streamed token by token, checking that the
+    /// default and plain themes lay it out identically.
+    macro_rules! assert_streamed_snapshot {
+        ($name:literal, $markdown:expr) => {
+            let actual = fixture_streamed_output($markdown, Theme::default());
+            let plain = fixture_streamed_output($markdown, Theme::plain());
+            assert_eq!(plain, actual, "plain theme changed the layout");
+            insta::assert_snapshot!($name, actual);
+        };
+    }
+
+    #[test]
+    fn test_snapshot_headings() {
+        assert_streamed_snapshot!(
+            "headings",
+            "# Title\n## Section with **bold**\n### Third\n#### Fourth\n##### Fifth\n###### Sixth\n"
+        );
+    }
+
+    #[test]
+    fn test_snapshot_inline_formatting() {
+        assert_streamed_snapshot!(
+            "inline_formatting",
+            concat!(
+                "Plain **bold** *italic* ***both*** __underline__ ~~struck~~ `code`.\n",
+                "A [link](https://forgecode.dev), H~2~O, E=mc^2^ and $x_1 + y_1$ for $5.\n",
+                "Entities: AT&amp;T &lt;tag&gt;\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_snapshot_code_blocks() {
+        assert_streamed_snapshot!(
+            "code_blocks",
+            concat!(
+                "```rust\n",
+                "fn main() {\n",
+                "    println!(\"{}\", \"a long line that has to wrap because it does not fit\");\n",
+                "}\n",
+                "```\n",
+                "\n",
+                "```python\n",
+                "def greet(name):\n",
+                "    return f\"hello {name}\"\n",
+                "```\n",
+                "\n",
+                "```\n",
+                "no language\n",
+                "```\n",
+                "\n",
+                "```unknown-lang\n",
+                "still rendered}```\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_snapshot_nested_lists() {
+        assert_streamed_snapshot!(
+            "nested_lists",
+            concat!(
+                "- first\n",
+                "  - nested **bold**\n",
+                "    - deeper\n",
+                "- second\n",
+                "\n",
+                "1. one\n",
+                "2. two\n",
+                "   1. two point one\n",
+                "3. three\n",
+                "\n",
+                "- [x] done\n",
+                "- [ ] todo\n",
+                "* asterisk\n",
+                "+ plus\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_snapshot_table() {
+        assert_streamed_snapshot!(
+            "table",
+            concat!(
+                "| Feature | Status | Notes |\n",
+                "|---------|:------:|------:|\n",
+                "| **Tables** | Done | aligned |\n",
+                "| `code` | WIP | a much longer note that needs wrapping in the cell |\n",
+                "\n",
+                "After the table.\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_snapshot_blockquotes() {
+        assert_streamed_snapshot!(
+            "blockquotes",
+            concat!(
+                "> A quote with *emphasis* that is long enough to wrap around\n",
+                ">> Nested quote\n",
+                "\n",
+                "<think>\n",
+                "Reasoning with $x$ and ~tilde~\n",
+                "</think>\n",
+                "\n",
+                "---\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_snapshot_math_block() {
+        assert_streamed_snapshot!(
+            "math_block",
+            concat!(
+                "Before\n",
+                "$$\n",
+                "\\sum_{i=1}^n i = \\frac{n(n+1)}{2}\n",
+                "$$\n",
+                "After\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_all_matches_line_by_line_push() {
+        let fixture = concat!(
+            "# Overview\n",
+            "Intro with **bold** text.\n",
+            "\n",
+            "## Steps\n",
+            "1. first\n",
+            "2. second\n",
+            "\n",
+            "```rust\n",
+            "let x = 1;\n",
+            "```\n",
+            "| a | b |\n",
+            "|---|---|\n",
+            "| 1 | 2 |\n",
+            "\n",
+            "Done without trailing newline",
+        );
+
+        let mut actual = Vec::new();
+        StreamdownRenderer::with_theme(&mut actual, 80, Theme::dark())
+            .render_all(fixture)
+            .unwrap();
+
+        let mut expected = Vec::new();
+        let mut renderer = StreamdownRenderer::with_theme(&mut expected, 80, Theme::dark());
+        for line in fixture.split_inclusive('\n') {
+            renderer.push(line).unwrap();
+        }
+        renderer.finish().unwrap();
+
+        let mut document = Vec::new();
+        crate::Renderer::with_theme(&mut document, 80, Theme::dark())
+            .render_document(fixture)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(actual).unwrap(),
+            String::from_utf8(expected.clone()).unwrap()
+        );
+        assert_eq!(
+            String::from_utf8(document).unwrap(),
+            String::from_utf8(expected).unwrap()
+        );
+    }
 }
@@ -16,29 +16,59 @@ const BULLETS_ASTERISK: [&str; 4] = ["∗", "⁎", "✱", "✳"];
 /// Bullet characters for plus lists at different nesting levels.
 const BULLETS_PLUS: [&str; 4] = ["⊕", "⊙", "⊛", "⊜"];
 
-/// Checkbox characters for task list items.
-const CHECKBOX_UNCHECKED: &str = "";
-const CHECKBOX_CHECKED: &str = "";
-
-/// Strips checkbox prefix from content and returns (checkbox_char,
+/// Strips checkbox prefix from content and returns (checked,
 /// remaining_content). Returns None if no checkbox is found at the start.
-fn strip_checkbox_prefix(content: &str) -> Option<(&'static str, &str)> {
+fn strip_checkbox_prefix(content: &str) -> Option<(bool, &str)> {
     if let Some(rest) = content.strip_prefix("[ ] ") {
-        Some((CHECKBOX_UNCHECKED, rest))
+        Some((false, rest))
     } else if let Some(rest) = content
         .strip_prefix("[x] ")
         .or_else(|| content.strip_prefix("[X] "))
     {
-        Some((CHECKBOX_CHECKED, rest))
+        Some((true, rest))
     } else if content == "[ ]" {
-        Some((CHECKBOX_UNCHECKED, ""))
+        Some((false, ""))
     } else if content == "[x]" || content == "[X]" {
-        Some((CHECKBOX_CHECKED, ""))
+        Some((true, ""))
     } else {
         None
     }
 }
 
+/// Render the glyph for a task checkbox.
+fn render_checkbox<S: ListStyler>(checked: bool, styler: &S) -> String {
+    if checked {
+        styler.checkbox_checked(styler.task_checked())
+    } else {
+        styler.checkbox_unchecked(styler.task_unchecked())
+    }
+}
+
+/// Split a task item the parser leaves as plain text, such as a list inside a
+/// blockquote, into its marker with the rendered checkbox and the remaining
+/// content. Returns None if the line isn't a task item.
+pub fn split_task_item<'a, S: ListStyler>(line: &'a str, styler: &S) -> Option<(String, &'a str)> {
+    let trimmed = line.trim_start();
+    let indent = line.len() - trimmed.len();
+    let (marker, content) = trimmed.split_once(' ')?;
+    let is_marker = matches!(marker, "-" | "*" | "+")
+        || marker
+            .strip_suffix('.')
+            .is_some_and(|number| !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()));
+    if !is_marker {
+        return None;
+    }
+
+    let (checked, rest) = strip_checkbox_prefix(content)?;
+    let prefix = format!(
+        "{}{} {} ",
+        line.get(..indent).unwrap_or(""),
+        marker,
+        render_checkbox(checked, styler)
+    );
+    Some((prefix, rest))
+}
+
 /// List rendering state for tracking nesting and numbering.
 #[derive(Default)]
 pub struct ListState {
@@ -129,16 +159,22 @@ pub fn render_list_item<S: InlineStyler + ListStyler>(
     let level = list_state.level().saturating_sub(1);
 
     // Check for checkbox at start of content
-    let (checkbox_prefix, actual_content) = match strip_checkbox_prefix(content) {
-        Some((checkbox, rest)) => {
-            let styled = if checkbox == CHECKBOX_CHECKED {
-                styler.checkbox_checked(checkbox)
+    let (checkbox_prefix, checkbox_width, actual_content) = match strip_checkbox_prefix(content) {
+        Some((checked, rest)) => {
+            let glyph = if checked {
+                styler.task_checked()
             } else {
-                styler.checkbox_unchecked(checkbox)
+                styler.task_unchecked()
             };
-            (format!("{} ", styled), rest)
+            // checkbox + space
+            let width = visible_length(glyph) + 1;
+            (
+                format!("{} ", render_checkbox(checked, styler)),
+                width,
+                rest,
+            )
         }
-        None => (String::new(), content),
+        None => (String::new(), 0, content),
     };
 
     // Calculate marker - use our own counter for ordered lists to work around
@@ -166,7 +202,6 @@ pub fn render_list_item<S: InlineStyler + ListStyler>(
     // Calculate indentation
     let indent_spaces = indent * 2;
     let marker_width = visible_length(&marker);
-    let checkbox_width = if checkbox_prefix.is_empty() { 0 } else { 2 }; // checkbox + space
     let content_indent = indent_spaces + marker_width + 1 + checkbox_width;
 
     // Color the marker based on bullet type
@@ -441,7 +476,7 @@ mod tests {
     #[test]
     fn test_wrapping_respects_checkbox_prefix_width() {
         let actual = render_with_width(0, ListBullet::Dash, "[ ] 한글 공백", 8);
-        let expected = "  <dash>•</dash> <unchecked></unchecked> 한글\n      공백";
+        let expected = "  <dash>•</dash> <unchecked>☐</unchecked> 한글\n      공백";
 
         pretty_assertions::assert_eq!(actual, expected);
     }
@@ -561,14 +596,14 @@ mod tests {
 
             #[test]
             fn valid_patterns() {
-                // (input, expected_checkbox, expected_remaining)
+                // (input, expected_checked, expected_remaining)
                 let cases = [
-                    ("[ ] Task", Some((CHECKBOX_UNCHECKED, "Task"))),
-                    ("[x] Done", Some((CHECKBOX_CHECKED, "Done"))),
-                    ("[X] Done", Some((CHECKBOX_CHECKED, "Done"))),
-                    ("[ ]", Some((CHECKBOX_UNCHECKED, ""))),
-                    ("[x]", Some((CHECKBOX_CHECKED, ""))),
-                    ("[X]", Some((CHECKBOX_CHECKED, ""))),
+                    ("[ ] Task", Some((false, "Task"))),
+                    ("[x] Done", Some((true, "Done"))),
+                    ("[X] Done", Some((true, "Done"))),
+                    ("[ ]", Some((false, ""))),
+                    ("[x]", Some((true, ""))),
+                    ("[X]", Some((true, ""))),
                 ];
 
                 for (input, expected) in cases {
@@ -603,7 +638,7 @@ mod tests {
             fn checkbox_unchecked() {
                 insta::assert_snapshot!(
                     render(0, ListBullet::Dash, "[ ] Task to do"),
-                    @"  <dash>•</dash> <unchecked></unchecked> Task to do"
+                    @"  <dash>•</dash> <unchecked>☐</unchecked> Task to do"
                 );
             }
 
@@ -611,7 +646,7 @@ mod tests {
             fn checkbox_checked_lowercase() {
                 insta::assert_snapshot!(
                     render(0, ListBullet::Dash, "[x] Completed task"),
-                    @"  <dash>•</dash> <checked></checked> Completed task"
+                    @"  <dash>•</dash> <checked>☑</checked> Completed task"
                 );
             }
 
@@ -619,7 +654,7 @@ mod tests {
             fn checkbox_checked_uppercase() {
                 insta::assert_snapshot!(
                     render(0, ListBullet::Dash, "[X] Another completed task"),
-                    @"  <dash>•</dash> <checked></checked> Another completed task"
+                    @"  <dash>•</dash> <checked>☑</checked> Another completed task"
                 );
             }
 
@@ -627,7 +662,7 @@ mod tests {
             fn checkbox_unchecked_empty_content() {
                 insta::assert_snapshot!(
                     render(0, ListBullet::Dash, "[ ]"),
-                    @"  <dash>•</dash> <unchecked></unchecked>"
+                    @"  <dash>•</dash> <unchecked>☐</unchecked>"
                 );
             }
 
@@ -635,7 +670,7 @@ mod tests {
             fn checkbox_checked_empty_content() {
                 insta::assert_snapshot!(
                     render(0, ListBullet::Dash, "[x]"),
-                    @"  <dash>•</dash> <checked></checked>"
+                    @"  <dash>•</dash> <checked>☑</checked>"
                 );
             }
 
@@ -643,11 +678,53 @@ mod tests {
             fn checkbox_with_ordered_list() {
                 insta::assert_snapshot!(
                     render(0, ListBullet::Ordered(1), "[ ] Ordered task"),
-                    @"  <num>1.</num> <unchecked></unchecked> Ordered task"
+                    @"  <num>1.</num> <unchecked>☐</unchecked> Ordered task"
                 );
             }
         }
 
+        mod split_task_item_tests {
+            use super::*;
+
+            #[test]
+            fn task_items() {
+                let cases = [
+                    (
+                        "- [x] Done",
+                        Some(("- <checked>☑</checked> ".to_string(), "Done")),
+                    ),
+                    (
+                        "* [ ] Todo",
+                        Some(("* <unchecked>☐</unchecked> ".to_string(), "Todo")),
+                    ),
+                    (
+                        "  12. [X]",
+                        Some(("  12. <checked>☑</checked> ".to_string(), "")),
+                    ),
+                ];
+
+                for (input, expected) in cases {
+                    let actual = split_task_item(input, &TagStyler);
+                    assert_eq!(actual, expected, "input: {input:?}");
+                }
+            }
+
+            #[test]
+            fn not_task_items() {
+                let cases = [
+                    "[x] No marker",
+                    "-[x] No space",
+                    "a. [x] Letter",
+                    "- Plain item",
+                ];
+
+                for input in cases {
+                    let actual = split_task_item(input, &TagStyler);
+                    assert_eq!(actual, None, "input: {input:?} should not match");
+                }
+            }
+        }
+
         mod no_false_positives {
             use super::*;
 
@@ -0,0 +1,127 @@
+//! Hooks for inspecting or rewriting parse events before they are rendered.
+
+use std::sync::{Arc, Mutex};
+
+use streamdown_parser::ParseEvent;
+
+/// Processes parse events before the renderer sees them.
+///
+/// Each middleware receives the events produced by the one before it and
+/// returns the events to pass on, so it can drop, rewrite or insert events.
+/// Returning the event unchanged makes a purely observing middleware.
+pub trait RenderMiddleware: Send {
+    fn process_event(&mut self, event: &ParseEvent) -> Vec<ParseEvent>;
+}
+
+/// A code block's fence language and content.
+pub type CodeBlock = (Option<String>, String);
+
+/// Collects every code block as `(language, content)` while leaving the
+/// events untouched.
+///
+/// Clones share the collected blocks, so keep one clone to read them after
+/// handing another to the renderer.
+#[derive(Debug, Clone, Default)]
+pub struct CodeBlockExtractor {
+    current: Option<CodeBlock>,
+    blocks: Arc<Mutex<Vec<CodeBlock>>>,
+}
+
+impl CodeBlockExtractor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Code blocks completed so far, in document order.
+    pub fn blocks(&self) -> Vec<CodeBlock> {
+        self.blocks
+            .lock()
+            .map(|blocks| blocks.clone())
+            .unwrap_or_default()
+    }
+}
+
+impl RenderMiddleware for CodeBlockExtractor {
+    fn process_event(&mut self, event: &ParseEvent) -> Vec<ParseEvent> {
+        match event {
+            ParseEvent::CodeBlockStart { language, .. } => {
+                self.current = Some((language.clone(), String::new()));
+            }
+            ParseEvent::CodeBlockLine(line) => {
+                if let Some((_, content)) = &mut self.current {
+                    content.push_str(line);
+                    content.push('\n');
+                }
+            }
+            ParseEvent::CodeBlockEnd => {
+                if let Some(block) = self.current.take()
+                    && let Ok(mut blocks) = self.blocks.lock()
+                {
+                    blocks.push(block);
+                }
+            }
+            _ => {}
+        }
+        vec![event.clone()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::StreamdownRenderer;
+
+    #[test]
+    fn test_code_block_extractor_collects_all_blocks() {
+        let fixture = CodeBlockExtractor::new();
+        let markdown = concat!(
+            "# Example\n",
+            "```rust\n",
+            "fn main() {}\n",
+            "```\n",
+            "Some text.\n",
+            "```\n",
+            "echo one\n",
+            "echo two\n",
+            "```\n",
+        );
+
+        StreamdownRenderer::new(Vec::new(), 80)
+            .with_middleware(vec![Box::new(fixture.clone())])
+            .render_all(markdown)
+            .unwrap();
+
+        let actual = fixture.blocks();
+        let expected = vec![
+            (Some("rust".to_string()), "fn main() {}\n".to_string()),
+            (None, "echo one\necho two\n".to_string()),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    struct DropHeadings;
+
+    impl RenderMiddleware for DropHeadings {
+        fn process_event(&mut self, event: &ParseEvent) -> Vec<ParseEvent> {
+            match event {
+                ParseEvent::Heading { .. } => vec![],
+                _ => vec![event.clone()],
+            }
+        }
+    }
+
+    #[test]
+    fn test_middleware_can_drop_events() {
+        let mut output = Vec::new();
+        StreamdownRenderer::new(&mut output, 80)
+            .with_middleware(vec![Box::new(DropHeadings)])
+            .render_all("# Title\nBody\n")
+            .unwrap();
+
+        let actual = strip_ansi_escapes::strip_str(String::from_utf8(output).unwrap());
+        let expected = "Body\n";
+        assert_eq!(actual.trim_start_matches('\n'), expected);
+    }
+}
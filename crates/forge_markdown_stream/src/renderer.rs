@@ -3,16 +3,24 @@
 use std::io::{self, Write};
 
 use streamdown_ansi::utils::visible_length;
-use streamdown_parser::ParseEvent;
+use streamdown_parser::{ParseEvent, Parser};
 
-use crate::code::CodeHighlighter;
+use crate::code::{
+    CodeHighlighter, MATH_LANGUAGE, render_math_footer, render_math_header, render_math_line,
+};
+use crate::footnote::Footnotes;
 use crate::heading::render_heading;
-use crate::inline::{render_inline_content, render_inline_elements};
-use crate::list::{ListState, render_list_item};
+use crate::inline::{
+    InlineSpans, convert_scripts, is_inline_math, render_inline_content, render_inline_elements,
+    render_text, superscript_or_label,
+};
+use crate::list::{ListState, render_list_item, split_task_item};
+use crate::middleware::RenderMiddleware;
+use crate::repair::repair_line;
 use crate::style::InlineStyler;
-use crate::table::render_table;
+use crate::table::{Alignment, TableOptions, parse_alignments, render_table};
 use crate::theme::Theme;
-use crate::utils::wrap_text_preserving_spaces;
+use crate::utils::{no_color_requested, wrap_text_preserving_spaces};
 
 /// Main renderer for markdown events.
 pub struct Renderer<W: Write> {
@@ -25,18 +33,37 @@ pub struct Renderer<W: Write> {
     code_buffer: String,
     // Table buffering
     table_rows: Vec<Vec<String>>,
+    table_alignments: Vec<Alignment>,
+    table_options: TableOptions,
     // Blockquote state
     in_blockquote: bool,
     blockquote_depth: usize,
     // List state
     list_state: ListState,
+    // Footnotes shown after the document
+    footnotes: Footnotes,
     // Column tracking
     column: usize,
+    // Newlines ending the output so far, starting as if after a blank line
+    trailing_newlines: usize,
+    // Strikethrough and highlight spans open on the current line
+    spans: InlineSpans,
+    middleware: Vec<Box<dyn RenderMiddleware>>,
+    // Most recent heading level, reported by debug_state
+    #[cfg(any(test, feature = "debug"))]
+    heading_level: Option<u8>,
 }
 
 impl<W: Write> Renderer<W> {
+    /// Create a renderer with the detected theme, or the plain one when
+    /// `NO_COLOR` is set.
     pub fn new(writer: W, width: usize) -> Self {
-        Self::with_theme(writer, width, Theme::default())
+        let theme = if no_color_requested() {
+            Theme::plain()
+        } else {
+            Theme::default()
+        };
+        Self::with_theme(writer, width, theme)
     }
 
     pub fn with_theme(writer: W, width: usize, theme: Theme) -> Self {
@@ -48,13 +75,26 @@ impl<W: Write> Renderer<W> {
             current_language: None,
             code_buffer: String::new(),
             table_rows: Vec::new(),
+            table_alignments: Vec::new(),
+            table_options: TableOptions::default(),
             in_blockquote: false,
             blockquote_depth: 0,
             list_state: ListState::default(),
+            footnotes: Footnotes::default(),
             column: 0,
+            trailing_newlines: 2,
+            spans: InlineSpans::default(),
+            middleware: Vec::new(),
+            #[cfg(any(test, feature = "debug"))]
+            heading_level: None,
         }
     }
 
+    /// Consume the renderer and return the underlying writer.
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+
     /// Set a new theme.
     #[allow(dead_code)]
     pub fn set_theme(&mut self, theme: Theme) {
@@ -88,22 +128,72 @@ impl<W: Write> Renderer<W> {
     }
 
     fn write(&mut self, s: &str) -> io::Result<()> {
+        self.track_newlines(s);
         write!(self.writer, "{}", s)
     }
 
+    /// Write an already styled inline element inside the open spans.
+    fn write_inline(&mut self, s: &str) -> io::Result<()> {
+        let styled = self.spans.apply(s, &self.theme);
+        self.write(&styled)
+    }
+
+    /// Write the content of an inline element that may open or close spans,
+    /// styling its pieces with `style`.
+    fn write_marked(
+        &mut self,
+        text: &str,
+        style: impl Fn(&Theme, &str) -> String,
+    ) -> io::Result<()> {
+        let theme = &self.theme;
+        let styled = self
+            .spans
+            .render_marked(text, theme, |text| style(theme, text));
+        self.write(&styled)
+    }
+
     fn writeln(&mut self, s: &str) -> io::Result<()> {
+        self.track_newlines(s);
+        self.track_newlines("\n");
         writeln!(self.writer, "{}", s)?;
         self.column = 0;
         Ok(())
     }
 
+    fn track_newlines(&mut self, s: &str) {
+        let content = s.trim_end_matches('\n');
+        if content.is_empty() {
+            self.trailing_newlines += s.len();
+        } else {
+            self.trailing_newlines = s.len() - content.len();
+        }
+    }
+
+    #[cfg(any(test, feature = "debug"))]
+    pub(crate) fn heading_level(&self) -> Option<u8> {
+        self.heading_level
+    }
+
+    /// Whether the current code block holds LaTeX math rather than code.
+    fn in_math_block(&self) -> bool {
+        self.current_language.as_deref() == Some(MATH_LANGUAGE)
+    }
+
     fn flush_table(&mut self) -> io::Result<()> {
         if self.table_rows.is_empty() {
             return Ok(());
         }
         let rows = std::mem::take(&mut self.table_rows);
+        let alignments = std::mem::take(&mut self.table_alignments);
         let margin = self.left_margin();
-        let lines = render_table(&rows, &margin, &self.theme, self.width);
+        let lines = render_table(
+            &rows,
+            &alignments,
+            &margin,
+            &self.theme,
+            self.width,
+            &self.table_options,
+        );
         for line in lines {
             self.writeln(&line)?;
         }
@@ -122,6 +212,114 @@ impl<W: Write> Renderer<W> {
         )
     }
 
+    /// Run `middleware` over every parse event, in order, before rendering.
+    pub fn with_middleware(mut self, middleware: Vec<Box<dyn RenderMiddleware>>) -> Self {
+        self.middleware = middleware;
+        self
+    }
+
+    /// Render tables with `options`.
+    pub fn with_table_options(mut self, options: TableOptions) -> Self {
+        self.table_options = options;
+        self
+    }
+
+    /// Pass a parse event through the middleware and render what comes out.
+    pub fn handle_event(&mut self, event: &ParseEvent) -> io::Result<()> {
+        if self.middleware.is_empty() {
+            return self.render_event(event);
+        }
+        let mut events = vec![event.clone()];
+        for middleware in &mut self.middleware {
+            events = events
+                .iter()
+                .flat_map(|event| middleware.process_event(event))
+                .collect();
+        }
+        for event in &events {
+            self.render_event(event)?;
+        }
+        Ok(())
+    }
+
+    /// Parse and render a complete document.
+    ///
+    /// Unlike [`crate::StreamdownRenderer`] this takes the whole text at once
+    /// and keeps no parser state between calls.
+    pub fn render_document(&mut self, doc: &str) -> io::Result<()> {
+        let mut parser = Parser::new();
+        for line in doc.lines() {
+            self.render_line(&mut parser, line)?;
+        }
+        self.release_footnote_references(&mut parser)?;
+        for event in parser.finalize() {
+            self.handle_event(&event)?;
+        }
+        self.render_footnotes()
+    }
+
+    /// Render the footnote definitions collected so far as a section of their
+    /// own, to be called once the rest of the document is rendered.
+    pub fn render_footnotes(&mut self) -> io::Result<()> {
+        let definitions = self.footnotes.take_definitions();
+        if definitions.is_empty() {
+            return Ok(());
+        }
+
+        let mut parser = Parser::new();
+        for _ in self.trailing_newlines..2 {
+            self.writeln("")?;
+        }
+        self.render_line(&mut parser, "#### Footnotes")?;
+        for (number, text) in definitions {
+            let line = format!("{} {}", superscript_or_label(&number.to_string()), text);
+            self.render_line(&mut parser, &line)?;
+        }
+        for event in parser.finalize() {
+            self.handle_event(&event)?;
+        }
+        Ok(())
+    }
+
+    /// Repair, parse and render a single complete line.
+    ///
+    /// Parse events don't carry table alignment, so it is read from the raw
+    /// separator row here. Footnote definitions are held back for
+    /// [`Renderer::render_footnotes`], as are lines referencing a footnote
+    /// until it is defined.
+    pub fn render_line(&mut self, parser: &mut Parser, line: &str) -> io::Result<()> {
+        let lines = if parser.state().is_in_code() && !self.footnotes.is_holding() {
+            vec![line.to_string()]
+        } else {
+            self.footnotes.process(line)
+        };
+        for line in lines {
+            self.render_ready_line(parser, &line)?;
+        }
+        Ok(())
+    }
+
+    /// Render the lines held back for footnote definitions that never
+    /// arrived, to be called before the parser is finalized.
+    pub fn release_footnote_references(&mut self, parser: &mut Parser) -> io::Result<()> {
+        for line in self.footnotes.release() {
+            self.render_ready_line(parser, &line)?;
+        }
+        Ok(())
+    }
+
+    fn render_ready_line(&mut self, parser: &mut Parser, line: &str) -> io::Result<()> {
+        for repaired in repair_line(line, parser.state()) {
+            for event in parser.parse_line(&repaired) {
+                if matches!(event, ParseEvent::TableSeparator) {
+                    self.table_alignments = parse_alignments(&repaired);
+                }
+                self.handle_event(&event)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Render a single parse event.
     pub fn render_event(&mut self, event: &ParseEvent) -> io::Result<()> {
         // Reset pending list if this event breaks the list context
@@ -132,45 +330,56 @@ impl<W: Write> Renderer<W> {
         match event {
             // === Inline elements ===
             ParseEvent::Text(text) => {
-                let styled = self.theme.text(text);
+                let theme = &self.theme;
+                let styled = self
+                    .spans
+                    .render_marked(text, theme, |text| render_text(text, theme));
                 self.write(&styled)?;
                 self.column += styled.chars().count();
             }
 
+            ParseEvent::InlineCode(code) if is_inline_math(code) => {
+                self.write_inline(&self.theme.math(code))?;
+            }
+
             ParseEvent::InlineCode(code) => {
-                self.write(&self.theme.code(code))?;
+                self.write_inline(&self.theme.code(code))?;
             }
 
             ParseEvent::Bold(text) => {
-                self.write(&self.theme.bold(text))?;
+                self.write_marked(text, |theme, text| theme.bold(&convert_scripts(text)))?;
             }
 
             ParseEvent::Italic(text) => {
-                self.write(&self.theme.italic(text))?;
+                self.write_marked(text, |theme, text| theme.italic(&convert_scripts(text)))?;
             }
 
             ParseEvent::BoldItalic(text) => {
-                self.write(&self.theme.bold_italic(text))?;
+                self.write_marked(text, |theme, text| {
+                    theme.bold_italic(&convert_scripts(text))
+                })?;
             }
 
             ParseEvent::Underline(text) => {
-                self.write(&self.theme.underline(text))?;
+                self.write_marked(text, |theme, text| theme.underline(&convert_scripts(text)))?;
             }
 
             ParseEvent::Strikeout(text) => {
-                self.write(&self.theme.strikethrough(text))?;
+                self.write_marked(text, |theme, text| {
+                    theme.strikethrough(&convert_scripts(text))
+                })?;
             }
 
             ParseEvent::Link { text, url } => {
-                self.write(&self.theme.link(text, url))?;
+                self.write_inline(&self.theme.link(&convert_scripts(text), url))?;
             }
 
             ParseEvent::Image { alt, url } => {
-                self.write(&self.theme.image(alt, url))?;
+                self.write_inline(&self.theme.image(&convert_scripts(alt), url))?;
             }
 
             ParseEvent::Footnote(superscript) => {
-                self.write(&self.theme.footnote(superscript))?;
+                self.write_inline(&self.theme.footnote(superscript))?;
             }
 
             ParseEvent::Prompt(prompt) => {
@@ -179,6 +388,10 @@ impl<W: Write> Renderer<W> {
 
             // === Block elements ===
             ParseEvent::Heading { level, content } => {
+                #[cfg(any(test, feature = "debug"))]
+                {
+                    self.heading_level = Some(*level);
+                }
                 let margin = self.left_margin();
                 let width = self.current_width();
                 let lines = render_heading(*level, content, width, &margin, &self.theme);
@@ -190,6 +403,18 @@ impl<W: Write> Renderer<W> {
             ParseEvent::CodeBlockStart { language, .. } => {
                 self.current_language = language.clone();
                 self.code_buffer.clear();
+                if self.in_math_block() {
+                    let margin = self.left_margin();
+                    self.writeln(&render_math_header(&margin, &self.theme))?;
+                }
+            }
+
+            ParseEvent::CodeBlockLine(line) if self.in_math_block() => {
+                let margin = self.left_margin();
+                let width = self.current_width();
+                for rendered in render_math_line(line, &margin, width, &self.theme) {
+                    self.writeln(&rendered)?;
+                }
             }
 
             ParseEvent::CodeBlockLine(line) => {
@@ -205,6 +430,7 @@ impl<W: Write> Renderer<W> {
                     self.current_language.as_deref(),
                     &margin,
                     width,
+                    &self.theme,
                 );
                 for rendered in rendered_lines {
                     self.writeln(&rendered)?;
@@ -212,6 +438,10 @@ impl<W: Write> Renderer<W> {
             }
 
             ParseEvent::CodeBlockEnd => {
+                if self.in_math_block() {
+                    let margin = self.left_margin();
+                    self.writeln(&render_math_footer(&margin, &self.theme))?;
+                }
                 self.current_language = None;
                 self.code_buffer.clear();
             }
@@ -257,7 +487,12 @@ impl<W: Write> Renderer<W> {
                 let margin = self.left_margin();
                 let content_width = self.width.saturating_sub(visible_length(&margin));
                 // Parse inline formatting (bold, italic, etc.) in blockquote content
-                let rendered_content = render_inline_content(text, &self.theme);
+                let rendered_content = match split_task_item(text, &self.theme) {
+                    Some((prefix, rest)) => {
+                        format!("{}{}", prefix, render_inline_content(rest, &self.theme))
+                    }
+                    None => render_inline_content(text, &self.theme),
+                };
                 let wrapped = wrap_text_preserving_spaces(
                     &rendered_content,
                     content_width,
@@ -303,6 +538,7 @@ impl<W: Write> Renderer<W> {
             }
 
             ParseEvent::EmptyLine | ParseEvent::Newline => {
+                self.spans = InlineSpans::default();
                 self.writeln("")?;
             }
             ParseEvent::InlineElements(elements) => {
@@ -1,24 +1,125 @@
 //! Repair malformed markdown before parsing.
 //!
 //! This module handles common markdown issues that the parser doesn't handle
-//! well, such as closing code fences on the same line as content.
+//! well, such as closing code fences on the same line as content, and LaTeX
+//! math which the parser doesn't know about.
 
-use streamdown_core::ParseState;
+use streamdown_core::{BlockType, ParseState};
+
+use crate::code::MATH_LANGUAGE;
+use crate::inline::{HIGHLIGHT_MARKER, STRIKETHROUGH_MARKER, SUBSCRIPT_MARKER, TILDES_MARKER};
 
 /// Repair a line of markdown, returning one or more normalized lines.
 ///
 /// Handles:
 /// - Embedded closing fences: `}```\n` becomes `}\n` + ```` ``` ```` (only when
 ///   in code block)
+/// - Display math: `$$` delimiters become a ```` ```math ```` fence so the
+///   LaTeX passes through the parser untouched
+/// - Inline math: `$...$` spans are wrapped in backticks so `_` and `*` in the
+///   LaTeX aren't parsed as emphasis. A `$` that doesn't delimit math, such as
+///   a price, is left alone.
+/// - Subscripts: the `~` delimiters of `~sub~` become a private marker, since
+///   the parser drops single tildes.
+/// - Strikethrough: a `~~` that is never closed, and the delimiters of a
+///   strikethrough holding other markup such as `~~**bold**~~`, become private
+///   markers since the parser gets both wrong.
+/// - Highlights: the `==` delimiters of `==text==` become a private marker,
+///   since the parser doesn't know about them.
 pub fn repair_line(line: &str, state: &ParseState) -> Vec<String> {
-    // Only check for embedded closing fence when we're inside a code block
-    if state.is_in_code()
-        && let Some(lines) = split_embedded_fence(line)
-    {
-        return lines;
+    repair_line_with_report(line, state)
+        .into_iter()
+        .map(|result| result.repaired)
+        .collect()
+}
+
+/// A kind of repair made by [`repair_line`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepairAction {
+    /// A closing code fence at the end of a code line moved to its own line
+    EmbeddedFence,
+    /// A `$$` closing display math moved to its own fence line
+    MathClose,
+    /// `$$` display math turned into a math fence
+    DisplayMath,
+    /// `$...$` inline math wrapped in backticks
+    InlineMath,
+    /// `~sub~` delimiters replaced with a private marker
+    Subscript,
+    /// Unclosed or nesting `~~` delimiters replaced with private markers
+    Strikethrough,
+    /// `==highlight==` delimiters replaced with a private marker
+    Highlight,
+}
+
+/// One line produced by [`repair_line_with_report`] and the repairs behind
+/// it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepairResult {
+    /// The line as received
+    pub original: String,
+    /// The line handed to the parser
+    pub repaired: String,
+    /// Repairs made, empty if the line was passed through unchanged
+    pub actions: Vec<RepairAction>,
+}
+
+/// Repair a line like [`repair_line`], reporting which repairs were made.
+///
+/// A line split into several lines gives one result per line, each carrying
+/// the same original line and actions.
+pub fn repair_line_with_report(line: &str, state: &ParseState) -> Vec<RepairResult> {
+    let report = |lines: Vec<String>, actions: Vec<RepairAction>| {
+        lines
+            .into_iter()
+            .map(|repaired| RepairResult {
+                original: line.to_string(),
+                repaired,
+                actions: actions.clone(),
+            })
+            .collect()
+    };
+
+    if state.is_in_code() {
+        if state.code_language.as_deref() == Some(MATH_LANGUAGE)
+            && let Some(lines) = split_math_close(line)
+        {
+            return report(lines, vec![RepairAction::MathClose]);
+        }
+        // Only check for embedded closing fence when we're inside a code block
+        if let Some(lines) = split_embedded_fence(line) {
+            return report(lines, vec![RepairAction::EmbeddedFence]);
+        }
+        return report(vec![line.to_string()], Vec::new());
+    }
+
+    // Think blocks are shown verbatim
+    if state.block_type == Some(BlockType::Think) {
+        return report(vec![line.to_string()], Vec::new());
+    }
+
+    if let Some(lines) = split_display_math(line) {
+        return report(lines, vec![RepairAction::DisplayMath]);
     }
 
-    vec![line.to_string()]
+    let mut actions = Vec::new();
+    let math = wrap_inline_math(line);
+    if math != line {
+        actions.push(RepairAction::InlineMath);
+    }
+    let subscripts = mark_subscripts(&math);
+    if subscripts != math {
+        actions.push(RepairAction::Subscript);
+    }
+    let struck = mark_strikethrough(&subscripts);
+    if struck != subscripts {
+        actions.push(RepairAction::Strikethrough);
+    }
+    let repaired = mark_highlights(&struck);
+    if repaired != struck {
+        actions.push(RepairAction::Highlight);
+    }
+    report(vec![repaired], actions)
 }
 
 /// Split a line if it contains an embedded closing fence at the end.
@@ -43,6 +144,259 @@ fn split_embedded_fence(line: &str) -> Option<Vec<String>> {
     None
 }
 
+/// Rewrite a line opening display math into a math fence.
+/// e.g., `$$x = 1$$` becomes an opening math fence, `x = 1` and a closing fence
+fn split_display_math(line: &str) -> Option<Vec<String>> {
+    let fence = format!("```{MATH_LANGUAGE}");
+    let rest = line.trim().strip_prefix("$$")?;
+
+    if rest.trim().is_empty() {
+        return Some(vec![fence]);
+    }
+
+    match split_math_close(rest) {
+        Some(closed) => Some(std::iter::once(fence).chain(closed).collect()),
+        None => Some(vec![fence, rest.trim().to_string()]),
+    }
+}
+
+/// Split a line closing display math into its content, a closing fence and
+/// any text following the closing `$$`.
+/// e.g., `dx = 1$$ so` becomes Some(vec![`dx = 1`, ```` ``` ````, `so`])
+fn split_math_close(line: &str) -> Option<Vec<String>> {
+    let (math, trailing) = line.split_once("$$")?;
+
+    let mut lines = Vec::new();
+    if !math.trim().is_empty() {
+        lines.push(math.trim_end().to_string());
+    }
+    lines.push("```".to_string());
+    if !trailing.trim().is_empty() {
+        lines.push(trailing.trim().to_string());
+    }
+    Some(lines)
+}
+
+/// Wrap every inline math span of a line in backticks.
+/// e.g., `where $a_1 * b_1$ holds` becomes ``where `$a_1 * b_1$` holds``
+fn wrap_inline_math(line: &str) -> String {
+    if !line.contains('$') {
+        return line.to_string();
+    }
+
+    let mut result = String::with_capacity(line.len() + 4);
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        let len = match c {
+            // Code spans are copied verbatim
+            '`' => code_span_len(rest),
+            // Escaped characters such as `\$` are never delimiters
+            '\\' => rest.chars().take(2).map(char::len_utf8).sum(),
+            '$' => match inline_math_len(rest) {
+                Some(len) if !rest.get(..len).unwrap_or_default().contains('`') => {
+                    result.push('`');
+                    result.push_str(rest.get(..len).unwrap_or_default());
+                    result.push('`');
+                    rest = rest.get(len..).unwrap_or_default();
+                    continue;
+                }
+                _ => rest.len() - rest.trim_start_matches('$').len(),
+            },
+            _ => c.len_utf8(),
+        };
+        let (head, tail) = rest.split_at(len);
+        result.push_str(head);
+        rest = tail;
+    }
+    result
+}
+
+/// Replace the `~` delimiters of every subscript span with
+/// [`SUBSCRIPT_MARKER`], leaving code spans and `~~` strikethrough alone.
+/// e.g., `H~2~O` becomes `H\u{E000}2\u{E000}O`
+fn mark_subscripts(line: &str) -> String {
+    if !line.contains('~') {
+        return line.to_string();
+    }
+
+    let mut result = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        let len = match c {
+            '`' => code_span_len(rest),
+            '~' if rest.starts_with("~~") => rest.len() - rest.trim_start_matches('~').len(),
+            '~' => match subscript_len(rest) {
+                Some(len) => {
+                    result.push(SUBSCRIPT_MARKER);
+                    result.push_str(rest.get(1..len - 1).unwrap_or_default());
+                    result.push(SUBSCRIPT_MARKER);
+                    rest = rest.get(len..).unwrap_or_default();
+                    continue;
+                }
+                None => 1,
+            },
+            _ => c.len_utf8(),
+        };
+        let (head, tail) = rest.split_at(len);
+        result.push_str(head);
+        rest = tail;
+    }
+    result
+}
+
+/// Characters that start inline markup the parser can't strike through.
+const NESTED_MARKUP: [char; 4] = ['*', '_', '`', '['];
+
+/// Replace the `~~` delimiters the parser gets wrong, leaving code spans
+/// alone: a trailing unpaired `~~` becomes [`TILDES_MARKER`] and the
+/// delimiters of a strikethrough holding other markup become
+/// [`STRIKETHROUGH_MARKER`].
+/// e.g., `~~**done**~~ ~~` becomes `\u{E002}**done**\u{E002} \u{E001}`
+fn mark_strikethrough(line: &str) -> String {
+    if !line.contains("~~") {
+        return line.to_string();
+    }
+
+    let mut delimiters = Vec::new();
+    let mut offset = 0;
+    while let Some(rest) = line.get(offset..).filter(|rest| !rest.is_empty()) {
+        let len = match rest.chars().next() {
+            Some('`') => code_span_len(rest),
+            Some('~') => {
+                let run = rest.len() - rest.trim_start_matches('~').len();
+                if run == 2 {
+                    delimiters.push(offset);
+                }
+                run
+            }
+            Some(c) => c.len_utf8(),
+            None => break,
+        };
+        offset += len;
+    }
+
+    let replacements = delimiters.chunks(2).flat_map(|pair| match *pair {
+        [open, close]
+            if line
+                .get(open + 2..close)
+                .is_some_and(|inner| inner.contains(NESTED_MARKUP)) =>
+        {
+            vec![(open, STRIKETHROUGH_MARKER), (close, STRIKETHROUGH_MARKER)]
+        }
+        [open] => vec![(open, TILDES_MARKER)],
+        _ => Vec::new(),
+    });
+
+    let mut result = String::with_capacity(line.len());
+    let mut copied = 0;
+    for (offset, marker) in replacements {
+        result.push_str(line.get(copied..offset).unwrap_or_default());
+        result.push(marker);
+        copied = offset + 2;
+    }
+    result.push_str(line.get(copied..).unwrap_or_default());
+    result
+}
+
+/// Replace the `==` delimiters of every highlight span with
+/// [`HIGHLIGHT_MARKER`], leaving code spans alone. Like Obsidian, the opening
+/// `==` must be followed and the closing one preceded by a non-space
+/// character, so comparisons like `a == b` stay literal.
+/// e.g., `==note== this` becomes `\u{E003}note\u{E003} this`
+fn mark_highlights(line: &str) -> String {
+    if !line.contains("==") {
+        return line.to_string();
+    }
+
+    let mut delimiters = Vec::new();
+    let mut open = None;
+    let mut offset = 0;
+    while let Some(rest) = line.get(offset..).filter(|rest| !rest.is_empty()) {
+        let len = match rest.chars().next() {
+            Some('`') => code_span_len(rest),
+            Some('=') => {
+                let run = rest.len() - rest.trim_start_matches('=').len();
+                if run == 2 {
+                    let before = line.get(..offset).and_then(|head| head.chars().last());
+                    let after = rest.get(2..).and_then(|tail| tail.chars().next());
+                    let closes = before.is_some_and(|c| !c.is_whitespace());
+                    let opens = after.is_some_and(|c| !c.is_whitespace());
+                    match open {
+                        Some(start) if closes => {
+                            delimiters.extend([start, offset]);
+                            open = None;
+                        }
+                        _ if opens => open = Some(offset),
+                        _ => {}
+                    }
+                }
+                run
+            }
+            Some(c) => c.len_utf8(),
+            None => break,
+        };
+        offset += len;
+    }
+
+    let mut result = String::with_capacity(line.len());
+    let mut copied = 0;
+    for offset in delimiters {
+        result.push_str(line.get(copied..offset).unwrap_or_default());
+        result.push(HIGHLIGHT_MARKER);
+        copied = offset + 2;
+    }
+    result.push_str(line.get(copied..).unwrap_or_default());
+    result
+}
+
+/// Byte length of the `~sub~` span at the start of `text`. The span must be
+/// non-empty and free of whitespace, so paths like `~/src` aren't matched.
+fn subscript_len(text: &str) -> Option<usize> {
+    let body = text.strip_prefix('~')?;
+    let close = body.find('~')?;
+    let inner = body.get(..close)?;
+    let closes_alone = !body.get(close + 1..)?.starts_with('~');
+    (!inner.is_empty() && !inner.contains(char::is_whitespace) && closes_alone).then_some(close + 2)
+}
+
+/// Byte length of the code span at the start of `text`, or of its opening
+/// backticks if the span is never closed
+pub(crate) fn code_span_len(text: &str) -> usize {
+    let ticks = text.len() - text.trim_start_matches('`').len();
+    let fence = "`".repeat(ticks);
+    text.get(ticks..)
+        .and_then(|body| body.find(&fence))
+        .map_or(ticks, |close| ticks + close + ticks)
+}
+
+/// Byte length of the `$...$` or `$$...$$` math span at the start of `text`.
+///
+/// Like Pandoc, the opening delimiter must be followed and the closing one
+/// preceded by a non-space character, and a single `$` can't close right
+/// before a digit. This keeps prices like `$5 and $10` out of math spans.
+fn inline_math_len(text: &str) -> Option<usize> {
+    let delimiter = if text.starts_with("$$") { "$$" } else { "$" };
+    let body = text.strip_prefix(delimiter)?;
+    if body.is_empty() || body.starts_with(|c: char| c.is_whitespace() || c == '$') {
+        return None;
+    }
+
+    let mut search = 0;
+    while let Some(offset) = body.get(search..).and_then(|tail| tail.find(delimiter)) {
+        let close = search + offset;
+        let math = body.get(..close).unwrap_or_default();
+        let after = body.get(close + delimiter.len()..).unwrap_or_default();
+        let padded_or_escaped = math.ends_with(char::is_whitespace) || math.ends_with('\\');
+        let before_number =
+            delimiter == "$" && after.starts_with(|c: char| c.is_ascii_digit() || c == '$');
+        if !padded_or_escaped && !before_number {
+            return Some(delimiter.len() * 2 + close);
+        }
+        search = close + delimiter.len();
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use streamdown_core::Code;
@@ -120,4 +474,197 @@ mod tests {
         assert_eq!(repair_line("   ```", &state_inside_code()), vec!["   ```"]);
         assert_eq!(repair_line("\t```", &state_inside_code()), vec!["\t```"]);
     }
+
+    fn state_inside_math() -> ParseState {
+        let mut state = ParseState::new();
+        state.enter_code_block(Code::Backtick, Some("math".to_string()));
+        state
+    }
+
+    #[test]
+    fn test_single_line_display_math_becomes_fence() {
+        assert_eq!(
+            repair_line(r"$$\int_0^\infty e^{-x} dx = 1$$", &state_outside_code()),
+            vec!["```math", r"\int_0^\infty e^{-x} dx = 1", "```"]
+        );
+    }
+
+    #[test]
+    fn test_multi_line_display_math_becomes_fence() {
+        assert_eq!(repair_line("$$", &state_outside_code()), vec!["```math"]);
+        assert_eq!(
+            repair_line("$$ a_1 + b_1", &state_outside_code()),
+            vec!["```math", "a_1 + b_1"]
+        );
+        assert_eq!(
+            repair_line("a_1 + b_1", &state_inside_math()),
+            vec!["a_1 + b_1"]
+        );
+        assert_eq!(repair_line("$$", &state_inside_math()), vec!["```"]);
+        assert_eq!(
+            repair_line("= c_1$$", &state_inside_math()),
+            vec!["= c_1", "```"]
+        );
+    }
+
+    #[test]
+    fn test_dollars_in_code_block_unchanged() {
+        assert_eq!(repair_line("$$", &state_inside_code()), vec!["$$"]);
+        assert_eq!(
+            repair_line("echo $HOME $PATH", &state_inside_code()),
+            vec!["echo $HOME $PATH"]
+        );
+    }
+
+    #[test]
+    fn test_text_after_closing_display_math_follows_the_fence() {
+        assert_eq!(
+            repair_line("$$x$$ trailing", &state_outside_code()),
+            vec!["```math", "x", "```", "trailing"]
+        );
+        assert_eq!(
+            repair_line("= c_1$$ where c is constant", &state_inside_math()),
+            vec!["= c_1", "```", "where c is constant"]
+        );
+    }
+
+    #[test]
+    fn test_inline_math_wrapped_in_backticks() {
+        assert_eq!(
+            repair_line(
+                r"where $a_1 * b_1$ and $$\sum x$$ hold",
+                &state_outside_code()
+            ),
+            vec![r"where `$a_1 * b_1$` and `$$\sum x$$` hold"]
+        );
+    }
+
+    #[test]
+    fn test_currency_not_treated_as_math() {
+        assert_eq!(
+            repair_line("costs $5 and $10, or $5-$6", &state_outside_code()),
+            vec!["costs $5 and $10, or $5-$6"]
+        );
+    }
+
+    #[test]
+    fn test_dollars_in_code_span_and_escapes_unchanged() {
+        assert_eq!(
+            repair_line(r"run `echo $a$` for \$x\$", &state_outside_code()),
+            vec![r"run `echo $a$` for \$x\$"]
+        );
+    }
+
+    #[test]
+    fn test_subscripts_marked() {
+        assert_eq!(
+            repair_line("H~2~O and ~~gone~~ in ~/src", &state_outside_code()),
+            vec!["H\u{E000}2\u{E000}O and ~~gone~~ in ~/src"]
+        );
+        assert_eq!(repair_line("`a~1~`", &state_outside_code()), vec!["`a~1~`"]);
+    }
+
+    #[test]
+    fn test_strikethrough_marked() {
+        let fixture = [
+            (
+                "~~plain~~ ~~**bold**~~",
+                "~~plain~~ \u{E002}**bold**\u{E002}",
+            ),
+            ("~~a `b`~~ c", "\u{E002}a `b`\u{E002} c"),
+            ("~~done~~ and ~~open", "~~done~~ and \u{E001}open"),
+            ("`~~` ~~~ ~~", "`~~` ~~~ \u{E001}"),
+        ];
+        for (line, expected) in fixture {
+            let actual = repair_line(line, &state_outside_code());
+            assert_eq!(actual, vec![expected], "line: {line:?}");
+        }
+    }
+
+    #[test]
+    fn test_highlights_marked() {
+        let fixture = [
+            ("==note== this", "\u{E003}note\u{E003} this"),
+            ("**==x==**", "**\u{E003}x\u{E003}**"),
+            ("if a == b and c == d", "if a == b and c == d"),
+            ("`==x==` ==open", "`==x==` ==open"),
+            ("=== ==a ==b==", "=== ==a \u{E003}b\u{E003}"),
+        ];
+        for (line, expected) in fixture {
+            let actual = repair_line(line, &state_outside_code());
+            assert_eq!(actual, vec![expected], "line: {line:?}");
+        }
+    }
+
+    #[test]
+    fn test_report_lists_repairs() {
+        let actual = repair_line_with_report("$x_1$ and H~2~O", &state_outside_code());
+        let expected = vec![RepairResult {
+            original: "$x_1$ and H~2~O".to_string(),
+            repaired: "`$x_1$` and H\u{E000}2\u{E000}O".to_string(),
+            actions: vec![RepairAction::InlineMath, RepairAction::Subscript],
+        }];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_report_for_split_line() {
+        let actual = repair_line_with_report("}```", &state_inside_code());
+        let expected = ["}", "```"]
+            .map(|repaired| RepairResult {
+                original: "}```".to_string(),
+                repaired: repaired.to_string(),
+                actions: vec![RepairAction::EmbeddedFence],
+            })
+            .to_vec();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_report_for_unchanged_line() {
+        let actual = repair_line_with_report("plain text", &state_outside_code());
+        let expected = vec![RepairResult {
+            original: "plain text".to_string(),
+            repaired: "plain text".to_string(),
+            actions: Vec::new(),
+        }];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_dangling_delimiters_around_multibyte_text() {
+        let fixture = [
+            "$",
+            "$$",
+            "$$$",
+            "$한$",
+            "~",
+            "~~~",
+            "~한~",
+            "`",
+            "``$x$``",
+            "\\",
+            "$\\",
+            "é$$",
+            "$$é",
+            "~é",
+            "$é",
+            "^",
+            "&",
+            "€$€$€",
+        ];
+        for line in fixture {
+            for state in [
+                state_outside_code(),
+                state_inside_code(),
+                state_inside_math(),
+            ] {
+                let actual = repair_line(line, &state);
+                assert!(!actual.is_empty(), "no output for {line:?}");
+            }
+        }
+    }
 }
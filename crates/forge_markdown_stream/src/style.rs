@@ -5,8 +5,12 @@ pub trait InlineStyler {
     fn italic(&self, text: &str) -> String;
     fn bold_italic(&self, text: &str) -> String;
     fn strikethrough(&self, text: &str) -> String;
+    fn highlight(&self, text: &str) -> String;
     fn underline(&self, text: &str) -> String;
     fn code(&self, text: &str) -> String;
+    fn math(&self, text: &str) -> String;
+    fn superscript(&self, text: &str) -> String;
+    fn subscript(&self, text: &str) -> String;
     fn link(&self, text: &str, url: &str) -> String;
     fn image(&self, alt: &str, url: &str) -> String;
     fn footnote(&self, text: &str) -> String;
@@ -32,6 +36,8 @@ pub trait ListStyler {
     fn number(&self, text: &str) -> String;
     fn checkbox_checked(&self, text: &str) -> String;
     fn checkbox_unchecked(&self, text: &str) -> String;
+    fn task_checked(&self) -> &str;
+    fn task_unchecked(&self) -> &str;
 }
 
 /// Trait for styling table elements.
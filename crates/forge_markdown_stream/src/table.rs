@@ -6,19 +6,79 @@ use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 use crate::inline::render_inline_content;
 use crate::style::{InlineStyler, TableStyler};
 
+/// Horizontal alignment of a table column, taken from the separator row.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Right,
+    Center,
+}
+
+impl Alignment {
+    /// Split the padding needed to fill `width` into left and right parts.
+    fn padding(self, content_width: usize, width: usize) -> (String, String) {
+        let pad = width.saturating_sub(content_width);
+        let (left, right) = match self {
+            Self::Left => (0, pad),
+            Self::Right => (pad, 0),
+            Self::Center => (pad / 2, pad - pad / 2),
+        };
+        (" ".repeat(left), " ".repeat(right))
+    }
+}
+
+/// Parse column alignments from a separator row such as `| :-- | --: | :-: |`.
+pub fn parse_alignments(line: &str) -> Vec<Alignment> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = line.strip_suffix('|').unwrap_or(line);
+    line.split('|')
+        .map(|marker| {
+            let marker = marker.trim();
+            match (
+                marker.starts_with(':'),
+                marker.ends_with(':') && marker.len() > 1,
+            ) {
+                (true, true) => Alignment::Center,
+                (false, true) => Alignment::Right,
+                _ => Alignment::Left,
+            }
+        })
+        .collect()
+}
+
+/// Options for rendering tables.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TableOptions {
+    /// Widest a column may get. Longer cells are cut with `…` instead of
+    /// being wrapped.
+    pub max_column_width: Option<usize>,
+}
+
 /// Render a table with proper column widths, shrinking and wrapping if needed.
+///
+/// Columns without an entry in `alignments` are left-aligned.
 pub fn render_table<S: TableStyler + InlineStyler>(
     rows: &[Vec<String>],
+    alignments: &[Alignment],
     margin: &str,
     styler: &S,
     max_width: usize,
+    options: &TableOptions,
 ) -> Vec<String> {
     // First, render all cells with inline markdown
     let rendered_rows: Vec<Vec<String>> = rows
         .iter()
         .map(|row| {
             row.iter()
-                .map(|cell| render_inline_content(cell, styler))
+                .map(|cell| {
+                    let rendered = render_inline_content(cell, styler);
+                    match options.max_column_width {
+                        Some(max_column_width) => truncate_cell(&rendered, max_column_width),
+                        None => rendered,
+                    }
+                })
                 .collect()
         })
         .collect();
@@ -100,12 +160,14 @@ pub fn render_table<S: TableStyler + InlineStyler>(
                         .and_then(|w| w.get(li))
                         .map(|s| s.as_str())
                         .unwrap_or("");
+                    let c = &trim_trailing_space(c);
                     let width = w.get(i).copied().unwrap_or(0);
-                    let p = " ".repeat(width.saturating_sub(visible_length(c)));
+                    let alignment = alignments.get(i).copied().unwrap_or_default();
+                    let (left, right) = alignment.padding(visible_length(c), width);
                     if ri == 0 && li == 0 && !c.is_empty() {
-                        format!(" {}{} ", styler.header(c), p)
+                        format!(" {}{}{} ", left, styler.header(c), right)
                     } else {
-                        format!(" {}{} ", c, p)
+                        format!(" {}{}{} ", left, c, right)
                     }
                 })
                 .collect::<Vec<_>>()
@@ -129,6 +191,33 @@ pub fn render_table<S: TableStyler + InlineStyler>(
     out
 }
 
+/// Cut a rendered cell to `max_width` columns, ending it with `…`. Styles and
+/// hyperlinks open at the cut are closed so they don't leak into the border.
+fn truncate_cell(cell: &str, max_width: usize) -> String {
+    if visible_length(cell) <= max_width {
+        return cell.to_string();
+    }
+
+    let (mut kept, _) = split_word_at_width(cell, max_width.saturating_sub(1));
+    if kept.contains("\x1b]8;") {
+        kept.push_str("\x1b]8;;\x1b\\");
+    }
+    if kept.contains("\x1b[") {
+        kept.push_str("\x1b[0m");
+    }
+    kept.push('…');
+    kept
+}
+
+/// Drop trailing spaces a wrapped line keeps from the point it broke at,
+/// which would otherwise shift right-aligned and centered content.
+fn trim_trailing_space(line: &str) -> String {
+    match line.strip_suffix("\x1b[0m") {
+        Some(rest) => format!("{}\x1b[0m", rest.trim_end()),
+        None => line.trim_end().to_string(),
+    }
+}
+
 /// Wrap text by words, preserving ANSI codes across lines.
 /// Breaks at spaces, and tries to keep content together when possible.
 /// Handles both CSI sequences (\x1b[...m) and OSC sequences (\x1b]...\x1b\\).
@@ -137,6 +226,13 @@ fn wrap(text: &str, width: usize) -> Vec<String> {
         return vec![text.to_string()];
     }
 
+    // Lines only need closing when the text carries styles, plain text must
+    // stay free of escape codes
+    let reset = if text.contains("\x1b[") {
+        "\x1b[0m"
+    } else {
+        ""
+    };
     let mut lines = Vec::new();
     let mut line = String::new();
     let mut line_width = 0;
@@ -227,7 +323,7 @@ fn wrap(text: &str, width: usize) -> Vec<String> {
             // active style is set `line` carries an ANSI prefix with zero
             // visible chars, and pushing it would render as a blank row.
             if line_width > 0 && line_width + word_width + cw > width {
-                line.push_str("\x1b[0m");
+                line.push_str(reset);
                 lines.push(line);
                 line = active_style.clone().unwrap_or_default();
                 line_width = 0;
@@ -251,7 +347,7 @@ fn wrap(text: &str, width: usize) -> Vec<String> {
             // If word itself exceeds width, break it by character
             if word_width > width {
                 if line_width > 0 {
-                    line.push_str("\x1b[0m");
+                    line.push_str(reset);
                     lines.push(line);
                     line = active_style.clone().unwrap_or_default();
                     line_width = 0;
@@ -261,7 +357,7 @@ fn wrap(text: &str, width: usize) -> Vec<String> {
                     let (chunk, rem) = split_word_at_width(&word, width);
                     if !chunk.is_empty() {
                         line.push_str(&chunk);
-                        line.push_str("\x1b[0m");
+                        line.push_str(reset);
                         lines.push(line);
                         line = active_style.clone().unwrap_or_default();
                         line_width = 0;
@@ -277,7 +373,7 @@ fn wrap(text: &str, width: usize) -> Vec<String> {
     // Add remaining word to line
     if !word.is_empty() {
         if line_width + word_width > width && line_width > 0 {
-            line.push_str("\x1b[0m");
+            line.push_str(reset);
             lines.push(line);
             line = active_style.clone().unwrap_or_default();
             line.push_str(&word);
@@ -395,7 +491,8 @@ mod tests {
             .into_iter()
             .map(|r| r.into_iter().map(|s| s.to_string()).collect())
             .collect();
-        let output = render_table(&rows, "  ", &TagStyler, 80).join("\n");
+        let output =
+            render_table(&rows, &[], "  ", &TagStyler, 80, &TableOptions::default()).join("\n");
         strip_ansi(&output)
     }
 
@@ -404,7 +501,15 @@ mod tests {
             .into_iter()
             .map(|r| r.into_iter().map(|s| s.to_string()).collect())
             .collect();
-        let output = render_table(&rows, "  ", &TagStyler, width).join("\n");
+        let output = render_table(
+            &rows,
+            &[],
+            "  ",
+            &TagStyler,
+            width,
+            &TableOptions::default(),
+        )
+        .join("\n");
         strip_ansi(&output)
     }
 
@@ -413,7 +518,8 @@ mod tests {
             .into_iter()
             .map(|r| r.into_iter().map(|s| s.to_string()).collect())
             .collect();
-        let output = render_table(&rows, margin, &TagStyler, 80).join("\n");
+        let output =
+            render_table(&rows, &[], margin, &TagStyler, 80, &TableOptions::default()).join("\n");
         strip_ansi(&output)
     }
 
@@ -453,17 +559,53 @@ mod tests {
         ]));
     }
 
+    #[test]
+    fn test_max_column_width_truncates_at_limit() {
+        let rows = vec![
+            vec!["Name".to_string(), "Description".to_string()],
+            vec!["a".to_string(), "y".repeat(100)],
+        ];
+        let options = TableOptions { max_column_width: Some(10) };
+        let actual = render_table(&rows, &[], "", &TagStyler, 40, &options);
+
+        let expected = format!("│ a    │ {}… │", "y".repeat(9));
+        assert_eq!(actual.get(3), Some(&expected));
+        assert!(actual.iter().all(|line| line.width() <= 40));
+    }
+
+    #[test]
+    fn test_truncate_cell_closes_styles() {
+        let actual = truncate_cell("\x1b[1mbold text\x1b[0m", 5);
+        let expected = "\x1b[1mbold\x1b[0m…";
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_empty_table() {
         let rows: Vec<Vec<String>> = vec![];
-        let result = render_table(&rows, "  ", &Theme::dark(), 80);
+        let result = render_table(
+            &rows,
+            &[],
+            "  ",
+            &Theme::dark(),
+            80,
+            &TableOptions::default(),
+        );
         assert!(result.is_empty());
     }
 
     #[test]
     fn test_empty_row() {
         let rows: Vec<Vec<String>> = vec![vec![]];
-        let result = render_table(&rows, "  ", &Theme::dark(), 80);
+        let result = render_table(
+            &rows,
+            &[],
+            "  ",
+            &Theme::dark(),
+            80,
+            &TableOptions::default(),
+        );
         assert!(result.is_empty());
     }
 
@@ -494,6 +636,32 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_plain_theme_wrapped_table_has_no_escapes() {
+        let rows = vec![
+            vec!["Name".to_string(), "Description".to_string()],
+            vec![
+                "wrap".to_string(),
+                "A description long enough to wrap across several lines".to_string(),
+            ],
+        ];
+
+        let actual = render_table(
+            &rows,
+            &[],
+            "",
+            &Theme::plain(),
+            40,
+            &TableOptions::default(),
+        );
+
+        assert!(actual.len() > 5, "{actual:#?}");
+        for line in &actual {
+            assert!(!line.contains('\x1b'), "{line:?}");
+            assert_eq!(line.width(), 40, "{line:?}");
+        }
+    }
+
     #[test]
     fn test_narrow_first_col_not_padded() {
         insta::assert_snapshot!(render_with_width(
@@ -830,4 +998,49 @@ mod tests {
             ],
         ]));
     }
+
+    #[test]
+    fn test_parse_alignments() {
+        let actual = parse_alignments("| :--- | ---: | :---: | --- |");
+        let expected = vec![
+            Alignment::Left,
+            Alignment::Right,
+            Alignment::Center,
+            Alignment::Left,
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_aligned_columns() {
+        let rows: Vec<Vec<String>> = [["Label", "Count"], ["a", "7"], ["long label", "1234"]]
+            .iter()
+            .map(|r| r.iter().map(|s| s.to_string()).collect())
+            .collect();
+        let fixture = [Alignment::Center, Alignment::Right];
+
+        let actual = strip_ansi(
+            &render_table(
+                &rows,
+                &fixture,
+                "",
+                &TagStyler,
+                80,
+                &TableOptions::default(),
+            )
+            .join("\n"),
+        );
+
+        let expected = [
+            "┌────────────┬───────┐",
+            "│   Label    │ Count │",
+            "├────────────┼───────┤",
+            "│     a      │     7 │",
+            "├────────────┼───────┤",
+            "│ long label │  1234 │",
+            "└────────────┴───────┘",
+        ]
+        .join("\n");
+        assert_eq!(actual, expected);
+    }
 }
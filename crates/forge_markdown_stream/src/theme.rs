@@ -4,10 +4,33 @@
 //! crate.
 
 use colored::{Color, ColoredString, Colorize};
-use streamdown_parser::decode_html_entities;
 
+use crate::inline::{decode_entities, subscript_or_label, superscript_or_label};
 use crate::style::{HeadingStyler, InlineStyler, ListStyler, TableStyler};
 
+/// Default limit for URLs printed after link text.
+const DEFAULT_MAX_LINK_URL_LENGTH: usize = 80;
+
+/// Default glyphs for task list checkboxes.
+pub(crate) const TASK_UNCHECKED: &str = "☐";
+pub(crate) const TASK_CHECKED: &str = "☑";
+
+/// Nerd Font glyphs for task list checkboxes, see [`Theme::nerd_font_tasks`].
+pub(crate) const NERD_FONT_TASK_UNCHECKED: &str = "";
+pub(crate) const NERD_FONT_TASK_CHECKED: &str = "";
+
+/// Shorten a URL to at most `max_length` characters, ending it with `…` when
+/// cut.
+fn truncate_url(url: &str, max_length: Option<usize>) -> String {
+    match max_length {
+        Some(max_length) if url.chars().count() > max_length => {
+            let kept: String = url.chars().take(max_length.saturating_sub(1)).collect();
+            format!("{}…", kept)
+        }
+        _ => url.to_string(),
+    }
+}
+
 /// Style configuration for a single element.
 #[derive(Clone, Debug, Default)]
 pub struct Style {
@@ -98,8 +121,11 @@ pub struct Theme {
     pub italic: Style,
     pub code: Style,
     pub strikethrough: Style,
+    pub highlight: Style,
     pub link: Style,
     pub link_url: Style,
+    pub superscript: Style,
+    pub subscript: Style,
 
     // Block styles
     pub heading1: Style,
@@ -117,6 +143,10 @@ pub struct Theme {
     pub list_number: Style,
     pub checkbox_checked: Style,
     pub checkbox_unchecked: Style,
+    /// Glyph replacing `[x]` in task list items
+    pub task_checked: String,
+    /// Glyph replacing `[ ]` in task list items
+    pub task_unchecked: String,
 
     // Table styles
     pub table_header: Style,
@@ -132,8 +162,31 @@ pub struct Theme {
     // Code block
     pub code_block_lang: Style,
 
+    // Math
+    pub math_block: Style,
+    pub math_inline: Style,
+
     // Horizontal rule
     pub hr: Style,
+
+    // Link display
+    /// Whether to print the URL in parentheses after the link text
+    pub show_link_url: bool,
+    /// Longest printed URL in characters, longer ones are cut with `…`.
+    /// The terminal hyperlink always keeps the full URL.
+    pub max_link_url_length: Option<usize>,
+
+    /// Surround struck text with `--`, for terminals that can't draw the
+    /// strikethrough attribute
+    pub strikethrough_fallback: bool,
+
+    /// Surround highlighted text with `>>` and `<<`, for terminals that can't
+    /// draw background colors
+    pub highlight_fallback: bool,
+
+    /// Skip the escape sequences that aren't driven by a [`Style`]: the
+    /// underline markup, terminal hyperlinks and code block highlighting.
+    pub no_color: bool,
 }
 
 impl Default for Theme {
@@ -145,46 +198,78 @@ impl Default for Theme {
 /// Theme-based styler that outputs ANSI codes.
 impl InlineStyler for Theme {
     fn text(&self, text: &str) -> String {
-        decode_html_entities(text)
+        decode_entities(text)
     }
 
     fn bold(&self, text: &str) -> String {
-        self.bold.apply(&decode_html_entities(text)).to_string()
+        self.bold.apply(&decode_entities(text)).to_string()
     }
 
     fn italic(&self, text: &str) -> String {
-        self.italic.apply(&decode_html_entities(text)).to_string()
+        self.italic.apply(&decode_entities(text)).to_string()
     }
 
     fn bold_italic(&self, text: &str) -> String {
-        let decoded = decode_html_entities(text);
+        let decoded = decode_entities(text);
         let styled = self.bold.apply(&decoded);
         self.italic.apply(&styled.to_string()).to_string()
     }
 
     fn strikethrough(&self, text: &str) -> String {
-        self.strikethrough
-            .apply(&decode_html_entities(text))
-            .to_string()
+        if self.strikethrough_fallback {
+            return format!("--{}--", decode_entities(text));
+        }
+        self.strikethrough.apply(&decode_entities(text)).to_string()
+    }
+
+    fn highlight(&self, text: &str) -> String {
+        if self.highlight_fallback {
+            return format!(">>{}<<", decode_entities(text));
+        }
+        self.highlight.apply(&decode_entities(text)).to_string()
     }
 
     fn underline(&self, text: &str) -> String {
-        format!("\x1b[4m{}\x1b[24m", decode_html_entities(text))
+        if self.no_color {
+            return decode_entities(text);
+        }
+        format!("\x1b[4m{}\x1b[24m", decode_entities(text))
     }
 
     fn code(&self, text: &str) -> String {
         self.code.apply(text).to_string()
     }
 
+    fn math(&self, text: &str) -> String {
+        self.math_inline.apply(text).to_string()
+    }
+
+    fn superscript(&self, text: &str) -> String {
+        let rendered = superscript_or_label(&decode_entities(text));
+        self.superscript.apply(&rendered).to_string()
+    }
+
+    fn subscript(&self, text: &str) -> String {
+        let rendered = subscript_or_label(&decode_entities(text));
+        self.subscript.apply(&rendered).to_string()
+    }
+
     fn link(&self, text: &str, url: &str) -> String {
         let mut result = String::new();
-        result.push_str("\x1b]8;;");
-        result.push_str(url);
-        result.push_str("\x1b\\");
-        result.push_str(&self.link.apply(&decode_html_entities(text)).to_string());
-        result.push_str("\x1b]8;;\x1b\\");
-        result.push(' ');
-        result.push_str(&self.link_url.apply(&format!("({})", url)).to_string());
+        if self.no_color {
+            result.push_str(&decode_entities(text));
+        } else {
+            result.push_str("\x1b]8;;");
+            result.push_str(url);
+            result.push_str("\x1b\\");
+            result.push_str(&self.link.apply(&decode_entities(text)).to_string());
+            result.push_str("\x1b]8;;\x1b\\");
+        }
+        if self.show_link_url {
+            let url = truncate_url(url, self.max_link_url_length);
+            result.push(' ');
+            result.push_str(&self.link_url.apply(&format!("({})", url)).to_string());
+        }
         result
     }
 
@@ -255,6 +340,14 @@ impl ListStyler for Theme {
     fn checkbox_unchecked(&self, text: &str) -> String {
         self.checkbox_unchecked.apply(text).to_string()
     }
+
+    fn task_checked(&self) -> &str {
+        &self.task_checked
+    }
+
+    fn task_unchecked(&self) -> &str {
+        &self.task_unchecked
+    }
 }
 
 impl TableStyler for Theme {
@@ -279,6 +372,16 @@ impl Theme {
         }
     }
 
+    /// Use Nerd Font glyphs for task list checkboxes, for terminals with a
+    /// patched font installed.
+    pub fn nerd_font_tasks(self) -> Self {
+        Self {
+            task_checked: NERD_FONT_TASK_CHECKED.to_string(),
+            task_unchecked: NERD_FONT_TASK_UNCHECKED.to_string(),
+            ..self
+        }
+    }
+
     /// Dark theme (default).
     pub fn dark() -> Self {
         Self {
@@ -287,8 +390,11 @@ impl Theme {
             italic: Style::new().italic(),
             code: Style::new().fg(Color::Yellow),
             strikethrough: Style::new().strikethrough().dimmed(),
+            highlight: Style::new().fg(Color::Black).bg(Color::Yellow),
             link: Style::new().fg(Color::Cyan).underline(),
             link_url: Style::new().fg(Color::Blue).dimmed(),
+            superscript: Style::new(),
+            subscript: Style::new(),
 
             // Headings
             heading1: Style::new().fg(Color::Magenta).bold(),
@@ -306,6 +412,8 @@ impl Theme {
             list_number: Style::new().fg(Color::Cyan),
             checkbox_checked: Style::new().fg(Color::Green),
             checkbox_unchecked: Style::new().fg(Color::Red),
+            task_checked: TASK_CHECKED.to_string(),
+            task_unchecked: TASK_UNCHECKED.to_string(),
 
             // Tables
             table_header: Style::new().bold(),
@@ -321,8 +429,19 @@ impl Theme {
             // Code block
             code_block_lang: Style::new().fg(Color::BrightBlack).italic(),
 
+            // Math
+            math_block: Style::new().fg(Color::Green),
+            math_inline: Style::new().fg(Color::Green),
+
             // HR
             hr: Style::new().fg(Color::BrightBlack),
+
+            // Link display
+            show_link_url: true,
+            max_link_url_length: Some(DEFAULT_MAX_LINK_URL_LENGTH),
+            strikethrough_fallback: false,
+            highlight_fallback: false,
+            no_color: false,
         }
     }
 
@@ -334,8 +453,11 @@ impl Theme {
             italic: Style::new().italic(),
             code: Style::new().fg(Color::Red),
             strikethrough: Style::new().strikethrough().dimmed(),
+            highlight: Style::new().fg(Color::Black).bg(Color::Yellow),
             link: Style::new().fg(Color::Blue).underline(),
             link_url: Style::new().fg(Color::Cyan).dimmed(),
+            superscript: Style::new(),
+            subscript: Style::new(),
 
             // Headings
             heading1: Style::new().fg(Color::Magenta).bold(),
@@ -353,6 +475,8 @@ impl Theme {
             list_number: Style::new().fg(Color::Blue),
             checkbox_checked: Style::new().fg(Color::Green),
             checkbox_unchecked: Style::new().fg(Color::Red),
+            task_checked: TASK_CHECKED.to_string(),
+            task_unchecked: TASK_UNCHECKED.to_string(),
 
             // Tables
             table_header: Style::new().bold(),
@@ -368,8 +492,83 @@ impl Theme {
             // Code block
             code_block_lang: Style::new().fg(Color::Black).italic(),
 
+            // Math
+            math_block: Style::new().fg(Color::Magenta),
+            math_inline: Style::new().fg(Color::Magenta),
+
             // HR
             hr: Style::new().fg(Color::Black),
+
+            // Link display
+            show_link_url: true,
+            max_link_url_length: Some(DEFAULT_MAX_LINK_URL_LENGTH),
+            strikethrough_fallback: false,
+            highlight_fallback: false,
+            no_color: false,
+        }
+    }
+
+    /// Plain theme without any colors or text attributes, for output that
+    /// isn't shown in a terminal.
+    pub fn plain() -> Self {
+        Self {
+            // Inline
+            bold: Style::new(),
+            italic: Style::new(),
+            code: Style::new(),
+            strikethrough: Style::new(),
+            highlight: Style::new(),
+            link: Style::new(),
+            link_url: Style::new(),
+            superscript: Style::new(),
+            subscript: Style::new(),
+
+            // Headings
+            heading1: Style::new(),
+            heading2: Style::new(),
+            heading3: Style::new(),
+            heading4: Style::new(),
+            heading5: Style::new(),
+            heading6: Style::new(),
+
+            // Lists
+            bullet_dash: Style::new(),
+            bullet_asterisk: Style::new(),
+            bullet_plus: Style::new(),
+            bullet_plus_expand: Style::new(),
+            list_number: Style::new(),
+            checkbox_checked: Style::new(),
+            checkbox_unchecked: Style::new(),
+            task_checked: TASK_CHECKED.to_string(),
+            task_unchecked: TASK_UNCHECKED.to_string(),
+
+            // Tables
+            table_header: Style::new(),
+            table_border: Style::new(),
+            table_cell: Style::new(),
+
+            // Quotes
+            blockquote: Style::new(),
+            blockquote_border: Style::new(),
+            think: Style::new(),
+            think_border: Style::new(),
+
+            // Code block
+            code_block_lang: Style::new(),
+
+            // Math
+            math_block: Style::new(),
+            math_inline: Style::new(),
+
+            // HR
+            hr: Style::new(),
+
+            // Link display
+            show_link_url: true,
+            max_link_url_length: Some(DEFAULT_MAX_LINK_URL_LENGTH),
+            strikethrough_fallback: false,
+            highlight_fallback: false,
+            no_color: true,
         }
     }
 }
@@ -381,35 +580,51 @@ pub struct TagStyler;
 #[cfg(test)]
 impl InlineStyler for TagStyler {
     fn text(&self, text: &str) -> String {
-        decode_html_entities(text)
+        decode_entities(text)
     }
 
     fn bold(&self, text: &str) -> String {
-        format!("<b>{}</b>", decode_html_entities(text))
+        format!("<b>{}</b>", decode_entities(text))
     }
 
     fn italic(&self, text: &str) -> String {
-        format!("<i>{}</i>", decode_html_entities(text))
+        format!("<i>{}</i>", decode_entities(text))
     }
 
     fn bold_italic(&self, text: &str) -> String {
-        format!("<b><i>{}</i></b>", decode_html_entities(text))
+        format!("<b><i>{}</i></b>", decode_entities(text))
     }
 
     fn strikethrough(&self, text: &str) -> String {
-        format!("<s>{}</s>", decode_html_entities(text))
+        format!("<s>{}</s>", decode_entities(text))
+    }
+
+    fn highlight(&self, text: &str) -> String {
+        format!("<mark>{}</mark>", decode_entities(text))
     }
 
     fn underline(&self, text: &str) -> String {
-        format!("<u>{}</u>", decode_html_entities(text))
+        format!("<u>{}</u>", decode_entities(text))
     }
 
     fn code(&self, text: &str) -> String {
         format!("<code>{}</code>", text)
     }
 
+    fn math(&self, text: &str) -> String {
+        format!("<math>{}</math>", text)
+    }
+
+    fn superscript(&self, text: &str) -> String {
+        format!("<sup>{}</sup>", decode_entities(text))
+    }
+
+    fn subscript(&self, text: &str) -> String {
+        format!("<sub>{}</sub>", decode_entities(text))
+    }
+
     fn link(&self, text: &str, url: &str) -> String {
-        format!("<a href=\"{}\">{}</a>", url, decode_html_entities(text))
+        format!("<a href=\"{}\">{}</a>", url, decode_entities(text))
     }
 
     fn image(&self, alt: &str, url: &str) -> String {
@@ -481,6 +696,14 @@ impl ListStyler for TagStyler {
     fn checkbox_unchecked(&self, text: &str) -> String {
         format!("<unchecked>{}</unchecked>", text)
     }
+
+    fn task_checked(&self) -> &str {
+        TASK_CHECKED
+    }
+
+    fn task_unchecked(&self) -> &str {
+        TASK_UNCHECKED
+    }
 }
 
 #[cfg(test)]
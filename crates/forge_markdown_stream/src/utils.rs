@@ -38,6 +38,12 @@ pub fn detect_theme_mode() -> ThemeMode {
     })
 }
 
+/// Whether the user asked for output without colors by setting `NO_COLOR` to
+/// a non-empty value, see <https://no-color.org>.
+pub fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum WrapAtom<'a> {
     Escape(&'a str),
@@ -1,14 +1,23 @@
+use std::collections::BTreeSet;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use forge_app::{AgentRepository, DirectoryReaderInfra, EnvironmentInfra, FileInfoInfra};
 use forge_config::ForgeConfig;
 use forge_domain::{ModelId, ProviderId, Template, ToolName};
+use forge_stream::MpscStream;
 use gray_matter::Matter;
 use gray_matter::engine::YAML;
+use notify::{RecursiveMode, Watcher};
 
 use crate::agent_definition::AgentDefinition;
 
+/// How long the agent files must stay untouched before a batch of changes is
+/// reported, so one save that emits several events reloads the agents once
+const AGENT_WATCH_DEBOUNCE: Duration = Duration::from_millis(200);
+
 /// Infrastructure implementation for loading agent definitions from multiple
 /// sources:
 /// 1. Built-in agents (embedded in the application)
@@ -111,6 +120,23 @@ impl<I: FileInfoInfra + EnvironmentInfra<Config = ForgeConfig> + DirectoryReader
     }
 }
 
+/// Returns the agent files touched by a file system event. Reads are
+/// ignored, and watcher errors are logged so they don't stop the watcher.
+fn changed_agent_files(event: notify::Result<notify::Event>) -> Vec<PathBuf> {
+    match event {
+        Ok(event) if !event.kind.is_access() => event
+            .paths
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|extension| extension == "md"))
+            .collect(),
+        Ok(_) => Vec::new(),
+        Err(error) => {
+            tracing::warn!(%error, "Failed to watch agents");
+            Vec::new()
+        }
+    }
+}
+
 /// Implementation function for resolving agent ID conflicts by keeping the last
 /// occurrence. This implements the precedence order: CWD Custom > Global Custom
 /// > Built-in
@@ -189,7 +215,7 @@ fn parse_agent_file(content: &str) -> Result<AgentDefinition> {
 }
 
 #[async_trait::async_trait]
-impl<F: FileInfoInfra + EnvironmentInfra<Config = ForgeConfig> + DirectoryReaderInfra>
+impl<F: FileInfoInfra + EnvironmentInfra<Config = ForgeConfig> + DirectoryReaderInfra + 'static>
     AgentRepository for ForgeAgentRepository<F>
 {
     async fn get_agents(&self) -> anyhow::Result<Vec<forge_domain::Agent>> {
@@ -223,6 +249,39 @@ impl<F: FileInfoInfra + EnvironmentInfra<Config = ForgeConfig> + DirectoryReader
             })
             .collect())
     }
+
+    async fn watch_agents(&self) -> anyhow::Result<MpscStream<Vec<PathBuf>>> {
+        let env = self.infra.get_environment();
+        let (events_tx, mut events) = tokio::sync::mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            let _ = events_tx.send(event);
+        })?;
+        for dir in [env.agent_path(), env.agent_cwd_path()] {
+            if self.infra.exists(&dir).await.unwrap_or(false) {
+                watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+            }
+        }
+
+        Ok(MpscStream::spawn(move |tx| async move {
+            // The watcher stops when dropped, so it lives as long as the stream
+            let _watcher = watcher;
+            while let Some(event) = events.recv().await {
+                let mut changed: BTreeSet<PathBuf> =
+                    changed_agent_files(event).into_iter().collect();
+                if changed.is_empty() {
+                    continue;
+                }
+                while let Ok(Some(event)) =
+                    tokio::time::timeout(AGENT_WATCH_DEBOUNCE, events.recv()).await
+                {
+                    changed.extend(changed_agent_files(event));
+                }
+                if tx.send(changed.into_iter().collect()).await.is_err() {
+                    break;
+                }
+            }
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -233,6 +292,55 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_changed_agent_files() {
+        use notify::event::{AccessKind, EventKind, ModifyKind};
+
+        let modified = notify::Event::new(EventKind::Modify(ModifyKind::Any))
+            .add_path(PathBuf::from("agents/a.md"))
+            .add_path(PathBuf::from("agents/notes.txt"));
+        let read = notify::Event::new(EventKind::Access(AccessKind::Any))
+            .add_path(PathBuf::from("agents/b.md"));
+
+        let actual = (
+            changed_agent_files(Ok(modified)),
+            changed_agent_files(Ok(read)),
+        );
+
+        let expected = (vec![PathBuf::from("agents/a.md")], vec![]);
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_watch_agents_reports_modified_file() {
+        use futures::StreamExt;
+
+        let cwd = tempfile::tempdir().unwrap();
+        let agents_dir = cwd.path().join(".forge/agents");
+        std::fs::create_dir_all(&agents_dir).unwrap();
+        let agent_file = agents_dir.join("test-basic.md");
+        let content = forge_test_kit::fixture!("/src/fixtures/agents/basic.md").await;
+        std::fs::write(&agent_file, &content).unwrap();
+        let config = forge_config::ConfigReader::default()
+            .read_defaults()
+            .build()
+            .unwrap();
+        let infra = Arc::new(forge_infra::ForgeInfra::new(
+            cwd.path().to_path_buf(),
+            config,
+        ));
+        let repo = ForgeAgentRepository::new(infra);
+        let mut stream = repo.watch_agents().await.unwrap();
+
+        std::fs::write(&agent_file, content.replace("Basic Test", "Updated Test")).unwrap();
+        let actual = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(actual, vec![agent_file]);
+    }
+
     #[tokio::test]
     async fn test_parse_basic_agent() {
         let content = forge_test_kit::fixture!("/src/fixtures/agents/basic.md").await;
@@ -1,7 +1,7 @@
 use derive_setters::Setters;
 use forge_domain::{
-    Agent, AgentId, Compact, EventContext, MaxTokens, ModelId, ProviderId, ReasoningConfig,
-    SystemContext, Temperature, Template, ToolName, TopK, TopP,
+    Agent, AgentId, Compact, ContextPruner, EventContext, MaxTokens, MinP, ModelId, ProviderId,
+    ReasoningConfig, RepetitionPenalty, SystemContext, Temperature, Template, ToolName, TopK, TopP,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -61,6 +61,11 @@ pub(crate) struct AgentDefinition {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub compact: Option<Compact>,
 
+    /// Strategy used to keep requests within the model's context window
+    /// (`compact`, `oldest_first` or `summarize_and_prune`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pruner: Option<ContextPruner>,
+
     /// A set of custom rules that the agent should follow
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_rules: Option<String>,
@@ -102,6 +107,28 @@ pub(crate) struct AgentDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub top_k: Option<TopK>,
 
+    /// Min-p used for agent
+    ///
+    /// Discards tokens whose probability is below `min_p` times that of the
+    /// most likely token. Only forwarded to providers that support it, such
+    /// as Ollama and LM Studio.
+    /// - Valid range is 0.0 to 1.0
+    /// - If not specified, the model provider's default will be used
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_p: Option<MinP>,
+
+    /// Repetition penalty used for agent
+    ///
+    /// Penalizes tokens that already appeared in the prompt or the response.
+    /// Only forwarded to providers that support it, such as Together AI.
+    /// - 1.0 applies no penalty, higher values discourage repetition
+    /// - Valid range is 0.0 to 2.0
+    /// - If not specified, the model provider's default will be used
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repetition_penalty: Option<RepetitionPenalty>,
+
     /// Maximum number of tokens the model can generate
     ///
     /// Controls the maximum length of the model's response.
@@ -154,9 +181,12 @@ impl AgentDefinition {
             max_tokens: self.max_tokens,
             top_p: self.top_p,
             top_k: self.top_k,
+            min_p: self.min_p,
+            repetition_penalty: self.repetition_penalty,
             tools: self.tools,
             reasoning: self.reasoning,
             compact: self.compact.unwrap_or_default(),
+            pruner: self.pruner.unwrap_or_default(),
             max_turns: self.max_turns,
             custom_rules: self.custom_rules,
             max_tool_failure_per_turn: self.max_tool_failure_per_turn,
@@ -377,6 +377,7 @@ pub(super) enum ToolValueRecord {
         conversation_id: String,
     },
     Image(ImageRecord),
+    Json(serde_json::Value),
     Empty,
     // Legacy variants for backward compatibility with old conversations
     // These were removed from the domain model but may exist in stored data
@@ -405,6 +406,7 @@ impl From<&forge_domain::ToolValue> for ToolValueRecord {
                 conversation_id: conversation_id.into_string(),
             },
             forge_domain::ToolValue::Image(img) => Self::Image(ImageRecord::from(img)),
+            forge_domain::ToolValue::Json(json) => Self::Json(json.clone()),
             forge_domain::ToolValue::Empty => Self::Empty,
         }
     }
@@ -421,6 +423,7 @@ impl TryFrom<ToolValueRecord> for forge_domain::ToolValue {
                 conversation_id: ConversationId::parse(conversation_id)?,
             },
             ToolValueRecord::Image(img) => Self::Image(img.into()),
+            ToolValueRecord::Json(json) => Self::Json(json),
             ToolValueRecord::Empty => Self::Empty,
             // Legacy variant migrations
             ToolValueRecord::Markdown(md) => Self::Text(md),
@@ -741,6 +744,10 @@ pub(super) struct ContextRecord {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     top_k: Option<u32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_p: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    repetition_penalty: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     reasoning: Option<ReasoningConfigRecord>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     stream: Option<bool>,
@@ -766,6 +773,8 @@ impl From<&Context> for ContextRecord {
             temperature: context.temperature.map(|t| t.value()),
             top_p: context.top_p.map(|t| t.value()),
             top_k: context.top_k.map(|t| t.value()),
+            min_p: context.min_p.map(|t| t.value()),
+            repetition_penalty: context.repetition_penalty.map(|t| t.value()),
             reasoning: context.reasoning.as_ref().map(ReasoningConfigRecord::from),
             stream: context.stream,
         }
@@ -816,6 +825,10 @@ impl TryFrom<ContextRecord> for Context {
                 .map(forge_domain::Temperature::new_unchecked),
             top_p: record.top_p.map(forge_domain::TopP::new_unchecked),
             top_k: record.top_k.map(forge_domain::TopK::new_unchecked),
+            min_p: record.min_p.map(forge_domain::MinP::new_unchecked),
+            repetition_penalty: record
+                .repetition_penalty
+                .map(forge_domain::RepetitionPenalty::new_unchecked),
             reasoning: record.reasoning.map(Into::into),
             stream: record.stream,
             response_format: None,
@@ -949,6 +962,7 @@ pub(super) struct ConversationRecord {
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: Option<chrono::NaiveDateTime>,
     pub metrics: Option<String>,
+    pub request_metadata: Option<String>,
 }
 
 impl ConversationRecord {
@@ -966,6 +980,9 @@ impl ConversationRecord {
         let updated_at = context.as_ref().map(|_| chrono::Utc::now().naive_utc());
         let metrics_record = MetricsRecord::from(&conversation.metrics);
         let metrics = serde_json::to_string(&metrics_record).ok();
+        let request_metadata = Some(&conversation.metadata.request_metadata)
+            .filter(|metadata| !metadata.is_empty())
+            .and_then(|metadata| serde_json::to_string(metadata).ok());
 
         Self {
             conversation_id: conversation.id.into_string(),
@@ -975,6 +992,7 @@ impl ConversationRecord {
             updated_at,
             workspace_id: workspace_id.id() as i64,
             metrics,
+            request_metadata,
         }
     }
 }
@@ -1017,13 +1035,19 @@ impl TryFrom<ConversationRecord> for forge_domain::Conversation {
                 forge_domain::Metrics::default().started_at(record.created_at.and_utc())
             });
 
+        let request_metadata: std::collections::HashMap<String, serde_json::Value> = record
+            .request_metadata
+            .and_then(|m| serde_json::from_str(&m).ok())
+            .unwrap_or_default();
+
         Ok(forge_domain::Conversation::new(id)
             .context(context)
             .title(record.title)
             .metrics(metrics)
             .metadata(
                 forge_domain::MetaData::new(record.created_at.and_utc())
-                    .updated_at(record.updated_at.map(|updated_at| updated_at.and_utc())),
+                    .updated_at(record.updated_at.map(|updated_at| updated_at.and_utc()))
+                    .request_metadata(request_metadata),
             ))
     }
 }
@@ -52,10 +52,17 @@ impl ConversationRepository for ConversationRepositoryImpl {
                 .on_conflict(conversations::conversation_id)
                 .do_update()
                 .set((
-                    conversations::title.eq(&record.title),
+                    // A turn saved without a title must not clear one that was
+                    // generated in the background meanwhile
+                    conversations::title.eq(diesel::dsl::sql::<
+                        diesel::sql_types::Nullable<diesel::sql_types::Text>,
+                    >(
+                        "COALESCE(excluded.title, conversations.title)"
+                    )),
                     conversations::context.eq(&record.context),
                     conversations::updated_at.eq(record.updated_at),
                     conversations::metrics.eq(&record.metrics),
+                    conversations::request_metadata.eq(&record.request_metadata),
                 ))
                 .execute(connection)?;
             Ok(())
@@ -63,6 +70,22 @@ impl ConversationRepository for ConversationRepositoryImpl {
         .await
     }
 
+    async fn update_title(
+        &self,
+        conversation_id: &ConversationId,
+        title: String,
+    ) -> anyhow::Result<()> {
+        let conversation_id = *conversation_id;
+        self.run_with_connection(move |connection, _wid| {
+            diesel::update(conversations::table)
+                .filter(conversations::conversation_id.eq(conversation_id.into_string()))
+                .set(conversations::title.eq(title))
+                .execute(connection)?;
+            Ok(())
+        })
+        .await
+    }
+
     async fn get_conversation(
         &self,
         conversation_id: &ConversationId,
@@ -381,6 +404,7 @@ mod tests {
             updated_at: None,
             workspace_id: 0,
             metrics: None,
+            request_metadata: None,
         };
 
         let actual = Conversation::try_from(fixture)?;
@@ -437,6 +461,26 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_upsert_and_retrieve_conversation_with_request_metadata() -> anyhow::Result<()> {
+        let repo = repository()?;
+        let mut fixture = Conversation::generate();
+        fixture
+            .metadata
+            .request_metadata
+            .insert("ticket".to_string(), serde_json::json!("FORGE-42"));
+
+        repo.upsert_conversation(fixture.clone()).await?;
+        let actual = repo
+            .get_conversation(&fixture.id)
+            .await?
+            .expect("Conversation should exist");
+
+        let expected = fixture.metadata.request_metadata;
+        assert_eq!(actual.metadata.request_metadata, expected);
+        Ok(())
+    }
+
     #[test]
     fn test_metrics_record_conversion_preserves_all_fields() {
         // This test ensures compile-time safety: if Metrics schema changes,
@@ -825,6 +869,7 @@ mod tests {
             updated_at: None,
             workspace_id: 0,
             metrics: None,
+            request_metadata: None,
         };
 
         let result = Conversation::try_from(fixture);
@@ -945,6 +990,45 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_generated_title_survives_later_turn() -> anyhow::Result<()> {
+        let repo = repository()?;
+        let conversation = Conversation::new(ConversationId::generate());
+        repo.upsert_conversation(conversation.clone()).await?;
+
+        // The title arrives from the background job while the turn still holds
+        // its untitled copy, which is saved once the turn ends
+        repo.update_title(&conversation.id, "Generated Title".to_string())
+            .await?;
+        let turn = conversation.context(Some(
+            Context::default().messages(vec![ContextMessage::user("Hello", None).into()]),
+        ));
+        repo.upsert_conversation(turn.clone()).await?;
+
+        let actual = repo.get_conversation(&turn.id).await?.unwrap();
+        assert_eq!(actual.title, Some("Generated Title".to_string()));
+        assert_eq!(actual.context, turn.context);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_title_keeps_context() -> anyhow::Result<()> {
+        let repo = repository()?;
+        let fixture = Conversation::new(ConversationId::generate()).context(Some(
+            Context::default().messages(vec![ContextMessage::user("Hello", None).into()]),
+        ));
+        repo.upsert_conversation(fixture.clone()).await?;
+
+        repo.update_title(&fixture.id, "Greeting".to_string())
+            .await?;
+
+        let actual = repo.get_conversation(&fixture.id).await?.unwrap();
+        let expected = fixture.title(Some("Greeting".to_string()));
+        assert_eq!(actual.title, expected.title);
+        assert_eq!(actual.context, expected.context);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_rename_conversation_from_none() -> anyhow::Result<()> {
         let repo = repository()?;
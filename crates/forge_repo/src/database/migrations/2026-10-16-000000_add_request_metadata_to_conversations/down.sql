@@ -0,0 +1,2 @@
+-- Remove request_metadata column from conversations table
+ALTER TABLE conversations DROP COLUMN request_metadata;
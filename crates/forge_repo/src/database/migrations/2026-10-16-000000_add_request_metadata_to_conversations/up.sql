@@ -0,0 +1,2 @@
+-- Add request_metadata column to conversations table
+ALTER TABLE conversations ADD COLUMN request_metadata TEXT;
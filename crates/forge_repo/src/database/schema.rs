@@ -9,5 +9,6 @@ diesel::table! {
         created_at -> Timestamp,
         updated_at -> Nullable<Timestamp>,
         metrics -> Nullable<Text>,
+        request_metadata -> Nullable<Text>,
     }
 }
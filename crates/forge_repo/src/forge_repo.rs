@@ -6,19 +6,21 @@ use bytes::Bytes;
 use forge_app::{
     AgentRepository, CommandInfra, DirectoryReaderInfra, EnvironmentInfra, FileDirectoryInfra,
     FileInfoInfra, FileReaderInfra, FileRemoverInfra, FileWriterInfra, GrpcInfra, HttpInfra,
-    KVStore, McpServerInfra, StrategyFactory, UserInfra, WalkedFile, Walker, WalkerInfra,
+    KVStore, KeychainInfra, McpServerInfra, StrategyFactory, UserInfra, WalkedFile, Walker,
+    WalkerInfra,
 };
 use forge_config::ForgeConfig;
 use forge_domain::{
-    AnyProvider, AuthCredential, ChatCompletionMessage, ChatRepository, CommandOutput, Context,
-    Conversation, ConversationId, ConversationRepository, Environment, FileInfo,
-    FuzzySearchRepository, McpServerConfig, MigrationResult, Model, ModelId, Provider, ProviderId,
-    ProviderRepository, ResultStream, SearchMatch, Skill, SkillRepository, Snapshot,
-    SnapshotRepository, TextPatchBlock, TextPatchRepository,
+    AnyProvider, AuthCredential, BatchRepository, BatchRequest, BatchStatus, ChatCompletionMessage,
+    ChatRepository, CommandOutput, Context, Conversation, ConversationId, ConversationRepository,
+    Environment, FileInfo, FuzzySearchRepository, McpServerConfig, MigrationResult, Model, ModelId,
+    Provider, ProviderId, ProviderRepository, ResultStream, SearchMatch, Skill, SkillRepository,
+    Snapshot, SnapshotRepository, TextPatchBlock, TextPatchRepository,
 };
 use forge_eventsource::EventSource;
 // Re-export CacacheStorage from forge_infra
 pub use forge_infra::CacacheStorage;
+use forge_stream::MpscStream;
 use reqwest::Response;
 use reqwest::header::HeaderMap;
 use url::Url;
@@ -108,6 +110,20 @@ impl<F: Send + Sync> SnapshotRepository for ForgeRepo<F> {
     async fn undo_snapshot(&self, file_path: &Path) -> anyhow::Result<()> {
         self.file_snapshot_service.undo_snapshot(file_path).await
     }
+
+    async fn list_snapshots_for_turn(
+        &self,
+        file_paths: &[PathBuf],
+        turn_started_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Vec<Snapshot>> {
+        self.file_snapshot_service
+            .list_snapshots_for_turn(file_paths, turn_started_at)
+            .await
+    }
+
+    async fn restore_snapshot(&self, snapshot: &Snapshot) -> anyhow::Result<()> {
+        self.file_snapshot_service.restore_snapshot(snapshot).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -118,6 +134,16 @@ impl<F: Send + Sync> ConversationRepository for ForgeRepo<F> {
             .await
     }
 
+    async fn update_title(
+        &self,
+        conversation_id: &ConversationId,
+        title: String,
+    ) -> anyhow::Result<()> {
+        self.conversation_repository
+            .update_title(conversation_id, title)
+            .await
+    }
+
     async fn get_conversation(
         &self,
         conversation_id: &ConversationId,
@@ -179,6 +205,38 @@ impl<
         + HttpInfra
         + Send
         + Sync,
+> BatchRepository for ForgeRepo<F>
+{
+    async fn submit_batch(
+        &self,
+        model_id: &ModelId,
+        requests: Vec<BatchRequest>,
+        provider: Provider<Url>,
+    ) -> anyhow::Result<String> {
+        self.chat_repository
+            .submit_batch(model_id, requests, provider)
+            .await
+    }
+
+    async fn poll_batch(
+        &self,
+        batch_id: &str,
+        provider: Provider<Url>,
+    ) -> anyhow::Result<BatchStatus> {
+        self.chat_repository.poll_batch(batch_id, provider).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<
+    F: EnvironmentInfra<Config = forge_config::ForgeConfig>
+        + FileReaderInfra
+        + FileWriterInfra
+        + FileRemoverInfra
+        + HttpInfra
+        + KeychainInfra
+        + Send
+        + Sync,
 > ProviderRepository for ForgeRepo<F>
 {
     async fn get_all_providers(&self) -> anyhow::Result<Vec<AnyProvider>> {
@@ -198,6 +256,10 @@ impl<
         self.provider_repository.get_credential(id).await
     }
 
+    async fn get_credentials(&self, id: &ProviderId) -> anyhow::Result<Vec<AuthCredential>> {
+        self.provider_repository.get_credentials(id).await
+    }
+
     async fn remove_credential(&self, id: &ProviderId) -> anyhow::Result<()> {
         // All providers now use file-based credentials
         self.provider_repository.remove_credential(id).await
@@ -488,8 +550,14 @@ where
 }
 
 #[async_trait::async_trait]
-impl<F: FileInfoInfra + EnvironmentInfra<Config = ForgeConfig> + DirectoryReaderInfra + Send + Sync>
-    AgentRepository for ForgeRepo<F>
+impl<
+    F: FileInfoInfra
+        + EnvironmentInfra<Config = ForgeConfig>
+        + DirectoryReaderInfra
+        + Send
+        + Sync
+        + 'static,
+> AgentRepository for ForgeRepo<F>
 {
     async fn get_agents(&self) -> anyhow::Result<Vec<forge_domain::Agent>> {
         self.agent_repository.get_agents().await
@@ -498,6 +566,10 @@ impl<F: FileInfoInfra + EnvironmentInfra<Config = ForgeConfig> + DirectoryReader
     async fn get_agent_infos(&self) -> anyhow::Result<Vec<forge_domain::AgentInfo>> {
         self.agent_repository.get_agent_infos().await
     }
+
+    async fn watch_agents(&self) -> anyhow::Result<MpscStream<Vec<PathBuf>>> {
+        self.agent_repository.watch_agents().await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1,7 +1,8 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use forge_domain::{Environment, Snapshot, SnapshotRepository};
 
 pub struct ForgeFileSnapshotService {
@@ -27,4 +28,75 @@ impl SnapshotRepository for ForgeFileSnapshotService {
     async fn undo_snapshot(&self, file_path: &Path) -> Result<()> {
         self.inner.undo_snapshot(file_path.to_path_buf()).await
     }
+
+    // Rollback
+    async fn list_snapshots_for_turn(
+        &self,
+        file_paths: &[PathBuf],
+        turn_started_at: DateTime<Utc>,
+    ) -> Result<Vec<Snapshot>> {
+        let mut snapshots = Vec::new();
+        for path in file_paths {
+            let earliest = self
+                .inner
+                .list_snapshots(path.clone())
+                .await?
+                .into_iter()
+                .find(|snapshot| snapshot.created_at() >= turn_started_at);
+            snapshots.extend(earliest);
+        }
+        Ok(snapshots)
+    }
+
+    async fn restore_snapshot(&self, snapshot: &Snapshot) -> Result<()> {
+        self.inner.restore_snapshot(snapshot).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::{Fake, Faker};
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn service(base: &Path) -> ForgeFileSnapshotService {
+        let mut env: Environment = Faker.fake();
+        env.base_path = base.to_path_buf();
+        ForgeFileSnapshotService::new(env)
+    }
+
+    #[tokio::test]
+    async fn test_rollback_turn_restores_content_before_turn() -> Result<()> {
+        let temp = TempDir::new()?;
+        let base = temp.path().canonicalize()?;
+        let file = base.join("file.txt");
+        let service = service(&base);
+
+        // Earlier turn
+        tokio::fs::write(&file, "original").await?;
+        service.insert_snapshot(&file).await?;
+        tokio::fs::write(&file, "first turn").await?;
+
+        // Last turn writes the file twice
+        let turn_started_at = Utc::now();
+        service.insert_snapshot(&file).await?;
+        tokio::fs::write(&file, "second turn").await?;
+        service.insert_snapshot(&file).await?;
+        tokio::fs::write(&file, "second turn, again").await?;
+
+        let snapshots = service
+            .list_snapshots_for_turn(std::slice::from_ref(&file), turn_started_at)
+            .await?;
+        for snapshot in &snapshots {
+            service.restore_snapshot(snapshot).await?;
+        }
+
+        let actual = tokio::fs::read_to_string(&file).await?;
+        let expected = "first turn";
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(actual, expected);
+        Ok(())
+    }
 }
@@ -1,3 +1,5 @@
+mod batch;
+
 use std::sync::Arc;
 
 use anyhow::Context as _;
@@ -6,8 +8,8 @@ use forge_app::domain::{
 };
 use forge_app::dto::anthropic::{
     AuthSystemMessage, CapitalizeToolNames, DropInvalidToolUse, EnforceStrictObjectSchema,
-    EventData, ListModelResponse, McpToolNames, ReasoningTransform, RemoveOutputFormat, Request,
-    SanitizeToolIds, SetCache,
+    EventData, ListModelResponse, McpToolNames, MessageStart, ReasoningTransform,
+    RemoveOutputFormat, Request, SanitizeToolIds, SetCache,
 };
 use forge_app::{EnvironmentInfra, HttpInfra};
 use forge_domain::{ChatRepository, Provider, ProviderId};
@@ -152,11 +154,17 @@ impl<T: HttpInfra> Anthropic<T> {
                 .transform(request)
         };
 
+        let stream = request.stream != Some(false);
         let url = if self.provider.id == ProviderId::VERTEX_AI_ANTHROPIC {
             // For Vertex AI, we need to append the model ID and streamRawPredict to the URL
             // The chat_url from provider.json ends with .../models
             let base = self.provider.url.as_str().trim_end_matches('/');
-            format!("{}/{}:streamRawPredict", base, model.as_str())
+            let method = if stream {
+                "streamRawPredict"
+            } else {
+                "rawPredict"
+            };
+            format!("{}/{}:{}", base, model.as_str(), method)
         } else {
             self.provider.url.to_string()
         };
@@ -169,6 +177,10 @@ impl<T: HttpInfra> Anthropic<T> {
         let parsed_url = Url::parse(&url).with_context(|| format!("Invalid URL: {}", url))?;
         let headers = create_headers(self.get_headers(Some(model)));
 
+        if !stream {
+            return self.chat_complete(&parsed_url, headers, json_bytes).await;
+        }
+
         if self.should_use_raw_sse() {
             return self.chat_raw_sse(&parsed_url, headers, json_bytes).await;
         }
@@ -184,6 +196,38 @@ impl<T: HttpInfra> Anthropic<T> {
         Ok(Box::pin(stream))
     }
 
+    /// Sends a non-streaming Messages request and yields the complete response
+    /// as a single message
+    async fn chat_complete(
+        &self,
+        parsed_url: &Url,
+        headers: HeaderMap,
+        json_bytes: Vec<u8>,
+    ) -> ResultStream<ChatCompletionMessage, anyhow::Error> {
+        let response = self
+            .http
+            .http_post(parsed_url, Some(headers), json_bytes.into())
+            .await
+            .with_context(|| format_http_context(None, "POST", parsed_url))?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .with_context(|| format_http_context(Some(status), "POST", parsed_url))?;
+        if !status.is_success() {
+            return Err(anyhow::anyhow!(body))
+                .with_context(|| format_http_context(Some(status), "POST", parsed_url));
+        }
+
+        let message = serde_json::from_str::<MessageStart>(&body)
+            .with_context(|| format!("Failed to parse provider response: {body}"))
+            .and_then(ChatCompletionMessage::try_from)
+            .with_context(|| format_http_context(Some(status), "POST", parsed_url))?;
+
+        Ok(Box::pin(futures::stream::once(async move { Ok(message) })))
+    }
+
     /// Streams Anthropic events from a raw byte response body and parses
     /// SSE events manually. This bypasses reqwest-eventsource content-type
     /// validation for providers that return non-standard SSE content types.
@@ -429,11 +473,15 @@ mod tests {
 
         async fn http_post(
             &self,
-            _url: &Url,
-            _headers: Option<HeaderMap>,
-            _body: Bytes,
+            url: &Url,
+            headers: Option<HeaderMap>,
+            body: Bytes,
         ) -> anyhow::Result<reqwest::Response> {
-            unimplemented!()
+            let mut request = self.client.post(url.clone()).body(body);
+            if let Some(headers) = headers {
+                request = request.headers(headers);
+            }
+            Ok(request.send().await?)
         }
 
         async fn http_delete(&self, _url: &Url) -> anyhow::Result<reqwest::Response> {
@@ -466,6 +514,8 @@ mod tests {
                     "sk-test-key".to_string(),
                 )),
                 url_params: std::collections::HashMap::new(),
+                alias: None,
+                expires_at: None,
             }),
             auth_methods: vec![forge_domain::AuthMethod::ApiKey],
             url_params: vec![],
@@ -534,6 +584,8 @@ mod tests {
                     "sk-some-key".to_string(),
                 )),
                 url_params: std::collections::HashMap::new(),
+                alias: None,
+                expires_at: None,
             }),
             auth_methods: vec![forge_domain::AuthMethod::ApiKey],
             url_params: vec![],
@@ -608,6 +660,46 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_chat_without_streaming() -> anyhow::Result<()> {
+        let mut fixture = MockServer::new().await;
+        let mock = fixture
+            .mock_chat_completion(
+                "/messages",
+                serde_json::json!({
+                    "id": "msg_01",
+                    "type": "message",
+                    "role": "assistant",
+                    "model": "claude-sonnet-4-5",
+                    "content": [{ "type": "text", "text": "Hello there" }],
+                    "stop_reason": "end_turn",
+                    "stop_sequence": null,
+                    "usage": { "input_tokens": 5, "output_tokens": 2 }
+                }),
+            )
+            .await;
+        let anthropic = create_anthropic(&fixture.url())?;
+        let context = Context::default()
+            .add_message(ContextMessage::user("Hi", None))
+            .stream(false);
+
+        let actual = anthropic
+            .chat(&ModelId::new("claude-sonnet-4-5"), context)
+            .await?
+            .collect::<Vec<_>>()
+            .await;
+
+        mock.assert_async().await;
+        assert_eq!(actual.len(), 1);
+        let message = actual.into_iter().next().unwrap()?;
+        assert_eq!(message.content.unwrap().as_str(), "Hello there");
+        assert_eq!(
+            message.finish_reason,
+            Some(forge_domain::FinishReason::Stop)
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_fetch_models_http_error_status() -> anyhow::Result<()> {
         let mut fixture = MockServer::new().await;
@@ -674,6 +766,8 @@ mod tests {
                     "sk-test-key".to_string(),
                 )),
                 url_params: std::collections::HashMap::new(),
+                alias: None,
+                expires_at: None,
             }),
             auth_methods: vec![forge_domain::AuthMethod::ApiKey],
             url_params: vec![],
@@ -756,6 +850,8 @@ mod tests {
                     },
                 },
                 url_params: std::collections::HashMap::new(),
+                alias: None,
+                expires_at: None,
             }),
             auth_methods: vec![forge_domain::AuthMethod::ApiKey],
             url_params: vec![],
@@ -822,6 +918,8 @@ mod tests {
                     "sk-test-key".to_string(),
                 )),
                 url_params: std::collections::HashMap::new(),
+                alias: None,
+                expires_at: None,
             }),
             auth_methods: vec![forge_domain::AuthMethod::ApiKey],
             url_params: vec![],
@@ -880,6 +978,8 @@ mod tests {
                     "sk-test-key".to_string(),
                 )),
                 url_params: std::collections::HashMap::new(),
+                alias: None,
+                expires_at: None,
             }),
             auth_methods: vec![forge_domain::AuthMethod::ApiKey],
             url_params: vec![],
@@ -944,6 +1044,8 @@ mod tests {
                     "test-token".to_string(),
                 )),
                 url_params: std::collections::HashMap::new(),
+                alias: None,
+                expires_at: None,
             }),
             auth_methods: vec![forge_domain::AuthMethod::GoogleAdc],
             url_params: vec![],
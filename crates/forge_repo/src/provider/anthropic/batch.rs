@@ -0,0 +1,398 @@
+use anyhow::Context as _;
+use forge_app::HttpInfra;
+use forge_domain::{BatchRequest, BatchResult, BatchStatus, ModelId, Provider, ProviderId};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use super::{Anthropic, AnthropicResponseRepository};
+use crate::provider::utils::{create_headers, format_http_context};
+
+/// Maximum number of tokens generated for each request in a batch
+const BATCH_MAX_TOKENS: u64 = 4000;
+
+#[derive(Serialize)]
+struct CreateBatchRequest<'a> {
+    requests: Vec<BatchItem<'a>>,
+}
+
+#[derive(Serialize)]
+struct BatchItem<'a> {
+    custom_id: &'a str,
+    params: BatchParams<'a>,
+}
+
+#[derive(Serialize)]
+struct BatchParams<'a> {
+    model: &'a str,
+    max_tokens: u64,
+    messages: [BatchMessage<'a>; 1],
+}
+
+#[derive(Serialize)]
+struct BatchMessage<'a> {
+    role: &'static str,
+    content: &'a str,
+}
+
+#[derive(Deserialize)]
+struct MessageBatch {
+    id: String,
+    processing_status: String,
+    #[serde(default)]
+    results_url: Option<Url>,
+}
+
+#[derive(Deserialize)]
+struct BatchResultLine {
+    custom_id: String,
+    result: BatchResultBody,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum BatchResultBody {
+    Succeeded { message: BatchResultMessage },
+    Errored { error: serde_json::Value },
+    Canceled,
+    Expired,
+}
+
+#[derive(Deserialize)]
+struct BatchResultMessage {
+    content: Vec<BatchResultContent>,
+}
+
+#[derive(Deserialize)]
+struct BatchResultContent {
+    #[serde(default)]
+    text: Option<String>,
+}
+
+impl From<BatchResultLine> for BatchResult {
+    fn from(line: BatchResultLine) -> Self {
+        let (content, error) = match line.result {
+            BatchResultBody::Succeeded { message } => {
+                let text = message
+                    .content
+                    .into_iter()
+                    .filter_map(|content| content.text)
+                    .collect::<String>();
+                (Some(text), None)
+            }
+            BatchResultBody::Errored { error } => (None, Some(error.to_string())),
+            BatchResultBody::Canceled => (None, Some("canceled".to_string())),
+            BatchResultBody::Expired => (None, Some("expired".to_string())),
+        };
+        Self { id: line.custom_id, content, error }
+    }
+}
+
+impl<T: HttpInfra> Anthropic<T> {
+    /// Returns the Message Batches endpoint, derived from the messages URL
+    fn batches_url(&self) -> anyhow::Result<Url> {
+        if self.provider.id == ProviderId::VERTEX_AI_ANTHROPIC {
+            anyhow::bail!("Message batches are not supported for {}", self.provider.id);
+        }
+        let base = self.provider.url.as_str().trim_end_matches('/');
+        Url::parse(&format!("{base}/batches")).with_context(|| format!("Invalid URL: {base}"))
+    }
+
+    /// Sends a request and returns the response body, treating non-2xx
+    /// statuses as errors
+    async fn send_batch_request(
+        &self,
+        method: &str,
+        url: &Url,
+        body: Option<Vec<u8>>,
+    ) -> anyhow::Result<String> {
+        let headers = Some(create_headers(self.get_headers(None)));
+        let response = match body {
+            Some(body) => self.http.http_post(url, headers, body.into()).await,
+            None => self.http.http_get(url, headers).await,
+        }
+        .with_context(|| format_http_context(None, method, url))?;
+
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .with_context(|| format_http_context(Some(status), method, url))?;
+        if !status.is_success() {
+            return Err(anyhow::anyhow!(text))
+                .with_context(|| format_http_context(Some(status), method, url));
+        }
+        Ok(text)
+    }
+
+    pub async fn submit_batch(
+        &self,
+        model: &ModelId,
+        requests: &[BatchRequest],
+    ) -> anyhow::Result<String> {
+        let body = CreateBatchRequest {
+            requests: requests
+                .iter()
+                .map(|request| BatchItem {
+                    custom_id: &request.id,
+                    params: BatchParams {
+                        model: model.as_str(),
+                        max_tokens: BATCH_MAX_TOKENS,
+                        messages: [BatchMessage { role: "user", content: &request.prompt }],
+                    },
+                })
+                .collect(),
+        };
+        let url = self.batches_url()?;
+        debug!(url = %url, model = %model, requests = requests.len(), "Submitting batch");
+
+        let text = self
+            .send_batch_request("POST", &url, Some(serde_json::to_vec(&body)?))
+            .await?;
+        let batch: MessageBatch =
+            serde_json::from_str(&text).context("Failed to deserialize batch response")?;
+        Ok(batch.id)
+    }
+
+    pub async fn poll_batch(&self, batch_id: &str) -> anyhow::Result<BatchStatus> {
+        let url = Url::parse(&format!("{}/{batch_id}", self.batches_url()?))
+            .with_context(|| format!("Invalid batch ID: {batch_id}"))?;
+        let text = self.send_batch_request("GET", &url, None).await?;
+        let batch: MessageBatch =
+            serde_json::from_str(&text).context("Failed to deserialize batch response")?;
+
+        if batch.processing_status != "ended" {
+            return Ok(BatchStatus::InProgress);
+        }
+
+        let results_url = batch
+            .results_url
+            .with_context(|| format!("Batch {} ended without a results URL", batch.id))?;
+        let text = self.send_batch_request("GET", &results_url, None).await?;
+        let results = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str::<BatchResultLine>(line)
+                    .map(BatchResult::from)
+                    .with_context(|| format!("Failed to parse batch result: {line}"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(BatchStatus::Ended(results))
+    }
+}
+
+#[async_trait::async_trait]
+impl<F: HttpInfra> forge_domain::BatchRepository for AnthropicResponseRepository<F> {
+    async fn submit_batch(
+        &self,
+        model_id: &ModelId,
+        requests: Vec<BatchRequest>,
+        provider: Provider<Url>,
+    ) -> anyhow::Result<String> {
+        self.create_client(provider)?
+            .submit_batch(model_id, &requests)
+            .await
+            .context("Failed to submit batch to Anthropic provider")
+    }
+
+    async fn poll_batch(
+        &self,
+        batch_id: &str,
+        provider: Provider<Url>,
+    ) -> anyhow::Result<BatchStatus> {
+        self.create_client(provider)?
+            .poll_batch(batch_id)
+            .await
+            .context("Failed to poll batch from Anthropic provider")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use bytes::Bytes;
+    use forge_eventsource::EventSource;
+    use pretty_assertions::assert_eq;
+    use reqwest::header::HeaderMap;
+
+    use super::*;
+
+    struct MockHttpClient {
+        client: reqwest::Client,
+    }
+
+    #[async_trait::async_trait]
+    impl HttpInfra for MockHttpClient {
+        async fn http_get(
+            &self,
+            url: &Url,
+            headers: Option<HeaderMap>,
+        ) -> anyhow::Result<reqwest::Response> {
+            let request = self
+                .client
+                .get(url.clone())
+                .headers(headers.unwrap_or_default());
+            Ok(request.send().await?)
+        }
+
+        async fn http_post(
+            &self,
+            url: &Url,
+            headers: Option<HeaderMap>,
+            body: Bytes,
+        ) -> anyhow::Result<reqwest::Response> {
+            let request = self
+                .client
+                .post(url.clone())
+                .headers(headers.unwrap_or_default())
+                .body(body);
+            Ok(request.send().await?)
+        }
+
+        async fn http_delete(&self, _url: &Url) -> anyhow::Result<reqwest::Response> {
+            unimplemented!()
+        }
+
+        async fn http_eventsource(
+            &self,
+            _url: &Url,
+            _headers: Option<HeaderMap>,
+            _body: Bytes,
+        ) -> anyhow::Result<EventSource> {
+            unimplemented!()
+        }
+    }
+
+    fn anthropic(base_url: &str) -> Anthropic<MockHttpClient> {
+        let provider = Provider {
+            id: ProviderId::ANTHROPIC,
+            provider_type: forge_domain::ProviderType::Llm,
+            response: Some(forge_domain::ProviderResponse::Anthropic),
+            url: Url::parse(&format!("{base_url}/v1/messages")).unwrap(),
+            credential: Some(forge_domain::AuthCredential {
+                id: ProviderId::ANTHROPIC,
+                auth_details: forge_domain::AuthDetails::ApiKey(forge_domain::ApiKey::from(
+                    "sk-test-key".to_string(),
+                )),
+                url_params: std::collections::HashMap::new(),
+                alias: None,
+                expires_at: None,
+            }),
+            auth_methods: vec![forge_domain::AuthMethod::ApiKey],
+            url_params: vec![],
+            models: None,
+            custom_headers: None,
+        };
+        Anthropic::new(
+            Arc::new(MockHttpClient { client: reqwest::Client::new() }),
+            provider,
+            "2023-06-01".to_string(),
+            false,
+        )
+    }
+
+    fn requests() -> Vec<BatchRequest> {
+        ["a", "b", "c"]
+            .into_iter()
+            .map(|id| BatchRequest { id: id.to_string(), prompt: format!("prompt {id}") })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_submit_batch_returns_batch_id() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v1/messages/batches")
+            .match_header("x-api-key", "sk-test-key")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "requests": [
+                    {"custom_id": "a", "params": {"model": "claude-test", "messages": [{"role": "user", "content": "prompt a"}]}},
+                    {"custom_id": "b"},
+                    {"custom_id": "c"}
+                ]
+            })))
+            .with_status(200)
+            .with_body(r#"{"id":"msgbatch_123","type":"message_batch","processing_status":"in_progress"}"#)
+            .create_async()
+            .await;
+
+        let actual = anthropic(&server.url())
+            .submit_batch(&ModelId::new("claude-test"), &requests())
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(actual, "msgbatch_123");
+    }
+
+    #[tokio::test]
+    async fn test_poll_batch_in_progress() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v1/messages/batches/msgbatch_123")
+            .with_status(200)
+            .with_body(
+                r#"{"id":"msgbatch_123","processing_status":"in_progress","results_url":null}"#,
+            )
+            .create_async()
+            .await;
+
+        let actual = anthropic(&server.url())
+            .poll_batch("msgbatch_123")
+            .await
+            .unwrap();
+
+        assert_eq!(actual, BatchStatus::InProgress);
+    }
+
+    #[tokio::test]
+    async fn test_poll_batch_ended_returns_results() {
+        let mut server = mockito::Server::new_async().await;
+        let results_url = format!("{}/v1/messages/batches/msgbatch_123/results", server.url());
+        server
+            .mock("GET", "/v1/messages/batches/msgbatch_123")
+            .with_status(200)
+            .with_body(
+                serde_json::json!({
+                    "id": "msgbatch_123",
+                    "processing_status": "ended",
+                    "results_url": results_url
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/messages/batches/msgbatch_123/results")
+            .with_status(200)
+            .with_body(concat!(
+                r#"{"custom_id":"a","result":{"type":"succeeded","message":{"content":[{"type":"text","text":"answer a"}]}}}"#,
+                "\n",
+                r#"{"custom_id":"b","result":{"type":"expired"}}"#,
+                "\n"
+            ))
+            .create_async()
+            .await;
+
+        let actual = anthropic(&server.url())
+            .poll_batch("msgbatch_123")
+            .await
+            .unwrap();
+
+        let expected = BatchStatus::Ended(vec![
+            BatchResult {
+                id: "a".to_string(),
+                content: Some("answer a".to_string()),
+                error: None,
+            },
+            BatchResult {
+                id: "b".to_string(),
+                content: None,
+                error: Some("expired".to_string()),
+            },
+        ]);
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,12 +1,14 @@
 use std::sync::Arc;
 
 use anyhow::{Context as _, Result};
+use aws_config::sts::AssumeRoleProvider;
+use aws_credential_types::provider::SharedCredentialsProvider;
 use aws_sdk_bedrockruntime::Client;
 use aws_sdk_bedrockruntime::config::Token;
 use forge_config::RetryConfig;
 use forge_domain::{
-    AuthDetails, ChatCompletionMessage, ChatRepository, Context, Model, ModelId, Provider,
-    ResultStream, Transformer,
+    AuthCredential, AuthDetails, ChatCompletionMessage, ChatRepository, Context, Model, ModelId,
+    Provider, ResultStream, Transformer,
 };
 use reqwest::Url;
 use tokio::sync::OnceCell;
@@ -17,18 +19,67 @@ use crate::provider::bedrock_sanitize_ids::SanitizeToolIds;
 use crate::provider::retry::into_retry;
 use crate::provider::{FromDomain, IntoDomain};
 
+/// Environment variable holding a Bedrock API key (bearer token)
+const BEARER_TOKEN_VAR: &str = "AWS_BEARER_TOKEN_BEDROCK";
+
+/// URL param naming a role to assume for SigV4. The `AWS_ROLE_ARN`
+/// environment variable is left to the default credential chain, which
+/// already assumes it for web identity credentials.
+const ROLE_ARN_PARAM: &str = "AWS_ROLE_ARN";
+
 /// Authentication mode for the Bedrock provider
 enum BedrockAuthMode {
     BearerToken(String),
     AwsProfile(String),
+    /// IAM request signing with credentials from the standard AWS chain,
+    /// optionally assuming `role_arn` first
+    SigV4 {
+        role_arn: Option<String>,
+    },
+}
+
+impl BedrockAuthMode {
+    /// Picks the authentication mode for a credential
+    ///
+    /// A stored API key or AWS profile wins. Otherwise
+    /// `AWS_BEARER_TOKEN_BEDROCK` selects bearer token auth, and everything
+    /// else falls back to SigV4 with the default AWS credential chain, which
+    /// also covers instance roles and `~/.aws/credentials`. Missing
+    /// credentials are reported by the SDK when the request is signed.
+    fn resolve(credential: &AuthCredential, env: impl Fn(&str) -> Option<String>) -> Self {
+        match &credential.auth_details {
+            AuthDetails::ApiKey(key) if !key.is_empty() => {
+                return Self::BearerToken(key.as_ref().to_string());
+            }
+            AuthDetails::AwsProfile(profile) if !profile.is_empty() => {
+                return Self::AwsProfile(profile.as_ref().to_string());
+            }
+            _ => {}
+        }
+
+        if let Some(token) = env(BEARER_TOKEN_VAR).filter(|value| !value.is_empty()) {
+            return Self::BearerToken(token);
+        }
+
+        let role_param: forge_domain::URLParam = ROLE_ARN_PARAM.to_string().into();
+        let role_arn = credential
+            .url_params
+            .get(&role_param)
+            .map(|v| v.to_string())
+            .filter(|value| !value.is_empty());
+        Self::SigV4 { role_arn }
+    }
 }
 
 /// Provider implementation for Amazon Bedrock
 ///
-/// Supports two authentication modes:
-/// - Bearer token: For use with Bedrock Access Gateway (via API key)
+/// Supports three authentication modes:
+/// - Bearer token: For use with Bedrock Access Gateway (via API key or
+///   `AWS_BEARER_TOKEN_BEDROCK`)
 /// - AWS Profile: For use with AWS SSO or IAM credentials configured in
 ///   ~/.aws/config
+/// - SigV4: For IAM credentials found by the standard AWS credential chain,
+///   e.g. `AWS_ACCESS_KEY_ID` or an instance role
 struct BedrockProvider {
     provider: Provider<Url>,
     region: String,
@@ -41,7 +92,10 @@ impl BedrockProvider {
     ///
     /// Credentials are loaded from the provider's credential:
     /// - API key field: Bearer token for Bedrock Access Gateway
-    /// - URL params: AWS_REGION (defaults to us-east-1)
+    /// - URL params: AWS_REGION (defaults to us-east-1) and AWS_ROLE_ARN
+    ///
+    /// Without an API key or profile the auth mode is picked from the
+    /// environment, see [`BedrockAuthMode::resolve`].
     pub fn new(provider: Provider<Url>) -> Result<Self> {
         // Validate credentials are present
         let credential = provider
@@ -49,17 +103,7 @@ impl BedrockProvider {
             .as_ref()
             .context("Bedrock requires credentials")?;
 
-        let auth_mode = match &credential.auth_details {
-            AuthDetails::ApiKey(key) if !key.is_empty() => {
-                BedrockAuthMode::BearerToken(key.as_ref().to_string())
-            }
-            AuthDetails::AwsProfile(profile) if !profile.is_empty() => {
-                BedrockAuthMode::AwsProfile(profile.as_ref().to_string())
-            }
-            _ => anyhow::bail!(
-                "Bedrock requires either a bearer token (API key) or an AWS profile name"
-            ),
-        };
+        let auth_mode = BedrockAuthMode::resolve(credential, |name| std::env::var(name).ok());
 
         // Extract region from URL params
         let region_param: forge_domain::URLParam = "AWS_REGION".to_string().into();
@@ -105,6 +149,26 @@ impl BedrockProvider {
                             .await;
                         Ok(aws_sdk_bedrockruntime::Client::new(&sdk_config))
                     }
+                    BedrockAuthMode::SigV4 { role_arn } => {
+                        let region =
+                            aws_sdk_bedrockruntime::config::Region::new(self.region.clone());
+                        let sdk_config = aws_config::from_env().region(region.clone()).load().await;
+                        let sdk_config = match role_arn {
+                            Some(role_arn) => {
+                                let assumed = AssumeRoleProvider::builder(role_arn)
+                                    .region(region)
+                                    .configure(&sdk_config)
+                                    .build()
+                                    .await;
+                                sdk_config
+                                    .into_builder()
+                                    .credentials_provider(SharedCredentialsProvider::new(assumed))
+                                    .build()
+                            }
+                            None => sdk_config,
+                        };
+                        Ok(aws_sdk_bedrockruntime::Client::new(&sdk_config))
+                    }
                 }
             })
             .await
@@ -697,8 +761,7 @@ impl FromDomain<forge_domain::Context>
 impl FromDomain<Vec<forge_domain::ContextMessage>> for aws_sdk_bedrockruntime::types::Message {
     fn from_domain(tool_results: Vec<forge_domain::ContextMessage>) -> anyhow::Result<Self> {
         use aws_sdk_bedrockruntime::types::{
-            ContentBlock, ConversationRole, Message, ToolResultBlock, ToolResultContentBlock,
-            ToolResultStatus,
+            ContentBlock, ConversationRole, Message, ToolResultBlock, ToolResultStatus,
         };
 
         if tool_results.is_empty() {
@@ -718,13 +781,7 @@ impl FromDomain<Vec<forge_domain::ContextMessage>> for aws_sdk_bedrockruntime::t
                                 .ok_or_else(|| anyhow::anyhow!("Tool result missing call ID"))?
                                 .as_str(),
                         )
-                        .set_content(Some(vec![ToolResultContentBlock::Text(
-                            tool_result
-                                .output
-                                .as_str()
-                                .ok_or_else(|| anyhow::anyhow!("Tool result has no text output"))?
-                                .to_string(),
-                        )]))
+                        .set_content(Some(vec![tool_result_content(&tool_result.output)?]))
                         .status(if is_error {
                             ToolResultStatus::Error
                         } else {
@@ -754,7 +811,7 @@ impl FromDomain<forge_domain::ContextMessage> for aws_sdk_bedrockruntime::types:
         use aws_sdk_bedrockruntime::primitives::Blob;
         use aws_sdk_bedrockruntime::types::{
             ContentBlock, ConversationRole, ImageBlock, ImageSource, Message, ToolResultBlock,
-            ToolResultContentBlock, ToolResultStatus, ToolUseBlock,
+            ToolResultStatus, ToolUseBlock,
         };
 
         match msg {
@@ -851,13 +908,7 @@ impl FromDomain<forge_domain::ContextMessage> for aws_sdk_bedrockruntime::types:
                             .ok_or_else(|| anyhow::anyhow!("Tool result missing call ID"))?
                             .as_str(),
                     )
-                    .set_content(Some(vec![ToolResultContentBlock::Text(
-                        tool_result
-                            .output
-                            .as_str()
-                            .ok_or_else(|| anyhow::anyhow!("Tool result has no text output"))?
-                            .to_string(),
-                    )]))
+                    .set_content(Some(vec![tool_result_content(&tool_result.output)?]))
                     .status(if is_error {
                         ToolResultStatus::Error
                     } else {
@@ -966,6 +1017,27 @@ impl FromDomain<forge_domain::ToolCallArguments> for aws_smithy_types::Document
     }
 }
 
+/// Converts the first text or JSON value of a tool output into a Bedrock
+/// tool result block. JSON is sent as a native JSON block.
+fn tool_result_content(
+    output: &forge_domain::ToolOutput,
+) -> anyhow::Result<aws_sdk_bedrockruntime::types::ToolResultContentBlock> {
+    use aws_sdk_bedrockruntime::types::ToolResultContentBlock;
+
+    output
+        .values
+        .iter()
+        .find_map(|value| match value {
+            forge_domain::ToolValue::Json(json) => Some(ToolResultContentBlock::Json(
+                json_value_to_document(json.clone()),
+            )),
+            value => value
+                .as_str()
+                .map(|text| ToolResultContentBlock::Text(text.to_string())),
+        })
+        .ok_or_else(|| anyhow::anyhow!("Tool result has no text output"))
+}
+
 /// Helper function to convert serde_json::Value to aws_smithy_types::Document
 fn json_value_to_document(value: serde_json::Value) -> aws_smithy_types::Document {
     use std::collections::HashMap;
@@ -1118,6 +1190,8 @@ mod tests {
                 id: ProviderId::from("bedrock".to_string()),
                 auth_details: AuthDetails::ApiKey(ApiKey::from(token.to_string())),
                 url_params,
+                alias: None,
+                expires_at: None,
             }),
             custom_headers: None,
         }
@@ -1152,14 +1226,10 @@ mod tests {
     }
 
     #[test]
-    fn test_new_with_empty_token() {
+    fn test_new_with_empty_token_uses_default_credential_chain() {
         let fixture = provider_fixture("", None);
-        let actual = BedrockProvider::new(fixture);
-        assert!(actual.is_err());
-        assert_eq!(
-            actual.err().unwrap().to_string(),
-            "Bedrock requires either a bearer token (API key) or an AWS profile name"
-        );
+        let actual = BedrockProvider::new(fixture).unwrap();
+        assert!(matches!(actual.auth_mode, BedrockAuthMode::SigV4 { .. }));
     }
 
     #[test]
@@ -1430,6 +1500,8 @@ mod tests {
                 supports_parallel_tool_calls: None,
                 supports_reasoning: None,
                 input_modalities: vec![InputModality::Text],
+                cost_per_million_input_tokens: None,
+                cost_per_million_output_tokens: None,
             },
             Model {
                 id: ModelId::from("claude-3-sonnet".to_string()),
@@ -1440,6 +1512,8 @@ mod tests {
                 supports_parallel_tool_calls: None,
                 supports_reasoning: None,
                 input_modalities: vec![InputModality::Text],
+                cost_per_million_input_tokens: None,
+                cost_per_million_output_tokens: None,
             },
         ];
         fixture_provider.models = Some(ModelSource::Hardcoded(fixture_models.clone()));
@@ -1847,6 +1921,8 @@ mod tests {
             temperature: None,
             top_p: None,
             top_k: None,
+            min_p: None,
+            repetition_penalty: None,
             max_tokens: None,
             reasoning: None,
             stream: None,
@@ -1877,6 +1953,8 @@ mod tests {
             temperature: Some(Temperature::new(0.7).unwrap()),
             top_p: None,
             top_k: None,
+            min_p: None,
+            repetition_penalty: None,
             max_tokens: None,
             reasoning: None,
             stream: None,
@@ -1903,6 +1981,8 @@ mod tests {
             temperature: None,
             top_p: Some(TopP::new(0.5).unwrap()), // Below 0.95
             top_k: None,
+            min_p: None,
+            repetition_penalty: None,
             max_tokens: None,
             reasoning: Some(ReasoningConfig {
                 effort: None,
@@ -1937,6 +2017,8 @@ mod tests {
             temperature: None,
             top_p: None,
             top_k: None,
+            min_p: None,
+            repetition_penalty: None,
             max_tokens: None,
             reasoning: Some(ReasoningConfig {
                 effort: None,
@@ -1971,6 +2053,8 @@ mod tests {
             temperature: None,
             top_p: None,
             top_k: None,
+            min_p: None,
+            repetition_penalty: None,
             max_tokens: None,
             reasoning: Some(ReasoningConfig {
                 effort: None,
@@ -2027,6 +2111,8 @@ mod tests {
             temperature: None,
             top_p: None,
             top_k: None,
+            min_p: None,
+            repetition_penalty: None,
             max_tokens: None,
             reasoning: Some(ReasoningConfig {
                 effort: None,
@@ -2070,6 +2156,8 @@ mod tests {
             temperature: None,
             top_p: Some(TopP::new(0.5).unwrap()),
             top_k: None,
+            min_p: None,
+            repetition_penalty: None,
             max_tokens: None,
             reasoning: Some(ReasoningConfig {
                 effort: None,
@@ -2105,6 +2193,8 @@ mod tests {
             temperature: None,
             top_p: None,
             top_k: None,
+            min_p: None,
+            repetition_penalty: None,
             max_tokens: None,
             reasoning: Some(ReasoningConfig {
                 effort: Some(Effort::High),
@@ -2188,6 +2278,8 @@ mod tests {
                 id: ProviderId::from("bedrock".to_string()),
                 auth_details: AuthDetails::AwsProfile(ApiKey::from(profile.to_string())),
                 url_params,
+                alias: None,
+                expires_at: None,
             }),
             custom_headers: None,
         }
@@ -2204,10 +2296,10 @@ mod tests {
     }
 
     #[test]
-    fn test_new_with_empty_aws_profile_fails() {
+    fn test_new_with_empty_aws_profile_uses_default_credential_chain() {
         let provider = aws_profile_fixture("", Some("us-east-1"));
-        let result = BedrockProvider::new(provider);
-        assert!(result.is_err());
+        let actual = BedrockProvider::new(provider).unwrap();
+        assert!(matches!(actual.auth_mode, BedrockAuthMode::SigV4 { .. }));
     }
 
     /// Integration test: validates real SSO profile can create a client and
@@ -2255,4 +2347,76 @@ mod tests {
         }
         assert!(got_text, "Expected text content in stream response");
     }
+
+    fn env_fixture(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: std::collections::HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_resolve_prefers_stored_api_key() {
+        let fixture = provider_fixture("stored-token", None);
+        let env = env_fixture(&[("AWS_BEARER_TOKEN_BEDROCK", "env-token")]);
+        let actual = BedrockAuthMode::resolve(fixture.credential.as_ref().unwrap(), env);
+        assert!(matches!(actual, BedrockAuthMode::BearerToken(ref t) if t == "stored-token"));
+    }
+
+    #[test]
+    fn test_resolve_bearer_token_from_env() {
+        let fixture = provider_fixture("", None);
+        let env = env_fixture(&[
+            ("AWS_BEARER_TOKEN_BEDROCK", "env-token"),
+            ("AWS_ACCESS_KEY_ID", "AKIA"),
+        ]);
+        let actual = BedrockAuthMode::resolve(fixture.credential.as_ref().unwrap(), env);
+        assert!(matches!(actual, BedrockAuthMode::BearerToken(ref t) if t == "env-token"));
+    }
+
+    #[test]
+    fn test_resolve_sigv4_from_aws_env() {
+        let fixture = provider_fixture("", None);
+        let env = env_fixture(&[
+            ("AWS_ACCESS_KEY_ID", "AKIA"),
+            ("AWS_SECRET_ACCESS_KEY", "secret"),
+        ]);
+        let actual = BedrockAuthMode::resolve(fixture.credential.as_ref().unwrap(), env);
+        assert!(matches!(actual, BedrockAuthMode::SigV4 { role_arn: None }));
+    }
+
+    #[test]
+    fn test_resolve_sigv4_with_role_arn_url_param() {
+        let mut fixture = provider_fixture("", None);
+        let credential = fixture.credential.as_mut().unwrap();
+        credential.url_params.insert(
+            forge_domain::URLParam::from("AWS_ROLE_ARN".to_string()),
+            forge_domain::URLParamValue::from("arn:aws:iam::123:role/forge".to_string()),
+        );
+        let actual = BedrockAuthMode::resolve(credential, env_fixture(&[]));
+        assert!(matches!(
+            actual,
+            BedrockAuthMode::SigV4 { role_arn: Some(ref arn) } if arn == "arn:aws:iam::123:role/forge"
+        ));
+    }
+
+    #[test]
+    fn test_resolve_sigv4_with_role_arn_env_only() {
+        let fixture = provider_fixture("", None);
+        let env = env_fixture(&[
+            ("AWS_ROLE_ARN", "arn:aws:iam::123:role/irsa"),
+            ("AWS_WEB_IDENTITY_TOKEN_FILE", "/var/run/token"),
+        ]);
+        let actual = BedrockAuthMode::resolve(fixture.credential.as_ref().unwrap(), env);
+        assert!(matches!(actual, BedrockAuthMode::SigV4 { role_arn: None }));
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_default_credential_chain() {
+        let fixture = provider_fixture("", None);
+        let actual =
+            BedrockAuthMode::resolve(fixture.credential.as_ref().unwrap(), env_fixture(&[]));
+        assert!(matches!(actual, BedrockAuthMode::SigV4 { role_arn: None }));
+    }
 }
@@ -100,6 +100,8 @@ mod tests {
             temperature: None,
             top_p: None,
             top_k: None,
+            min_p: None,
+            repetition_penalty: None,
             reasoning: None,
             stream: None,
             response_format: None,
@@ -128,6 +130,8 @@ mod tests {
             temperature: None,
             top_p: None,
             top_k: None,
+            min_p: None,
+            repetition_penalty: None,
             reasoning: None,
             stream: None,
             response_format: None,
@@ -158,6 +162,8 @@ mod tests {
             temperature: None,
             top_p: None,
             top_k: None,
+            min_p: None,
+            repetition_penalty: None,
             reasoning: None,
             stream: None,
             response_format: None,
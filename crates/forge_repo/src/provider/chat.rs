@@ -4,13 +4,16 @@ use forge_app::domain::{
     ChatCompletionMessage, Context, Model, ModelId, ProviderResponse, ResultStream,
 };
 use forge_app::{EnvironmentInfra, HttpInfra};
-use forge_domain::{ChatRepository, Provider, ProviderId};
+use forge_domain::{
+    BatchRepository, BatchRequest, BatchStatus, ChatRepository, Provider, ProviderId,
+};
 use forge_infra::CacacheStorage;
 use tokio::task::AbortHandle;
 use url::Url;
 
 use crate::provider::anthropic::AnthropicResponseRepository;
 use crate::provider::bedrock::BedrockResponseRepository;
+use crate::provider::cohere::CohereResponseRepository;
 use crate::provider::google::GoogleResponseRepository;
 use crate::provider::openai::OpenAIResponseRepository;
 use crate::provider::openai_responses::OpenAIResponsesResponseRepository;
@@ -42,6 +45,7 @@ impl<F: EnvironmentInfra<Config = forge_config::ForgeConfig> + HttpInfra> ForgeC
             BedrockResponseRepository::new(Arc::new(config.retry.unwrap_or_default()));
         let google_repo = GoogleResponseRepository::new(infra.clone());
         let opencode_zen_repo = OpenCodeZenResponseRepository::new(infra.clone());
+        let cohere_repo = CohereResponseRepository::new(infra.clone());
 
         let model_cache = Arc::new(CacacheStorage::new(
             env.cache_dir().join("model_cache"),
@@ -56,6 +60,7 @@ impl<F: EnvironmentInfra<Config = forge_config::ForgeConfig> + HttpInfra> ForgeC
                 bedrock_repo,
                 google_repo,
                 opencode_zen_repo,
+                cohere_repo,
             }),
             model_cache,
             bg_refresh: BgRefresh::default(),
@@ -120,6 +125,51 @@ impl<F: EnvironmentInfra<Config = forge_config::ForgeConfig> + HttpInfra + Sync>
     }
 }
 
+#[async_trait::async_trait]
+impl<F: EnvironmentInfra<Config = forge_config::ForgeConfig> + HttpInfra + Sync> BatchRepository
+    for ForgeChatRepository<F>
+{
+    async fn submit_batch(
+        &self,
+        model_id: &ModelId,
+        requests: Vec<BatchRequest>,
+        provider: Provider<Url>,
+    ) -> anyhow::Result<String> {
+        match provider.response {
+            Some(ProviderResponse::Anthropic) => {
+                self.router
+                    .anthropic_repo
+                    .submit_batch(model_id, requests, provider)
+                    .await
+            }
+            _ => Err(unsupported_batch_provider(&provider)),
+        }
+    }
+
+    async fn poll_batch(
+        &self,
+        batch_id: &str,
+        provider: Provider<Url>,
+    ) -> anyhow::Result<BatchStatus> {
+        match provider.response {
+            Some(ProviderResponse::Anthropic) => {
+                self.router
+                    .anthropic_repo
+                    .poll_batch(batch_id, provider)
+                    .await
+            }
+            _ => Err(unsupported_batch_provider(&provider)),
+        }
+    }
+}
+
+fn unsupported_batch_provider(provider: &Provider<Url>) -> anyhow::Error {
+    anyhow::anyhow!(
+        "Batch processing is only supported by Anthropic providers, the active provider is {}",
+        provider.id
+    )
+}
+
 /// Routes chat and model requests to the correct provider backend.
 struct ProviderRouter<F> {
     openai_repo: OpenAIResponseRepository<F>,
@@ -128,6 +178,7 @@ struct ProviderRouter<F> {
     bedrock_repo: BedrockResponseRepository,
     google_repo: GoogleResponseRepository<F>,
     opencode_zen_repo: OpenCodeZenResponseRepository<F>,
+    cohere_repo: CohereResponseRepository<F>,
 }
 
 impl<F: HttpInfra + EnvironmentInfra<Config = forge_config::ForgeConfig> + Sync> ProviderRouter<F> {
@@ -170,6 +221,9 @@ impl<F: HttpInfra + EnvironmentInfra<Config = forge_config::ForgeConfig> + Sync>
                     .chat(model_id, context, provider)
                     .await
             }
+            Some(ProviderResponse::Cohere) => {
+                self.cohere_repo.chat(model_id, context, provider).await
+            }
             None => Err(anyhow::anyhow!(
                 "Provider response type not configured for provider: {}",
                 provider.id
@@ -185,6 +239,7 @@ impl<F: HttpInfra + EnvironmentInfra<Config = forge_config::ForgeConfig> + Sync>
             Some(ProviderResponse::Bedrock) => self.bedrock_repo.models(provider).await,
             Some(ProviderResponse::Google) => self.google_repo.models(provider).await,
             Some(ProviderResponse::OpenCode) => self.opencode_zen_repo.models(provider).await,
+            Some(ProviderResponse::Cohere) => self.cohere_repo.models(provider).await,
             None => Err(anyhow::anyhow!(
                 "Provider response type not configured for provider: {}",
                 provider.id
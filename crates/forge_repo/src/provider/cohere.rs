@@ -0,0 +1,347 @@
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use forge_app::domain::{ChatCompletionMessage, Context, Model, ModelId, ResultStream};
+use forge_app::dto::cohere::{Request, StreamEvent};
+use forge_app::dto::openai::Error;
+use forge_app::{EnvironmentInfra, HttpInfra};
+use forge_domain::{ChatRepository, Provider};
+use futures::{Stream, StreamExt, TryStreamExt};
+use reqwest::Url;
+use tracing::debug;
+
+use crate::provider::retry::into_retry;
+use crate::provider::utils::{create_headers, format_http_context, read_http_error_reason};
+
+#[derive(Clone)]
+struct Cohere<T> {
+    http: Arc<T>,
+    api_key: String,
+    chat_url: Url,
+    models: Option<forge_domain::ModelSource<Url>>,
+}
+
+impl<H: HttpInfra> Cohere<H> {
+    fn get_headers(&self) -> Vec<(String, String)> {
+        vec![
+            ("Content-Type".to_string(), "application/json".to_string()),
+            (
+                "Authorization".to_string(),
+                format!("Bearer {}", self.api_key),
+            ),
+        ]
+    }
+
+    async fn chat(
+        &self,
+        model: &ModelId,
+        context: Context,
+    ) -> ResultStream<ChatCompletionMessage, anyhow::Error> {
+        let request = Request::from(context).model(model.to_string()).stream(true);
+        let url = self.chat_url.clone();
+        debug!(url = %url, model = %model, "Connecting Upstream");
+
+        let json_bytes =
+            serde_json::to_vec(&request).with_context(|| "Failed to serialize request")?;
+        let response = self
+            .http
+            .http_post(
+                &url,
+                Some(create_headers(self.get_headers())),
+                json_bytes.into(),
+            )
+            .await
+            .with_context(|| format_http_context(None, "POST", &url))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let (code, reason) = read_http_error_reason(response).await;
+            return Err(anyhow::Error::from(Error::InvalidStatusCode(code)).context(reason))
+                .with_context(|| format_http_context(Some(status), "POST", &url));
+        }
+
+        // Cohere streams newline-delimited JSON rather than server-sent events
+        let lines = into_lines(response.bytes_stream().map_err(anyhow::Error::from));
+        let stream = lines.map(move |line| {
+            let line = line?;
+            serde_json::from_str::<StreamEvent>(&line)
+                .with_context(|| format!("Failed to parse provider response: {line}"))
+                .and_then(ChatCompletionMessage::try_from)
+                .with_context(|| format_http_context(None, "POST", &url))
+        });
+
+        Ok(Box::pin(stream))
+    }
+
+    async fn models(&self) -> anyhow::Result<Vec<Model>> {
+        match self.models.as_ref() {
+            Some(forge_domain::ModelSource::Url(url)) => {
+                debug!(url = %url, "Fetching models");
+                let response = self
+                    .http
+                    .http_get(url, Some(create_headers(self.get_headers())))
+                    .await
+                    .with_context(|| format_http_context(None, "GET", url))
+                    .with_context(|| "Failed to fetch models")?;
+
+                let status = response.status();
+                let ctx_msg = format_http_context(Some(status), "GET", url);
+                let text = response
+                    .text()
+                    .await
+                    .with_context(|| ctx_msg.clone())
+                    .with_context(|| "Failed to decode response into text")?;
+
+                if !status.is_success() {
+                    return Err(anyhow::anyhow!(text))
+                        .with_context(|| ctx_msg)
+                        .with_context(|| "Failed to fetch the models");
+                }
+
+                #[derive(serde::Deserialize)]
+                struct ModelsResponse {
+                    models: Vec<forge_app::dto::cohere::Model>,
+                }
+
+                let response: ModelsResponse = serde_json::from_str(&text)
+                    .with_context(|| ctx_msg)
+                    .with_context(|| "Failed to deserialize models response")?;
+                Ok(response.models.into_iter().map(Into::into).collect())
+            }
+            Some(forge_domain::ModelSource::Hardcoded(models)) => Ok(models.clone()),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+/// Splits a byte stream into non-empty lines, buffering partial lines across
+/// chunks
+fn into_lines<S, B>(bytes: S) -> impl Stream<Item = anyhow::Result<String>>
+where
+    S: Stream<Item = anyhow::Result<B>> + Unpin,
+    B: AsRef<[u8]>,
+{
+    futures::stream::unfold(
+        (bytes, Vec::<u8>::new(), false),
+        |(mut bytes, mut buffer, mut done)| async move {
+            loop {
+                if let Some(pos) = buffer.iter().position(|b| *b == b'\n') {
+                    let line: Vec<u8> = buffer.drain(..=pos).collect();
+                    match decode_line(&line) {
+                        Some(line) => return Some((line, (bytes, buffer, done))),
+                        None => continue,
+                    }
+                }
+                if done {
+                    let rest = std::mem::take(&mut buffer);
+                    return decode_line(&rest).map(|line| (line, (bytes, buffer, done)));
+                }
+                match bytes.next().await {
+                    Some(Ok(chunk)) => buffer.extend_from_slice(chunk.as_ref()),
+                    Some(Err(error)) => return Some((Err(error), (bytes, buffer, true))),
+                    None => done = true,
+                }
+            }
+        },
+    )
+}
+
+fn decode_line(line: &[u8]) -> Option<anyhow::Result<String>> {
+    let line = line.trim_ascii();
+    if line.is_empty() {
+        return None;
+    }
+    Some(String::from_utf8(line.to_vec()).with_context(|| "Provider response is not valid UTF-8"))
+}
+
+/// Repository for Cohere's chat API
+pub struct CohereResponseRepository<F> {
+    infra: Arc<F>,
+}
+
+impl<F> CohereResponseRepository<F> {
+    pub fn new(infra: Arc<F>) -> Self {
+        Self { infra }
+    }
+}
+
+impl<F: HttpInfra> CohereResponseRepository<F> {
+    fn create_client(&self, provider: &Provider<Url>) -> anyhow::Result<Cohere<F>> {
+        let api_key = match &provider
+            .credential
+            .as_ref()
+            .context("Cohere provider requires credentials")?
+            .auth_details
+        {
+            forge_domain::AuthDetails::ApiKey(api_key) => api_key.as_str().to_string(),
+            _ => anyhow::bail!("Cohere provider only supports API key authentication"),
+        };
+
+        Ok(Cohere {
+            http: self.infra.clone(),
+            api_key,
+            chat_url: provider.url.clone(),
+            models: provider.models.clone(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<F: HttpInfra + EnvironmentInfra<Config = forge_config::ForgeConfig> + 'static> ChatRepository
+    for CohereResponseRepository<F>
+{
+    async fn chat(
+        &self,
+        model_id: &ModelId,
+        context: Context,
+        provider: Provider<Url>,
+    ) -> ResultStream<ChatCompletionMessage, anyhow::Error> {
+        let retry_config = self.infra.get_config()?.retry.unwrap_or_default();
+        let provider_client = self.create_client(&provider)?;
+
+        let stream = provider_client
+            .chat(model_id, context)
+            .await
+            .map_err(|e| into_retry(e, &retry_config))?;
+
+        Ok(Box::pin(stream.map(move |item| {
+            item.map_err(|e| into_retry(e, &retry_config))
+        })))
+    }
+
+    async fn models(&self, provider: Provider<Url>) -> anyhow::Result<Vec<Model>> {
+        let retry_config = self.infra.get_config()?.retry.unwrap_or_default();
+        let provider_client = self.create_client(&provider)?;
+
+        provider_client
+            .models()
+            .await
+            .map_err(|e| into_retry(e, &retry_config))
+            .context("Failed to fetch models from Cohere provider")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use forge_app::domain::ContextMessage;
+    use forge_eventsource::EventSource;
+    use pretty_assertions::assert_eq;
+    use reqwest::header::HeaderMap;
+
+    use super::*;
+    use crate::provider::mock_server::MockServer;
+
+    struct MockHttpClient {
+        client: reqwest::Client,
+    }
+
+    #[async_trait::async_trait]
+    impl HttpInfra for MockHttpClient {
+        async fn http_get(
+            &self,
+            url: &Url,
+            headers: Option<HeaderMap>,
+        ) -> anyhow::Result<reqwest::Response> {
+            let request = self.client.get(url.clone());
+            Ok(request.headers(headers.unwrap_or_default()).send().await?)
+        }
+
+        async fn http_post(
+            &self,
+            url: &Url,
+            headers: Option<HeaderMap>,
+            body: Bytes,
+        ) -> anyhow::Result<reqwest::Response> {
+            let request = self.client.post(url.clone()).body(body);
+            Ok(request.headers(headers.unwrap_or_default()).send().await?)
+        }
+
+        async fn http_delete(&self, _url: &Url) -> anyhow::Result<reqwest::Response> {
+            unimplemented!()
+        }
+
+        async fn http_eventsource(
+            &self,
+            _url: &Url,
+            _headers: Option<HeaderMap>,
+            _body: Bytes,
+        ) -> anyhow::Result<EventSource> {
+            unimplemented!()
+        }
+    }
+
+    fn create_cohere(base_url: &str) -> anyhow::Result<Cohere<MockHttpClient>> {
+        Ok(Cohere {
+            http: Arc::new(MockHttpClient { client: reqwest::Client::new() }),
+            api_key: "test-key".to_string(),
+            chat_url: Url::parse(base_url)?.join("v1/chat")?,
+            models: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_chat_sends_preamble_and_streams_text() -> anyhow::Result<()> {
+        let mut fixture = MockServer::new().await;
+        let mock = fixture
+            .mock_cohere_chat_stream(
+                serde_json::json!({
+                    "model": "command-r-plus",
+                    "message": "Hi",
+                    "preamble": "You are terse.",
+                    "stream": true
+                }),
+                vec![
+                    r#"{"is_finished":false,"event_type":"stream-start","generation_id":"g1"}"#
+                        .to_string(),
+                    r#"{"is_finished":false,"event_type":"text-generation","text":"Hello"}"#
+                        .to_string(),
+                    r#"{"is_finished":false,"event_type":"text-generation","text":" there"}"#
+                        .to_string(),
+                    r#"{"is_finished":true,"event_type":"stream-end","finish_reason":"COMPLETE"}"#
+                        .to_string(),
+                ],
+                200,
+            )
+            .await;
+        let cohere = create_cohere(&fixture.url())?;
+        let context = Context::default()
+            .add_message(ContextMessage::system("You are terse."))
+            .add_message(ContextMessage::user("Hi", None));
+
+        let messages: Vec<ChatCompletionMessage> = cohere
+            .chat(&ModelId::new("command-r-plus"), context)
+            .await?
+            .try_collect()
+            .await?;
+
+        mock.assert_async().await;
+        let actual: String = messages
+            .iter()
+            .filter_map(|message| message.content.as_ref())
+            .map(|content| content.as_str())
+            .collect();
+        assert_eq!(actual, "Hello there");
+        assert_eq!(
+            messages
+                .last()
+                .and_then(|message| message.finish_reason.clone()),
+            Some(forge_domain::FinishReason::Stop)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_into_lines_joins_lines_split_across_chunks() {
+        let fixture = futures::stream::iter(vec![
+            Ok::<_, anyhow::Error>("{\"a\":".to_string()),
+            Ok("1}\n\n{\"b\"".to_string()),
+            Ok(":2}".to_string()),
+        ]);
+
+        let actual: Vec<String> = into_lines(fixture).try_collect().await.unwrap();
+
+        let expected = vec![r#"{"a":1}"#.to_string(), r#"{"b":2}"#.to_string()];
+        assert_eq!(actual, expected);
+    }
+}
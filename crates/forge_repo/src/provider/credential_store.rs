@@ -0,0 +1,110 @@
+use forge_domain::{AuthCredential, ProviderId};
+use serde::{Deserialize, Serialize};
+
+/// Provider credentials as persisted in the credentials file
+///
+/// A provider may hold several credentials, one per alias, so users can keep
+/// e.g. a work and a personal account side by side. A credential without an
+/// alias is the provider's default account.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub(crate) struct CredentialStore(Vec<AuthCredential>);
+
+impl CredentialStore {
+    /// Inserts the credential, replacing any existing one with the same
+    /// provider and alias
+    pub fn upsert(&mut self, credential: AuthCredential) {
+        match self
+            .0
+            .iter_mut()
+            .find(|c| c.id == credential.id && c.alias == credential.alias)
+        {
+            Some(existing) => *existing = credential,
+            None => self.0.push(credential),
+        }
+    }
+
+    /// Finds the credential stored for the provider under `alias`
+    pub fn find(&self, id: &ProviderId, alias: Option<&str>) -> Option<&AuthCredential> {
+        self.0
+            .iter()
+            .find(|c| &c.id == id && c.alias.as_deref() == alias)
+    }
+
+    /// Lists every credential stored for the provider
+    pub fn list(&self, id: &ProviderId) -> Vec<&AuthCredential> {
+        self.0.iter().filter(|c| &c.id == id).collect()
+    }
+
+    /// Removes the credential stored for the provider under `alias`
+    pub fn remove(&mut self, id: &ProviderId, alias: Option<&str>) {
+        self.0
+            .retain(|c| &c.id != id || c.alias.as_deref() != alias);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use forge_domain::ApiKey;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn credential(key: &str, alias: Option<&str>) -> AuthCredential {
+        AuthCredential::new_api_key(ProviderId::OPENAI, ApiKey::from(key.to_string()))
+            .alias(alias.map(str::to_string))
+    }
+
+    #[test]
+    fn test_upsert_keeps_credentials_with_different_aliases() {
+        let mut fixture = CredentialStore::default();
+        fixture.upsert(credential("work-key", Some("work")));
+        fixture.upsert(credential("personal-key", Some("personal")));
+
+        let actual: Vec<_> = fixture
+            .list(&ProviderId::OPENAI)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        let expected = vec![
+            credential("work-key", Some("work")),
+            credential("personal-key", Some("personal")),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_upsert_replaces_credential_with_same_alias() {
+        let mut fixture = CredentialStore::default();
+        fixture.upsert(credential("old-key", Some("work")));
+        fixture.upsert(credential("new-key", Some("work")));
+
+        let actual = fixture.find(&ProviderId::OPENAI, Some("work"));
+
+        let expected = credential("new-key", Some("work"));
+        assert_eq!(actual, Some(&expected));
+        assert_eq!(fixture.list(&ProviderId::OPENAI).len(), 1);
+    }
+
+    #[test]
+    fn test_remove_only_drops_matching_alias() {
+        let mut fixture = CredentialStore::default();
+        fixture.upsert(credential("default-key", None));
+        fixture.upsert(credential("work-key", Some("work")));
+
+        fixture.remove(&ProviderId::OPENAI, None);
+        let actual: Vec<_> = fixture
+            .list(&ProviderId::OPENAI)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        let expected = vec![credential("work-key", Some("work"))];
+        assert_eq!(actual, expected);
+    }
+}
@@ -520,7 +520,7 @@ mod tests {
         let response2 = format!("data: {}", create_mock_chat_response(" World"));
 
         let mock = fixture
-            .mock_google_chat_stream(model_id, vec![response1, response2], 200)
+            .mock_google_chat_stream("", model_id, vec![response1, response2], 200)
             .await;
 
         let google = create_google(&fixture.url())?;
@@ -548,4 +548,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_chat_vertex_ai_url() -> anyhow::Result<()> {
+        let mut fixture = MockServer::new().await;
+        let base_path = "/v1beta1/projects/my-project/locations/us-central1/publishers/google";
+        let mock = fixture
+            .mock_google_chat_stream(
+                base_path,
+                "gemini-3.5-flash",
+                vec![format!("data: {}", create_mock_chat_response("Hello"))],
+                200,
+            )
+            .await;
+        let chat_url = Url::parse(&format!("{}{base_path}", fixture.url()))?;
+        let google = Google::new(
+            Arc::new(MockHttpClient::new()),
+            "adc-token".to_string(),
+            chat_url,
+            forge_domain::ModelSource::Hardcoded(vec![]),
+            false,
+        );
+
+        let model = ModelId::new("gemini-3.5-flash");
+        let context = Context::default().add_message(ContextMessage::user("Hi", None));
+        let mut stream = google.chat(&model, context).await?;
+        while let Some(result) = stream.next().await {
+            result?;
+        }
+
+        mock.assert_async().await;
+        Ok(())
+    }
 }
@@ -50,6 +50,23 @@ impl MockServer {
             .await
     }
 
+    /// Mock a non-streaming chat completion. The request body must ask for
+    /// `stream: false` and the `Accept` header must be reqwest's default
+    /// rather than `text/event-stream`.
+    pub async fn mock_chat_completion(&mut self, path: &str, body: serde_json::Value) -> Mock {
+        self.server
+            .mock("POST", path)
+            .match_header("accept", "*/*")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "stream": false }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+            .create_async()
+            .await
+    }
+
     pub async fn mock_responses_stream(&mut self, events: Vec<String>, status: usize) -> Mock {
         let sse_body = events.join("\n\n");
         self.server
@@ -82,15 +99,39 @@ impl MockServer {
             .await
     }
 
+    /// Mock Cohere's `/v1/chat` endpoint streaming newline-delimited JSON.
+    /// The request body must contain `body`.
+    pub async fn mock_cohere_chat_stream(
+        &mut self,
+        body: serde_json::Value,
+        events: Vec<String>,
+        status: usize,
+    ) -> Mock {
+        let mut ndjson_body = events.join("\n");
+        ndjson_body.push('\n');
+        self.server
+            .mock("POST", "/v1/chat")
+            .match_body(mockito::Matcher::PartialJson(body))
+            .with_status(status)
+            .with_header("content-type", "application/stream+json")
+            .with_body(ndjson_body)
+            .create_async()
+            .await
+    }
+
+    /// Mock a Google streaming chat endpoint. `base_path` is the path of the
+    /// configured chat URL, e.g. the Vertex AI project and location prefix,
+    /// or empty for the Gemini API.
     pub async fn mock_google_chat_stream(
         &mut self,
+        base_path: &str,
         model: &str,
         events: Vec<String>,
         status: usize,
     ) -> Mock {
         let mut sse_body = events.join("\n\n");
         sse_body.push_str("\n\n");
-        let path = format!("/models/{}:streamGenerateContent", model);
+        let path = format!("{base_path}/models/{model}:streamGenerateContent");
         self.server
             .mock("POST", path.as_str())
             .match_query(mockito::Matcher::UrlEncoded("alt".into(), "sse".into()))
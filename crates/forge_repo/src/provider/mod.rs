@@ -3,6 +3,8 @@ mod bedrock;
 mod bedrock_cache;
 mod bedrock_sanitize_ids;
 mod chat;
+mod cohere;
+mod credential_store;
 mod event;
 mod google;
 #[cfg(test)]
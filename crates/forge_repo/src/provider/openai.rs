@@ -5,7 +5,9 @@ use forge_app::domain::{
     ChatCompletionMessage, Context as ChatContext, Model, ModelId, ProviderId, ResultStream,
     Transformer,
 };
-use forge_app::dto::openai::{ListModelResponse, ProviderPipeline, Request, Response};
+use forge_app::dto::openai::{
+    ListModelResponse, ProviderPipeline, Request, Response, TogetherModel,
+};
 use forge_app::{EnvironmentInfra, HttpInfra};
 use forge_domain::{ChatRepository, Provider};
 use forge_infra::sanitize_headers;
@@ -215,6 +217,11 @@ impl<H: HttpInfra> OpenAIProvider<H> {
         let json_bytes =
             serde_json::to_vec(&request).with_context(|| "Failed to serialize request")?;
 
+        if request.stream == Some(false) {
+            let message = self.complete(&url, headers, json_bytes).await?;
+            return Ok(Box::pin(tokio_stream::once(Ok(message))));
+        }
+
         let es = self
             .http
             .http_eventsource(&url, Some(headers), json_bytes.into())
@@ -227,6 +234,41 @@ impl<H: HttpInfra> OpenAIProvider<H> {
         Ok(Box::pin(stream))
     }
 
+    /// Sends a non-streaming chat request and converts the single JSON
+    /// response into a complete message
+    async fn complete(
+        &self,
+        url: &Url,
+        headers: reqwest::header::HeaderMap,
+        body: Vec<u8>,
+    ) -> Result<ChatCompletionMessage> {
+        let response = self
+            .http
+            .http_post(url, Some(headers), body.into())
+            .await
+            .with_context(|| format_http_context(None, "POST", url))
+            .map_err(|e| enhance_error(e, &self.provider.id))?;
+
+        let status = response.status();
+        let ctx_message = format_http_context(Some(status), "POST", url);
+        let response_text = response
+            .text()
+            .await
+            .with_context(|| ctx_message.clone())
+            .with_context(|| "Failed to decode response into text")?;
+
+        if !status.is_success() {
+            return Err(anyhow::anyhow!(response_text))
+                .with_context(|| ctx_message)
+                .map_err(|e| enhance_error(e, &self.provider.id));
+        }
+
+        let response: Response = serde_json::from_str(&response_text)
+            .with_context(|| ctx_message.clone())
+            .with_context(|| "Failed to deserialize chat completion response")?;
+        ChatCompletionMessage::try_from(response).with_context(|| ctx_message)
+    }
+
     async fn inner_models(&self) -> Result<Vec<forge_app::domain::Model>> {
         // For Vertex AI, load models from static JSON file using VertexProvider logic
         if self.provider.id == ProviderId::VERTEX_AI {
@@ -246,6 +288,16 @@ impl<H: HttpInfra> OpenAIProvider<H> {
                             tracing::error!(error = ?error, "Failed to fetch models");
                             anyhow::bail!(error)
                         }
+                        Ok(response) if self.provider.id == ProviderId::TOGETHER_AI => {
+                            let data: Vec<TogetherModel> = serde_json::from_str(&response)
+                                .with_context(|| format_http_context(None, "GET", url))
+                                .with_context(|| "Failed to deserialize models response")?;
+                            Ok(data
+                                .into_iter()
+                                .filter(TogetherModel::is_chat)
+                                .map(Into::into)
+                                .collect())
+                        }
                         Ok(response) => {
                             let data: ListModelResponse = serde_json::from_str(&response)
                                 .with_context(|| format_http_context(None, "GET", url))
@@ -398,6 +450,8 @@ mod tests {
                 key.to_string(),
             )),
             url_params: HashMap::new(),
+            alias: None,
+            expires_at: None,
         })
     }
 
@@ -493,11 +547,15 @@ mod tests {
 
         async fn http_post(
             &self,
-            _url: &Url,
-            _headers: Option<HeaderMap>,
-            _body: Bytes,
+            url: &Url,
+            headers: Option<HeaderMap>,
+            body: Bytes,
         ) -> anyhow::Result<reqwest::Response> {
-            unimplemented!()
+            let mut request = self.client.post(url.clone()).body(body);
+            if let Some(headers) = headers {
+                request = request.headers(headers);
+            }
+            Ok(request.send().await?)
         }
 
         async fn http_delete(&self, _url: &Url) -> anyhow::Result<reqwest::Response> {
@@ -583,6 +641,97 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_chat_without_streaming() -> anyhow::Result<()> {
+        let mut fixture = MockServer::new().await;
+        let mock = fixture
+            .mock_chat_completion(
+                "/chat/completions",
+                serde_json::json!({
+                    "id": "chatcmpl-1",
+                    "model": "gpt-4o",
+                    "choices": [{
+                        "index": 0,
+                        "finish_reason": "stop",
+                        "message": { "role": "assistant", "content": "Hello there" }
+                    }],
+                    "usage": { "prompt_tokens": 5, "completion_tokens": 2, "total_tokens": 7 }
+                }),
+            )
+            .await;
+        let provider = create_provider(&format!("{}/chat/completions", fixture.url()))?;
+        let context = ChatContext::default()
+            .add_message(forge_domain::ContextMessage::user("Hi", None))
+            .stream(false);
+
+        let actual = provider
+            .chat(&ModelId::new("gpt-4o"), context, false)
+            .await?
+            .collect::<Vec<_>>()
+            .await;
+
+        mock.assert_async().await;
+        assert_eq!(actual.len(), 1);
+        let message = actual.into_iter().next().unwrap()?;
+        assert_eq!(message.content.unwrap().as_str(), "Hello there");
+        assert_eq!(
+            message.finish_reason,
+            Some(forge_domain::FinishReason::Stop)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fetch_models_together_ai_keeps_chat_models() -> anyhow::Result<()> {
+        let mut fixture = MockServer::new().await;
+        let mock = fixture
+            .mock_models(
+                serde_json::json!([
+                    {
+                        "id": "meta-llama/Llama-3.3-70B-Instruct-Turbo",
+                        "object": "model",
+                        "type": "chat",
+                        "display_name": "Meta Llama 3.3 70B Instruct Turbo",
+                        "context_length": 131072,
+                        "pricing": {"input": 0.88, "output": 0.88}
+                    },
+                    {
+                        "id": "BAAI/bge-large-en-v1.5",
+                        "object": "model",
+                        "type": "embedding",
+                        "display_name": "BAAI-Bge-Large-1.5",
+                        "context_length": 512
+                    },
+                    {
+                        "id": "black-forest-labs/FLUX.1-schnell",
+                        "object": "model",
+                        "type": "image",
+                        "display_name": "Flux.1 [schnell]"
+                    }
+                ]),
+                200,
+            )
+            .await;
+        let mut provider = create_provider(&fixture.url())?;
+        provider.provider.id = ProviderId::TOGETHER_AI;
+
+        let actual: Vec<_> = provider
+            .models()
+            .await?
+            .into_iter()
+            .map(|model| (model.id.to_string(), model.name, model.context_length))
+            .collect();
+
+        mock.assert_async().await;
+        let expected = vec![(
+            "meta-llama/Llama-3.3-70B-Instruct-Turbo".to_string(),
+            Some("Meta Llama 3.3 70B Instruct Turbo".to_string()),
+            Some(131072),
+        )];
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_fetch_models_http_error_status() -> anyhow::Result<()> {
         let mut fixture = MockServer::new().await;
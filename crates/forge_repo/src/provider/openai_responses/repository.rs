@@ -519,6 +519,8 @@ mod tests {
                 key.to_string(),
             )),
             url_params: HashMap::new(),
+            alias: None,
+            expires_at: None,
         })
     }
 
@@ -852,6 +854,8 @@ mod tests {
                     },
                 },
                 url_params: HashMap::new(),
+                alias: None,
+                expires_at: None,
             }),
             auth_methods: vec![],
             url_params: vec![],
@@ -892,6 +896,8 @@ mod tests {
                     },
                 },
                 url_params: HashMap::new(),
+                alias: None,
+                expires_at: None,
             }),
             auth_methods: vec![],
             url_params: vec![],
@@ -1190,6 +1196,8 @@ mod tests {
                     },
                 },
                 url_params,
+                alias: None,
+                expires_at: None,
             }),
             auth_methods: vec![],
             url_params: vec![],
@@ -1247,6 +1255,8 @@ mod tests {
                     "test-key".to_string(),
                 )),
                 url_params,
+                alias: None,
+                expires_at: None,
             }),
             auth_methods: vec![],
             url_params: vec![],
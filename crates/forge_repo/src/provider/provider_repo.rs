@@ -2,7 +2,10 @@ use std::sync::{Arc, LazyLock};
 
 use bytes::Bytes;
 use forge_app::domain::{ProviderId, ProviderResponse};
-use forge_app::{EnvironmentInfra, FileReaderInfra, FileWriterInfra, HttpInfra};
+use forge_app::{
+    EnvironmentInfra, FileReaderInfra, FileRemoverInfra, FileWriterInfra, HttpInfra, KeychainInfra,
+};
+use forge_config::StorageBackend;
 use forge_domain::{
     AnyProvider, ApiKey, AuthCredential, AuthDetails, Error, MigrationResult, Provider,
     ProviderRepository, ProviderType, URLParam, URLParamSpec, URLParamValue,
@@ -10,6 +13,11 @@ use forge_domain::{
 use merge::Merge;
 use serde::Deserialize;
 
+use super::credential_store::CredentialStore;
+
+/// Keychain account under which all provider credentials are stored
+const KEYCHAIN_ACCOUNT: &str = "provider-credentials";
+
 /// Represents the source of models for a provider
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 #[serde(untagged)]
@@ -198,6 +206,7 @@ impl From<forge_config::ProviderEntry> for ProviderConfig {
             forge_config::ProviderResponseType::Bedrock => ProviderResponse::Bedrock,
             forge_config::ProviderResponseType::Google => ProviderResponse::Google,
             forge_config::ProviderResponseType::OpenCode => ProviderResponse::OpenCode,
+            forge_config::ProviderResponseType::Cohere => ProviderResponse::Cohere,
         });
 
         let models = entry.models.map(|m| match m {
@@ -275,7 +284,9 @@ impl<
     F: EnvironmentInfra<Config = forge_config::ForgeConfig>
         + FileReaderInfra
         + FileWriterInfra
-        + HttpInfra,
+        + FileRemoverInfra
+        + HttpInfra
+        + KeychainInfra,
 > ForgeProviderRepository<F>
 {
     async fn get_custom_provider_configs(&self) -> anyhow::Result<Vec<ProviderConfig>> {
@@ -336,12 +347,12 @@ impl<
     pub async fn migrate_env_to_file(&self) -> anyhow::Result<Option<MigrationResult>> {
         let path = self.infra.get_environment().credentials_path();
 
-        // Check if credentials file already exists
-        if self.infra.read_utf8(&path).await.is_ok() {
+        // Check if credentials were already stored
+        if self.infra.read_utf8(&path).await.is_ok() || self.read_keychain().await?.is_some() {
             return Ok(None);
         }
 
-        let mut credentials = Vec::new();
+        let mut credentials = CredentialStore::default();
         let mut migrated_providers = Vec::new();
         let configs = self.get_merged_configs().await;
 
@@ -375,7 +386,7 @@ impl<
             // Try to create credential from environment variables
             if let Ok(credential) = self.create_credential_from_env(&config) {
                 migrated_providers.push(config.id);
-                credentials.push(credential);
+                credentials.upsert(credential);
             }
         }
 
@@ -437,6 +448,8 @@ impl<
             id: config.id.clone(),
             auth_details: AuthDetails::ApiKey(ApiKey::from(api_key)),
             url_params,
+            alias: None,
+            expires_at: None,
         })
     }
 
@@ -542,7 +555,8 @@ impl<
             original_credential.id.clone(),
             forge_domain::ApiKey::from(access_token.token),
         )
-        .url_params(original_credential.url_params.clone()))
+        .url_params(original_credential.url_params.clone())
+        .alias(original_credential.alias.clone()))
     }
 
     async fn provider_from_id(
@@ -581,21 +595,45 @@ impl<
         configs.0
     }
 
-    async fn read_credentials(&self) -> Vec<AuthCredential> {
+    /// Reads the stored credentials, from the keychain when it is the
+    /// configured storage backend and holds any, otherwise from the JSON file
+    ///
+    /// # Errors
+    /// Returns an error if the keychain is locked or unavailable, so a caller
+    /// never mistakes it for an empty store and overwrites it
+    async fn read_credentials(&self) -> anyhow::Result<CredentialStore> {
+        if let Some(content) = self.read_keychain().await? {
+            return Ok(serde_json::from_str(&content).unwrap_or_default());
+        }
+
         let path = self.infra.get_environment().credentials_path();
 
-        match self.infra.read_utf8(&path).await {
+        Ok(match self.infra.read_utf8(&path).await {
             Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
-            Err(_) => Vec::new(),
-        }
+            Err(_) => CredentialStore::default(),
+        })
     }
 
-    /// Writes credentials to the JSON file
+    /// Writes credentials to the keychain when it is the configured storage
+    /// backend, otherwise to the JSON file
     ///
-    /// Sets file permissions to 0o600 (user read/write only) on Unix systems
-    /// to prevent other users from reading sensitive credentials.
-    async fn write_credentials(&self, credentials: &Vec<AuthCredential>) -> anyhow::Result<()> {
+    /// Once the keychain holds the credentials, any plaintext credentials file
+    /// left from file storage is removed. Sets file permissions to 0o600 (user
+    /// read/write only) on Unix systems to prevent other users from reading
+    /// sensitive credentials.
+    async fn write_credentials(&self, credentials: &CredentialStore) -> anyhow::Result<()> {
         let path = self.infra.get_environment().credentials_path();
+
+        if self.uses_keychain() {
+            self.infra
+                .set_password(KEYCHAIN_ACCOUNT, &serde_json::to_string(credentials)?)
+                .await?;
+            if self.infra.read_utf8(&path).await.is_ok() {
+                self.infra.remove(&path).await?;
+            }
+            return Ok(());
+        }
+
         let content = serde_json::to_string_pretty(credentials)?;
         self.infra.write(&path, Bytes::from(content)).await?;
 
@@ -604,6 +642,30 @@ impl<
 
         Ok(())
     }
+
+    fn uses_keychain(&self) -> bool {
+        self.infra
+            .get_config()
+            .is_ok_and(|config| config.credential_storage == Some(StorageBackend::Keychain))
+    }
+
+    /// Reads the credentials stored in the keychain, if the keychain is the
+    /// configured storage backend and holds any
+    async fn read_keychain(&self) -> anyhow::Result<Option<String>> {
+        if !self.uses_keychain() {
+            return Ok(None);
+        }
+        self.infra.get_password(KEYCHAIN_ACCOUNT).await
+    }
+
+    /// Returns the credential alias configured for the provider, if any
+    fn active_alias(&self, id: &ProviderId) -> Option<String> {
+        self.infra
+            .get_config()
+            .ok()?
+            .provider_aliases
+            .remove(&id.as_ref().to_string())
+    }
 }
 
 /// Restricts a file's permissions to owner read/write only (`0o600`).
@@ -621,7 +683,9 @@ impl<
     F: EnvironmentInfra<Config = forge_config::ForgeConfig>
         + FileReaderInfra
         + FileWriterInfra
+        + FileRemoverInfra
         + HttpInfra
+        + KeychainInfra
         + Sync,
 > ProviderRepository for ForgeProviderRepository<F>
 {
@@ -633,29 +697,34 @@ impl<
         self.provider_from_id(id).await
     }
 
-    async fn upsert_credential(&self, credential: AuthCredential) -> anyhow::Result<()> {
-        let mut credentials = self.read_credentials().await;
-        let id = credential.id.clone();
-
-        // Update existing credential or add new one
-        if let Some(existing) = credentials.iter_mut().find(|c| c.id == id) {
-            *existing = credential;
-        } else {
-            credentials.push(credential);
+    async fn upsert_credential(&self, mut credential: AuthCredential) -> anyhow::Result<()> {
+        // Credentials created by login and refresh flows are stored under the
+        // provider's active alias
+        if credential.alias.is_none() {
+            credential.alias = self.active_alias(&credential.id);
         }
+
+        let mut credentials = self.read_credentials().await?;
+        credentials.upsert(credential);
         self.write_credentials(&credentials).await?;
 
         Ok(())
     }
 
     async fn get_credential(&self, id: &ProviderId) -> anyhow::Result<Option<AuthCredential>> {
-        let credentials = self.read_credentials().await;
-        Ok(credentials.into_iter().find(|c| &c.id == id))
+        let credentials = self.read_credentials().await?;
+        let alias = self.active_alias(id);
+        Ok(credentials.find(id, alias.as_deref()).cloned())
+    }
+
+    async fn get_credentials(&self, id: &ProviderId) -> anyhow::Result<Vec<AuthCredential>> {
+        let credentials = self.read_credentials().await?;
+        Ok(credentials.list(id).into_iter().cloned().collect())
     }
 
     async fn remove_credential(&self, id: &ProviderId) -> anyhow::Result<()> {
-        let mut credentials = self.read_credentials().await;
-        credentials.retain(|c| &c.id != id);
+        let mut credentials = self.read_credentials().await?;
+        credentials.remove(id, self.active_alias(id).as_deref());
         self.write_credentials(&credentials).await?;
 
         Ok(())
@@ -902,6 +971,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cohere_config() {
+        let configs = get_provider_configs();
+        let config = configs.iter().find(|c| c.id == ProviderId::COHERE).unwrap();
+        assert_eq!(config.api_key_vars, Some("COHERE_API_KEY".to_string()));
+        assert_eq!(config.response_type, Some(ProviderResponse::Cohere));
+        assert_eq!(config.url.as_str(), "https://api.cohere.com/v1/chat");
+    }
+
+    #[test]
+    fn test_together_ai_config() {
+        let configs = get_provider_configs();
+        let config = configs
+            .iter()
+            .find(|c| c.id == ProviderId::TOGETHER_AI)
+            .unwrap();
+        assert_eq!(config.api_key_vars, Some("TOGETHER_API_KEY".to_string()));
+        assert_eq!(config.response_type, Some(ProviderResponse::OpenAI));
+        assert_eq!(
+            config.url.as_str(),
+            "https://api.together.xyz/v1/chat/completions"
+        );
+    }
+
+    #[test]
+    fn test_perplexity_config() {
+        let configs = get_provider_configs();
+        let config = configs
+            .iter()
+            .find(|c| c.id == ProviderId::PERPLEXITY)
+            .unwrap();
+        assert_eq!(config.api_key_vars, Some("PERPLEXITY_API_KEY".to_string()));
+        assert_eq!(config.response_type, Some(ProviderResponse::OpenAI));
+        assert_eq!(
+            config.url.as_str(),
+            "https://api.perplexity.ai/chat/completions"
+        );
+        let param = &config.url_param_vars[0];
+        assert_eq!(param.param_name(), "PERPLEXITY_SEARCH_RECENCY_FILTER");
+        assert!(param.is_optional());
+    }
+
     #[test]
     fn test_neuralwatt_config() {
         let configs = get_provider_configs();
@@ -1025,6 +1136,10 @@ mod env_tests {
         env_vars: HashMap<String, String>,
         base_path: PathBuf,
         credentials: tokio::sync::Mutex<Option<Vec<AuthCredential>>>,
+        config: forge_config::ForgeConfig,
+        keychain: std::sync::Mutex<HashMap<String, String>>,
+        keychain_calls: std::sync::Mutex<Vec<String>>,
+        keychain_locked: bool,
         _tmp: tempfile::TempDir,
     }
 
@@ -1036,9 +1151,23 @@ mod env_tests {
                 env_vars,
                 base_path,
                 credentials: tokio::sync::Mutex::new(None),
+                config: forge_config::ForgeConfig::default(),
+                keychain: Default::default(),
+                keychain_calls: Default::default(),
+                keychain_locked: false,
                 _tmp: tmp,
             }
         }
+
+        fn with_keychain(mut self) -> Self {
+            self.config.credential_storage = Some(StorageBackend::Keychain);
+            self
+        }
+
+        fn with_locked_keychain(mut self) -> Self {
+            self.keychain_locked = true;
+            self.with_keychain()
+        }
     }
 
     impl EnvironmentInfra for MockInfra {
@@ -1059,7 +1188,7 @@ mod env_tests {
         }
 
         fn get_config(&self) -> anyhow::Result<forge_config::ForgeConfig> {
-            Ok(forge_config::ForgeConfig::default())
+            Ok(self.config.clone())
         }
 
         fn get_env_var(&self, key: &str) -> Option<String> {
@@ -1074,6 +1203,35 @@ mod env_tests {
         }
     }
 
+    #[async_trait::async_trait]
+    impl KeychainInfra for MockInfra {
+        async fn get_password(&self, account: &str) -> anyhow::Result<Option<String>> {
+            self.keychain_calls
+                .lock()
+                .unwrap()
+                .push(format!("get_password {account}"));
+            if self.keychain_locked {
+                anyhow::bail!("Keychain is locked");
+            }
+            Ok(self.keychain.lock().unwrap().get(account).cloned())
+        }
+
+        async fn set_password(&self, account: &str, password: &str) -> anyhow::Result<()> {
+            self.keychain_calls
+                .lock()
+                .unwrap()
+                .push(format!("set_password {account}"));
+            if self.keychain_locked {
+                anyhow::bail!("Keychain is locked");
+            }
+            self.keychain
+                .lock()
+                .unwrap()
+                .insert(account.to_string(), password.to_string());
+            Ok(())
+        }
+    }
+
     #[async_trait::async_trait]
     impl FileReaderInfra for MockInfra {
         async fn read_utf8(&self, path: &std::path::Path) -> anyhow::Result<String> {
@@ -1141,6 +1299,17 @@ mod env_tests {
         }
     }
 
+    #[async_trait::async_trait]
+    impl FileRemoverInfra for MockInfra {
+        async fn remove(&self, path: &std::path::Path) -> anyhow::Result<()> {
+            if path == self.get_environment().credentials_path() {
+                *self.credentials.lock().await = None;
+                tokio::fs::remove_file(path).await?;
+            }
+            Ok(())
+        }
+    }
+
     #[async_trait::async_trait]
     impl HttpInfra for MockInfra {
         async fn http_get(
@@ -1214,6 +1383,13 @@ mod env_tests {
             Ok(None)
         }
 
+        async fn get_credentials(
+            &self,
+            _id: &ProviderId,
+        ) -> anyhow::Result<Vec<forge_domain::AuthCredential>> {
+            Ok(vec![])
+        }
+
         async fn remove_credential(&self, _id: &ProviderId) -> anyhow::Result<()> {
             Ok(())
         }
@@ -1352,6 +1528,8 @@ mod env_tests {
                 id: ProviderId::OPENAI,
                 auth_details: AuthDetails::ApiKey(ApiKey::from("sk-test".to_string())),
                 url_params: std::collections::HashMap::new(),
+                alias: None,
+                expires_at: None,
             })
             .await
             .unwrap();
@@ -1371,6 +1549,68 @@ mod env_tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_keychain_storage_round_trips_credentials() {
+        let infra = Arc::new(MockInfra::new(HashMap::new()).with_keychain());
+        let registry = ForgeProviderRepository::new(infra.clone());
+        let fixture =
+            AuthCredential::new_api_key(ProviderId::OPENAI, ApiKey::from("sk-test".to_string()));
+
+        registry.upsert_credential(fixture.clone()).await.unwrap();
+        let actual = registry.get_credential(&ProviderId::OPENAI).await.unwrap();
+
+        assert_eq!(actual, Some(fixture));
+        assert_eq!(
+            infra.keychain_calls.lock().unwrap().clone(),
+            vec![
+                "get_password provider-credentials".to_string(),
+                "set_password provider-credentials".to_string(),
+                "get_password provider-credentials".to_string(),
+            ]
+        );
+        assert!(infra.credentials.lock().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_keychain_storage_removes_plaintext_credentials_file() {
+        let infra = Arc::new(MockInfra::new(HashMap::new()).with_keychain());
+        let fixture =
+            AuthCredential::new_api_key(ProviderId::OPENAI, ApiKey::from("sk-test".to_string()));
+        let path = infra.get_environment().credentials_path();
+        let content = serde_json::to_string(&vec![fixture.clone()]).unwrap();
+        infra.write(&path, Bytes::from(content)).await.unwrap();
+        let registry = ForgeProviderRepository::new(infra.clone());
+
+        registry.upsert_credential(fixture.clone()).await.unwrap();
+        let actual = registry.get_credential(&ProviderId::OPENAI).await.unwrap();
+
+        assert_eq!(actual, Some(fixture));
+        assert!(infra.credentials.lock().await.is_none());
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_locked_keychain_fails_instead_of_reading_empty_store() {
+        let infra = Arc::new(MockInfra::new(HashMap::new()).with_locked_keychain());
+        let registry = ForgeProviderRepository::new(infra.clone());
+        let fixture =
+            AuthCredential::new_api_key(ProviderId::OPENAI, ApiKey::from("sk-test".to_string()));
+
+        let read = registry.get_credential(&ProviderId::OPENAI).await;
+        let upsert = registry.upsert_credential(fixture).await;
+
+        assert!(read.is_err());
+        assert!(upsert.is_err());
+        assert_eq!(
+            infra.keychain_calls.lock().unwrap().clone(),
+            vec![
+                "get_password provider-credentials".to_string(),
+                "get_password provider-credentials".to_string(),
+            ]
+        );
+        assert!(infra.credentials.lock().await.is_none());
+    }
+
     /// Verifies that an existing credentials file with overly broad permissions
     /// (e.g. 0o644) is tightened to 0o600 when credentials are updated.
     #[cfg(unix)]
@@ -1385,6 +1625,8 @@ mod env_tests {
             id: ProviderId::OPENAI,
             auth_details: AuthDetails::ApiKey(ApiKey::from("sk-test".to_string())),
             url_params: std::collections::HashMap::new(),
+            alias: None,
+            expires_at: None,
         };
 
         // First write — establishes the file
@@ -1893,6 +2135,24 @@ mod env_tests {
             }
         }
 
+        #[async_trait::async_trait]
+        impl FileRemoverInfra for CustomMockInfra {
+            async fn remove(&self, _path: &std::path::Path) -> anyhow::Result<()> {
+                Ok(())
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl KeychainInfra for CustomMockInfra {
+            async fn get_password(&self, _account: &str) -> anyhow::Result<Option<String>> {
+                Err(anyhow::anyhow!("Keychain not available in mock"))
+            }
+
+            async fn set_password(&self, _account: &str, _password: &str) -> anyhow::Result<()> {
+                Err(anyhow::anyhow!("Keychain not available in mock"))
+            }
+        }
+
         #[async_trait::async_trait]
         impl HttpInfra for CustomMockInfra {
             async fn http_get(
@@ -1966,6 +2226,13 @@ mod env_tests {
                 Ok(None)
             }
 
+            async fn get_credentials(
+                &self,
+                _id: &ProviderId,
+            ) -> anyhow::Result<Vec<forge_domain::AuthCredential>> {
+                Ok(vec![])
+            }
+
             async fn remove_credential(&self, _id: &ProviderId) -> anyhow::Result<()> {
                 Ok(())
             }
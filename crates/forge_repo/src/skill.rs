@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::Context;
-use forge_app::domain::Skill;
+use forge_app::domain::{Skill, SkillMetadata};
 use forge_app::{EnvironmentInfra, FileInfoInfra, FileReaderInfra, Walker, WalkerInfra};
 use forge_domain::SkillRepository;
 use futures::future::join_all;
@@ -251,11 +251,14 @@ impl<I: FileInfoInfra + EnvironmentInfra + FileReaderInfra + WalkerInfra> ForgeS
 
 /// Private type for parsing skill YAML front matter
 #[derive(Debug, Deserialize)]
-struct SkillMetadata {
+struct SkillFrontMatter {
     /// Optional name of the skill (overrides filename if present)
     name: Option<String>,
     /// Optional description of the skill
     description: Option<String>,
+    /// Optional version, required tools and author of the skill
+    #[serde(flatten)]
+    metadata: SkillMetadata,
 }
 
 /// Extracts metadata from the skill markdown content using YAML front matter
@@ -266,21 +269,32 @@ struct SkillMetadata {
 /// ---
 /// name: "skill-name"
 /// description: "Your description here"
+/// version: "1.0"
+/// requires_tools: [fs_read, shell]
+/// author: "Jane Doe"
 /// ---
 /// # Skill content...
 /// ```
 ///
-/// Returns a tuple of (name, description) where both are Option<String>.
+/// Returns `None` unless both the name and description are present; the
+/// version, required tools and author are optional.
 fn extract_skill(path: &str, content: &str) -> Option<Skill> {
     let matter = Matter::<YAML>::new();
-    let result = matter.parse::<SkillMetadata>(content);
-    result.ok().and_then(|parsed| {
-        let command = parsed.content;
-        parsed
-            .data
-            .and_then(|data| data.name.zip(data.description))
-            .map(|(name, description)| Skill::new(name, command, description).path(path))
-    })
+    let result = matter.parse::<SkillFrontMatter>(content);
+    result
+        .inspect_err(|e| tracing::warn!("Failed to parse skill front matter in {}: {}", path, e))
+        .ok()
+        .and_then(|parsed| {
+            let command = parsed.content;
+            parsed.data.and_then(|data| {
+                let metadata = data.metadata;
+                data.name.zip(data.description).map(|(name, description)| {
+                    Skill::new(name, command, description)
+                        .path(path)
+                        .metadata(metadata)
+                })
+            })
+        })
 }
 
 /// Resolves skill conflicts by keeping the last occurrence of each skill name
@@ -332,7 +346,8 @@ fn path_sort_key(path: Option<&Path>) -> String {
 
 #[cfg(test)]
 mod tests {
-    use forge_config::ForgeConfig;
+    use forge_app::domain::ToolName;
+    use forge_config::ConfigReader;
     use forge_infra::ForgeInfra;
     use pretty_assertions::assert_eq;
 
@@ -341,7 +356,7 @@ mod tests {
     fn fixture_skill_repo() -> (ForgeSkillRepository<ForgeInfra>, std::path::PathBuf) {
         let skill_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("src/fixtures/skills_with_resources");
-        let config = ForgeConfig::read().unwrap_or_default();
+        let config = ConfigReader::default().read_defaults().build().unwrap();
         let infra = Arc::new(ForgeInfra::new(std::env::current_dir().unwrap(), config));
         let repo = ForgeSkillRepository::new(infra);
         (repo, skill_dir)
@@ -473,6 +488,38 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[tokio::test]
+    async fn test_extract_skill_with_version_and_required_tools() {
+        // Fixture
+        let path = "fixtures/skills/with_metadata.md";
+        let content = forge_test_kit::fixture!("/src/fixtures/skills/with_metadata.md").await;
+
+        // Act
+        let actual = extract_skill(path, &content).unwrap().metadata;
+
+        // Assert
+        let expected = SkillMetadata::default()
+            .version("1.0")
+            .required_tools(vec![ToolName::new("fs_read"), ToolName::new("shell")])
+            .author("Forge Team");
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_extract_skill_with_numeric_metadata() {
+        // Fixture
+        let path = "fixtures/skills/with_numeric_metadata.md";
+        let content =
+            forge_test_kit::fixture!("/src/fixtures/skills/with_numeric_metadata.md").await;
+
+        // Act
+        let actual = extract_skill(path, &content).map(|skill| skill.metadata);
+
+        // Assert
+        let expected = Some(SkillMetadata::default().version("1.0").author("2024"));
+        assert_eq!(actual, expected);
+    }
+
     #[tokio::test]
     async fn test_extract_skill_with_incomplete_metadata() {
         // Fixture
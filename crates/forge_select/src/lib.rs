@@ -7,6 +7,8 @@ mod widget;
 
 pub use input::InputBuilder;
 pub use multi::MultiSelectBuilder;
-pub use preview::{PreviewLayout, PreviewPlacement, SelectMode, SelectRow, SelectUiOptions};
+pub use preview::{
+    PreviewLayout, PreviewPlacement, SelectMode, SelectRow, SelectUiOptions, filter_rows,
+};
 pub use select::SelectBuilder;
 pub use widget::ForgeWidget;
@@ -229,6 +229,8 @@ pub struct SelectUiOptions {
     pub preview_layout: PreviewLayout,
     /// Optional raw value to focus initially.
     pub initial_raw: Option<String>,
+    /// Optional help message displayed as a header above the list.
+    pub help_message: Option<String>,
 }
 
 impl SelectUiOptions {
@@ -243,6 +245,7 @@ impl SelectUiOptions {
             preview: None,
             preview_layout: PreviewLayout::default(),
             initial_raw: None,
+            help_message: None,
         }
     }
 
@@ -296,7 +299,16 @@ fn run_select_ui_values(options: SelectUiOptions) -> anyhow::Result<Option<Vec<S
         preview,
         preview_layout,
         initial_raw,
+        help_message,
     } = options;
+    let (rows, header_lines) = match help_message {
+        Some(help) => {
+            let mut rows = rows;
+            rows.insert(0, SelectRow::header(help));
+            (rows, header_lines + 1)
+        }
+        None => (rows, header_lines),
+    };
     let header_count = header_lines.min(rows.len());
     let header_rows = rows.iter().take(header_count).collect::<Vec<_>>();
     let data_rows = rows.iter().skip(header_count).cloned().collect::<Vec<_>>();
@@ -304,21 +316,8 @@ fn run_select_ui_values(options: SelectUiOptions) -> anyhow::Result<Option<Vec<S
         return Ok(None);
     }
 
-    let mut matcher = Nucleo::new(NucleoConfig::DEFAULT, Arc::new(|| {}), None, 1);
-    let injector = matcher.injector();
-    for row in data_rows.iter().cloned() {
-        injector.push(row, |item, columns| {
-            if let Some(column) = columns.get_mut(0) {
-                *column = Utf32String::from(item.search.as_str());
-            }
-        });
-    }
-    drop(injector);
-
     let mut query = query.unwrap_or_default();
-    matcher
-        .pattern
-        .reparse(0, &query, CaseMatching::Smart, Normalization::Smart, false);
+    let mut matcher = build_matcher(data_rows.iter().cloned(), &query);
     let _ = matcher.tick(50);
 
     let guard = TerminalGuard::enter()?;
@@ -807,6 +806,34 @@ fn mouse_over_preview(
     }
 }
 
+/// Creates a fuzzy matcher over the `search` text of `rows` with `query`
+/// applied.
+fn build_matcher(rows: impl IntoIterator<Item = SelectRow>, query: &str) -> Nucleo<SelectRow> {
+    let mut matcher = Nucleo::new(NucleoConfig::DEFAULT, Arc::new(|| {}), None, 1);
+    let injector = matcher.injector();
+    for row in rows {
+        injector.push(row, |item, columns| {
+            if let Some(column) = columns.get_mut(0) {
+                *column = Utf32String::from(item.search.as_str());
+            }
+        });
+    }
+    drop(injector);
+
+    matcher
+        .pattern
+        .reparse(0, query, CaseMatching::Smart, Normalization::Smart, false);
+    matcher
+}
+
+/// Returns the rows matching `query` using the same fuzzy matching as the
+/// interactive selector, best matches first.
+pub fn filter_rows(rows: Vec<SelectRow>, query: &str) -> Vec<SelectRow> {
+    let mut matcher = build_matcher(rows, query);
+    while matcher.tick(50).running {}
+    matched_rows(&matcher).into_iter().cloned().collect()
+}
+
 fn matched_rows(matcher: &Nucleo<SelectRow>) -> Vec<&SelectRow> {
     matcher
         .snapshot()
@@ -1324,6 +1351,23 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_filter_rows_matches_search_text() {
+        let fixture = vec![
+            SelectRow::new("1", "Fix login bug").search("Fix login bug"),
+            SelectRow::new("2", "Add dark mode").search("Add dark mode"),
+            SelectRow::new("3", "Refactor login flow").search("Refactor login flow"),
+        ];
+
+        let actual = filter_rows(fixture, "login")
+            .into_iter()
+            .map(|row| row.raw)
+            .collect::<BTreeSet<_>>();
+
+        let expected = BTreeSet::from(["1".to_string(), "3".to_string()]);
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_desired_select_viewport_height_right_ignores_preview_line_count() {
         let fixture = PreviewLayout { placement: PreviewPlacement::Right, percent: 50 };
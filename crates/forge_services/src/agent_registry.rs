@@ -1,9 +1,12 @@
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use dashmap::DashMap;
 use forge_app::domain::AgentId;
 use forge_app::{AgentRepository, EnvironmentInfra};
 use forge_domain::{Agent, AgentInfo};
+use forge_stream::MpscStream;
+use futures::StreamExt;
 use tokio::sync::RwLock;
 
 /// AgentRegistryService manages the active-agent ID and a registry of runtime
@@ -15,8 +18,9 @@ pub struct ForgeAgentRegistryService<R> {
 
     // In-memory storage for agents keyed by AgentId string
     // Lazily initialized on first access
-    // Wrapped in RwLock to allow invalidation
-    agents: RwLock<Option<DashMap<String, Agent>>>,
+    // Wrapped in RwLock to allow invalidation, and shared with the agent
+    // file watcher
+    agents: Arc<RwLock<Option<DashMap<String, Agent>>>>,
 
     // In-memory storage for the active agent ID
     active_agent_id: RwLock<Option<AgentId>>,
@@ -27,7 +31,7 @@ impl<R> ForgeAgentRegistryService<R> {
     pub fn new(repository: Arc<R>) -> Self {
         Self {
             repository,
-            agents: RwLock::new(None),
+            agents: Arc::new(RwLock::new(None)),
             active_agent_id: RwLock::new(None),
         }
     }
@@ -73,18 +77,42 @@ impl<R: AgentRepository + EnvironmentInfra<Config = forge_config::ForgeConfig>>
     /// defaults.
     async fn load_agents(&self) -> anyhow::Result<DashMap<String, Agent>> {
         let agents = self.repository.get_agents().await?;
-        let agents_map = DashMap::new();
-        for agent in agents {
-            agents_map.insert(agent.id.as_str().to_string(), agent);
-        }
+        Ok(index_agents(agents))
+    }
+}
 
-        Ok(agents_map)
+/// Builds the in-memory agent map keyed by AgentId string
+fn index_agents(agents: Vec<Agent>) -> DashMap<String, Agent> {
+    let agents_map = DashMap::new();
+    for agent in agents {
+        agents_map.insert(agent.id.as_str().to_string(), agent);
     }
+    agents_map
+}
+
+/// Returns the IDs of the agents defined in `changed` files. Removed files no
+/// longer have an agent, so their file stem is reported instead.
+fn reloaded_agent_ids(agents: &DashMap<String, Agent>, changed: &[PathBuf]) -> Vec<AgentId> {
+    changed
+        .iter()
+        .filter_map(|path| {
+            agents
+                .iter()
+                .find(|entry| entry.value().path.as_deref().map(Path::new) == Some(path))
+                .map(|entry| entry.value().id.clone())
+                .or_else(|| {
+                    path.file_stem()
+                        .and_then(|stem| stem.to_str())
+                        .map(AgentId::new)
+                })
+        })
+        .collect()
 }
 
 #[async_trait::async_trait]
-impl<R: AgentRepository + EnvironmentInfra<Config = forge_config::ForgeConfig> + Send + Sync>
-    forge_app::AgentRegistry for ForgeAgentRegistryService<R>
+impl<
+    R: AgentRepository + EnvironmentInfra<Config = forge_config::ForgeConfig> + Send + Sync + 'static,
+> forge_app::AgentRegistry for ForgeAgentRegistryService<R>
 {
     async fn get_active_agent_id(&self) -> anyhow::Result<Option<AgentId>> {
         let agent_id = self.active_agent_id.read().await;
@@ -117,4 +145,126 @@ impl<R: AgentRepository + EnvironmentInfra<Config = forge_config::ForgeConfig> +
         self.ensure_agents_loaded().await?;
         Ok(())
     }
+
+    async fn watch_agents(&self) -> anyhow::Result<MpscStream<AgentId>> {
+        let mut changes = self.repository.watch_agents().await?;
+        let repository = self.repository.clone();
+        let cache = self.agents.clone();
+
+        Ok(MpscStream::spawn(move |tx| async move {
+            while let Some(changed) = changes.next().await {
+                // Keep serving the previous agents if the edited file doesn't parse
+                let agents = match repository.get_agents().await {
+                    Ok(agents) => index_agents(agents),
+                    Err(error) => {
+                        tracing::warn!(%error, "Failed to reload agents");
+                        continue;
+                    }
+                };
+                let reloaded = reloaded_agent_ids(&agents, &changed);
+                *cache.write().await = Some(agents);
+
+                for agent_id in reloaded {
+                    if tx.send(agent_id).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use forge_app::AgentRegistry;
+    use forge_domain::{ConfigOperation, Environment, ModelId, ProviderId};
+    use pretty_assertions::assert_eq;
+    use tokio::sync::mpsc;
+
+    use super::*;
+
+    struct MockAgentRepository {
+        agents: Mutex<Vec<Agent>>,
+        changes: Mutex<Option<mpsc::Receiver<Vec<PathBuf>>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl AgentRepository for MockAgentRepository {
+        async fn get_agents(&self) -> anyhow::Result<Vec<Agent>> {
+            Ok(self.agents.lock().unwrap().clone())
+        }
+
+        async fn get_agent_infos(&self) -> anyhow::Result<Vec<AgentInfo>> {
+            Ok(vec![])
+        }
+
+        async fn watch_agents(&self) -> anyhow::Result<MpscStream<Vec<PathBuf>>> {
+            let mut changes = self.changes.lock().unwrap().take().unwrap();
+            Ok(MpscStream::spawn(move |tx| async move {
+                while let Some(changed) = changes.recv().await {
+                    let _ = tx.send(changed).await;
+                }
+            }))
+        }
+    }
+
+    impl EnvironmentInfra for MockAgentRepository {
+        type Config = forge_config::ForgeConfig;
+
+        fn get_environment(&self) -> Environment {
+            use fake::{Fake, Faker};
+            Faker.fake()
+        }
+
+        fn get_config(&self) -> anyhow::Result<forge_config::ForgeConfig> {
+            Ok(forge_config::ForgeConfig::default())
+        }
+
+        async fn update_environment(&self, _ops: Vec<ConfigOperation>) -> anyhow::Result<()> {
+            unimplemented!()
+        }
+
+        fn get_env_var(&self, _key: &str) -> Option<String> {
+            None
+        }
+
+        fn get_env_vars(&self) -> std::collections::BTreeMap<String, String> {
+            std::collections::BTreeMap::new()
+        }
+    }
+
+    fn fixture_agent(title: &str) -> Agent {
+        Agent::new("test", ProviderId::OPENAI, ModelId::new("gpt-4"))
+            .title(title)
+            .path("/agents/test.md")
+    }
+
+    #[tokio::test]
+    async fn test_watch_agents_reloads_changed_agent() {
+        let (changes_tx, changes_rx) = mpsc::channel(1);
+        let repository = Arc::new(MockAgentRepository {
+            agents: Mutex::new(vec![fixture_agent("Old")]),
+            changes: Mutex::new(Some(changes_rx)),
+        });
+        let fixture = ForgeAgentRegistryService::new(repository.clone());
+        fixture.get_agents().await.unwrap();
+        let mut reloaded = fixture.watch_agents().await.unwrap();
+
+        *repository.agents.lock().unwrap() = vec![fixture_agent("New")];
+        changes_tx
+            .send(vec![PathBuf::from("/agents/test.md")])
+            .await
+            .unwrap();
+        let actual = tokio::time::timeout(Duration::from_millis(500), reloaded.next())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(actual, AgentId::new("test"));
+        let agent = fixture.get_agent(&AgentId::new("test")).await.unwrap();
+        assert_eq!(agent.unwrap().title, Some("New".to_string()));
+    }
 }
@@ -111,6 +111,8 @@ mod tests {
                                 forge_domain::ApiKey::from("test-key".to_string()),
                             ),
                             url_params: HashMap::new(),
+                            alias: None,
+                            expires_at: None,
                         }),
                         auth_methods: vec![forge_domain::AuthMethod::ApiKey],
                         url_params: vec![],
@@ -123,6 +125,8 @@ mod tests {
                             supports_parallel_tool_calls: Some(true),
                             supports_reasoning: Some(false),
                             input_modalities: vec![InputModality::Text],
+                            cost_per_million_input_tokens: None,
+                            cost_per_million_output_tokens: None,
                         }])),
                         custom_headers: None,
                     },
@@ -139,6 +143,8 @@ mod tests {
                                 forge_domain::ApiKey::from("test-key".to_string()),
                             ),
                             url_params: HashMap::new(),
+                            alias: None,
+                            expires_at: None,
                         }),
                         models: Some(ModelSource::Hardcoded(vec![Model {
                             id: "claude-3".to_string().into(),
@@ -149,6 +155,8 @@ mod tests {
                             supports_parallel_tool_calls: Some(true),
                             supports_reasoning: Some(true),
                             input_modalities: vec![InputModality::Text],
+                            cost_per_million_input_tokens: None,
+                            cost_per_million_output_tokens: None,
                         }])),
                         custom_headers: None,
                     },
@@ -198,7 +206,9 @@ mod tests {
                                 mc.model.to_string(),
                             ));
                         }
-                        ConfigOperation::SetReasoningEffort(_) => {
+                        ConfigOperation::SetReasoningEffort(_)
+                        | ConfigOperation::SetProviderAlias(..)
+                        | ConfigOperation::SetPinnedVersion(_) => {
                             // No-op in tests
                         }
                     }
@@ -290,6 +300,13 @@ mod tests {
             Ok(None)
         }
 
+        async fn get_credentials(
+            &self,
+            _id: &ProviderId,
+        ) -> anyhow::Result<Vec<forge_domain::AuthCredential>> {
+            Ok(vec![])
+        }
+
         async fn remove_credential(&self, _id: &ProviderId) -> anyhow::Result<()> {
             Ok(())
         }
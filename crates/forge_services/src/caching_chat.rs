@@ -0,0 +1,201 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use dashmap::DashMap;
+use forge_domain::{
+    ChatCompletionMessage, ChatRepository, Context, Model, ModelId, Provider, ResultStream,
+};
+use futures::StreamExt;
+use url::Url;
+
+/// A fully received response stored alongside the time it was recorded.
+#[derive(Clone)]
+struct CachedResponse {
+    messages: Vec<ChatCompletionMessage>,
+    created_at: Instant,
+}
+
+/// Decorates a [`ChatRepository`] with an in-memory cache of complete
+/// responses keyed by provider, model and serialized context.
+///
+/// Identical requests issued within `ttl` are replayed from the cache without
+/// contacting the provider. Streams that end in an error are never cached.
+pub struct CachingChatRepository<R> {
+    inner: Arc<R>,
+    ttl: Duration,
+    cache: Arc<DashMap<u64, CachedResponse>>,
+}
+
+impl<R> CachingChatRepository<R> {
+    /// Creates a new caching wrapper around `inner` whose entries expire after
+    /// `ttl`.
+    pub fn new(inner: Arc<R>, ttl: Duration) -> Self {
+        Self { inner, ttl, cache: Arc::new(DashMap::new()) }
+    }
+
+    fn cache_key(model_id: &ModelId, context: &Context, provider: &Provider<Url>) -> Result<u64> {
+        let mut hasher = DefaultHasher::new();
+        provider.id.hash(&mut hasher);
+        model_id.hash(&mut hasher);
+        serde_json::to_string(context)?.hash(&mut hasher);
+        Ok(hasher.finish())
+    }
+
+    fn lookup(&self, key: u64) -> Option<Vec<ChatCompletionMessage>> {
+        let entry = self.cache.get(&key)?;
+        if entry.created_at.elapsed() < self.ttl {
+            return Some(entry.messages.clone());
+        }
+        drop(entry);
+        self.cache.remove(&key);
+        None
+    }
+}
+
+#[async_trait::async_trait]
+impl<R: ChatRepository + 'static> ChatRepository for CachingChatRepository<R> {
+    async fn chat(
+        &self,
+        model_id: &ModelId,
+        context: Context,
+        provider: Provider<Url>,
+    ) -> ResultStream<ChatCompletionMessage, anyhow::Error> {
+        let key = Self::cache_key(model_id, &context, &provider)?;
+
+        if let Some(messages) = self.lookup(key) {
+            tracing::debug!(model = %model_id, "Serving chat response from cache");
+            return Ok(Box::pin(futures::stream::iter(
+                messages.into_iter().map(Ok),
+            )));
+        }
+
+        let mut stream = self.inner.chat(model_id, context, provider).await?;
+        let cache = self.cache.clone();
+        Ok(Box::pin(async_stream::stream! {
+            let mut messages = Vec::new();
+            let mut failed = false;
+            while let Some(item) = stream.next().await {
+                match &item {
+                    Ok(message) => messages.push(message.clone()),
+                    Err(_) => failed = true,
+                }
+                yield item;
+            }
+            if !failed {
+                cache.insert(key, CachedResponse { messages, created_at: Instant::now() });
+            }
+        }))
+    }
+
+    async fn models(&self, provider: Provider<Url>) -> Result<Vec<Model>> {
+        self.inner.models(provider).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use forge_domain::{Content, ContextMessage, ProviderId, ProviderResponse, ProviderType};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct CountingChatRepository {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl ChatRepository for CountingChatRepository {
+        async fn chat(
+            &self,
+            _model_id: &ModelId,
+            _context: Context,
+            _provider: Provider<Url>,
+        ) -> ResultStream<ChatCompletionMessage, anyhow::Error> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let messages = vec![
+                Ok(ChatCompletionMessage::assistant(Content::part("Hello"))),
+                Ok(ChatCompletionMessage::assistant(Content::part(" world"))),
+            ];
+            Ok(Box::pin(futures::stream::iter(messages)))
+        }
+
+        async fn models(&self, _provider: Provider<Url>) -> Result<Vec<Model>> {
+            Ok(vec![])
+        }
+    }
+
+    fn fixture_provider() -> Provider<Url> {
+        Provider {
+            id: ProviderId::OPENAI,
+            provider_type: ProviderType::Llm,
+            response: Some(ProviderResponse::OpenAI),
+            url: Url::parse("https://api.openai.com/v1/chat/completions").unwrap(),
+            auth_methods: vec![],
+            url_params: vec![],
+            credential: None,
+            models: None,
+            custom_headers: None,
+        }
+    }
+
+    async fn collect(
+        repo: &CachingChatRepository<CountingChatRepository>,
+        context: Context,
+    ) -> Vec<ChatCompletionMessage> {
+        repo.chat(&ModelId::new("gpt-4"), context, fixture_provider())
+            .await
+            .unwrap()
+            .map(|message| message.unwrap())
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_identical_requests_hit_inner_once() {
+        let inner = Arc::new(CountingChatRepository::default());
+        let fixture = CachingChatRepository::new(inner.clone(), Duration::from_secs(60));
+        let context = Context::default().add_message(ContextMessage::user("Hi", None));
+
+        let first = collect(&fixture, context.clone()).await;
+        let second = collect(&fixture, context).await;
+
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(second, first);
+    }
+
+    #[tokio::test]
+    async fn test_different_context_misses_cache() {
+        let inner = Arc::new(CountingChatRepository::default());
+        let fixture = CachingChatRepository::new(inner.clone(), Duration::from_secs(60));
+
+        collect(
+            &fixture,
+            Context::default().add_message(ContextMessage::user("Hi", None)),
+        )
+        .await;
+        collect(
+            &fixture,
+            Context::default().add_message(ContextMessage::user("Bye", None)),
+        )
+        .await;
+
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_expired_entry_is_refreshed() {
+        let inner = Arc::new(CountingChatRepository::default());
+        let fixture = CachingChatRepository::new(inner.clone(), Duration::ZERO);
+        let context = Context::default().add_message(ContextMessage::user("Hi", None));
+
+        collect(&fixture, context.clone()).await;
+        collect(&fixture, context).await;
+
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+}
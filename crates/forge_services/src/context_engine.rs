@@ -256,6 +256,7 @@ impl<
         path: PathBuf,
         params: forge_domain::SearchParams<'_>,
     ) -> Result<Vec<forge_domain::Node>> {
+        let excluded = params.exclude_matcher()?;
         let (token, user_id) = self.get_workspace_credentials().await?;
 
         let workspace = self
@@ -272,6 +273,18 @@ impl<
             .await
             .context("Failed to search")?;
 
+        // The index has no notion of exclusions, so they are applied to the
+        // retrieved results
+        let results = results
+            .into_iter()
+            .filter(|node| {
+                !node
+                    .node
+                    .file_path()
+                    .is_some_and(|path| excluded.is_match(path))
+            })
+            .collect();
+
         Ok(results)
     }
 
@@ -401,6 +414,8 @@ impl<
             id: ProviderId::FORGE_SERVICES,
             auth_details: auth.clone().into(),
             url_params,
+            alias: None,
+            expires_at: None,
         };
 
         self.infra
@@ -421,3 +436,177 @@ impl<
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use forge_app::WalkedFile;
+    use forge_app::domain::{CommandOutput, Environment};
+    use forge_domain::{
+        AnyProvider, ApiKey, ConfigOperation, FileChunk, FileDeletion, FileHash, FileInfo,
+        FileUpload, FileUploadInfo, MigrationResult, Node, NodeData, NodeId, ProviderTemplate,
+        SearchParams, WorkspaceAuth, WorkspaceFiles, WorkspaceInfo,
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// Serves a single indexed workspace rooted at `working_dir` whose search
+    /// returns one chunk per entry in `paths`
+    struct MockInfra {
+        working_dir: PathBuf,
+        paths: Vec<&'static str>,
+    }
+
+    #[async_trait]
+    #[rustfmt::skip]
+    impl ProviderRepository for MockInfra {
+        async fn get_credential(&self, id: &ProviderId) -> Result<Option<AuthCredential>> {
+            let mut credential = AuthCredential::new_api_key(id.clone(), ApiKey::from("token".to_string()));
+            credential.url_params.insert("user_id".to_string().into(), UserId::generate().to_string().into());
+            Ok(Some(credential))
+        }
+        async fn get_all_providers(&self) -> Result<Vec<AnyProvider>> { unreachable!() }
+        async fn get_provider(&self, _: ProviderId) -> Result<ProviderTemplate> { unreachable!() }
+        async fn upsert_credential(&self, _: AuthCredential) -> Result<()> { unreachable!() }
+        async fn get_credentials(&self, _: &ProviderId) -> Result<Vec<AuthCredential>> { unreachable!() }
+        async fn remove_credential(&self, _: &ProviderId) -> Result<()> { unreachable!() }
+        async fn migrate_env_credentials(&self) -> Result<Option<MigrationResult>> { unreachable!() }
+    }
+
+    #[async_trait]
+    #[rustfmt::skip]
+    impl WorkspaceIndexRepository for MockInfra {
+        async fn list_workspaces(&self, _: &ApiKey) -> Result<Vec<WorkspaceInfo>> {
+            Ok(vec![WorkspaceInfo {
+                workspace_id: WorkspaceId::generate(),
+                working_dir: self.working_dir.to_string_lossy().to_string(),
+                node_count: None,
+                relation_count: None,
+                last_updated: None,
+                created_at: chrono::Utc::now(),
+            }])
+        }
+        async fn search(&self, _: &forge_domain::CodeSearchQuery<'_>, _: &ApiKey) -> Result<Vec<Node>> {
+            Ok(self.paths.iter().map(|path| chunk(path)).collect())
+        }
+        async fn authenticate(&self) -> Result<WorkspaceAuth> { unreachable!() }
+        async fn create_workspace(&self, _: &Path, _: &ApiKey) -> Result<WorkspaceId> { unreachable!() }
+        async fn upload_files(&self, _: &FileUpload, _: &ApiKey) -> Result<FileUploadInfo> { unreachable!() }
+        async fn get_workspace(&self, _: &WorkspaceId, _: &ApiKey) -> Result<Option<WorkspaceInfo>> { unreachable!() }
+        async fn list_workspace_files(&self, _: &WorkspaceFiles, _: &ApiKey) -> Result<Vec<FileHash>> { unreachable!() }
+        async fn delete_files(&self, _: &FileDeletion, _: &ApiKey) -> Result<()> { unreachable!() }
+        async fn delete_workspace(&self, _: &WorkspaceId, _: &ApiKey) -> Result<()> { unreachable!() }
+    }
+
+    #[async_trait]
+    #[rustfmt::skip]
+    impl FileReaderInfra for MockInfra {
+        async fn read_utf8(&self, _: &Path) -> Result<String> { unreachable!() }
+        fn read_batch_utf8(&self, _: usize, _: Vec<PathBuf>) -> impl futures::Stream<Item = (PathBuf, Result<String>)> + Send { futures::stream::empty() }
+        async fn read(&self, _: &Path) -> Result<Vec<u8>> { unreachable!() }
+        async fn range_read_utf8(&self, _: &Path, _: u64, _: u64) -> Result<(String, FileInfo)> { unreachable!() }
+    }
+
+    #[rustfmt::skip]
+    impl EnvironmentInfra for MockInfra {
+        type Config = forge_config::ForgeConfig;
+
+        fn get_env_var(&self, _: &str) -> Option<String> { None }
+        fn get_env_vars(&self) -> std::collections::BTreeMap<String, String> { Default::default() }
+        fn get_environment(&self) -> Environment { unreachable!() }
+        fn get_config(&self) -> Result<Self::Config> { unreachable!() }
+        async fn update_environment(&self, _: Vec<ConfigOperation>) -> Result<()> { unreachable!() }
+    }
+
+    #[async_trait]
+    #[rustfmt::skip]
+    impl CommandInfra for MockInfra {
+        async fn execute_command(&self, _: String, _: PathBuf, _: bool, _: Option<Vec<String>>) -> Result<CommandOutput> { unreachable!() }
+        async fn execute_command_raw(&self, _: &str, _: PathBuf, _: Option<Vec<String>>) -> Result<std::process::ExitStatus> { unreachable!() }
+    }
+
+    #[async_trait]
+    #[rustfmt::skip]
+    impl WalkerInfra for MockInfra {
+        async fn walk(&self, _: forge_app::Walker) -> Result<Vec<WalkedFile>> { unreachable!() }
+    }
+
+    /// Discovery is not invoked by `query_workspace`; a no-op satisfies the
+    /// bound.
+    struct NoDiscovery;
+    #[async_trait]
+    impl FileDiscovery for NoDiscovery {
+        async fn discover(&self, _: &Path) -> Result<Vec<PathBuf>> {
+            Ok(vec![])
+        }
+    }
+
+    fn chunk(path: &str) -> Node {
+        Node {
+            node_id: NodeId::new(path),
+            node: NodeData::FileChunk(FileChunk {
+                file_path: path.to_string(),
+                content: String::new(),
+                start_line: 1,
+                end_line: 1,
+            }),
+            relevance: None,
+            distance: None,
+        }
+    }
+
+    fn fixture(
+        dir: &tempfile::TempDir,
+        paths: Vec<&'static str>,
+    ) -> ForgeWorkspaceService<MockInfra, NoDiscovery> {
+        let working_dir = dir.path().canonicalize().unwrap();
+        ForgeWorkspaceService::new(
+            Arc::new(MockInfra { working_dir, paths }),
+            Arc::new(NoDiscovery),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_query_workspace_drops_excluded_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let service = fixture(
+            &dir,
+            vec!["src/auth.rs", "tests/auth.rs", "api.generated.rs"],
+        );
+        let params = SearchParams::new("auth", "find auth")
+            .exclude_paths(vec!["tests/**".to_string(), "*.generated.rs".to_string()]);
+
+        let actual = service
+            .query_workspace(dir.path().to_path_buf(), params)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter_map(|node| node.node.file_path().map(str::to_string))
+            .collect::<Vec<_>>();
+
+        let expected = vec!["src/auth.rs".to_string()];
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_query_workspace_rejects_invalid_exclude_pattern() {
+        let dir = tempfile::tempdir().unwrap();
+        let service = fixture(&dir, vec!["src/auth.rs"]);
+        let params =
+            SearchParams::new("auth", "find auth").exclude_paths(vec!["src/[auth".to_string()]);
+
+        let actual = service
+            .query_workspace(dir.path().to_path_buf(), params)
+            .await
+            .unwrap_err()
+            .downcast::<forge_domain::Error>()
+            .unwrap();
+
+        assert!(matches!(
+            actual,
+            forge_domain::Error::InvalidExcludePattern(_)
+        ));
+    }
+}
@@ -51,6 +51,10 @@ impl<S: ConversationRepository> ConversationService for ForgeConversationService
         Ok(())
     }
 
+    async fn update_conversation_title(&self, id: &ConversationId, title: String) -> Result<()> {
+        self.conversation_repository.update_title(id, title).await
+    }
+
     async fn get_conversations(&self, limit: Option<usize>) -> Result<Option<Vec<Conversation>>> {
         self.conversation_repository
             .get_all_conversations(limit)
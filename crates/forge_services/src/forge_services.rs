@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use forge_app::{
     AgentRepository, CommandInfra, DirectoryReaderInfra, EnvironmentInfra, FileDirectoryInfra,
@@ -116,7 +117,13 @@ impl<
         let suggestion_service = Arc::new(ForgeDiscoveryService::new(infra.clone()));
         let conversation_service = Arc::new(ForgeConversationService::new(infra.clone()));
         let auth_service = Arc::new(ForgeAuthService::new(infra.clone()));
-        let chat_service = Arc::new(ForgeProviderService::new(infra.clone()));
+        let chat_service = ForgeProviderService::new(infra.clone());
+        let chat_service = match infra.get_config() {
+            Ok(config) if config.cache_responses => chat_service
+                .with_response_cache(Duration::from_secs(config.response_cache_ttl_secs)),
+            _ => chat_service,
+        };
+        let chat_service = Arc::new(chat_service);
         let config_service = Arc::new(ForgeAppConfigService::new(infra.clone()));
         let file_create_service = Arc::new(ForgeFsWrite::new(infra.clone()));
         let plan_create_service = Arc::new(ForgePlanCreate::new(infra.clone()));
@@ -2,6 +2,7 @@ mod agent_registry;
 mod app_config;
 mod attachment;
 mod auth;
+mod caching_chat;
 mod clipper;
 mod command;
 mod context_engine;
@@ -25,6 +26,7 @@ mod tool_services;
 mod utils;
 
 pub use app_config::*;
+pub use caching_chat::*;
 pub use clipper::*;
 pub use command::*;
 pub use context_engine::*;
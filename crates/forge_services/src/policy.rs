@@ -4,14 +4,15 @@ use std::sync::{Arc, LazyLock};
 use anyhow::Context;
 use bytes::Bytes;
 use forge_app::domain::{
-    ExecuteRule, Fetch, Permission, PermissionOperation, Policy, PolicyConfig, PolicyEngine,
-    ReadRule, Rule, WriteRule,
+    ExecuteRule, Fetch, Permission, PermissionOperation, Policy, PolicyAuditEntry, PolicyConfig,
+    PolicyEngine, ReadRule, Rule, WriteRule,
 };
 use forge_app::{
     DirectoryReaderInfra, EnvironmentInfra, FileInfoInfra, FileReaderInfra, FileWriterInfra,
     PolicyDecision, PolicyService, UserInfra,
 };
 use strum_macros::{Display, EnumIter};
+use tokio::task::JoinHandle;
 
 /// User response for permission confirmation requests
 #[derive(Debug, Clone, PartialEq, Eq, Display, EnumIter, strum_macros::EnumString)]
@@ -154,7 +155,8 @@ where
         + FileInfoInfra
         + EnvironmentInfra
         + DirectoryReaderInfra
-        + UserInfra,
+        + UserInfra
+        + 'static,
 {
     /// Check if an operation is allowed based on policies and handle user
     /// confirmation
@@ -167,9 +169,9 @@ where
         let engine = PolicyEngine::new(&policies);
         let permission = engine.can_perform(operation);
 
-        match permission {
-            Permission::Deny => Ok(PolicyDecision { allowed: false, path }),
-            Permission::Allow => Ok(PolicyDecision { allowed: true, path }),
+        let decision = match permission {
+            Permission::Deny => PolicyDecision { allowed: false, path },
+            Permission::Allow => PolicyDecision { allowed: true, path },
             Permission::Confirm => {
                 // Request user confirmation using UserInfra
                 let confirmation_msg = match operation {
@@ -192,20 +194,50 @@ where
                     .select_one_enum::<PolicyPermission>(&confirmation_msg)
                     .await?
                 {
-                    Some(PolicyPermission::Accept) => Ok(PolicyDecision { allowed: true, path }),
+                    Some(PolicyPermission::Accept) => PolicyDecision { allowed: true, path },
                     Some(PolicyPermission::AcceptAndRemember) => {
                         let update_path = self.add_policy_for_operation(operation).await?;
-                        Ok(PolicyDecision { allowed: true, path: update_path.or(path) })
+                        PolicyDecision { allowed: true, path: update_path.or(path) }
                     }
                     Some(PolicyPermission::Reject) | None => {
-                        Ok(PolicyDecision { allowed: false, path })
+                        PolicyDecision { allowed: false, path }
                     }
                 }
             }
+        };
+
+        if let Some(audit_log) = &policies.audit_log {
+            let verdict = if decision.allowed {
+                Permission::Allow
+            } else {
+                Permission::Deny
+            };
+            let entry = PolicyAuditEntry::new(operation, verdict, engine.matching_rule(operation));
+            spawn_audit_entry(self.infra.clone(), audit_log.clone(), entry);
         }
+
+        Ok(decision)
     }
 }
 
+/// Appends `entry` to the audit log at `path` in the background so that
+/// permission checks never wait on the log file
+fn spawn_audit_entry<I: FileWriterInfra + 'static>(
+    infra: Arc<I>,
+    path: PathBuf,
+    entry: PolicyAuditEntry,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let result = match entry.to_json_line() {
+            Ok(line) => infra.append(&path, Bytes::from(line)).await,
+            Err(error) => Err(error.into()),
+        };
+        if let Err(error) = result {
+            tracing::warn!(path = %path.display(), error = ?error, "Failed to write policy audit entry");
+        }
+    })
+}
+
 /// Create a policy for an operation based on its type
 fn create_policy_for_operation(
     operation: &PermissionOperation,
@@ -443,4 +475,214 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    /// Infra backed by a real directory holding `permissions.yaml` and the
+    /// audit log; user prompts are never expected
+    struct MockPolicyInfra {
+        base_path: PathBuf,
+    }
+
+    #[async_trait::async_trait]
+    impl FileReaderInfra for MockPolicyInfra {
+        async fn read_utf8(&self, path: &std::path::Path) -> anyhow::Result<String> {
+            Ok(tokio::fs::read_to_string(path).await?)
+        }
+
+        fn read_batch_utf8(
+            &self,
+            _batch_size: usize,
+            _paths: Vec<PathBuf>,
+        ) -> impl futures::Stream<Item = (PathBuf, anyhow::Result<String>)> + Send {
+            futures::stream::empty()
+        }
+
+        async fn read(&self, _path: &std::path::Path) -> anyhow::Result<Vec<u8>> {
+            unimplemented!()
+        }
+
+        async fn range_read_utf8(
+            &self,
+            _path: &std::path::Path,
+            _start_line: u64,
+            _end_line: u64,
+        ) -> anyhow::Result<(String, forge_domain::FileInfo)> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl FileWriterInfra for MockPolicyInfra {
+        async fn write(&self, _path: &std::path::Path, _contents: Bytes) -> anyhow::Result<()> {
+            unimplemented!()
+        }
+
+        async fn append(&self, path: &std::path::Path, contents: Bytes) -> anyhow::Result<()> {
+            use std::io::Write;
+            let mut file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)?;
+            file.write_all(&contents)?;
+            Ok(())
+        }
+
+        async fn write_temp(
+            &self,
+            _prefix: &str,
+            _ext: &str,
+            _content: &str,
+        ) -> anyhow::Result<PathBuf> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl FileInfoInfra for MockPolicyInfra {
+        async fn is_binary(&self, _path: &std::path::Path) -> anyhow::Result<bool> {
+            unimplemented!()
+        }
+
+        async fn is_file(&self, path: &std::path::Path) -> anyhow::Result<bool> {
+            Ok(path.is_file())
+        }
+
+        async fn exists(&self, path: &std::path::Path) -> anyhow::Result<bool> {
+            Ok(path.exists())
+        }
+
+        async fn file_size(&self, _path: &std::path::Path) -> anyhow::Result<u64> {
+            unimplemented!()
+        }
+    }
+
+    impl EnvironmentInfra for MockPolicyInfra {
+        type Config = forge_config::ForgeConfig;
+
+        fn get_environment(&self) -> forge_domain::Environment {
+            use fake::{Fake, Faker};
+            let mut env: forge_domain::Environment = Faker.fake();
+            env.base_path = self.base_path.clone();
+            env
+        }
+
+        fn get_config(&self) -> anyhow::Result<forge_config::ForgeConfig> {
+            Ok(forge_config::ForgeConfig::default())
+        }
+
+        async fn update_environment(
+            &self,
+            _ops: Vec<forge_domain::ConfigOperation>,
+        ) -> anyhow::Result<()> {
+            unimplemented!()
+        }
+
+        fn get_env_var(&self, _key: &str) -> Option<String> {
+            None
+        }
+
+        fn get_env_vars(&self) -> std::collections::BTreeMap<String, String> {
+            std::collections::BTreeMap::new()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl DirectoryReaderInfra for MockPolicyInfra {
+        async fn list_directory_entries(
+            &self,
+            _directory: &std::path::Path,
+        ) -> anyhow::Result<Vec<(PathBuf, bool)>> {
+            unimplemented!()
+        }
+
+        async fn read_directory_files(
+            &self,
+            _directory: &std::path::Path,
+            _pattern: Option<&str>,
+        ) -> anyhow::Result<Vec<(PathBuf, String)>> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl UserInfra for MockPolicyInfra {
+        async fn prompt_question(&self, _question: &str) -> anyhow::Result<Option<String>> {
+            unimplemented!()
+        }
+
+        async fn select_one<T: Clone + std::fmt::Display + Send + 'static>(
+            &self,
+            _message: &str,
+            _options: Vec<T>,
+        ) -> anyhow::Result<Option<T>> {
+            unimplemented!()
+        }
+
+        async fn select_many<T: std::fmt::Display + Clone + Send + 'static>(
+            &self,
+            _message: &str,
+            _options: Vec<T>,
+        ) -> anyhow::Result<Option<Vec<T>>> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_records_each_decision() {
+        use forge_app::domain::AllowedPathsRule;
+
+        let dir = tempfile::tempdir().unwrap();
+        let audit_log = dir.path().join("audit.jsonl");
+        let policies = PolicyConfig::new()
+            .add_policy(Policy::Simple {
+                permission: Permission::Allow,
+                rule: Rule::AllowedPaths(AllowedPathsRule { allowed_paths: "src/**".to_string() }),
+            })
+            .audit_log(&audit_log);
+        std::fs::write(
+            dir.path().join("permissions.yaml"),
+            serde_yml::to_string(&policies).unwrap(),
+        )
+        .unwrap();
+        let service =
+            ForgePolicyService::new(Arc::new(MockPolicyInfra { base_path: dir.path().into() }));
+
+        let mut decisions = Vec::new();
+        for path in ["src/main.rs", "Cargo.toml"] {
+            let operation = PermissionOperation::Write {
+                path: PathBuf::from(path),
+                cwd: PathBuf::from("/test/cwd"),
+                message: format!("Create/overwrite file: {path}"),
+            };
+            let decision = service
+                .check_operation_permission(&operation)
+                .await
+                .unwrap();
+            decisions.push(decision.allowed);
+        }
+        // Audit entries are written by spawned tasks; let them run
+        tokio::task::yield_now().await;
+
+        let actual = std::fs::read_to_string(&audit_log)
+            .unwrap()
+            .lines()
+            .map(|line| {
+                let entry: PolicyAuditEntry = serde_json::from_str(line).unwrap();
+                (entry.operation, entry.decision, entry.rule)
+            })
+            .collect::<Vec<_>>();
+        let expected = vec![
+            (
+                "write src/main.rs".to_string(),
+                Permission::Allow,
+                Some("allowed paths 'src/**'".to_string()),
+            ),
+            (
+                "write Cargo.toml".to_string(),
+                Permission::Deny,
+                Some("outside allowed paths".to_string()),
+            ),
+        ];
+        assert_eq!(decisions, vec![true, false]);
+        assert_eq!(actual, expected);
+    }
 }
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use forge_app::ProviderService;
@@ -12,15 +13,25 @@ use forge_domain::{
 };
 use url::Url;
 
+use crate::caching_chat::CachingChatRepository;
+
 /// Service layer wrapper for ProviderRepository that handles template rendering
 pub struct ForgeProviderService<R> {
     repository: Arc<R>,
+    response_cache: Option<CachingChatRepository<R>>,
 }
 
 impl<R> ForgeProviderService<R> {
     /// Creates a new ForgeProviderService instance
     pub fn new(repository: Arc<R>) -> Self {
-        Self { repository }
+        Self { repository, response_cache: None }
+    }
+
+    /// Serves identical chat requests from an in-memory cache whose entries
+    /// expire after `ttl`.
+    pub fn with_response_cache(mut self, ttl: Duration) -> Self {
+        self.response_cache = Some(CachingChatRepository::new(self.repository.clone(), ttl));
+        self
     }
 
     /// Renders a URL template with provided parameters.
@@ -104,13 +115,16 @@ impl<R> ForgeProviderService<R> {
 }
 
 #[async_trait::async_trait]
-impl<R: ChatRepository + ProviderRepository> ProviderService for ForgeProviderService<R> {
+impl<R: ChatRepository + ProviderRepository + 'static> ProviderService for ForgeProviderService<R> {
     async fn chat(
         &self,
         model_id: &ModelId,
         context: Context,
         provider: Provider<Url>,
     ) -> ResultStream<ChatCompletionMessage, anyhow::Error> {
+        if let Some(cache) = &self.response_cache {
+            return cache.chat(model_id, context, provider).await;
+        }
         // Repository builds client on each call (no caching at repository level)
         self.repository.chat(model_id, context, provider).await
     }
@@ -152,6 +166,10 @@ impl<R: ChatRepository + ProviderRepository> ProviderService for ForgeProviderSe
         self.repository.upsert_credential(credential).await
     }
 
+    async fn get_credentials(&self, id: &ProviderId) -> Result<Vec<AuthCredential>> {
+        self.repository.get_credentials(id).await
+    }
+
     async fn remove_credential(&self, id: &ProviderId) -> Result<()> {
         self.repository.remove_credential(id).await
     }
@@ -218,6 +236,10 @@ mod tests {
             Ok(None)
         }
 
+        async fn get_credentials(&self, _id: &ProviderId) -> Result<Vec<AuthCredential>> {
+            Ok(vec![])
+        }
+
         async fn upsert_credential(&self, _credential: AuthCredential) -> Result<()> {
             Ok(())
         }
@@ -245,6 +267,8 @@ mod tests {
                     "test-key".to_string(),
                 )),
                 url_params: HashMap::new(),
+                alias: None,
+                expires_at: None,
             }),
             models: Some(ModelSource::Url(
                 Url::parse("https://api.openai.com/v1/models").unwrap(),
@@ -269,6 +293,8 @@ mod tests {
                     "test-key".to_string(),
                 )),
                 url_params: HashMap::new(),
+                alias: None,
+                expires_at: None,
             }),
             models: Some(ModelSource::Url(
                 Template::<forge_domain::URLParameters>::new("https://api.openai.com/v1/models"),
@@ -287,6 +313,8 @@ mod tests {
             supports_parallel_tool_calls: Some(true),
             supports_reasoning: Some(false),
             input_modalities: vec![InputModality::Text],
+            cost_per_million_input_tokens: None,
+            cost_per_million_output_tokens: None,
         }
     }
 
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use forge_app::{FileInfoInfra, FileReaderInfra, FsUndoOutput, FsUndoService};
@@ -36,4 +36,26 @@ impl<F: FileInfoInfra + FileReaderInfra + SnapshotRepository> FsUndoService for
 
         Ok(output)
     }
+
+    async fn rollback(
+        &self,
+        file_paths: Vec<PathBuf>,
+        turn_started_at: chrono::DateTime<chrono::Utc>,
+        dry_run: bool,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        let snapshots = self
+            .infra
+            .list_snapshots_for_turn(&file_paths, turn_started_at)
+            .await?;
+
+        let mut restored = Vec::with_capacity(snapshots.len());
+        for snapshot in snapshots {
+            if !dry_run {
+                self.infra.restore_snapshot(&snapshot).await?;
+            }
+            restored.push(PathBuf::from(snapshot.path));
+        }
+
+        Ok(restored)
+    }
 }
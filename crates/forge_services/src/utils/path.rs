@@ -4,6 +4,8 @@ use anyhow::bail;
 
 /// Ensures that the given path is absolute
 ///
+/// `s3://bucket/key` URLs are treated as absolute.
+///
 /// # Arguments
 /// * `path` - The path to validate
 ///
@@ -11,7 +13,8 @@ use anyhow::bail;
 /// * `Ok(())` if the path is absolute
 /// * `Err(String)` with an error message if the path is relative
 pub fn assert_absolute_path(path: &Path) -> anyhow::Result<()> {
-    if !path.is_absolute() {
+    let is_s3 = path.to_str().is_some_and(|path| path.starts_with("s3://"));
+    if !path.is_absolute() && !is_s3 {
         bail!("Path must be absolute. Please provide an absolute path starting with '/' (Unix) or 'C:\\' (Windows)".to_string())
     } else {
         Ok(())
@@ -35,6 +38,12 @@ mod tests {
         assert!(assert_absolute_path(path).is_ok());
     }
 
+    #[test]
+    fn test_s3_path() {
+        let path = Path::new("s3://bucket/docs/notes.txt");
+        assert!(assert_absolute_path(path).is_ok());
+    }
+
     #[test]
     fn test_basic_relative_path() {
         let path = Path::new("relative/path");
@@ -79,6 +79,49 @@ impl SnapshotService {
 
         Ok(())
     }
+
+    /// Lists all snapshots stored for `path`, oldest first
+    pub async fn list_snapshots(&self, path: PathBuf) -> Result<Vec<Snapshot>> {
+        let probe = Snapshot::create(path)?;
+        let snapshot_dir = self.snapshots_directory.join(probe.path_hash());
+        if !ForgeFS::exists(&snapshot_dir) {
+            return Ok(Vec::new());
+        }
+
+        let mut snapshots = Vec::new();
+        let mut dir = ForgeFS::read_dir(&snapshot_dir).await?;
+        while let Some(entry) = dir.next_entry().await? {
+            let filename = entry.file_name().to_string_lossy().to_string();
+            if let Some(snapshot) = Snapshot::from_file_name(probe.path.clone(), &filename) {
+                snapshots.push(snapshot);
+            }
+        }
+        snapshots.sort_by_key(|snapshot| snapshot.timestamp);
+
+        Ok(snapshots)
+    }
+
+    /// Restores the file captured by `snapshot`, then removes that snapshot
+    /// and every newer snapshot of the same file
+    pub async fn restore_snapshot(&self, snapshot: &Snapshot) -> Result<()> {
+        let snapshot_path = snapshot.snapshot_path(Some(self.snapshots_directory.clone()));
+        let content = ForgeFS::read(&snapshot_path)
+            .await
+            .with_context(|| format!("Snapshot not found for {}", snapshot.path))?;
+        ForgeFS::write(&snapshot.path, content).await?;
+
+        for newer in self
+            .list_snapshots(PathBuf::from(&snapshot.path))
+            .await?
+            .into_iter()
+            .filter(|newer| newer.timestamp >= snapshot.timestamp)
+        {
+            ForgeFS::remove_file(newer.snapshot_path(Some(self.snapshots_directory.clone())))
+                .await?;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -229,6 +272,45 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_restore_snapshot_discards_newer_snapshots() -> Result<()> {
+        // Arrange
+        let ctx = TestContext::new().await?;
+        ctx.write_content("Initial content").await?;
+        let first = ctx.create_snapshot().await?;
+        ctx.write_content("Second content").await?;
+        ctx.create_snapshot().await?;
+        ctx.write_content("Final content").await?;
+
+        // Act
+        ctx.service.restore_snapshot(&first).await?;
+
+        // Assert
+        assert_eq!(ctx.read_content().await?, "Initial content");
+        let remaining = ctx.service.list_snapshots(ctx.test_file.clone()).await?;
+        assert!(remaining.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_snapshots_oldest_first() -> Result<()> {
+        // Arrange
+        let ctx = TestContext::new().await?;
+        ctx.write_content("Initial content").await?;
+        let first = ctx.create_snapshot().await?;
+        let second = ctx.create_snapshot().await?;
+
+        // Act
+        let actual = ctx.service.list_snapshots(ctx.test_file.clone()).await?;
+
+        // Assert
+        let actual = actual.iter().map(|s| s.timestamp).collect::<Vec<_>>();
+        assert_eq!(actual, vec![first.timestamp, second.timestamp]);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_multiple_snapshots_undo_twice() -> Result<()> {
         // Arrange
@@ -1,3 +1,4 @@
+use std::io::IsTerminal;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread::{self, JoinHandle};
@@ -14,6 +15,7 @@ pub use progress_bar::*;
 
 const TICK_DURATION_MS: u64 = 60;
 const TICKS: &[&str; 10] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const ASCII_TICKS: &[&str; 4] = &["-", "\\", "|", "/"];
 const MIN_TERMINAL_WIDTH: usize = 12;
 const WRAP_GUARD_COLUMNS: usize = 8;
 
@@ -75,7 +77,12 @@ struct ActiveSpinner<P: ConsoleWriter> {
 }
 
 impl<P: ConsoleWriter + 'static> ActiveSpinner<P> {
-    fn start(printer: Arc<P>, accumulated_elapsed: Duration, message: String) -> Self {
+    fn start(
+        printer: Arc<P>,
+        accumulated_elapsed: Duration,
+        message: String,
+        ticks: Vec<&'static str>,
+    ) -> Self {
         let stop = Arc::new(AtomicBool::new(false));
         let paused = Arc::new(AtomicBool::new(false));
         let stop_signal = Arc::clone(&stop);
@@ -96,8 +103,8 @@ impl<P: ConsoleWriter + 'static> ActiveSpinner<P> {
 
                 let elapsed = accumulated_elapsed + started_at.elapsed();
                 let tick_index = ((elapsed.as_millis() / TICK_DURATION_MS as u128)
-                    % TICKS.len() as u128) as usize;
-                let tick = TICKS.get(tick_index).unwrap_or(&"⠋");
+                    % ticks.len() as u128) as usize;
+                let tick = ticks.get(tick_index).unwrap_or(&"⠋");
                 let line = styled_loader_line(tick, &message, elapsed, terminal_width());
 
                 if !stop_signal.load(Ordering::Acquire) && !paused_signal.load(Ordering::Acquire) {
@@ -207,26 +214,68 @@ fn format_elapsed_time(duration: Duration) -> String {
 /// and redraws a single truncated line on each tick. Accumulated time is
 /// preserved across start/stop cycles so paused output can resume without
 /// resetting the elapsed timer.
+///
+/// A quiet manager never draws the loader, so logs captured without a TTY
+/// only receive the lines written through it. It still tracks elapsed time.
 pub struct SpinnerManager<P: ConsoleWriter> {
     spinner: Option<ActiveSpinner<P>>,
+    /// When the current quiet run started, as no spinner times it
+    quiet_started_at: Option<Instant>,
     accumulated_elapsed: Duration,
     word_index: Option<usize>,
     message: Option<String>,
+    tick_strings: Option<Vec<&'static str>>,
+    quiet: bool,
     printer: Arc<P>,
 }
 
 impl<P: ConsoleWriter + 'static> SpinnerManager<P> {
-    /// Creates a new SpinnerManager with the given output printer.
+    /// Creates a new SpinnerManager with the given output printer. The
+    /// manager is quiet when stdout isn't a terminal.
     pub fn new(printer: Arc<P>) -> Self {
+        let quiet = !std::io::stdout().is_terminal();
+        Self::with_quiet(printer, quiet)
+    }
+
+    /// Creates a SpinnerManager that never draws the loader, whether or not a
+    /// terminal is attached.
+    pub fn quiet(printer: Arc<P>) -> Self {
+        Self::with_quiet(printer, true)
+    }
+
+    fn with_quiet(printer: Arc<P>, quiet: bool) -> Self {
         Self {
             spinner: None,
+            quiet_started_at: None,
             accumulated_elapsed: Duration::ZERO,
             word_index: None,
             message: None,
+            tick_strings: None,
+            quiet,
             printer,
         }
     }
 
+    /// Creates a SpinnerManager that animates with ASCII-only frames, for
+    /// terminals that can't draw the braille characters.
+    pub fn ascii_mode(printer: Arc<P>) -> Self {
+        Self::new(printer).with_tick_strings(ASCII_TICKS.to_vec())
+    }
+
+    /// Sets the animation frames. An empty list keeps the default frames.
+    pub fn with_tick_strings(mut self, frames: Vec<&'static str>) -> Self {
+        self.tick_strings = Some(frames);
+        self
+    }
+
+    /// Animation frames to draw, falling back to the default ones
+    fn ticks(&self) -> Vec<&'static str> {
+        match &self.tick_strings {
+            Some(frames) if !frames.is_empty() => frames.clone(),
+            _ => TICKS.to_vec(),
+        }
+    }
+
     /// Start the spinner with a message
     pub fn start(&mut self, message: Option<&str>) -> Result<()> {
         self.stop(None)?;
@@ -255,7 +304,17 @@ impl<P: ConsoleWriter + 'static> SpinnerManager<P> {
 
         self.message = Some(word.clone());
 
-        let spinner = ActiveSpinner::start(self.printer.clone(), self.accumulated_elapsed, word);
+        if self.quiet {
+            self.quiet_started_at = Some(Instant::now());
+            return Ok(());
+        }
+
+        let spinner = ActiveSpinner::start(
+            self.printer.clone(),
+            self.accumulated_elapsed,
+            word,
+            self.ticks(),
+        );
         self.spinner = Some(spinner);
 
         Ok(())
@@ -283,8 +342,13 @@ impl<P: ConsoleWriter + 'static> SpinnerManager<P> {
             if let Some(msg) = message {
                 self.println(&msg);
             }
-        } else if let Some(message) = message {
-            self.println(&message);
+        } else {
+            if let Some(started_at) = self.quiet_started_at.take() {
+                self.accumulated_elapsed += started_at.elapsed();
+            }
+            if let Some(message) = message {
+                self.println(&message);
+            }
         }
 
         self.message = None;
@@ -302,6 +366,21 @@ impl<P: ConsoleWriter + 'static> SpinnerManager<P> {
         Ok(())
     }
 
+    /// Time the spinner has been running, accumulated across start/stop
+    /// cycles since the last [`reset`](Self::reset).
+    pub fn elapsed(&self) -> Duration {
+        match (&self.spinner, self.quiet_started_at) {
+            (Some(spinner), _) => spinner.elapsed(),
+            (None, Some(started_at)) => self.accumulated_elapsed + started_at.elapsed(),
+            (None, None) => self.accumulated_elapsed,
+        }
+    }
+
+    /// Whether the spinner is currently drawn.
+    pub fn is_running(&self) -> bool {
+        self.spinner.is_some()
+    }
+
     /// Resets the elapsed time to zero.
     /// Call this when starting a completely new task/conversation.
     pub fn reset(&mut self) {
@@ -376,7 +455,7 @@ mod tests {
     use forge_domain::ConsoleWriter;
     use pretty_assertions::assert_eq;
 
-    use super::{SpinnerManager, format_elapsed_time};
+    use super::{ASCII_TICKS, SpinnerManager, TICKS, format_elapsed_time};
 
     /// A simple printer that writes directly to stdout/stderr.
     /// Used for testing when synchronized output is not needed.
@@ -402,7 +481,7 @@ mod tests {
     }
 
     fn fixture_spinner() -> SpinnerManager<DirectPrinter> {
-        SpinnerManager::new(Arc::new(DirectPrinter))
+        SpinnerManager::with_quiet(Arc::new(DirectPrinter), false)
     }
 
     #[test]
@@ -489,6 +568,80 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[tokio::test]
+    async fn test_quiet_spinner_handles_every_method() {
+        let mut fixture = SpinnerManager::quiet(Arc::new(DirectPrinter));
+
+        fixture.start(None).unwrap();
+        fixture.pause();
+        fixture.resume();
+        fixture.set_message("Indexing").unwrap();
+        fixture.write_ln("stdout line").unwrap();
+        fixture.ewrite_ln("stderr line").unwrap();
+        fixture.stop(Some("done".to_string())).unwrap();
+        fixture.start(Some("Again")).unwrap();
+        fixture.reset();
+
+        let actual = fixture.spinner.is_none();
+        let expected = true;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_quiet_spinner_tracks_elapsed_time() {
+        let mut fixture = SpinnerManager::quiet(Arc::new(DirectPrinter));
+        fixture.accumulated_elapsed = Duration::from_secs(5);
+
+        fixture.start(Some("Thinking")).unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        let running = fixture.elapsed();
+        fixture.stop(None).unwrap();
+        let stopped = fixture.elapsed();
+
+        assert!(running >= Duration::from_millis(5020), "{running:?}");
+        assert!(stopped >= running, "{stopped:?} < {running:?}");
+        assert!(!fixture.is_running());
+    }
+
+    #[test]
+    fn test_elapsed_and_is_running_follow_spinner() {
+        let mut fixture = fixture_spinner();
+        fixture.accumulated_elapsed = Duration::from_secs(5);
+
+        let actual = (fixture.is_running(), fixture.elapsed());
+        let expected = (false, Duration::from_secs(5));
+        assert_eq!(actual, expected);
+
+        fixture.start(Some("Thinking")).unwrap();
+        let running = fixture.is_running();
+        let elapsed = fixture.elapsed();
+        fixture.stop(None).unwrap();
+
+        assert!(running);
+        assert!(elapsed >= Duration::from_secs(5));
+        assert!(!fixture.is_running());
+        assert!(fixture.elapsed() >= elapsed);
+    }
+
+    #[test]
+    fn test_custom_tick_strings_are_used() {
+        let fixture = vec!["🌑", "🌓", "🌕", "🌗"];
+        let actual = fixture_spinner().with_tick_strings(fixture.clone()).ticks();
+        let expected = fixture;
+        assert_eq!(actual, expected);
+
+        let actual = SpinnerManager::ascii_mode(Arc::new(DirectPrinter)).ticks();
+        let expected = ASCII_TICKS.to_vec();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_empty_tick_strings_fall_back_to_default() {
+        let actual = fixture_spinner().with_tick_strings(Vec::new()).ticks();
+        let expected = TICKS.to_vec();
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_format_elapsed_time_seconds_only() {
         let actual = format_elapsed_time(Duration::from_secs(5));
@@ -1,23 +1,45 @@
 use anyhow::Result;
 use indicatif::{ProgressBar, ProgressStyle};
 
+const TEMPLATE: &str = "{spinner:.green} {elapsed} {msg:.green} {bar:20.green} [{pos}/{len}]";
+
 /// Manages determinate progress bar for operations with known total
 #[derive(Default)]
 pub struct ProgressBarManager {
     bar: Option<ProgressBar>,
+    stages: Vec<(String, u32)>,
+    stage: usize,
+    eta_enabled: bool,
 }
 
 impl ProgressBarManager {
+    /// Creates a manager for an operation made of named stages, each
+    /// weighted by its share of the whole operation
+    pub fn with_stages(stages: &[(&str, u32)]) -> Self {
+        Self {
+            stages: stages
+                .iter()
+                .map(|(name, weight)| (name.to_string(), *weight))
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Shows the estimated time remaining, based on how fast the position
+    /// has advanced so far
+    pub fn with_eta(mut self, enabled: bool) -> Self {
+        self.eta_enabled = enabled;
+        self
+    }
+
     /// Starts a progress bar with a known total
     pub fn start(&mut self, total: u64, message: &str) -> Result<()> {
         let pb = ProgressBar::new(total);
         pb.set_style(
-            ProgressStyle::with_template(
-                "{spinner:.green} {elapsed} {msg:.green} {bar:20.green} [{pos}/{len}]",
-            )
-            .unwrap()
-            .progress_chars("█░░")
-            .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]),
+            ProgressStyle::with_template(&self.template())
+                .unwrap()
+                .progress_chars("█░░")
+                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]),
         );
         pb.enable_steady_tick(std::time::Duration::from_millis(60));
         pb.set_message(message.to_string());
@@ -25,6 +47,31 @@ impl ProgressBarManager {
         Ok(())
     }
 
+    /// Completes the current stage, moving the bar forward by its weight and
+    /// labelling it with `message`. The bar is started on the first call.
+    pub fn advance_stage(&mut self, message: &str) -> Result<()> {
+        if self.bar.is_none() {
+            let total = self
+                .stages
+                .iter()
+                .map(|(_, weight)| u64::from(*weight))
+                .sum();
+            let first = self
+                .stages
+                .first()
+                .map(|(name, _)| name.clone())
+                .unwrap_or_default();
+            self.start(total, &first)?;
+        }
+
+        let weight = self.stages.get(self.stage).map_or(0, |(_, weight)| *weight);
+        self.stage = (self.stage + 1).min(self.stages.len());
+        if let Some(bar) = &self.bar {
+            bar.inc(u64::from(weight));
+        }
+        self.set_message(message)
+    }
+
     /// Updates the progress bar position
     pub fn set_position(&self, current: u64) -> Result<()> {
         if let Some(bar) = &self.bar {
@@ -43,6 +90,7 @@ impl ProgressBarManager {
 
     /// Stops the progress bar and optionally prints a message
     pub async fn stop(&mut self, message: Option<String>) -> Result<()> {
+        self.stage = 0;
         if let Some(bar) = self.bar.take() {
             tokio::time::sleep(std::time::Duration::from_millis(100)).await;
             bar.finish_and_clear();
@@ -55,6 +103,14 @@ impl ProgressBarManager {
         Ok(())
     }
 
+    fn template(&self) -> String {
+        if self.eta_enabled {
+            format!("{TEMPLATE} eta {{eta}}")
+        } else {
+            TEMPLATE.to_string()
+        }
+    }
+
     pub fn is_active(&self) -> bool {
         self.bar.as_ref().is_some_and(|bar| !bar.is_finished())
     }
@@ -62,6 +118,12 @@ impl ProgressBarManager {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use indicatif::{ProgressDrawTarget, TermLike};
+    use pretty_assertions::assert_eq;
+
     use super::*;
 
     #[test]
@@ -69,4 +131,106 @@ mod tests {
         let manager = ProgressBarManager::default();
         assert!(manager.bar.is_none());
     }
+
+    #[tokio::test]
+    async fn test_advance_stage_moves_by_stage_weight() {
+        let mut fixture = ProgressBarManager::with_stages(&[
+            ("Discovering", 10),
+            ("Comparing", 30),
+            ("Syncing", 60),
+        ]);
+        let mut actual = Vec::new();
+        for message in ["Discovered", "Compared", "Synced", "Done"] {
+            fixture.advance_stage(message).unwrap();
+            let bar = fixture.bar.as_ref().unwrap();
+            actual.push((bar.position(), bar.length(), bar.message()));
+        }
+        fixture.stop(None).await.unwrap();
+
+        let expected = vec![
+            (10, Some(100), "Discovered".to_string()),
+            (40, Some(100), "Compared".to_string()),
+            (100, Some(100), "Synced".to_string()),
+            (100, Some(100), "Done".to_string()),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    /// Terminal that records everything the bar draws
+    #[derive(Debug, Default, Clone)]
+    struct CapturedTerm(Arc<Mutex<String>>);
+
+    impl TermLike for CapturedTerm {
+        fn width(&self) -> u16 {
+            120
+        }
+
+        fn move_cursor_up(&self, _: usize) -> std::io::Result<()> {
+            Ok(())
+        }
+
+        fn move_cursor_down(&self, _: usize) -> std::io::Result<()> {
+            Ok(())
+        }
+
+        fn move_cursor_right(&self, _: usize) -> std::io::Result<()> {
+            Ok(())
+        }
+
+        fn move_cursor_left(&self, _: usize) -> std::io::Result<()> {
+            Ok(())
+        }
+
+        fn write_line(&self, s: &str) -> std::io::Result<()> {
+            self.write_str(s)
+        }
+
+        fn write_str(&self, s: &str) -> std::io::Result<()> {
+            self.0
+                .lock()
+                .unwrap()
+                .push_str(&console::strip_ansi_codes(s));
+            Ok(())
+        }
+
+        fn clear_line(&self) -> std::io::Result<()> {
+            Ok(())
+        }
+
+        fn flush(&self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_eta_is_shown_when_enabled() {
+        let term = CapturedTerm::default();
+        let mut fixture = ProgressBarManager::default().with_eta(true);
+        fixture.start(100, "Syncing").unwrap();
+        let bar = fixture.bar.clone().unwrap();
+        bar.disable_steady_tick();
+        bar.set_draw_target(ProgressDrawTarget::term_like(Box::new(term.clone())));
+
+        for position in 1..=10 {
+            std::thread::sleep(Duration::from_millis(10));
+            fixture.set_position(position * 5).unwrap();
+        }
+        std::thread::sleep(Duration::from_millis(60));
+        bar.tick();
+        let output = term.0.lock().unwrap().clone();
+        fixture.stop(None).await.unwrap();
+
+        let actual = output
+            .rsplit_once(" eta ")
+            .and_then(|(_, eta)| eta.split_whitespace().next())
+            .unwrap_or_default();
+        assert!(!actual.is_empty(), "output: {output:?}");
+    }
+
+    #[test]
+    fn test_eta_is_hidden_by_default() {
+        let actual = ProgressBarManager::default().template();
+        let expected = TEMPLATE;
+        assert_eq!(actual, expected);
+    }
 }
@@ -3,6 +3,14 @@
 //! This crate provides common utilities for testing, including fixture loading
 //! helpers that reduce boilerplate in test code.
 
+use std::collections::HashMap;
+
+#[cfg(feature = "provider")]
+pub mod mock_provider;
+
+#[cfg(feature = "provider")]
+pub use mock_provider::MockProvider;
+
 /// Loads a fixture file from the calling crate's directory
 ///
 /// # Arguments
@@ -32,6 +40,46 @@ macro_rules! fixture {
     };
 }
 
+/// Loads every file in a fixture directory, keyed by file name
+///
+/// Sub-directories are skipped.
+///
+/// # Arguments
+/// * `path` - Path to the directory containing the fixtures
+///
+/// # Example
+/// ```ignore
+/// let files = fixture_dir("src/fixtures/conversations/");
+/// ```
+pub fn fixture_dir(path: &str) -> HashMap<String, String> {
+    std::fs::read_dir(path)
+        .unwrap_or_else(|e| panic!("Failed to read fixture directory at {path}: {e}"))
+        .map(|entry| entry.unwrap_or_else(|e| panic!("Failed to read entry in {path}: {e}")))
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| {
+            let file_path = entry.path();
+            let content = std::fs::read_to_string(&file_path).unwrap_or_else(|e| {
+                panic!("Failed to load fixture at {}: {e}", file_path.display())
+            });
+            (entry.file_name().to_string_lossy().into_owned(), content)
+        })
+        .collect()
+}
+
+/// Macro to load every file in a fixture directory relative to the calling
+/// crate's manifest directory
+///
+/// # Example
+/// ```ignore
+/// let files = fixture_dir!("src/fixtures/conversations/");
+/// ```
+#[macro_export]
+macro_rules! fixture_dir {
+    ($path:expr) => {
+        $crate::fixture_dir(&format!("{}/{}", env!("CARGO_MANIFEST_DIR"), $path))
+    };
+}
+
 /// Loads a fixture file and parses it as JSON
 ///
 /// # Example
@@ -58,3 +106,54 @@ macro_rules! json_fixture {
         $crate::json_fixture(&format!("{}/{}", env!("CARGO_MANIFEST_DIR"), $path))
     };
 }
+
+/// Loads every file in a fixture directory and parses each one as JSON, keyed
+/// by file name
+///
+/// # Example
+/// ```ignore
+/// let data: HashMap<String, MyType> = json_fixture_dir("src/fixtures/conversations/");
+/// ```
+#[cfg(feature = "json")]
+pub fn json_fixture_dir<T: serde::de::DeserializeOwned>(path: &str) -> HashMap<String, T> {
+    fixture_dir(path)
+        .into_iter()
+        .map(|(name, content)| {
+            let value = serde_json::from_str(&content)
+                .unwrap_or_else(|e| panic!("Failed to parse JSON fixture {name} in {path}: {e}"));
+            (name, value)
+        })
+        .collect()
+}
+
+/// Macro to load and parse every JSON fixture in a directory
+///
+/// # Example
+/// ```ignore
+/// let data: HashMap<String, MyType> = json_fixture_dir!("src/fixtures/conversations/");
+/// ```
+#[cfg(feature = "json")]
+#[macro_export]
+macro_rules! json_fixture_dir {
+    ($path:expr) => {
+        $crate::json_fixture_dir(&format!("{}/{}", env!("CARGO_MANIFEST_DIR"), $path))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_fixture_dir_loads_all_files() {
+        let actual = fixture_dir!("src/fixtures/dir/");
+        let actual = actual
+            .iter()
+            .map(|(name, content)| (name.as_str(), content.trim()))
+            .collect::<BTreeSet<_>>();
+        let expected = BTreeSet::from([("a.txt", "alpha"), ("b.txt", "beta"), ("c.txt", "gamma")]);
+        assert_eq!(actual, expected);
+    }
+}
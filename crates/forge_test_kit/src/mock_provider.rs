@@ -0,0 +1,76 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use forge_domain::{
+    ChatCompletionMessage, ChatRepository, Context, Model, ModelId, Provider, ResultStream,
+};
+use url::Url;
+
+/// In-process provider that replays scripted chat completions
+///
+/// Each call to [`ChatRepository::chat`] pops the next message from the
+/// configured queue and yields it as a single-item stream. Clones share the
+/// same queue so a provider can be handed to the system under test while the
+/// test keeps a handle to inspect how many responses remain.
+#[derive(Clone, Default)]
+pub struct MockProvider {
+    responses: Arc<Mutex<VecDeque<ChatCompletionMessage>>>,
+    models: Vec<Model>,
+}
+
+impl MockProvider {
+    /// Creates a provider with an empty response queue
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends scripted responses to the queue, in the order they should be
+    /// returned
+    ///
+    /// # Arguments
+    /// * `responses` - Completions yielded by successive `chat` calls
+    pub fn respond_with(self, responses: impl IntoIterator<Item = ChatCompletionMessage>) -> Self {
+        self.responses.lock().unwrap().extend(responses);
+        self
+    }
+
+    /// Sets the models returned by [`ChatRepository::models`]
+    ///
+    /// # Arguments
+    /// * `models` - Models reported by the provider
+    pub fn models(mut self, models: Vec<Model>) -> Self {
+        self.models = models;
+        self
+    }
+
+    /// Returns the number of scripted responses that have not been consumed
+    pub fn remaining(&self) -> usize {
+        self.responses.lock().unwrap().len()
+    }
+}
+
+#[async_trait::async_trait]
+impl ChatRepository for MockProvider {
+    async fn chat(
+        &self,
+        _model_id: &ModelId,
+        context: Context,
+        _provider: Provider<Url>,
+    ) -> ResultStream<ChatCompletionMessage, anyhow::Error> {
+        let next = self.responses.lock().unwrap().pop_front();
+        match next {
+            Some(message) => Ok(Box::pin(tokio_stream::iter(std::iter::once(Ok(message))))),
+            None => {
+                let total_messages = context.messages.len();
+                let last_message = context.messages.last();
+                panic!(
+                    "MockProvider has no scripted responses left. Total Messages: {total_messages}. Last Message: {last_message:#?}"
+                )
+            }
+        }
+    }
+
+    async fn models(&self, _provider: Provider<Url>) -> anyhow::Result<Vec<Model>> {
+        Ok(self.models.clone())
+    }
+}
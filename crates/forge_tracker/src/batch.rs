@@ -0,0 +1,46 @@
+use std::time::{Duration, Instant};
+
+use crate::Event;
+
+/// Buffers events so they can be sent to collectors in batches.
+#[derive(Debug)]
+pub struct EventBatcher {
+    max_size: usize,
+    events: Vec<Event>,
+    last_push: Instant,
+}
+
+impl EventBatcher {
+    /// Creates a new batcher.
+    ///
+    /// # Arguments
+    /// - `max_size`: Number of buffered events at which a batch is released.
+    pub fn new(max_size: usize) -> Self {
+        Self { max_size, events: Vec::new(), last_push: Instant::now() }
+    }
+
+    /// Buffers an event.
+    ///
+    /// Returns the buffered batch once it reaches the maximum size, leaving
+    /// the buffer empty.
+    pub fn push(&mut self, event: Event) -> Option<Vec<Event>> {
+        self.events.push(event);
+        self.last_push = Instant::now();
+        if self.events.len() >= self.max_size {
+            Some(self.drain())
+        } else {
+            None
+        }
+    }
+
+    /// Takes all buffered events.
+    pub fn drain(&mut self) -> Vec<Event> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Returns `true` when there are buffered events and nothing has been
+    /// pushed for at least `window`.
+    pub fn is_idle(&self, window: Duration) -> bool {
+        !self.events.is_empty() && self.last_push.elapsed() >= window
+    }
+}
@@ -6,5 +6,6 @@ pub mod posthog;
 /// Defines the interface for an event collector.
 #[async_trait::async_trait]
 pub trait Collect: Send + Sync {
-    async fn collect(&self, event: Event) -> super::Result<()>;
+    /// Sends a batch of events in a single request.
+    async fn collect(&self, events: Vec<Event>) -> super::Result<()>;
 }
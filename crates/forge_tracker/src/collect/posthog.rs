@@ -32,8 +32,13 @@ impl Tracker {
 }
 
 #[derive(Debug, Serialize)]
-struct Payload {
+struct BatchPayload {
     api_key: String,
+    batch: Vec<Payload>,
+}
+
+#[derive(Debug, Serialize)]
+struct Payload {
     event: String,
     distinct_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -44,7 +49,7 @@ struct Payload {
 }
 
 impl Payload {
-    fn new(api_key: String, mut input: Event) -> Self {
+    fn new(mut input: Event) -> Self {
         let mut properties = HashMap::new();
         let distinct_id = input.client_id.to_string();
         let event = input.event_name.to_string();
@@ -62,7 +67,6 @@ impl Payload {
         }
 
         Self {
-            api_key,
             event,
             distinct_id,
             properties: Some(properties),
@@ -73,15 +77,18 @@ impl Payload {
 }
 
 impl Tracker {
-    fn create_request(&self, event: Event) -> Result<reqwest::Request> {
-        let url = reqwest::Url::parse("https://us.i.posthog.com/capture/")?;
+    fn create_request(&self, events: Vec<Event>) -> Result<reqwest::Request> {
+        let url = reqwest::Url::parse("https://us.i.posthog.com/batch/")?;
         let mut request = reqwest::Request::new(reqwest::Method::POST, url);
         request.headers_mut().insert(
             HeaderName::from_static("content-type"),
             HeaderValue::from_static("application/json"),
         );
 
-        let payload = Payload::new(self.api_secret.to_string(), event);
+        let payload = BatchPayload {
+            api_key: self.api_secret.to_string(),
+            batch: events.into_iter().map(Payload::new).collect(),
+        };
 
         let _ = request
             .body_mut()
@@ -94,8 +101,8 @@ impl Tracker {
 #[async_trait::async_trait]
 impl Collect for Tracker {
     // TODO: move http request to a dispatch
-    async fn collect(&self, event: Event) -> Result<()> {
-        let request = self.create_request(event)?;
+    async fn collect(&self, events: Vec<Event>) -> Result<()> {
+        let request = self.create_request(events)?;
         self.client.execute(request).await?;
 
         Ok(())
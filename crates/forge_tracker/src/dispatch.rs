@@ -2,6 +2,7 @@ use std::collections::HashSet;
 use std::process::Output;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, LazyLock};
+use std::time::Duration;
 
 use bstr::ByteSlice;
 use chrono::{DateTime, Utc};
@@ -11,6 +12,7 @@ use tokio::process::Command;
 use tokio::sync::Mutex;
 
 use super::Result;
+use crate::batch::EventBatcher;
 use crate::can_track::can_track;
 use crate::collect::{Collect, posthog};
 use crate::event::Identity;
@@ -29,6 +31,9 @@ const VERSION: &str = match option_env!("APP_VERSION") {
 
 const TRACKING_ENV_VAR_NAME: &str = "FORGE_TRACKER";
 
+/// Setting this to `1` or `true` disables telemetry entirely.
+const NO_TELEMETRY_ENV_VAR_NAME: &str = "FORGE_NO_TELEMETRY";
+
 // Cached system information that doesn't change during application lifetime
 static CACHED_CORES: LazyLock<usize> = LazyLock::new(|| System::physical_core_count().unwrap_or(0));
 static CACHED_CLIENT_ID: LazyLock<String> = LazyLock::new(|| {
@@ -58,10 +63,22 @@ static CACHED_ARGS: LazyLock<Vec<String>> = LazyLock::new(|| std::env::args().sk
 /// while allowing normal tracking to continue for long-running sessions.
 const MAX_EVENTS_PER_MINUTE: usize = 1_000;
 
+/// Number of buffered events that triggers an immediate flush.
+const MAX_BATCH_SIZE: usize = 20;
+
+/// Buffered events are flushed once no new event arrived for this long.
+const BATCH_IDLE_WINDOW: Duration = Duration::from_secs(5);
+
+/// How often the background task checks whether the buffer went idle.
+const BATCH_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
 #[derive(Clone)]
 pub struct Tracker {
     collectors: Arc<Vec<Box<dyn Collect>>>,
+    batcher: Arc<Mutex<EventBatcher>>,
+    idle_flusher_started: Arc<AtomicBool>,
     can_track: bool,
+    opted_out: bool,
     start_time: DateTime<Utc>,
     email: Arc<Mutex<Option<Vec<String>>>>,
     model: Arc<Mutex<Option<String>>>,
@@ -73,11 +90,19 @@ pub struct Tracker {
 impl Default for Tracker {
     fn default() -> Self {
         let posthog_tracker = Box::new(posthog::Tracker::new(POSTHOG_API_SECRET));
+        Self::new(vec![posthog_tracker], can_track(), telemetry_opted_out())
+    }
+}
+
+impl Tracker {
+    fn new(collectors: Vec<Box<dyn Collect>>, can_track: bool, opted_out: bool) -> Self {
         let start_time = Utc::now();
-        let can_track = can_track();
         Self {
-            collectors: Arc::new(vec![posthog_tracker]),
-            can_track,
+            collectors: Arc::new(collectors),
+            batcher: Arc::new(Mutex::new(EventBatcher::new(MAX_BATCH_SIZE))),
+            idle_flusher_started: Arc::new(AtomicBool::new(false)),
+            can_track: can_track && !opted_out,
+            opted_out,
             start_time,
             email: Arc::new(Mutex::new(None)),
             model: Arc::new(Mutex::new(None)),
@@ -89,6 +114,12 @@ impl Default for Tracker {
 }
 
 impl Tracker {
+    /// Returns `true` when the user disabled telemetry via
+    /// `FORGE_NO_TELEMETRY`, in which case all events are discarded.
+    pub fn is_opted_out(&self) -> bool {
+        self.opted_out
+    }
+
     pub async fn set_model<S: Into<String>>(&'static self, model: S) {
         let mut guard = self.model.lock().await;
         *guard = Some(model.into());
@@ -138,13 +169,57 @@ impl Tracker {
             },
         };
 
-        // Dispatch the event to all collectors
+        self.start_idle_flusher();
+
+        // Buffer the event and dispatch once a full batch is available
+        let batch = self.batcher.lock().await.push(event);
+        match batch {
+            Some(events) => self.send(events).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Sends all buffered events to the collectors, regardless of batch size.
+    pub async fn flush(&self) -> Result<()> {
+        let events = self.batcher.lock().await.drain();
+        self.send(events).await
+    }
+
+    async fn send(&self, events: Vec<Event>) -> Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
         for collector in self.collectors.as_ref() {
-            collector.collect(event.clone()).await?;
+            collector.collect(events.clone()).await?;
         }
         Ok(())
     }
 
+    /// Spawns a background task, once per tracker, that flushes buffered
+    /// events after they have been idle for `BATCH_IDLE_WINDOW`.
+    fn start_idle_flusher(&self) {
+        if self.idle_flusher_started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let tracker = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(BATCH_POLL_INTERVAL);
+            loop {
+                interval.tick().await;
+                let events = {
+                    let mut batcher = tracker.batcher.lock().await;
+                    if !batcher.is_idle(BATCH_IDLE_WINDOW) {
+                        continue;
+                    }
+                    batcher.drain()
+                };
+                tracker.send(events).await.ok();
+            }
+        });
+    }
+
     async fn system_info(&self) -> Vec<String> {
         let mut guard = self.email.lock().await;
         if guard.is_none() {
@@ -164,6 +239,15 @@ impl Tracker {
     }
 }
 
+fn telemetry_opted_out() -> bool {
+    is_opt_out_value(std::env::var(NO_TELEMETRY_ENV_VAR_NAME).ok().as_deref())
+}
+
+/// Returns `true` if `value` of `FORGE_NO_TELEMETRY` asks to disable telemetry
+fn is_opt_out_value(value: Option<&str>) -> bool {
+    value.is_some_and(|value| value == "1" || value.eq_ignore_ascii_case("true"))
+}
+
 fn tracking_enabled() -> bool {
     std::env::var(TRACKING_ENV_VAR_NAME)
         .map(|value| !value.eq_ignore_ascii_case("false"))
@@ -280,6 +364,7 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::ToolCallPayload;
 
     static TRACKER: LazyLock<Tracker> = LazyLock::new(Tracker::default);
 
@@ -318,6 +403,88 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
+    struct MockCollector {
+        requests: Arc<Mutex<Vec<usize>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Collect for MockCollector {
+        async fn collect(&self, events: Vec<Event>) -> Result<()> {
+            self.requests.lock().await.push(events.len());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_sends_events_in_batches() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let collector = MockCollector { requests: requests.clone() };
+        let fixture = Tracker::new(vec![Box::new(collector)], true, false);
+
+        for i in 0..25 {
+            fixture
+                .dispatch(EventKind::Prompt(format!("ping {i}")))
+                .await
+                .unwrap();
+        }
+        fixture.flush().await.unwrap();
+
+        let actual = requests.lock().await.clone();
+        let expected = vec![20, 5];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_is_opt_out_value() {
+        let fixture = [
+            (None, false),
+            (Some("1"), true),
+            (Some("true"), true),
+            (Some("TRUE"), true),
+            (Some("0"), false),
+            (Some("false"), false),
+            (Some(""), false),
+        ];
+
+        let actual = fixture
+            .iter()
+            .map(|(value, _)| (*value, is_opt_out_value(*value)))
+            .collect::<Vec<_>>();
+
+        let expected = fixture.to_vec();
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_opted_out_tracker_sends_nothing() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let collector = MockCollector { requests: requests.clone() };
+        let fixture: &'static Tracker = Box::leak(Box::new(Tracker::new(
+            vec![Box::new(collector)],
+            true,
+            true,
+        )));
+
+        fixture
+            .dispatch(EventKind::Error("boom".to_string()))
+            .await
+            .unwrap();
+        fixture.login("user").await;
+        fixture
+            .dispatch(EventKind::ToolCall(ToolCallPayload::new(
+                "fs_read".to_string(),
+            )))
+            .await
+            .unwrap();
+        fixture.flush().await.unwrap();
+
+        assert!(fixture.is_opted_out());
+        let actual = requests.lock().await.len();
+        let expected = 0;
+        assert_eq!(actual, expected);
+    }
+
     #[tokio::test]
     async fn test_tracker() {
         if let Err(e) = TRACKER
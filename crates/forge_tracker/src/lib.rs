@@ -1,3 +1,4 @@
+mod batch;
 mod can_track;
 mod client_id;
 mod collect;
@@ -1,5 +1,10 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use anyhow::Context;
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::OTEL_EXPORTER_OTLP_ENDPOINT;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use tokio::runtime::{Handle, RuntimeFlavor};
 use tracing::debug;
 use tracing_appender::non_blocking::{self, WorkerGuard};
 use tracing_subscriber::prelude::*;
@@ -8,12 +13,16 @@ use tracing_subscriber::{self, Layer, filter};
 use crate::Tracker;
 use crate::can_track::can_track;
 
-pub fn init_tracing(log_path: PathBuf, tracker: Tracker) -> anyhow::Result<Guard> {
+pub fn init_tracing(
+    log_path: PathBuf,
+    log_file: Option<PathBuf>,
+    tracker: Tracker,
+) -> anyhow::Result<Guard> {
     debug!(path = %log_path.display(), "Initializing logging system in JSON format");
 
     // If tracking is enabled, use PostHog for logging; otherwise, use a rolling
     // file appender.
-    let (writer, guard, level) = prepare_writer(log_path, tracker);
+    let (writer, guard, level) = prepare_writer(log_path, tracker.clone());
 
     // Create a filter that only allows logs from forge_ modules
     let filter = filter::filter_fn(|metadata| metadata.target().starts_with("forge_"));
@@ -28,12 +37,83 @@ pub fn init_tracing(log_path: PathBuf, tracker: Tracker) -> anyhow::Result<Guard
         .with_writer(writer)
         .with_filter(filter);
 
+    // Export spans over OTLP only when a collector endpoint is configured
+    let tracer_provider = std::env::var_os(OTEL_EXPORTER_OTLP_ENDPOINT)
+        .map(|_| otlp_tracer_provider())
+        .transpose()?;
+    let otel_layer = tracer_provider.as_ref().map(|provider| {
+        tracing_opentelemetry::layer()
+            .with_tracer(provider.tracer("forge"))
+            .with_filter(filter::filter_fn(|metadata| {
+                metadata.target().starts_with("forge_")
+            }))
+    });
+
+    // Mirror events as JSON lines into a user-supplied file for log aggregation
+    let (file_layer, file_guard) = match log_file {
+        Some(path) => {
+            let (layer, guard) = json_file_layer(&path)?;
+            (Some(layer), Some(guard))
+        }
+        None => (None, None),
+    };
+
     tracing_subscriber::registry()
         .with(tracing_subscriber::EnvFilter::try_from_env("FORGE_LOG").unwrap_or(level))
         .with(fmt_layer)
+        .with(otel_layer)
+        .with(file_layer)
         .init();
 
-    Ok(Guard(guard))
+    Ok(Guard {
+        _worker: guard,
+        _file_worker: file_guard,
+        tracker,
+        tracer_provider,
+    })
+}
+
+/// Builds a layer that appends one JSON object per event to `path`, with
+/// top-level `timestamp`, `level` and `message` fields.
+fn json_file_layer<S>(path: &Path) -> anyhow::Result<(impl Layer<S>, WorkerGuard)>
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open log file {}", path.display()))?;
+    let (writer, guard) = tracing_appender::non_blocking(file);
+
+    let layer = tracing_subscriber::fmt::layer()
+        .json()
+        .flatten_event(true)
+        .with_current_span(false)
+        .with_span_list(false)
+        .with_ansi(false)
+        .with_writer(writer)
+        .with_filter(filter::filter_fn(|metadata| {
+            metadata.target().starts_with("forge_")
+        }));
+    Ok((layer, guard))
+}
+
+/// Builds a tracer provider that batches spans to the OTLP endpoint read from
+/// the standard `OTEL_EXPORTER_OTLP_*` environment variables.
+fn otlp_tracer_provider() -> anyhow::Result<SdkTracerProvider> {
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_tonic()
+        .build()?;
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .build())
 }
 
 fn prepare_writer(
@@ -44,7 +124,7 @@ fn prepare_writer(
     WorkerGuard,
     tracing_subscriber::EnvFilter,
 ) {
-    let ((non_blocking, guard), env) = if can_track() {
+    let ((non_blocking, guard), env) = if can_track() && !tracker.is_opted_out() {
         let append = PostHogWriter::new(tracker);
         (
             tracing_appender::non_blocking(append),
@@ -60,7 +140,39 @@ fn prepare_writer(
     (non_blocking, guard, env)
 }
 
-pub struct Guard(#[allow(dead_code)] WorkerGuard);
+/// Keeps the log writer alive and flushes buffered tracker events and
+/// exported spans when dropped at process exit.
+pub struct Guard {
+    _worker: WorkerGuard,
+    _file_worker: Option<WorkerGuard>,
+    tracker: Tracker,
+    tracer_provider: Option<SdkTracerProvider>,
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        if let Some(provider) = self.tracer_provider.take() {
+            let _ = provider.shutdown();
+        }
+
+        let flush = self.tracker.flush();
+        match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                tokio::task::block_in_place(|| handle.block_on(flush)).ok();
+            }
+            // Blocking is not allowed on a current-thread runtime
+            Ok(_) => {}
+            Err(_) => {
+                if let Ok(runtime) = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                {
+                    runtime.block_on(flush).ok();
+                }
+            }
+        }
+    }
+}
 
 struct PostHogWriter {
     tracker: Tracker,
@@ -92,3 +204,41 @@ impl std::io::Write for PostHogWriter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_json_file_layer_writes_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("forge.log");
+
+        let (layer, guard) = json_file_layer(&path).unwrap();
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(target: "forge_main", model = "gpt-4o", "Request sent");
+            tracing::warn!(target: "forge_main", "Retrying");
+            tracing::info!(target: "other_crate", "Ignored");
+        });
+        drop(guard);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines = contents
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            assert!(line["timestamp"].is_string());
+            assert!(line["level"].is_string());
+            assert!(line["message"].is_string());
+        }
+        assert_eq!(lines[0]["message"], "Request sent");
+        assert_eq!(lines[0]["level"], "INFO");
+        assert_eq!(lines[0]["model"], "gpt-4o");
+    }
+}
@@ -0,0 +1,69 @@
+#!/usr/bin/env bash
+
+# Runs `cargo audit` and fails only when an advisory is rated high or critical
+# Reads the JSON report and rates each advisory from its CVSS v3 base score:
+# 7.0 or more is high, 9.0 or more is critical
+# Advisories with a lower or no severity rating are printed but do not fail
+# Usage: ./audit-severity.sh [cargo audit args]
+# Example: ./audit-severity.sh --ignore RUSTSEC-2023-0071
+
+set -euo pipefail
+
+REPORT=$(mktemp)
+trap 'rm -f "$REPORT"' EXIT
+
+# cargo audit exits non-zero for any advisory, so the report decides the result
+STATUS=0
+cargo audit --json "$@" >"$REPORT" || STATUS=$?
+
+# A report that isn't JSON means cargo audit itself failed
+if ! jq -e '.vulnerabilities' "$REPORT" >/dev/null 2>&1; then
+    echo "❌ cargo audit failed"
+    exit $((STATUS == 0 ? 1 : STATUS))
+fi
+
+# CVSS v3 base score of a vector such as `CVSS:3.1/AV:N/AC:L/...`, or null
+# for a missing or other version vector
+SCORE='
+def roundup: (. * 100000 | round) as $n
+    | if $n % 10000 == 0 then $n / 100000 else (($n / 10000 | floor) + 1) / 10 end;
+def score:
+    if type == "string" and startswith("CVSS:3.") then
+        (split("/")[1:] | map(split(":") | {(.[0]): .[1]}) | add) as $m
+        | ($m.S == "C") as $changed
+        | ({"N": 0.85, "A": 0.62, "L": 0.55, "P": 0.2}[$m.AV]) as $av
+        | ({"L": 0.77, "H": 0.44}[$m.AC]) as $ac
+        | (if $changed then {"N": 0.85, "L": 0.68, "H": 0.5} else {"N": 0.85, "L": 0.62, "H": 0.27} end)[$m.PR] as $pr
+        | ({"N": 0.85, "R": 0.62}[$m.UI]) as $ui
+        | [$m.C, $m.I, $m.A] | map({"H": 0.56, "L": 0.22, "N": 0}[.])
+        | (1 - ((1 - .[0]) * (1 - .[1]) * (1 - .[2]))) as $iss
+        | (if $changed then 7.52 * ($iss - 0.029) - 3.25 * pow($iss - 0.02; 15) else 6.42 * $iss end) as $impact
+        | (8.22 * $av * $ac * $pr * $ui) as $exploitability
+        | if $impact <= 0 then 0
+          elif $changed then [1.08 * ($impact + $exploitability), 10] | min | roundup
+          else [$impact + $exploitability, 10] | min | roundup
+          end
+    else null end;
+def severity:
+    if . == null then "none"
+    elif . >= 9 then "critical"
+    elif . >= 7 then "high"
+    elif . >= 4 then "medium"
+    elif . > 0 then "low"
+    else "none" end;
+'
+
+jq -r "$SCORE"'
+    .vulnerabilities.list[]
+    | (.advisory.cvss | score) as $score
+    | "\(.advisory.id) \(.package.name) \(.package.version): \($score // "unrated") (\($score | severity)) \(.advisory.title)"
+' "$REPORT"
+
+if jq -e "$SCORE"'
+    any(.vulnerabilities.list[]; (.advisory.cvss | score) as $score | $score != null and $score >= 7)
+' "$REPORT" >/dev/null; then
+    echo "❌ Found advisories with high or critical severity"
+    exit 1
+fi
+
+echo "✅ No high or critical severity advisories"
@@ -0,0 +1,52 @@
+#!/usr/bin/env bash
+
+# Fails when a criterion benchmark got slower than its saved baseline
+# Reads the change estimates criterion writes after `-- --baseline <name>` and
+# flags benchmarks whose mean time is, with 95% confidence, more than the
+# threshold percentage slower
+# Usage: ./bench-regression.sh [--threshold PERCENT] [criterion dir]
+# Example: ./bench-regression.sh --threshold 10 target/criterion
+
+set -euo pipefail
+
+THRESHOLD=10
+CRITERION_DIR="target/criterion"
+
+while [[ $# -gt 0 ]]; do
+    case $1 in
+        --threshold)
+            THRESHOLD="$2"
+            shift 2
+            ;;
+        *)
+            CRITERION_DIR="$1"
+            shift
+            ;;
+    esac
+done
+
+FAILED=0
+FOUND=0
+while IFS= read -r estimates; do
+    FOUND=1
+    name=$(dirname "$(dirname "${estimates#"$CRITERION_DIR"/}")")
+    lower=$(jq '.mean.confidence_interval.lower_bound * 100' "$estimates")
+    mean=$(jq '.mean.point_estimate * 100' "$estimates")
+    if jq -e --argjson threshold "$THRESHOLD" \
+        '.mean.confidence_interval.lower_bound * 100 > $threshold' "$estimates" >/dev/null; then
+        printf "❌ %s: %+.2f%% (at least %+.2f%%)\n" "$name" "$mean" "$lower"
+        FAILED=1
+    else
+        printf "✅ %s: %+.2f%%\n" "$name" "$mean"
+    fi
+done < <(find "$CRITERION_DIR" -path '*/change/estimates.json' | sort)
+
+if [ "$FOUND" -eq 0 ]; then
+    echo "No baseline comparisons found in $CRITERION_DIR"
+    exit 0
+fi
+
+if [ "$FAILED" -ne 0 ]; then
+    echo "Benchmarks regressed by more than ${THRESHOLD}%"
+    exit 1
+fi